@@ -0,0 +1,51 @@
+// Plugin de exemplo: previsão ingênua (repete o último valor observado).
+// Compile com `cargo build --example naive_plugin` e copie a biblioteca gerada
+// em target/debug/examples para o diretório de plugins.
+use std::ffi::{c_char, c_void};
+
+struct NaiveModel {
+    last: f64,
+}
+
+#[no_mangle]
+pub extern "C" fn rustfin_plugin_name() -> *const c_char {
+    c"Naive (plugin)".as_ptr()
+}
+
+/// # Safety
+/// `values` deve apontar para `len` valores f64 válidos.
+#[no_mangle]
+pub unsafe extern "C" fn rustfin_fit(values: *const f64, len: usize) -> *mut c_void {
+    if values.is_null() || len == 0 {
+        return std::ptr::null_mut();
+    }
+
+    let values = std::slice::from_raw_parts(values, len);
+    let model = NaiveModel { last: values[len - 1] };
+
+    Box::into_raw(Box::new(model)) as *mut c_void
+}
+
+/// # Safety
+/// `model` deve ter sido criado por `rustfin_fit` e `out` deve ter espaço para `steps` valores.
+#[no_mangle]
+pub unsafe extern "C" fn rustfin_forecast(model: *mut c_void, steps: usize, out: *mut f64) -> i32 {
+    if model.is_null() || out.is_null() {
+        return 1;
+    }
+
+    let model = &*(model as *const NaiveModel);
+    let out = std::slice::from_raw_parts_mut(out, steps);
+    out.fill(model.last);
+
+    0
+}
+
+/// # Safety
+/// `model` deve ter sido criado por `rustfin_fit` e não pode ser usado depois desta chamada.
+#[no_mangle]
+pub unsafe extern "C" fn rustfin_free(model: *mut c_void) {
+    if !model.is_null() {
+        drop(Box::from_raw(model as *mut NaiveModel));
+    }
+}
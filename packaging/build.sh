@@ -0,0 +1,44 @@
+#!/bin/sh
+# Gera os pacotes de distribuição do RustFin para o sistema atual.
+# Requer cargo-bundle (cargo install cargo-bundle); no Linux o AppImage requer appimagetool.
+set -e
+
+cd "$(dirname "$0")/.."
+BUNDLE_DIR=target/release/bundle
+
+case "$(uname -s)" in
+    Darwin)
+        cargo bundle --release --format osx
+        hdiutil create -volname RustFin -srcfolder "$BUNDLE_DIR/osx/RustFin.app" \
+            -ov -format UDZO "$BUNDLE_DIR/RustFin.dmg"
+        ;;
+    Linux)
+        cargo bundle --release --format deb
+
+        # Página de manual e completações, geradas pelo próprio binário
+        target/release/rustfin man | gzip -9 > "$BUNDLE_DIR/rustfin.1.gz"
+        for shell in bash zsh fish; do
+            target/release/rustfin completions "$shell" > "$BUNDLE_DIR/rustfin.$shell"
+        done
+
+        if command -v appimagetool >/dev/null 2>&1; then
+            APPDIR="$BUNDLE_DIR/RustFin.AppDir"
+            rm -rf "$APPDIR"
+            mkdir -p "$APPDIR/usr/bin"
+            cp target/release/rustfin "$APPDIR/usr/bin/"
+            cp assets/icon-256.png "$APPDIR/rustfin.png"
+            cp packaging/rustfin.desktop "$APPDIR/"
+            ln -s usr/bin/rustfin "$APPDIR/AppRun"
+            appimagetool "$APPDIR" "$BUNDLE_DIR/RustFin-x86_64.AppImage"
+        else
+            echo "appimagetool não encontrado, AppImage não gerado"
+        fi
+        ;;
+    MINGW*|MSYS*|CYGWIN*)
+        cargo bundle --release --format msi
+        ;;
+    *)
+        echo "Sistema não suportado: $(uname -s)"
+        exit 1
+        ;;
+esac
@@ -0,0 +1,126 @@
+use crate::data::date_sort_key;
+use crate::stats;
+
+// Quantil da normal para as faixas de 95% em torno da trajetória média
+const Z_95: f64 = 1.96;
+
+// Média e faixa de cada defasagem
+type Moments = (Vec<Option<f64>>, Vec<Option<(f64, f64)>>);
+
+// Série alinhada em tempo de evento: a posição t (de -janela a +janela) é contada em observações a
+// partir da data do evento
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventStudy {
+    // Defasagens, de -janela a +janela
+    pub offsets: Vec<i64>,
+    // Trajetória de cada evento na ordem recebida, com None fora da série
+    pub paths: Vec<(String, Vec<Option<f64>>)>,
+    // Média dos eventos com observação em cada defasagem
+    pub mean: Vec<Option<f64>>,
+    // Intervalo de 95% da média (erro padrão entre eventos); None com menos de dois eventos
+    pub bands: Vec<Option<(f64, f64)>>,
+}
+
+// Posição do evento: a primeira data igual ou posterior a ele
+fn event_position(event: &str, dates: &[String]) -> Option<usize> {
+    let key = date_sort_key(event);
+    dates.iter().position(|date| date_sort_key(date) >= key)
+}
+
+// Estudo de evento: alinha a série (datas em ordem cronológica) em torno de cada data de evento,
+// de t-`window` a t+`window`, e calcula a trajetória média com faixas. Com `relative`, cada
+// trajetória é a diferença para o valor em t=0, o que isola o movimento depois do evento.
+pub fn event_study(
+    dates: &[String],
+    values: &[f64],
+    events: &[String],
+    window: usize,
+    relative: bool,
+) -> Result<EventStudy, String> {
+    if dates.len() != values.len() {
+        return Err("Datas e valores com tamanhos diferentes".to_string());
+    }
+
+    let window = window as i64;
+    let offsets: Vec<i64> = (-window..=window).collect();
+    let mut paths = vec![];
+    for event in events {
+        let Some(origin) = event_position(event, dates) else {
+            continue;
+        };
+        let base = if relative { values[origin] } else { 0.0 };
+        let path: Vec<Option<f64>> = offsets
+            .iter()
+            .map(|offset| {
+                let i = usize::try_from(origin as i64 + offset).ok()?;
+                values.get(i).map(|value| value - base)
+            })
+            .collect();
+        paths.push((event.clone(), path));
+    }
+    if paths.is_empty() {
+        return Err("Nenhum evento dentro do período da série".to_string());
+    }
+
+    let (mean, bands): Moments = (0..offsets.len())
+        .map(|k| {
+            let observed: Vec<f64> = paths.iter().filter_map(|(_, path)| path[k]).collect();
+            if observed.is_empty() {
+                return (None, None);
+            }
+            let mean = stats::mean(&observed);
+            let band = (observed.len() >= 2).then(|| {
+                let error = Z_95 * stats::std_dev(&observed) / (observed.len() as f64).sqrt();
+                (mean - error, mean + error)
+            });
+            (Some(mean), band)
+        })
+        .unzip();
+
+    Ok(EventStudy {
+        offsets,
+        paths,
+        mean,
+        bands,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monthly(values: &[f64]) -> (Vec<String>, Vec<f64>) {
+        let dates = (0..values.len())
+            .map(|i| format!("01/{:02}/{}", i % 12 + 1, 2020 + i / 12))
+            .collect();
+        (dates, values.to_vec())
+    }
+
+    #[test]
+    fn aligns_events_and_averages_their_paths() {
+        let (dates, values) = monthly(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let events = vec!["01/03/2020".to_string(), "15/05/2020".to_string()];
+
+        let study = event_study(&dates, &values, &events, 2, false).unwrap();
+        assert_eq!(study.offsets, vec![-2, -1, 0, 1, 2]);
+        // O segundo evento cai na primeira data depois dele, 01/06/2020
+        assert_eq!(study.paths[1].1, vec![Some(4.0), Some(5.0), Some(6.0), Some(7.0), Some(8.0)]);
+        assert_eq!(study.mean[2], Some(4.5));
+        let (lower, upper) = study.bands[2].unwrap();
+        assert!(lower < 4.5 && upper > 4.5);
+    }
+
+    #[test]
+    fn relative_paths_start_at_zero_and_respect_the_series_edges() {
+        let (dates, values) = monthly(&[1.0, 3.0, 6.0]);
+        let events = vec!["01/01/2020".to_string(), "01/01/2030".to_string()];
+
+        let study = event_study(&dates, &values, &events, 1, true).unwrap();
+        assert_eq!(study.paths.len(), 1);
+        assert_eq!(study.paths[0].1, vec![None, Some(0.0), Some(2.0)]);
+        assert_eq!(study.mean, vec![None, Some(0.0), Some(2.0)]);
+        assert!(study.bands.iter().all(Option::is_none));
+
+        assert!(event_study(&dates, &values, &["01/01/2031".to_string()], 1, false).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::data::date_sort_key;
+
+// Marcador de evento (eleição, troca de moeda, pandemia...) exibido nos gráficos do país
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub date: String,
+    pub label: String,
+}
+
+pub fn annotations_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("annotations.json"))
+}
+
+// Anotações de todos os países, indexadas pelo país
+pub fn load_all() -> BTreeMap<String, Vec<Annotation>> {
+    annotations_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn load(country: &str) -> Vec<Annotation> {
+    load_all().remove(country).unwrap_or_default()
+}
+
+pub fn save(country: &str, annotations: &[Annotation]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut all = load_all();
+    let mut annotations = annotations.to_vec();
+    annotations.sort_by_key(|annotation| date_sort_key(&annotation.date));
+    all.insert(country.to_string(), annotations);
+
+    save_all(&all)
+}
+
+// Substitui as anotações de todos os países (ex.: ao abrir um projeto)
+pub fn save_all(all: &BTreeMap<String, Vec<Annotation>>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = annotations_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(all)?)?;
+
+    Ok(())
+}
+
+// Posição da anotação em uma série de datas cronológicas: a primeira data igual ou posterior
+pub fn position(annotation: &Annotation, dates: &[String]) -> Option<usize> {
+    let key = date_sort_key(&annotation.date);
+    dates.iter().position(|date| date_sort_key(date) >= key)
+}
@@ -0,0 +1,200 @@
+use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+use crate::config::Config;
+use crate::FORECAST_STEPS;
+
+// Pacotes Python exigidos pelos modelos, na ordem em que são importados
+const REQUIRED_MODULES: [&str; 2] = ["numpy", "statsmodels.tsa.arima.model"];
+const PACKAGES: [&str; 2] = ["numpy", "statsmodels"];
+
+pub const INSTALL_HINT: &str = "pip install numpy statsmodels";
+
+// Resultado da última verificação; refeito ao trocar de ambiente virtual
+static ENVIRONMENT: Mutex<Option<Result<(), String>>> = Mutex::new(None);
+
+fn check() -> Result<(), String> {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        for module in REQUIRED_MODULES {
+            py.import(module)
+                .map_err(|e| format!("Não foi possível importar {} no Python: {}", module, e))?;
+        }
+        Ok(())
+    })
+}
+
+// Verifica se o Python encontrado pelo pyo3 tem numpy e statsmodels. Sem eles, os modelos
+// em Python ficam indisponíveis e os nativos continuam funcionando.
+pub fn environment() -> Result<(), String> {
+    ENVIRONMENT.lock().unwrap().get_or_insert_with(check).clone()
+}
+
+pub fn available() -> bool {
+    environment().is_ok()
+}
+
+// Versão do Python embutido; o venv precisa ser da mesma versão
+pub fn python_version() -> (u8, u8) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let version = py.version_info();
+        (version.major, version.minor)
+    })
+}
+
+fn add_site_packages(dir: &Path) -> Result<(), String> {
+    let (major, minor) = python_version();
+    let site_packages = [
+        dir.join("lib").join(format!("python{}.{}", major, minor)).join("site-packages"),
+        // Layout do venv no Windows
+        dir.join("Lib").join("site-packages"),
+    ]
+    .into_iter()
+    .find(|path| path.is_dir())
+    .ok_or_else(|| format!("{} não é um ambiente virtual do Python {}.{}", dir.display(), major, minor))?;
+
+    Python::with_gil(|py| {
+        py.import("site")?
+            .call_method1("addsitedir", (site_packages.to_string_lossy().into_owned(),))?;
+        Ok::<_, PyErr>(())
+    })
+    .map_err(|e| e.to_string())
+}
+
+// Usa os pacotes de um ambiente virtual (venv) e refaz a verificação
+pub fn use_venv(dir: &Path) -> Result<(), String> {
+    let result = add_site_packages(dir).and_then(|_| check());
+    *ENVIRONMENT.lock().unwrap() = Some(result.clone());
+    result
+}
+
+// Aplica o venv da configuração, se houver; o resultado fica disponível em `environment()`
+pub fn configure(config: &Config) {
+    if let Some(dir) = config.python_env.as_deref().filter(|dir| !dir.is_empty()) {
+        let _ = use_venv(Path::new(dir));
+    }
+}
+
+// Ambiente criado pela própria aplicação, no diretório de configuração
+pub fn dedicated_venv() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("python"))
+}
+
+fn run(command: &mut Command) -> Result<(), String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command
+        .output()
+        .map_err(|e| format!("Erro ao executar {}: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!("{} falhou: {}", program, String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(())
+}
+
+// Cria um venv com o interpretador da mesma versão do embutido e instala numpy e statsmodels
+// nele. Bloqueante (o pip pode levar minutos); não altera o ambiente em uso.
+pub fn create_venv(dir: &Path) -> Result<(), String> {
+    let (major, minor) = python_version();
+    let (interpreter, pip) = if cfg!(windows) {
+        ("python".to_string(), dir.join("Scripts").join("pip.exe"))
+    } else {
+        (format!("python{}.{}", major, minor), dir.join("bin").join("pip"))
+    };
+
+    run(Command::new(interpreter).args(["-m", "venv"]).arg(dir))?;
+    run(Command::new(pip).arg("install").args(PACKAGES))
+}
+
+// Modelo ARIMA do statsmodels sobre a série, ainda sem ajuste
+fn arima_model<'py>(py: Python<'py>, values: &[f64], (p, d, q): (u32, u32, u32)) -> PyResult<&'py PyAny> {
+    let statsmodels = py.import("statsmodels.tsa.arima.model")?;
+
+    // Convertendo valores para array numpy
+    let np_array = numpy_array(py, values)?;
+
+    // Criando o dicionário de parâmetros
+    let kwargs = [("order", (p, d, q))].into_py_dict(py);
+    statsmodels.call_method("ARIMA", (np_array,), Some(kwargs))
+}
+
+pub fn run_arima_model(values: &[f64], p: u32, d: u32, q_arg: u32) -> PyResult<Vec<f64>> {
+    Python::with_gil(|py| {
+        let arima_model = arima_model(py, values, (p, d, q_arg))?.call_method0("fit")?;
+
+        // Fazendo previsões (FORECAST_STEPS passos futuros)
+        let forecast = arima_model.call_method1("forecast", (FORECAST_STEPS,))?;
+        let forecast_values: Vec<f64> = forecast.extract()?;
+        Ok(forecast_values)
+    })
+}
+
+fn numpy_array<'py>(py: Python<'py>, values: &[f64]) -> PyResult<&'py PyAny> {
+    py.import("numpy")?.call_method1("array", (values.to_vec(),))
+}
+
+// Modelo ajustado mantido no Python. Observações novas entram com `append`, que atualiza o
+// estado do filtro de Kalman sem reestimar os parâmetros.
+pub struct ArimaState {
+    results: PyObject,
+}
+
+impl ArimaState {
+    pub fn fit(values: &[f64], order: (u32, u32, u32)) -> PyResult<Self> {
+        Python::with_gil(|py| {
+            let results = arima_model(py, values, order)?.call_method0("fit")?;
+            Ok(Self {
+                results: results.into_py(py),
+            })
+        })
+    }
+
+    // Estado com parâmetros já estimados: o filtro percorre a série sem reestimá-los
+    pub fn with_params(values: &[f64], order: (u32, u32, u32), params: &[f64]) -> PyResult<Self> {
+        Python::with_gil(|py| {
+            let results = arima_model(py, values, order)?.call_method1("filter", (numpy_array(py, params)?,))?;
+            Ok(Self {
+                results: results.into_py(py),
+            })
+        })
+    }
+
+    // Parâmetros estimados com seus nomes, na ordem do statsmodels (ex.: ar.L1, ma.L1, sigma2)
+    pub fn params(&self) -> PyResult<Vec<(String, f64)>> {
+        Python::with_gil(|py| {
+            let results = self.results.as_ref(py);
+            let names: Vec<String> = results.getattr("param_names")?.extract()?;
+            let params: Vec<f64> = results.getattr("params")?.extract()?;
+
+            Ok(names.into_iter().zip(params).collect())
+        })
+    }
+
+    pub fn append(&mut self, values: &[f64]) -> PyResult<()> {
+        Python::with_gil(|py| {
+            let results = self.results.as_ref(py).call_method1("append", (numpy_array(py, values)?,))?;
+            self.results = results.into_py(py);
+            Ok(())
+        })
+    }
+
+    pub fn forecast(&self, steps: usize) -> PyResult<Vec<f64>> {
+        Python::with_gil(|py| self.results.as_ref(py).call_method1("forecast", (steps,))?.extract())
+    }
+}
+
+pub fn fit_arima_params(values: &[f64], order: (u32, u32, u32)) -> PyResult<Vec<(String, f64)>> {
+    ArimaState::fit(values, order)?.params()
+}
+
+pub fn forecast_arima_params(
+    values: &[f64],
+    order: (u32, u32, u32),
+    params: &[f64],
+    steps: usize,
+) -> PyResult<Vec<f64>> {
+    ArimaState::with_params(values, order, params)?.forecast(steps)
+}
@@ -0,0 +1,366 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "python-models")]
+use crate::arima;
+use crate::cache::now_secs;
+use crate::croston::{Croston, Variant};
+#[cfg(feature = "ml")]
+use crate::ml;
+use crate::models::Candidate;
+use crate::plugins::Plugin;
+use crate::rng;
+use crate::theta::Theta;
+
+// Versão do formato dos arquivos; arquivos de versões mais novas são recusados
+pub const FORMAT_VERSION: u32 = 1;
+
+// Camada da rede: valores em ordem de linha, com as dimensões do tensor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Layer {
+    pub name: String,
+    pub shape: Vec<usize>,
+    pub values: Vec<f32>,
+}
+
+// Rede treinada, com a normalização usada no treino
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MlpParameters {
+    pub lags: usize,
+    pub mean: f64,
+    pub std: f64,
+    // Variância dos resíduos do treino, na escala da série
+    pub sigma2: f64,
+    pub layers: Vec<Layer>,
+}
+
+// Parâmetros estimados de cada modelo. Os dados não dependem das features; prever com eles
+// exige a feature do modelo (python-models para o ARIMA, ml para a MLP).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Parameters {
+    Naive,
+    Arima {
+        order: (u32, u32, u32),
+        // Coeficientes na ordem do statsmodels, sem a variância dos resíduos
+        coefficients: Vec<(String, f64)>,
+        sigma2: f64,
+    },
+    Mlp(MlpParameters),
+    // O nível é refeito a partir da série a prever
+    Theta {
+        alpha: f64,
+        slope: f64,
+        sigma2: f64,
+    },
+    // O tamanho e o intervalo suavizados são refeitos a partir da série a prever
+    Croston {
+        alpha: f64,
+        variant: Variant,
+        sigma2: f64,
+    },
+}
+
+// Dados usados no ajuste
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Training {
+    pub series: String,
+    pub observations: usize,
+    pub first_date: Option<String>,
+    pub last_date: Option<String>,
+    // Momento do ajuste (segundos Unix)
+    pub fitted_at: u64,
+    pub seed: Option<u64>,
+}
+
+// Modelo ajustado salvo em disco: pode ser recarregado e usado em dados novos sem reajuste
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelArtifact {
+    pub format: u32,
+    pub model: String,
+    pub parameters: Parameters,
+    pub training: Training,
+}
+
+// Diretório dos modelos salvos, podendo ser sobrescrito por MODELS_DIR
+pub fn models_dir() -> Option<PathBuf> {
+    std::env::var("MODELS_DIR")
+        .map(PathBuf::from)
+        .ok()
+        .or_else(|| dirs::config_dir().map(|dir| dir.join("rustfin").join("models")))
+}
+
+pub fn list_models(dir: &Path) -> Vec<PathBuf> {
+    let mut models: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                .collect()
+        })
+        .unwrap_or_default();
+    models.sort();
+    models
+}
+
+#[cfg(not(all(feature = "python-models", feature = "ml")))]
+fn unsupported(model: &str, feature: &str) -> String {
+    format!("Prever com o modelo {} exige a feature {}", model, feature)
+}
+
+// Vetor de parâmetros na ordem do statsmodels, em que a variância dos resíduos é o último
+#[cfg(feature = "python-models")]
+pub fn arima_params(coefficients: &[(String, f64)], sigma2: f64) -> Vec<f64> {
+    coefficients
+        .iter()
+        .map(|(_, value)| *value)
+        .chain(std::iter::once(sigma2))
+        .collect()
+}
+
+impl ModelArtifact {
+    // Ajusta o candidato na série. Plugins não podem ser salvos: seus parâmetros ficam na biblioteca.
+    pub fn fit(
+        candidate: Candidate,
+        plugins: &[Plugin],
+        series: &str,
+        dates: &[String],
+        values: &[f64],
+    ) -> Result<Self, String> {
+        let parameters = match candidate {
+            Candidate::Naive => {
+                values.last().ok_or("Série vazia")?;
+                Parameters::Naive
+            }
+            #[cfg(feature = "python-models")]
+            Candidate::Arima(p, d, q) => {
+                arima::environment()?;
+                let mut coefficients = arima::fit_arima_params(values, (p, d, q)).map_err(|e| e.to_string())?;
+                let position = coefficients
+                    .iter()
+                    .position(|(name, _)| name == "sigma2")
+                    .ok_or("O ajuste do ARIMA não estimou a variância dos resíduos")?;
+                let (_, sigma2) = coefficients.remove(position);
+
+                Parameters::Arima {
+                    order: (p, d, q),
+                    coefficients,
+                    sigma2,
+                }
+            }
+            #[cfg(feature = "ml")]
+            Candidate::Mlp => Parameters::Mlp(ml::fit_mlp(values).map_err(|e| e.to_string())?),
+            Candidate::Theta => {
+                let theta = Theta::fit(values).ok_or("Série inválida para o método Theta")?;
+                Parameters::Theta {
+                    alpha: theta.alpha,
+                    slope: theta.slope,
+                    sigma2: theta.sigma2,
+                }
+            }
+            Candidate::Croston => {
+                let croston = Croston::fit(values, Variant::Sba).ok_or("Série inválida para o método de Croston")?;
+                Parameters::Croston {
+                    alpha: croston.alpha,
+                    variant: croston.variant,
+                    sigma2: croston.sigma2,
+                }
+            }
+            Candidate::Plugin(i) => {
+                return Err(format!("O plugin {} não pode ser salvo; apenas os modelos nativos", plugins[i].name()));
+            }
+        };
+
+        Ok(Self {
+            format: FORMAT_VERSION,
+            model: candidate.label(plugins),
+            parameters,
+            training: Training {
+                series: series.to_string(),
+                observations: values.len(),
+                first_date: dates.first().cloned(),
+                last_date: dates.last().cloned(),
+                fitted_at: now_secs(),
+                seed: rng::global_seed(),
+            },
+        })
+    }
+
+    // Variância dos resíduos do ajuste (não definida para o ingênuo)
+    pub fn sigma2(&self) -> Option<f64> {
+        match &self.parameters {
+            Parameters::Naive => None,
+            Parameters::Arima { sigma2, .. } => Some(*sigma2),
+            Parameters::Mlp(parameters) => Some(parameters.sigma2),
+            Parameters::Theta { sigma2, .. } => Some(*sigma2),
+            Parameters::Croston { sigma2, .. } => Some(*sigma2),
+        }
+    }
+
+    // Resumo para a interface, ex.: "ARIMA(1,1,1) ajustado em inflation_brazil (120 observações, até 01/12/2023)"
+    pub fn describe(&self) -> String {
+        let until = self
+            .training
+            .last_date
+            .as_ref()
+            .map(|date| format!(", até {}", date))
+            .unwrap_or_default();
+        format!(
+            "{} ajustado em {} ({} observações{})",
+            self.model, self.training.series, self.training.observations, until
+        )
+    }
+
+    // Prevê `steps` passos a partir de `values` com os parâmetros salvos, sem reajustar
+    pub fn forecast(&self, values: &[f64], steps: usize) -> Result<Vec<f64>, String> {
+        let mut forecast = match &self.parameters {
+            Parameters::Naive => {
+                let last = values.last().ok_or("Série vazia")?;
+                vec![*last; steps]
+            }
+            #[cfg(feature = "python-models")]
+            Parameters::Arima {
+                order,
+                coefficients,
+                sigma2,
+            } => {
+                arima::environment()?;
+                let params = arima_params(coefficients, *sigma2);
+                arima::forecast_arima_params(values, *order, &params, steps).map_err(|e| e.to_string())?
+            }
+            #[cfg(not(feature = "python-models"))]
+            Parameters::Arima { .. } => return Err(unsupported(&self.model, "python-models")),
+            #[cfg(feature = "ml")]
+            Parameters::Mlp(parameters) => ml::forecast_mlp(parameters, values, steps).map_err(|e| e.to_string())?,
+            #[cfg(not(feature = "ml"))]
+            Parameters::Mlp(_) => return Err(unsupported(&self.model, "ml")),
+            Parameters::Theta { alpha, slope, .. } => Theta::with_params(values, *alpha, *slope)
+                .ok_or("Série vazia")?
+                .forecast(steps),
+            Parameters::Croston { alpha, variant, .. } => {
+                values.last().ok_or("Série vazia")?;
+                Croston::with_params(values, *alpha, *variant).forecast(steps)
+            }
+        };
+
+        if forecast.len() < steps || forecast.iter().any(|v| !v.is_finite()) {
+            return Err(format!("{} não produziu uma previsão válida", self.model));
+        }
+        forecast.truncate(steps);
+        Ok(forecast)
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let artifact: Self = serde_json::from_str(json)?;
+        if artifact.format > FORMAT_VERSION {
+            return Err(format!(
+                "Modelo salvo no formato {}, mais novo que o suportado ({}); atualize o RustFin",
+                artifact.format, FORMAT_VERSION
+            )
+            .into());
+        }
+        Ok(artifact)
+    }
+
+    // Nome do arquivo a partir da série e do modelo, ex.: inflation_brazil-arima-1-1-1.json
+    pub fn file_name(&self) -> String {
+        let name: String = format!("{}-{}", self.training.series, self.model)
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '-' })
+            .collect();
+        let name: Vec<&str> = name.split('-').filter(|part| !part.is_empty()).collect();
+        format!("{}.json", name.join("-"))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        std::fs::write(path, self.to_json()?)?;
+
+        Ok(())
+    }
+
+    // Salva no diretório dos modelos e retorna o caminho do arquivo
+    pub fn save_to_models_dir(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let path = models_dir()
+            .ok_or("Diretório de configuração não encontrado")?
+            .join(self.file_name());
+        self.save(&path)?;
+        Ok(path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = std::fs::read_to_string(path).map_err(|e| format!("Erro ao ler {}: {}", path.display(), e))?;
+        Self::from_json(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dates(len: usize) -> Vec<String> {
+        (0..len).map(|i| format!("01/{:02}/2023", i + 1)).collect()
+    }
+
+    #[test]
+    fn naive_artifact_round_trips_and_forecasts_fresh_data() {
+        let artifact = ModelArtifact::fit(Candidate::Naive, &[], "inflation_brazil", &dates(3), &[4.0, 4.5, 5.0]).unwrap();
+        let loaded = ModelArtifact::from_json(&artifact.to_json().unwrap()).unwrap();
+
+        assert_eq!(loaded, artifact);
+        assert_eq!(loaded.training.observations, 3);
+        assert_eq!(loaded.training.last_date.as_deref(), Some("01/03/2023"));
+        assert_eq!(loaded.forecast(&[4.0, 4.5, 5.0, 5.5], 2).unwrap(), vec![5.5, 5.5]);
+        assert_eq!(loaded.sigma2(), None);
+    }
+
+    #[test]
+    fn arima_parameters_are_stored_by_name() {
+        let artifact = ModelArtifact {
+            format: FORMAT_VERSION,
+            model: "ARIMA(1,1,1)".to_string(),
+            parameters: Parameters::Arima {
+                order: (1, 1, 1),
+                coefficients: vec![("ar.L1".to_string(), 0.5), ("ma.L1".to_string(), -0.25)],
+                sigma2: 0.04,
+            },
+            training: Training {
+                series: "inflation_brazil".to_string(),
+                observations: 120,
+                first_date: None,
+                last_date: Some("01/12/2023".to_string()),
+                fitted_at: 0,
+                seed: None,
+            },
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&artifact.to_json().unwrap()).unwrap();
+        assert_eq!(json["parameters"]["kind"], "arima");
+        assert_eq!(json["parameters"]["coefficients"][0][0], "ar.L1");
+        assert_eq!(artifact.sigma2(), Some(0.04));
+        assert_eq!(artifact.file_name(), "inflation_brazil-arima-1-1-1.json");
+        assert_eq!(
+            artifact.describe(),
+            "ARIMA(1,1,1) ajustado em inflation_brazil (120 observações, até 01/12/2023)"
+        );
+    }
+
+    #[test]
+    fn newer_formats_are_rejected() {
+        let artifact = ModelArtifact::fit(Candidate::Naive, &[], "inflation_chile", &dates(1), &[3.0]).unwrap();
+        let mut json: serde_json::Value = serde_json::from_str(&artifact.to_json().unwrap()).unwrap();
+        json["format"] = serde_json::json!(FORMAT_VERSION + 1);
+
+        assert!(ModelArtifact::from_json(&json.to_string()).is_err());
+        assert!(ModelArtifact::fit(Candidate::Naive, &[], "vazia", &[], &[]).is_err());
+    }
+}
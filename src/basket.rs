@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use crate::data::date_sort_key;
+
+// Como os componentes são combinados
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Method {
+    // Média ponderada dos componentes relativos à data-base (índice de Laspeyres), 100 na base
+    #[default]
+    Arithmetic,
+    // Média geométrica ponderada dos relativos, como nas cestas de moedas ponderadas pelo comércio
+    Geometric,
+    // Média ponderada dos próprios valores, para componentes que já são taxas (ex.: inflação de
+    // cada grupo de uma cesta de preços)
+    Average,
+}
+
+impl Method {
+    pub fn all() -> [Method; 3] {
+        [Method::Arithmetic, Method::Geometric, Method::Average]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Method::Arithmetic => "Índice aritmético",
+            Method::Geometric => "Índice geométrico",
+            Method::Average => "Média ponderada",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Component {
+    pub series: String,
+    pub weight: f64,
+}
+
+// Índice ponderado definido pelo usuário a partir de outras séries (cesta de moedas, cesta de
+// preços...), tratado como qualquer outra série
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Basket {
+    pub name: String,
+    pub components: Vec<Component>,
+    #[serde(default)]
+    pub method: Method,
+}
+
+pub fn baskets_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("baskets.json"))
+}
+
+pub fn load() -> Vec<Basket> {
+    baskets_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(baskets: &[Basket]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = baskets_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(baskets)?)?;
+
+    Ok(())
+}
+
+// Avalia a cesta nas datas presentes em todos os componentes, em ordem cronológica. Os pesos são
+// normalizados para somar 1; nos índices, a data-base é a primeira data comum.
+pub fn evaluate(
+    basket: &Basket,
+    series: &BTreeMap<String, Vec<(String, f64)>>,
+) -> Result<Vec<(String, f64)>, String> {
+    if basket.components.is_empty() {
+        return Err("A cesta não tem componentes".to_string());
+    }
+    let total: f64 = basket.components.iter().map(|component| component.weight).sum();
+    if basket.components.iter().any(|component| component.weight < 0.0) || total <= 0.0 {
+        return Err("Os pesos devem ser não negativos e somar mais que zero".to_string());
+    }
+
+    let components: Vec<(f64, HashMap<&str, f64>)> = basket
+        .components
+        .iter()
+        .map(|component| {
+            let values = series
+                .get(&component.series)
+                .ok_or_else(|| format!("Série desconhecida: {}", component.series))?;
+            let by_date = values.iter().map(|(date, value)| (date.as_str(), *value)).collect();
+            Ok((component.weight / total, by_date))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let mut dates: Vec<&str> = components[0]
+        .1
+        .keys()
+        .copied()
+        .filter(|date| components.iter().all(|(_, values)| values.contains_key(date)))
+        .collect();
+    dates.sort_by_key(|date| date_sort_key(date));
+    let Some(base) = dates.first() else {
+        return Err("Os componentes não têm datas em comum".to_string());
+    };
+
+    // Os relativos dos índices exigem valores positivos na data-base
+    if basket.method != Method::Average {
+        let non_positive = basket.components.iter().zip(&components).find(|(_, (_, values))| values[base] <= 0.0);
+        if let Some((component, _)) = non_positive {
+            return Err(format!("{} não é positiva na data-base {}; use a média ponderada", component.series, base));
+        }
+    }
+
+    Ok(dates
+        .iter()
+        .map(|date| {
+            let value = match basket.method {
+                Method::Arithmetic => {
+                    100.0 * components.iter().map(|(weight, values)| weight * values[date] / values[base]).sum::<f64>()
+                }
+                Method::Geometric => {
+                    100.0
+                        * components
+                            .iter()
+                            .map(|(weight, values)| (values[date] / values[base]).powf(*weight))
+                            .product::<f64>()
+                }
+                Method::Average => components.iter().map(|(weight, values)| weight * values[date]).sum(),
+            };
+            (date.to_string(), value)
+        })
+        .collect())
+}
+
+// Acrescenta às séries as cestas que puderam ser avaliadas; cestas definidas antes podem ser
+// componentes das seguintes
+pub fn extend(series: &mut BTreeMap<String, Vec<(String, f64)>>, baskets: &[Basket]) {
+    for basket in baskets {
+        if let Ok(values) = evaluate(basket, series) {
+            series.insert(basket.name.clone(), values);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> BTreeMap<String, Vec<(String, f64)>> {
+        BTreeMap::from([
+            (
+                "usd".to_string(),
+                vec![("01/02/2024".to_string(), 5.5), ("01/01/2024".to_string(), 5.0), ("01/03/2024".to_string(), 6.0)],
+            ),
+            ("eur".to_string(), vec![("01/01/2024".to_string(), 5.4), ("01/02/2024".to_string(), 5.94)]),
+        ])
+    }
+
+    fn basket(method: Method) -> Basket {
+        Basket {
+            name: "cesta".to_string(),
+            components: vec![
+                Component {
+                    series: "usd".to_string(),
+                    weight: 3.0,
+                },
+                Component {
+                    series: "eur".to_string(),
+                    weight: 1.0,
+                },
+            ],
+            method,
+        }
+    }
+
+    #[test]
+    fn index_starts_at_100_on_common_dates() {
+        let arithmetic = evaluate(&basket(Method::Arithmetic), &series()).unwrap();
+        assert_eq!(arithmetic.len(), 2);
+        assert_eq!(arithmetic[0], ("01/01/2024".to_string(), 100.0));
+        // Os dois componentes subiram 10%
+        assert!((arithmetic[1].1 - 110.0).abs() < 1e-9);
+
+        let geometric = evaluate(&basket(Method::Geometric), &series()).unwrap();
+        assert!((geometric[1].1 - 110.0).abs() < 1e-9);
+
+        let average = evaluate(&basket(Method::Average), &series()).unwrap();
+        assert!((average[0].1 - (0.75 * 5.0 + 0.25 * 5.4)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn baskets_can_build_on_earlier_ones() {
+        let mut all = series();
+        let nested = Basket {
+            name: "dupla".to_string(),
+            components: vec![Component {
+                series: "cesta".to_string(),
+                weight: 1.0,
+            }],
+            method: Method::Arithmetic,
+        };
+
+        extend(&mut all, &[basket(Method::Arithmetic), nested]);
+
+        assert_eq!(all["dupla"].len(), 2);
+        assert!((all["dupla"][1].1 - all["cesta"][1].1).abs() < 1e-9);
+        assert!(evaluate(&basket(Method::Arithmetic), &BTreeMap::new()).unwrap_err().contains("usd"));
+    }
+}
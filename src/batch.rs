@@ -0,0 +1,667 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{get_indicator_with_source, Indicator};
+use crate::export::escape;
+use crate::models::{ensemble, fit, Component, Fit, Weighting, DEFAULT_HORIZON};
+use crate::plugins::{self, Plugin};
+use crate::progress::{Progress, Reporter};
+use crate::webhooks::{self, Event};
+use crate::FORECAST_STEPS;
+
+pub const USAGE: &str = "uso: rustfin batch --countries <arquivo> [--indicator inflation|unemployment] \
+[--auto | --ensemble equal|inverse] [--horizon <passos>] [--out <diretório>] [--jobs <paralelas>] [--format text|json]";
+
+const DEFAULT_JOBS: usize = 4;
+
+// Categoria de uma falha do modo em lote, que define o código de saída do processo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorKind {
+    // Argumentos inválidos, configuração ausente ou arquivo de países ilegível
+    Config,
+    // Falha ao buscar os dados de um país no provedor
+    Provider,
+    // Nenhum modelo produziu uma previsão válida para um país
+    Model,
+    // Falha ao gravar os resultados ou tarefa interrompida
+    Internal,
+}
+
+impl ErrorKind {
+    pub fn all() -> [ErrorKind; 4] {
+        [ErrorKind::Internal, ErrorKind::Config, ErrorKind::Provider, ErrorKind::Model]
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ErrorKind::Internal => "erro ao gravar os resultados",
+            ErrorKind::Config => "configuração ou argumentos inválidos",
+            ErrorKind::Provider => "falha do provedor ao buscar algum país",
+            ErrorKind::Model => "nenhum modelo convergiu para algum país",
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ErrorKind::Internal => 1,
+            ErrorKind::Config => 2,
+            ErrorKind::Provider => 3,
+            ErrorKind::Model => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl BatchError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for BatchError {}
+
+// Saída do modo em lote no terminal: resumo legível ou JSON para scripts e CI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    // Formato pedido em `args`, identificado mesmo quando os demais argumentos são
+    // inválidos, para que o próprio erro de uso saia no formato esperado
+    pub fn requested(args: &[String]) -> Format {
+        match args.windows(2).find(|w| w[0] == "--format") {
+            Some(w) if w[1] == "json" => Format::Json,
+            _ => Format::Text,
+        }
+    }
+}
+
+// Opções do modo em lote (`rustfin batch ...`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchOptions {
+    // Arquivo com um país por linha; linhas vazias e iniciadas por '#' são ignoradas
+    pub countries: PathBuf,
+    pub indicator: Indicator,
+    // Escolhe o modelo de cada país pelo erro fora da amostra; sem ela usa o modelo padrão
+    pub auto: bool,
+    // Combina as previsões de todos os modelos em vez de escolher um
+    pub ensemble: Option<Weighting>,
+    pub horizon: usize,
+    pub out: PathBuf,
+    // Países processados ao mesmo tempo
+    pub jobs: usize,
+    pub format: Format,
+}
+
+fn value<'a>(args: &mut impl Iterator<Item = &'a String>, flag: &str) -> Result<&'a String, String> {
+    args.next().ok_or_else(|| format!("{} requer um valor", flag))
+}
+
+fn count(text: &str, flag: &str) -> Result<usize, String> {
+    match text.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("{} deve ser um inteiro positivo: {}", flag, text)),
+    }
+}
+
+impl BatchOptions {
+    // `args` são os argumentos após "batch"
+    pub fn parse(args: &[String]) -> Result<Self, BatchError> {
+        Self::parse_args(args).map_err(|e| BatchError::new(ErrorKind::Config, e))
+    }
+
+    fn parse_args(args: &[String]) -> Result<Self, String> {
+        let mut countries = None;
+        let mut options = BatchOptions {
+            countries: PathBuf::new(),
+            indicator: Indicator::Inflation,
+            auto: false,
+            ensemble: None,
+            horizon: DEFAULT_HORIZON,
+            out: PathBuf::from("."),
+            jobs: DEFAULT_JOBS,
+            format: Format::Text,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--countries" => countries = Some(PathBuf::from(value(&mut args, arg)?)),
+                "--indicator" => {
+                    let key = value(&mut args, arg)?;
+                    options.indicator = Indicator::all()
+                        .into_iter()
+                        .find(|indicator| indicator.key() == key)
+                        .ok_or_else(|| format!("Indicador desconhecido: {}", key))?;
+                }
+                "--auto" => options.auto = true,
+                "--ensemble" => {
+                    let key = value(&mut args, arg)?;
+                    options.ensemble = Some(
+                        Weighting::all()
+                            .into_iter()
+                            .find(|weighting| weighting.key() == key)
+                            .ok_or_else(|| format!("Ponderação desconhecida: {}", key))?,
+                    );
+                }
+                "--horizon" => options.horizon = count(value(&mut args, arg)?, arg)?,
+                "--out" => options.out = PathBuf::from(value(&mut args, arg)?),
+                "--jobs" => options.jobs = count(value(&mut args, arg)?, arg)?,
+                "--format" => {
+                    options.format = match value(&mut args, arg)?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        other => return Err(format!("Formato desconhecido: {}", other)),
+                    }
+                }
+                other => return Err(format!("Argumento desconhecido: {}", other)),
+            }
+        }
+
+        if options.auto && options.ensemble.is_some() {
+            return Err("--auto e --ensemble não podem ser usados juntos".to_string());
+        }
+        if options.horizon > FORECAST_STEPS {
+            return Err(format!("--horizon deve ser no máximo {}", FORECAST_STEPS));
+        }
+        options.countries = countries.ok_or("--countries é obrigatório")?;
+
+        Ok(options)
+    }
+}
+
+pub fn read_countries(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CountryResult {
+    pub country: String,
+    pub observations: usize,
+    pub last_date: Option<String>,
+    pub outcome: Result<Fit, BatchError>,
+    // Falhas que não impediram a previsão: provedores que falharam antes do reserva, cache local
+    pub warnings: Vec<String>,
+}
+
+async fn forecast_country(
+    config: Config,
+    plugins: Arc<Vec<Plugin>>,
+    options: BatchOptions,
+    country: String,
+) -> CountryResult {
+    let data = get_indicator_with_source(ApiClient::shared(), &config, options.indicator, &country)
+        .await
+        .map_err(|e| BatchError::new(ErrorKind::Provider, format!("Erro ao buscar os dados: {}", e)));
+    let (data, source) = match data {
+        Ok(data) => data,
+        Err(e) => {
+            return CountryResult {
+                country,
+                observations: 0,
+                last_date: None,
+                outcome: Err(e),
+                warnings: vec![],
+            }
+        }
+    };
+    let warnings = source
+        .failures
+        .iter()
+        .map(|failure| format!("Provedor reserva {} usado após a falha de {}", source.provider, failure))
+        .chain(source.warnings)
+        .collect();
+
+    let values: Vec<f64> = data.iter().map(|obs| obs.value).collect();
+    let observations = values.len();
+    // O ajuste (Python, candle ou plugins) é bloqueante
+    let outcome = tokio::task::spawn_blocking(move || match options.ensemble {
+        Some(weighting) => ensemble(&plugins, &values, options.horizon, weighting),
+        None => fit(&plugins, &values, options.horizon, options.auto),
+    })
+    .await
+        .unwrap_or_else(|e| Err(format!("Falha no ajuste: {}", e)))
+        .map_err(|e| BatchError::new(ErrorKind::Model, e));
+
+    CountryResult {
+        country,
+        observations,
+        last_date: data.last().map(|obs| obs.date.clone()),
+        outcome,
+        warnings,
+    }
+}
+
+// CSV "step,value" com a previsão de um país
+pub fn forecast_csv(forecast: &[f64]) -> String {
+    let mut csv = String::from("step,value\n");
+    for (step, value) in forecast.iter().enumerate() {
+        csv.push_str(&format!("{},{}\n", step + 1, value));
+    }
+    csv
+}
+
+// Uma linha por país, na ordem do arquivo; falhas ficam na coluna "error" e os pesos das
+// combinações (`--ensemble`) na coluna "weights"
+pub fn summary_csv(results: &[CountryResult]) -> String {
+    let mut csv =
+        String::from("country,model,observations,last_date,holdout_rmse,first_forecast,last_forecast,weights,error\n");
+
+    for result in results {
+        let (model, rmse, first, last, weights, error) = match &result.outcome {
+            Ok(fit) => (
+                fit.model.clone(),
+                fit.holdout_rmse.map(|v| v.to_string()).unwrap_or_default(),
+                fit.forecast.first().map(|v| v.to_string()).unwrap_or_default(),
+                fit.forecast.last().map(|v| v.to_string()).unwrap_or_default(),
+                fit.weights_summary(),
+                String::new(),
+            ),
+            Err(e) => (String::new(), String::new(), String::new(), String::new(), String::new(), e.message.clone()),
+        };
+
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            escape(&result.country),
+            escape(&model),
+            result.observations,
+            escape(result.last_date.as_deref().unwrap_or_default()),
+            rmse,
+            first,
+            last,
+            escape(&weights),
+            escape(&error)
+        ));
+    }
+
+    csv
+}
+
+// Eventos das séries cuja última data mudou desde a execução anterior, atualizando `seen`. A
+// primeira vez que uma série aparece só registra a data, sem notificar.
+pub fn new_data_events(
+    indicator: Indicator,
+    results: &[CountryResult],
+    seen: &mut BTreeMap<String, String>,
+) -> Vec<Event> {
+    let mut events = Vec::new();
+    for result in results {
+        let Some(last_date) = &result.last_date else {
+            continue;
+        };
+        let series = format!("{}_{}", indicator.key(), result.country);
+        let previous = seen.insert(series.clone(), last_date.clone());
+        if let Some(previous) = previous.filter(|previous| previous != last_date) {
+            let fit = result.outcome.as_ref().ok();
+            events.push(Event::NewData {
+                series,
+                indicator: indicator.key().to_string(),
+                country: result.country.clone(),
+                last_date: last_date.clone(),
+                previous_last_date: previous,
+                model: fit.map(|fit| fit.model.clone()),
+                forecast: fit.map(|fit| fit.forecast.clone()).unwrap_or_default(),
+            });
+        }
+    }
+    events
+}
+
+// Notifica os webhooks configurados sobre os dados novos; falhas só são relatadas, sem mudar o
+// resultado do lote
+async fn notify_new_data(options: &BatchOptions, config: &Config, results: &[CountryResult]) {
+    let mut seen = webhooks::load_last_seen();
+    let events = new_data_events(options.indicator, results, &mut seen);
+    if let Err(e) = webhooks::save_last_seen(&seen) {
+        eprintln!("Erro ao salvar as últimas datas vistas: {}", e);
+    }
+
+    for failure in webhooks::notify(ApiClient::shared(), &config.webhooks, &events).await {
+        eprintln!("{}", failure);
+    }
+}
+
+// Busca, ajusta e prevê cada país em paralelo (até `jobs` ao mesmo tempo), gravando
+// `<indicador>_<país>.csv` e `resumo.csv` em `out`. Falhas de um país não interrompem os demais;
+// `progress` recebe cada país concluído. Com webhooks configurados, notifica as séries com dados
+// novos.
+pub async fn run(
+    options: &BatchOptions,
+    config: &Config,
+    progress: &dyn Reporter,
+) -> Result<Vec<CountryResult>, BatchError> {
+    let internal = |e: &dyn std::error::Error| BatchError::new(ErrorKind::Internal, e.to_string());
+
+    let text = std::fs::read_to_string(&options.countries).map_err(|e| {
+        BatchError::new(ErrorKind::Config, format!("Erro ao ler {}: {}", options.countries.display(), e))
+    })?;
+    let countries = read_countries(&text);
+    if countries.is_empty() {
+        return Err(BatchError::new(
+            ErrorKind::Config,
+            format!("Nenhum país em {}", options.countries.display()),
+        ));
+    }
+    std::fs::create_dir_all(&options.out).map_err(|e| internal(&e))?;
+
+    let plugins = Arc::new(plugins::discover_plugins(&plugins::plugins_dir()));
+    let semaphore = Arc::new(Semaphore::new(options.jobs));
+    let mut tasks = JoinSet::new();
+    for (i, country) in countries.iter().enumerate() {
+        let (config, plugins, options, country) = (config.clone(), plugins.clone(), options.clone(), country.clone());
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire().await;
+            (i, forecast_country(config, plugins, options, country).await)
+        });
+    }
+
+    let total = countries.len();
+    let mut results = Vec::with_capacity(total);
+    progress.report(&Progress::new("", 0, total));
+    while let Some(joined) = tasks.join_next().await {
+        let (i, result) = joined.map_err(|e| internal(&e))?;
+        if let Ok(fit) = &result.outcome {
+            let name = format!("{}_{}.csv", options.indicator.key(), result.country);
+            std::fs::write(options.out.join(name), forecast_csv(&fit.forecast)).map_err(|e| internal(&e))?;
+        }
+
+        let country = result.country.clone();
+        results.push((i, result));
+        progress.report(&Progress::new(country, results.len(), total));
+    }
+
+    results.sort_by_key(|(i, _)| *i);
+    let results: Vec<CountryResult> = results.into_iter().map(|(_, result)| result).collect();
+    std::fs::write(options.out.join("resumo.csv"), summary_csv(&results)).map_err(|e| internal(&e))?;
+
+    if !config.webhooks.is_empty() {
+        notify_new_data(options, config, &results).await;
+    }
+
+    Ok(results)
+}
+
+// 0 quando todos os países foram previstos; com falhas, o código da categoria mais grave
+// (provedor antes de modelo), para que scripts distingam os casos
+pub fn exit_code(results: &[CountryResult]) -> i32 {
+    let failed = |kind: ErrorKind| {
+        results
+            .iter()
+            .any(|result| matches!(&result.outcome, Err(e) if e.kind == kind))
+    };
+
+    [ErrorKind::Provider, ErrorKind::Model]
+        .into_iter()
+        .find(|kind| failed(*kind))
+        .map(|kind| kind.exit_code())
+        .unwrap_or(0)
+}
+
+#[derive(Serialize)]
+struct ComponentReport<'a> {
+    model: &'a str,
+    holdout_rmse: f64,
+    weight: f64,
+}
+
+impl<'a> From<&'a Component> for ComponentReport<'a> {
+    fn from(component: &'a Component) -> Self {
+        Self {
+            model: &component.model,
+            holdout_rmse: component.holdout_rmse,
+            weight: component.weight,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CountryReport<'a> {
+    country: &'a str,
+    observations: usize,
+    last_date: Option<&'a str>,
+    model: Option<&'a str>,
+    holdout_rmse: Option<f64>,
+    forecast: &'a [f64],
+    // Modelos combinados e seus pesos, com `--ensemble`
+    components: Vec<ComponentReport<'a>>,
+    error: Option<&'a BatchError>,
+    warnings: &'a [String],
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    indicator: &'static str,
+    horizon: usize,
+    out: String,
+    exit_code: i32,
+    results: Vec<CountryReport<'a>>,
+}
+
+// Resultado completo em JSON (`--format json`), com as previsões de cada país
+pub fn report_json(options: &BatchOptions, results: &[CountryResult]) -> String {
+    let report = Report {
+        indicator: options.indicator.key(),
+        horizon: options.horizon,
+        out: options.out.display().to_string(),
+        exit_code: exit_code(results),
+        results: results
+            .iter()
+            .map(|result| {
+                let fit = result.outcome.as_ref().ok();
+                CountryReport {
+                    country: &result.country,
+                    observations: result.observations,
+                    last_date: result.last_date.as_deref(),
+                    model: fit.map(|fit| fit.model.as_str()),
+                    holdout_rmse: fit.and_then(|fit| fit.holdout_rmse),
+                    forecast: fit.map(|fit| fit.forecast.as_slice()).unwrap_or_default(),
+                    components: fit
+                        .map(|fit| fit.components.iter().map(ComponentReport::from).collect())
+                        .unwrap_or_default(),
+                    error: result.outcome.as_ref().err(),
+                    warnings: &result.warnings,
+                }
+            })
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+// Falha que impediu o processamento (ex.: configuração ausente), em JSON
+pub fn error_json(error: &BatchError) -> String {
+    serde_json::json!({ "exit_code": error.kind.exit_code(), "error": error }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Candidate;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn parses_batch_arguments() {
+        let options = BatchOptions::parse(&args("--countries paises.txt --indicator unemployment --auto --horizon 6 --out saida")).unwrap();
+
+        assert_eq!(options.countries, PathBuf::from("paises.txt"));
+        assert_eq!(options.indicator, Indicator::Unemployment);
+        assert!(options.auto);
+        assert_eq!(options.horizon, 6);
+        assert_eq!(options.out, PathBuf::from("saida"));
+        assert_eq!(options.jobs, DEFAULT_JOBS);
+
+        let options = BatchOptions::parse(&args("--countries a.txt --ensemble inverse")).unwrap();
+        assert_eq!(options.ensemble, Some(Weighting::InverseError));
+        assert!(BatchOptions::parse(&args("--countries a.txt --ensemble median")).is_err());
+        assert!(BatchOptions::parse(&args("--countries a.txt --ensemble equal --auto")).is_err());
+
+        assert!(BatchOptions::parse(&args("--auto")).is_err());
+        assert!(BatchOptions::parse(&args("--countries a.txt --horizon 0")).is_err());
+        assert!(BatchOptions::parse(&args("--countries a.txt --indicator gdp")).is_err());
+    }
+
+    #[test]
+    fn reads_countries_skipping_comments() {
+        assert_eq!(read_countries("Brazil\n\n# vizinhos\n  argentina \n"), vec!["brazil", "argentina"]);
+    }
+
+    #[test]
+    fn summary_lists_fits_and_failures() {
+        let results = [
+            CountryResult {
+                country: "brazil".to_string(),
+                observations: 3,
+                last_date: Some("01/03/2024".to_string()),
+                outcome: Candidate::Naive
+                    .run(&[], &[4.0, 4.5, 5.0], 2)
+                    .map(|forecast| Fit {
+                        model: "Ingênuo".to_string(),
+                        holdout_rmse: None,
+                        forecast,
+                        components: vec![],
+                    })
+                    .map_err(|e| BatchError::new(ErrorKind::Model, e)),
+                warnings: vec![],
+            },
+            CountryResult {
+                country: "chile".to_string(),
+                observations: 0,
+                last_date: None,
+                outcome: Err(BatchError::new(ErrorKind::Provider, "Erro ao buscar os dados: 404, não encontrado")),
+                warnings: vec![],
+            },
+        ];
+
+        assert_eq!(
+            summary_csv(&results),
+            "country,model,observations,last_date,holdout_rmse,first_forecast,last_forecast,weights,error\n\
+             brazil,Ingênuo,3,01/03/2024,,5,5,,\n\
+             chile,,0,,,,,,\"Erro ao buscar os dados: 404, não encontrado\"\n"
+        );
+    }
+
+    #[test]
+    fn exit_code_reflects_the_most_severe_failure() {
+        let result = |outcome: Result<Fit, BatchError>| CountryResult {
+            country: "brazil".to_string(),
+            observations: 0,
+            last_date: None,
+            outcome,
+            warnings: vec![],
+        };
+        let ok = result(Ok(Fit {
+            model: "Ingênuo".to_string(),
+            holdout_rmse: None,
+            forecast: vec![1.0],
+            components: vec![],
+        }));
+        let model = result(Err(BatchError::new(ErrorKind::Model, "sem convergência")));
+        let provider = result(Err(BatchError::new(ErrorKind::Provider, "timeout")));
+
+        assert_eq!(exit_code(std::slice::from_ref(&ok)), 0);
+        assert_eq!(exit_code(&[ok.clone(), model.clone()]), 4);
+        assert_eq!(exit_code(&[model, provider, ok]), 3);
+    }
+
+    #[test]
+    fn new_data_events_fire_only_when_the_last_date_changes() {
+        let result = |last_date: &str| CountryResult {
+            country: "brazil".to_string(),
+            observations: 2,
+            last_date: Some(last_date.to_string()),
+            outcome: Ok(Fit {
+                model: "Ingênuo".to_string(),
+                holdout_rmse: None,
+                forecast: vec![4.5],
+                components: vec![],
+            }),
+            warnings: vec![],
+        };
+        let mut seen = BTreeMap::new();
+
+        assert!(new_data_events(Indicator::Inflation, &[result("01/02/2024")], &mut seen).is_empty());
+        assert!(new_data_events(Indicator::Inflation, &[result("01/02/2024")], &mut seen).is_empty());
+
+        let events = new_data_events(Indicator::Inflation, &[result("01/03/2024")], &mut seen);
+        assert_eq!(
+            events,
+            vec![Event::NewData {
+                series: "inflation_brazil".to_string(),
+                indicator: "inflation".to_string(),
+                country: "brazil".to_string(),
+                last_date: "01/03/2024".to_string(),
+                previous_last_date: "01/02/2024".to_string(),
+                model: Some("Ingênuo".to_string()),
+                forecast: vec![4.5],
+            }]
+        );
+        assert_eq!(seen["inflation_brazil"], "01/03/2024");
+    }
+
+    #[test]
+    fn json_report_carries_forecasts_and_errors() {
+        let options = BatchOptions::parse(&args("--countries a.txt --format json --horizon 1")).unwrap();
+        assert_eq!(options.format, Format::Json);
+        let results = [
+            CountryResult {
+                country: "chile".to_string(),
+                observations: 0,
+                last_date: None,
+                outcome: Err(BatchError::new(ErrorKind::Provider, "timeout")),
+                warnings: vec![],
+            },
+            CountryResult {
+                country: "brazil".to_string(),
+                observations: 1,
+                last_date: Some("01/03/2024".to_string()),
+                outcome: Ok(Fit {
+                    model: "Ingênuo".to_string(),
+                    holdout_rmse: None,
+                    forecast: vec![4.5],
+                    components: vec![],
+                }),
+                warnings: vec!["Erro ao atualizar o cache de brazil: disco cheio".to_string()],
+            },
+        ];
+
+        let report: serde_json::Value = serde_json::from_str(&report_json(&options, &results)).unwrap();
+
+        assert_eq!(report["exit_code"], 3);
+        assert_eq!(report["results"][0]["error"]["kind"], "provider");
+        assert_eq!(report["results"][0]["forecast"], serde_json::json!([]));
+        assert_eq!(report["results"][1]["warnings"][0], "Erro ao atualizar o cache de brazil: disco cheio");
+        assert_eq!(Format::requested(&args("--format json --horizon x")), Format::Json);
+    }
+}
@@ -0,0 +1,24 @@
+use dotenv::dotenv;
+
+use rustfin::client::ApiClient;
+use rustfin::config::Config;
+use rustfin::{cache, rng, tui};
+
+// Modo terminal, sem dependências gráficas: para servidores acessados por SSH
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+    #[cfg(feature = "python-models")]
+    pyo3::prepare_freethreaded_python();
+
+    let config = Config::load().ok_or("Configuração não encontrada; defina API_TOKEN no ambiente ou no .env")?;
+    ApiClient::configure(&config)?;
+    rng::set_global_seed(config.seed);
+    cache::configure(&config);
+    #[cfg(feature = "python-models")]
+    rustfin::arima::configure(&config);
+
+    tui::run(&config)?;
+
+    Ok(())
+}
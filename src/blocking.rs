@@ -0,0 +1,69 @@
+// Versões síncronas das buscas, para quem não usa tokio. Como em `reqwest::blocking`, um runtime
+// interno executa as requisições; não chame estas funções de dentro de um runtime assíncrono
+// (o tokio entra em pânico ao bloquear uma de suas threads).
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::Runtime;
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{self, HistoricalSeriesValues, Indicator, InflationData, InflationRaw};
+use crate::fx::{self, Currency};
+use crate::market::{self, Candle};
+use crate::models::{Fit, ForecastRequest};
+use crate::plugins::Plugin;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+// Executa uma busca assíncrona até o fim; útil para as que não têm versão síncrona aqui
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME
+        .get_or_init(|| {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Falha ao criar o runtime das buscas síncronas")
+        })
+        .block_on(future)
+}
+
+// Série de um indicador em ordem cronológica, como `data::get_indicator`
+pub fn fetch_series(
+    config: &Config,
+    indicator: Indicator,
+    country: &str,
+) -> Result<Vec<InflationData>, Box<dyn std::error::Error>> {
+    block_on(data::get_indicator(ApiClient::shared(), config, indicator, country))
+}
+
+pub fn get_historical_inflation(config: &Config, country: &str) -> Result<InflationRaw, Box<dyn std::error::Error>> {
+    block_on(data::get_historical_inflation(ApiClient::shared(), config, country))
+}
+
+// Também atualiza o cache local, como a versão assíncrona
+pub fn get_historical_data(
+    config: &Config,
+    country: &str,
+) -> Result<Vec<HistoricalSeriesValues>, Box<dyn std::error::Error>> {
+    block_on(data::get_historical_data(ApiClient::shared(), config, country))
+}
+
+pub fn get_fx_history(
+    config: &Config,
+    from: &str,
+    to: Currency,
+) -> Result<Vec<InflationData>, Box<dyn std::error::Error>> {
+    block_on(fx::get_fx_history(ApiClient::shared(), config, from, to))
+}
+
+pub fn get_price_history(config: &Config, ticker: &str) -> Result<Vec<Candle>, Box<dyn std::error::Error>> {
+    block_on(market::get_price_history(ApiClient::shared(), config, ticker))
+}
+
+pub fn forecast(
+    config: &Config,
+    request: &ForecastRequest,
+    plugins: &[Plugin],
+) -> Result<Fit, Box<dyn std::error::Error>> {
+    block_on(request.run(ApiClient::shared(), config, plugins))
+}
@@ -0,0 +1,186 @@
+use crate::stats;
+
+// Faixa de λ avaliada na seleção: -1, -0.99, ..., 2
+const LAMBDA_MIN: i32 = -100;
+const LAMBDA_MAX: i32 = 200;
+
+// Transformação de Box-Cox: (y^λ − 1)/λ, ou ln y quando λ = 0. Estabiliza a variância de séries
+// cuja dispersão cresce com o nível; exige valores positivos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxCox {
+    pub lambda: f64,
+}
+
+// Previsão na escala original: média com correção de viés e intervalo de 95%
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Band {
+    pub mean: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+fn is_log(lambda: f64) -> bool {
+    lambda.abs() < 1e-9
+}
+
+fn mean_sd(values: &[f64]) -> (f64, f64) {
+    (stats::mean(values), stats::std_dev(values))
+}
+
+// Método de Guerrero (1993): divide a série em blocos de `period` observações (os mais recentes;
+// a sobra do início é descartada) e escolhe o λ que torna mais constante a razão entre o desvio
+// padrão e a média^(1−λ) dos blocos, pelo menor coeficiente de variação
+pub fn guerrero(values: &[f64], period: usize) -> Option<f64> {
+    let period = period.max(2);
+    let blocks = values.len() / period;
+    if blocks < 2 || values.iter().any(|v| !v.is_finite() || *v <= 0.0) {
+        return None;
+    }
+
+    let start = values.len() - blocks * period;
+    let moments: Vec<(f64, f64)> = values[start..].chunks(period).map(mean_sd).collect();
+
+    (LAMBDA_MIN..=LAMBDA_MAX)
+        .map(|i| i as f64 / 100.0)
+        .filter_map(|lambda| {
+            let ratios: Vec<f64> = moments.iter().map(|(mean, sd)| sd / mean.powf(1.0 - lambda)).collect();
+            let (mean, sd) = mean_sd(&ratios);
+            let cv = sd / mean;
+            cv.is_finite().then_some((lambda, cv))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(lambda, _)| lambda)
+}
+
+impl BoxCox {
+    // λ escolhido pelo método de Guerrero
+    pub fn fit(values: &[f64], period: usize) -> Option<Self> {
+        guerrero(values, period).map(|lambda| Self { lambda })
+    }
+
+    pub fn transform(&self, values: &[f64]) -> Vec<f64> {
+        values
+            .iter()
+            .map(|y| {
+                if is_log(self.lambda) {
+                    y.ln()
+                } else {
+                    (y.powf(self.lambda) - 1.0) / self.lambda
+                }
+            })
+            .collect()
+    }
+
+    // Volta à escala original; é a mediana da previsão, não a média
+    pub fn inverse(&self, w: f64) -> f64 {
+        if is_log(self.lambda) {
+            w.exp()
+        } else {
+            (self.lambda * w + 1.0).max(0.0).powf(1.0 / self.lambda)
+        }
+    }
+
+    // Média na escala original de uma previsão com variância `variance` na escala transformada,
+    // pela aproximação de segunda ordem (a simples inversão subestima a média)
+    pub fn inverse_mean(&self, w: f64, variance: f64) -> f64 {
+        let median = self.inverse(w);
+        if is_log(self.lambda) {
+            median * (1.0 + variance / 2.0)
+        } else {
+            median * (1.0 + variance * (1.0 - self.lambda) / (2.0 * (self.lambda * w + 1.0).powi(2)))
+        }
+    }
+
+    // Previsões na escala transformada para a original; a variância de h passos é h vezes
+    // `sigma2` (a de um passo), como em um passeio aleatório
+    pub fn back_transform(&self, forecast: &[f64], sigma2: f64) -> Vec<Band> {
+        let z = stats::normal_quantile(0.975);
+        forecast
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let variance = sigma2 * (i + 1) as f64;
+                let margin = z * variance.sqrt();
+                Band {
+                    mean: self.inverse_mean(*w, variance),
+                    lower: self.inverse(w - margin),
+                    upper: self.inverse(w + margin),
+                }
+            })
+            .collect()
+    }
+}
+
+// Ajusta o modelo (`run`) na série transformada e devolve as previsões na escala original. A
+// variância de um passo é a das variações da série transformada, o erro de um passo do modelo
+// ingênuo.
+pub fn forecast(
+    values: &[f64],
+    period: usize,
+    run: impl FnOnce(&[f64]) -> Result<Vec<f64>, String>,
+) -> Result<(BoxCox, Vec<Band>), String> {
+    let transform = BoxCox::fit(values, period).ok_or_else(|| {
+        format!(
+            "A transformação de Box-Cox exige valores positivos e ao menos {} observações",
+            2 * period.max(2)
+        )
+    })?;
+
+    let transformed = transform.transform(values);
+    let changes: Vec<f64> = transformed.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let sigma2 = stats::std_dev(&changes).powi(2);
+
+    let forecast = run(&transformed)?;
+    Ok((transform, transform.back_transform(&forecast, sigma2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Padrão sazonal repetido em blocos de 12 com nível crescente
+    fn blocks(dispersion: impl Fn(f64) -> f64) -> Vec<f64> {
+        (0..48)
+            .map(|t| {
+                let level = 10.0 * 2f64.powi(t / 12);
+                level + dispersion(level) * ((t % 12) as f64).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn guerrero_detects_multiplicative_and_additive_dispersion() {
+        let multiplicative = guerrero(&blocks(|level| 0.1 * level), 12).unwrap();
+        assert!(multiplicative.abs() < 0.02, "λ = {}", multiplicative);
+
+        let additive = guerrero(&blocks(|_| 1.0), 12).unwrap();
+        assert!((additive - 1.0).abs() < 0.02, "λ = {}", additive);
+
+        assert!(guerrero(&[1.0, -2.0, 3.0, 4.0], 2).is_none());
+        assert!(guerrero(&[1.0, 2.0, 3.0], 2).is_none());
+    }
+
+    #[test]
+    fn transform_round_trips() {
+        for lambda in [-0.5, 0.0, 0.5, 1.0] {
+            let transform = BoxCox { lambda };
+            let values = [0.5, 2.0, 7.5];
+            for (w, y) in transform.transform(&values).iter().zip(values) {
+                assert!((transform.inverse(*w) - y).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn back_transformed_mean_is_above_the_median() {
+        let transform = BoxCox { lambda: 0.0 };
+
+        let bands = transform.back_transform(&[1.0, 1.0], 0.04);
+
+        assert!((bands[0].mean - 1f64.exp() * 1.02).abs() < 1e-12);
+        assert!(bands[1].mean > bands[0].mean);
+        assert!(bands[1].lower < bands[0].lower && bands[1].upper > bands[0].upper);
+        // Intervalo assimétrico em torno da mediana
+        assert!(bands[0].upper - 1f64.exp() > 1f64.exp() - bands[0].lower);
+    }
+}
@@ -0,0 +1,143 @@
+// Detecção de quebras estruturais em uma regressão de tendência linear (nível + inclinação).
+// Cada segmento é testado pelo maior F de Chow entre os pontos candidatos (sup-F de
+// Andrews); havendo quebra significativa, o segmento é dividido e o teste se repete em
+// cada parte (segmentação binária, no espírito de Bai-Perron).
+
+// Parâmetros por regime: intercepto e inclinação
+const K: usize = 2;
+// Fração mínima de cada lado da quebra (trimming de 15%)
+const TRIMMING: f64 = 0.15;
+// Tamanho mínimo absoluto de um regime
+const MIN_SEGMENT: usize = 12;
+// Valor crítico a 5% do sup-F com k = 2 e trimming de 15% (Andrews, 1993)
+pub const SUP_F_CRITICAL: f64 = 11.7;
+
+// Somas acumuladas para obter a soma dos quadrados dos resíduos de qualquer trecho em O(1)
+struct Sums {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    xx: Vec<f64>,
+    xy: Vec<f64>,
+    yy: Vec<f64>,
+}
+
+impl Sums {
+    fn new(values: &[f64]) -> Self {
+        let mut sums = Sums {
+            x: vec![0.0],
+            y: vec![0.0],
+            xx: vec![0.0],
+            xy: vec![0.0],
+            yy: vec![0.0],
+        };
+
+        for (i, y) in values.iter().enumerate() {
+            let x = i as f64;
+            sums.x.push(sums.x[i] + x);
+            sums.y.push(sums.y[i] + y);
+            sums.xx.push(sums.xx[i] + x * x);
+            sums.xy.push(sums.xy[i] + x * y);
+            sums.yy.push(sums.yy[i] + y * y);
+        }
+
+        sums
+    }
+
+    // SSR da regressão y = a + b·t no trecho [start, end)
+    fn ssr(&self, start: usize, end: usize) -> f64 {
+        let n = (end - start) as f64;
+        let sx = self.x[end] - self.x[start];
+        let sy = self.y[end] - self.y[start];
+        let sxx = self.xx[end] - self.xx[start] - sx * sx / n;
+        let sxy = self.xy[end] - self.xy[start] - sx * sy / n;
+        let syy = self.yy[end] - self.yy[start] - sy * sy / n;
+
+        if sxx <= 0.0 {
+            return syy.max(0.0);
+        }
+        (syy - sxy * sxy / sxx).max(0.0)
+    }
+}
+
+// Estatística F de Chow para uma quebra em `split` no trecho [start, end)
+fn chow_f(sums: &Sums, start: usize, split: usize, end: usize) -> f64 {
+    let pooled = sums.ssr(start, end);
+    let separate = sums.ssr(start, split) + sums.ssr(split, end);
+    let dof = (end - start) as f64 - 2.0 * K as f64;
+
+    if separate <= 0.0 || dof <= 0.0 {
+        return 0.0;
+    }
+    ((pooled - separate) / K as f64) / (separate / dof)
+}
+
+// Teste de Chow para uma data de quebra conhecida (índice do primeiro ponto do novo regime)
+pub fn chow_test(values: &[f64], split: usize) -> Option<f64> {
+    if split < K + 1 || values.len() < split + K + 1 {
+        return None;
+    }
+
+    Some(chow_f(&Sums::new(values), 0, split, values.len()))
+}
+
+// Ponto de maior F no trecho, se houver espaço para dois regimes
+fn best_split(sums: &Sums, start: usize, end: usize) -> Option<(usize, f64)> {
+    let n = end - start;
+    let margin = ((n as f64 * TRIMMING).ceil() as usize).max(MIN_SEGMENT);
+    if n < 2 * margin {
+        return None;
+    }
+
+    (start + margin..=end - margin)
+        .map(|split| (split, chow_f(sums, start, split, end)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+// Índices onde começam novos regimes, em ordem crescente
+pub fn detect_breaks(values: &[f64]) -> Vec<usize> {
+    let sums = Sums::new(values);
+    let mut breaks = Vec::new();
+    let mut pending = vec![(0, values.len())];
+
+    while let Some((start, end)) = pending.pop() {
+        if let Some((split, f)) = best_split(&sums, start, end) {
+            if f > SUP_F_CRITICAL {
+                breaks.push(split);
+                pending.push((start, split));
+                pending.push((split, end));
+            }
+        }
+    }
+
+    breaks.sort_unstable();
+    breaks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_level_shift() {
+        // Nível 2 por 40 observações e 6 nas 40 seguintes, com uma pequena oscilação
+        let values: Vec<f64> = (0..80)
+            .map(|i| {
+                let level = if i < 40 { 2.0 } else { 6.0 };
+                let noise = if i % 2 == 0 { 0.1 } else { -0.1 };
+                level + noise
+            })
+            .collect();
+
+        let breaks = detect_breaks(&values);
+
+        assert_eq!(breaks.len(), 1);
+        assert!((38..=42).contains(&breaks[0]));
+    }
+
+    #[test]
+    fn stable_series_has_no_breaks() {
+        let values: Vec<f64> = (0..80).map(|i| if i % 2 == 0 { 1.0 } else { -1.0 }).collect();
+
+        assert!(detect_breaks(&values).is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::client::Validators;
+use crate::compact;
+use crate::config::{Config, DEFAULT_MEMORY_CACHE_MB};
+use crate::data::InflationData;
+use crate::frequency;
+use crate::lru::Lru;
+use crate::revisions::{self, Revision};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedObservation {
+    pub date: String,
+    pub value: f64,
+    // Momento (segundos Unix) em que a observação foi recebida pela primeira vez
+    pub first_seen: u64,
+}
+
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn cache_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("rustfin").join("cache"))
+}
+
+fn cache_path(country: &str) -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join(format!("inflation-{}.json", country)))
+}
+
+// Cache no formato compacto (`Config::compact_storage`)
+fn compact_path(country: &str) -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join(format!("inflation-{}.bin", country)))
+}
+
+// Validadores da última resposta de cada série em cada provedor, indexados por
+// "<série>@<url_base>"
+fn validators_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("validators.json"))
+}
+
+pub fn validators_key(series: &str, url_base: &str) -> String {
+    format!("{}@{}", series, url_base)
+}
+
+pub fn load_validators() -> BTreeMap<String, Validators> {
+    validators_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_validators(validators: &BTreeMap<String, Validators>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = validators_path().ok_or("Diretório de dados não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(validators)?)?;
+
+    Ok(())
+}
+
+// Lê o cache em qualquer um dos formatos, independentemente da configuração atual
+fn read(country: &str) -> Vec<CachedObservation> {
+    let compact = compact_path(country)
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|bytes| compact::decode(&bytes).ok());
+
+    compact.unwrap_or_else(|| {
+        cache_path(country)
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    })
+}
+
+// Série já lida do disco, com a data de modificação do arquivo na leitura
+#[derive(Clone)]
+struct Parsed {
+    modified: SystemTime,
+    observations: Arc<Vec<CachedObservation>>,
+}
+
+// Séries lidas recentemente, para que reajustes de modelos e redesenhos não releiam e
+// reinterpretem o arquivo; o orçamento vem de `Config::memory_cache_mb`
+static MEMORY: Mutex<Option<Lru<Parsed>>> = Mutex::new(None);
+
+fn with_memory<T>(f: impl FnOnce(&mut Lru<Parsed>) -> T) -> T {
+    let mut guard = MEMORY.lock().unwrap_or_else(|e| e.into_inner());
+    f(guard.get_or_insert_with(|| Lru::new(megabytes(DEFAULT_MEMORY_CACHE_MB))))
+}
+
+fn megabytes(mb: u64) -> usize {
+    usize::try_from(mb.saturating_mul(1024 * 1024)).unwrap_or(usize::MAX)
+}
+
+// Ajusta o cache em memória à configuração; chamada na inicialização
+pub fn configure(config: &Config) {
+    with_memory(|memory| memory.set_budget(megabytes(config.memory_cache_mb)));
+}
+
+// Bytes ocupados por uma série em memória, aproximadamente
+fn footprint(observations: &[CachedObservation]) -> usize {
+    observations
+        .iter()
+        .map(|obs| std::mem::size_of::<CachedObservation>() + obs.date.capacity())
+        .sum()
+}
+
+// Arquivo atual da série (o compacto tem prioridade, como na leitura)
+fn modified(country: &str) -> Option<SystemTime> {
+    [compact_path(country), cache_path(country)]
+        .into_iter()
+        .flatten()
+        .find_map(|path| std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok())
+}
+
+// Série do cache compartilhada com o cache em memória. O arquivo só é lido de novo quando muda
+// (gravado por outro processo, como o modo em lote); as gravações deste processo descartam a cópia.
+pub fn load_shared(country: &str) -> Arc<Vec<CachedObservation>> {
+    let Some(modified) = modified(country) else {
+        return Arc::new(Vec::new());
+    };
+    let cached = with_memory(|memory| memory.get(country)).filter(|parsed| parsed.modified == modified);
+    if let Some(parsed) = cached {
+        return parsed.observations;
+    }
+
+    let observations = Arc::new(read(country));
+    let parsed = Parsed {
+        modified,
+        observations: observations.clone(),
+    };
+    with_memory(|memory| memory.insert(country, parsed, footprint(&observations)));
+    observations
+}
+
+pub fn load(country: &str) -> Vec<CachedObservation> {
+    load_shared(country).to_vec()
+}
+
+// Grava no formato escolhido e remove o arquivo do outro, para a troca de formato não deixar
+// uma cópia desatualizada. Datas que o formato compacto não representa mantêm o JSON.
+fn save(
+    config: &Config,
+    country: &str,
+    observations: &[CachedObservation],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let compact_path = compact_path(country).ok_or("Diretório de dados não encontrado")?;
+    let json_path = cache_path(country).ok_or("Diretório de dados não encontrado")?;
+    with_memory(|memory| memory.remove(country));
+    if let Some(dir) = json_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let encoded = if config.compact_storage {
+        compact::encode(observations).ok()
+    } else {
+        None
+    };
+    let stale = match encoded {
+        Some(bytes) => {
+            std::fs::write(&compact_path, bytes)?;
+            json_path
+        }
+        None => {
+            std::fs::write(&json_path, serde_json::to_string(observations)?)?;
+            compact_path
+        }
+    };
+    if stale.exists() {
+        std::fs::remove_file(stale)?;
+    }
+
+    Ok(())
+}
+
+// Atualiza o cache com os dados recém-buscados, registrando no log as datas
+// já conhecidas cujo valor mudou e nos metadados a frequência detectada.
+// Retorna as revisões detectadas.
+pub fn update(
+    config: &Config,
+    country: &str,
+    fresh: &[InflationData],
+) -> Result<Vec<Revision>, Box<dyn std::error::Error>> {
+    let cached = load_shared(country);
+    let now = now_secs();
+
+    // Posição de cada data no cache (a primeira, se repetida)
+    let mut index: HashMap<&str, usize> = HashMap::with_capacity(cached.len());
+    for (i, obs) in cached.iter().enumerate() {
+        index.entry(obs.date.as_str()).or_insert(i);
+    }
+
+    let mut found = Vec::new();
+    let mut observations = Vec::with_capacity(fresh.len());
+    for item in fresh {
+        let previous = index.get(item.date.as_str()).map(|&i| &cached[i]);
+
+        if let Some(previous) = previous {
+            if previous.value != item.value {
+                found.push(Revision {
+                    country: country.to_string(),
+                    date: item.date.clone(),
+                    old_value: previous.value,
+                    new_value: item.value,
+                    detected_at: now,
+                });
+            }
+        }
+
+        observations.push(CachedObservation {
+            date: item.date.clone(),
+            value: item.value,
+            first_seen: previous.map(|obs| obs.first_seen).unwrap_or(now),
+        });
+    }
+
+    revisions::append(&found)?;
+    save(config, country, &observations)?;
+    let dates: Vec<String> = fresh.iter().map(|item| item.date.clone()).collect();
+    frequency::record(&format!("inflation_{}", country), &dates)?;
+
+    Ok(found)
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use crate::data::date_sort_key;
+
+pub const MONTHS: [&str; 12] = ["Jan", "Fev", "Mar", "Abr", "Mai", "Jun", "Jul", "Ago", "Set", "Out", "Nov", "Dez"];
+
+// Série organizada em ano × mês, para o mapa de calor
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calendar {
+    // Anos em ordem crescente, um por linha
+    pub years: Vec<i32>,
+    // Valor de cada mês do ano; None onde não há observação
+    pub values: Vec<[Option<f64>; 12]>,
+}
+
+impl Calendar {
+    // Menor e maior valor presentes, a escala de cores do mapa
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.values.iter().flatten().flatten().fold(None, |range, value| match range {
+            None => Some((*value, *value)),
+            Some((min, max)) => Some((min.min(*value), max.max(*value))),
+        })
+    }
+
+    // Média de cada mês entre os anos, que evidencia a sazonalidade
+    pub fn monthly_means(&self) -> [Option<f64>; 12] {
+        let mut means = [None; 12];
+        for (month, mean) in means.iter_mut().enumerate() {
+            let values: Vec<f64> = self.values.iter().filter_map(|row| row[month]).collect();
+            if !values.is_empty() {
+                *mean = Some(values.iter().sum::<f64>() / values.len() as f64);
+            }
+        }
+        means
+    }
+}
+
+// Agrupa as observações por ano e mês; várias no mesmo mês (séries diárias ou semanais) viram
+// a média do mês. Datas fora do formato DD/MM/AAAA são ignoradas.
+pub fn calendar(series: &[(String, f64)]) -> Calendar {
+    let mut months: BTreeMap<(i32, usize), (f64, usize)> = BTreeMap::new();
+    for (date, value) in series {
+        let key = date_sort_key(date);
+        let year = key.get(..4).and_then(|year| year.parse::<i32>().ok());
+        let month = key
+            .get(5..7)
+            .and_then(|month| month.parse::<usize>().ok())
+            .filter(|month| (1..=12).contains(month));
+        if let (Some(year), Some(month)) = (year, month) {
+            let (sum, count) = months.entry((year, month - 1)).or_insert((0.0, 0));
+            *sum += value;
+            *count += 1;
+        }
+    }
+
+    let mut calendar = Calendar {
+        years: vec![],
+        values: vec![],
+    };
+    for ((year, month), (sum, count)) in months {
+        if calendar.years.last() != Some(&year) {
+            calendar.years.push(year);
+            calendar.values.push([None; 12]);
+        }
+        if let Some(row) = calendar.values.last_mut() {
+            row[month] = Some(sum / count as f64);
+        }
+    }
+    calendar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> Vec<(String, f64)> {
+        vec![
+            ("01/03/2021".to_string(), 6.0),
+            ("01/01/2020".to_string(), 4.0),
+            ("15/01/2020".to_string(), 5.0),
+            ("01/02/2020".to_string(), 3.0),
+            ("data inválida".to_string(), 99.0),
+        ]
+    }
+
+    #[test]
+    fn groups_observations_by_year_and_month() {
+        let calendar = calendar(&series());
+
+        assert_eq!(calendar.years, vec![2020, 2021]);
+        assert_eq!(calendar.values[0][0], Some(4.5));
+        assert_eq!(calendar.values[0][1], Some(3.0));
+        assert_eq!(calendar.values[0][2], None);
+        assert_eq!(calendar.values[1][2], Some(6.0));
+        assert_eq!(calendar.range(), Some((3.0, 6.0)));
+    }
+
+    #[test]
+    fn monthly_means_ignore_missing_years() {
+        let mut series = series();
+        series.push(("01/01/2021".to_string(), 1.5));
+        let means = calendar(&series).monthly_means();
+
+        assert_eq!(means[0], Some(3.0));
+        assert_eq!(means[2], Some(6.0));
+        assert_eq!(means[11], None);
+        assert_eq!(calendar(&[]).range(), None);
+    }
+}
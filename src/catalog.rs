@@ -0,0 +1,116 @@
+use crate::data::{date_sort_key, Indicator, COUNTRIES};
+use crate::fx::{infer_frequency, Frequency};
+
+// Indicador oferecido pelo provedor, com os países em que pode ser buscado
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub indicator: Indicator,
+    pub description: &'static str,
+    pub countries: Vec<String>,
+}
+
+fn description(indicator: Indicator) -> &'static str {
+    match indicator {
+        Indicator::Inflation => "Inflação ao consumidor acumulada em 12 meses (endpoint inflation)",
+        Indicator::Unemployment => "Taxa de desemprego (endpoint unemployment; a brapi não o oferece)",
+    }
+}
+
+// Catálogo dos indicadores do provedor; a cobertura real de cada país só é conhecida buscando-o
+pub fn entries() -> Vec<Entry> {
+    Indicator::all()
+        .into_iter()
+        .map(|indicator| Entry {
+            indicator,
+            description: description(indicator),
+            countries: COUNTRIES.iter().map(|country| country.to_string()).collect(),
+        })
+        .collect()
+}
+
+impl Entry {
+    // Busca sem distinção de maiúsculas no nome, chave, unidade, descrição e países
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+
+        [
+            self.indicator.label(),
+            self.indicator.key(),
+            self.indicator.unit(),
+            self.description,
+        ]
+        .iter()
+        .any(|text| text.to_lowercase().contains(&query))
+            || self.countries.iter().any(|country| country.contains(&query))
+    }
+}
+
+pub fn frequency_label(frequency: Frequency) -> &'static str {
+    match frequency {
+        Frequency::Daily => "Diária",
+        Frequency::Monthly => "Mensal",
+        Frequency::Quarterly => "Trimestral",
+        Frequency::Annual => "Anual",
+    }
+}
+
+// Período coberto por uma série buscada
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    pub observations: usize,
+    pub first: String,
+    pub last: String,
+    pub frequency: Frequency,
+}
+
+impl Coverage {
+    // None para uma série vazia
+    pub fn of(dates: &[String]) -> Option<Self> {
+        let first = dates.iter().min_by_key(|date| date_sort_key(date))?;
+        let last = dates.iter().max_by_key(|date| date_sort_key(date))?;
+        Some(Self {
+            observations: dates.len(),
+            first: first.clone(),
+            last: last.clone(),
+            frequency: infer_frequency(dates),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_matches_names_units_and_countries() {
+        let entries = entries();
+        let find = |query: &str| -> Vec<Indicator> {
+            entries
+                .iter()
+                .filter(|entry| entry.matches(query))
+                .map(|entry| entry.indicator)
+                .collect()
+        };
+
+        assert_eq!(find("DESEMPREGO"), vec![Indicator::Unemployment]);
+        assert_eq!(find("12 meses"), vec![Indicator::Inflation]);
+        assert_eq!(find("chile").len(), 2);
+        assert_eq!(find("").len(), 2);
+        assert!(find("pib").is_empty());
+    }
+
+    #[test]
+    fn coverage_spans_the_dates() {
+        let dates: Vec<String> = ["01/03/2024", "01/01/2024", "01/02/2024"].map(String::from).to_vec();
+
+        let coverage = Coverage::of(&dates).unwrap();
+
+        assert_eq!((coverage.first.as_str(), coverage.last.as_str()), ("01/01/2024", "01/03/2024"));
+        assert_eq!(coverage.observations, 3);
+        assert_eq!(coverage.frequency, Frequency::Monthly);
+        assert!(Coverage::of(&[]).is_none());
+    }
+}
@@ -0,0 +1,338 @@
+use crate::batch::ErrorKind;
+
+// Valor esperado por uma opção, usado para completar o argumento seguinte
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    // Opção sem valor (ex.: --auto)
+    None,
+    File(&'static str),
+    Dir(&'static str),
+    Number(&'static str),
+    Choice(&'static str, &'static [&'static str]),
+}
+
+impl Value {
+    fn placeholder(&self) -> Option<&'static str> {
+        match *self {
+            Value::None => None,
+            Value::File(name) | Value::Dir(name) | Value::Number(name) | Value::Choice(name, _) => Some(name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Flag {
+    pub name: &'static str,
+    pub value: Value,
+    pub help: &'static str,
+}
+
+// Opções de `rustfin batch`, na ordem da ajuda; o parser fica em `batch::BatchOptions`
+pub const BATCH_FLAGS: &[Flag] = &[
+    Flag {
+        name: "--countries",
+        value: Value::File("arquivo"),
+        help: "arquivo com um país por linha (obrigatório)",
+    },
+    Flag {
+        name: "--indicator",
+        value: Value::Choice("indicador", &["inflation", "unemployment"]),
+        help: "indicador previsto (padrão: inflation)",
+    },
+    Flag {
+        name: "--auto",
+        value: Value::None,
+        help: "escolhe o modelo de cada país pelo erro fora da amostra",
+    },
+    Flag {
+        name: "--ensemble",
+        value: Value::Choice("ponderação", &["equal", "inverse"]),
+        help: "combina as previsões de todos os modelos, com pesos iguais ou pelo inverso do erro",
+    },
+    Flag {
+        name: "--horizon",
+        value: Value::Number("passos"),
+        help: "passos previstos (padrão: 12)",
+    },
+    Flag {
+        name: "--out",
+        value: Value::Dir("diretório"),
+        help: "diretório dos arquivos gerados (padrão: o atual)",
+    },
+    Flag {
+        name: "--jobs",
+        value: Value::Number("paralelas"),
+        help: "países processados ao mesmo tempo (padrão: 4)",
+    },
+    Flag {
+        name: "--format",
+        value: Value::Choice("formato", &["text", "json"]),
+        help: "saída no terminal: resumo legível ou JSON (padrão: text)",
+    },
+];
+
+pub const SUBCOMMANDS: &[(&str, &str)] = &[
+    ("batch", "prevê uma lista de países sem abrir a interface"),
+    ("completions", "gera o script de completação do shell"),
+    ("man", "gera a página de manual"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    pub fn all() -> [Shell; 3] {
+        [Shell::Bash, Shell::Zsh, Shell::Fish]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Zsh => "zsh",
+            Shell::Fish => "fish",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Shell> {
+        Shell::all().into_iter().find(|shell| shell.name() == name)
+    }
+}
+
+fn names<'a>(items: impl Iterator<Item = &'a str>) -> String {
+    items.collect::<Vec<_>>().join(" ")
+}
+
+fn bash() -> String {
+    let subcommands = names(SUBCOMMANDS.iter().map(|(name, _)| *name));
+    let shells = names(Shell::all().iter().map(Shell::name));
+    let flags = names(BATCH_FLAGS.iter().map(|flag| flag.name));
+
+    let mut values = String::new();
+    for flag in BATCH_FLAGS {
+        let completion = match flag.value {
+            Value::None => continue,
+            Value::File(_) => "COMPREPLY=($(compgen -f -- \"$cur\"))".to_string(),
+            Value::Dir(_) => "COMPREPLY=($(compgen -d -- \"$cur\"))".to_string(),
+            Value::Number(_) => "COMPREPLY=()".to_string(),
+            Value::Choice(_, choices) => {
+                format!("COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))", choices.join(" "))
+            }
+        };
+        values.push_str(&format!("                {}) {} ;;\n", flag.name, completion));
+    }
+
+    format!(
+        r#"# Completação de rustfin para bash: source <(rustfin completions bash)
+_rustfin() {{
+    local cur prev
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+
+    if [ "$COMP_CWORD" -eq 1 ]; then
+        COMPREPLY=($(compgen -W "{subcommands}" -- "$cur"))
+        return
+    fi
+
+    case "${{COMP_WORDS[1]}}" in
+        completions)
+            COMPREPLY=($(compgen -W "{shells}" -- "$cur")) ;;
+        batch)
+            case "$prev" in
+{values}                *) COMPREPLY=($(compgen -W "{flags}" -- "$cur")) ;;
+            esac ;;
+    esac
+}}
+complete -F _rustfin rustfin
+"#
+    )
+}
+
+// Descrições dentro de '...[descrição]' no _arguments do zsh
+fn zsh_escape(text: &str) -> String {
+    text.replace('\'', "'\\''")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+        .replace(':', "\\:")
+}
+
+fn zsh() -> String {
+    let subcommands = SUBCOMMANDS
+        .iter()
+        .map(|(name, help)| format!("{}\\:\"{}\"", name, zsh_escape(help)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let shells = names(Shell::all().iter().map(Shell::name));
+
+    let mut flags = String::new();
+    for flag in BATCH_FLAGS {
+        let action = match flag.value {
+            Value::None => String::new(),
+            Value::File(name) => format!(":{}:_files", name),
+            Value::Dir(name) => format!(":{}:_files -/", name),
+            Value::Number(name) => format!(":{}: ", name),
+            Value::Choice(name, choices) => format!(":{}:({})", name, choices.join(" ")),
+        };
+        flags.push_str(&format!(
+            "                        '{}[{}]{}' \\\n",
+            flag.name,
+            zsh_escape(flag.help),
+            action
+        ));
+    }
+
+    format!(
+        r#"#compdef rustfin
+# Completação de rustfin para zsh: rustfin completions zsh > "${{fpath[1]}}/_rustfin"
+_rustfin() {{
+    local context state line
+    _arguments -C \
+        '1:comando:(({subcommands}))' \
+        '*::argumento:->args'
+
+    case $state in
+        args)
+            case $line[1] in
+                batch)
+                    _arguments \
+{flags}                        ;;
+                completions)
+                    _arguments '1:shell:({shells})' ;;
+            esac
+            ;;
+    esac
+}}
+_rustfin "$@"
+"#
+    )
+}
+
+fn fish() -> String {
+    let escape = |text: &str| text.replace('\\', "\\\\").replace('\'', "\\'");
+    let mut script = String::from("# Completação de rustfin para fish: rustfin completions fish > ~/.config/fish/completions/rustfin.fish\n");
+    script.push_str("complete -c rustfin -f\n");
+
+    for (name, help) in SUBCOMMANDS {
+        script.push_str(&format!(
+            "complete -c rustfin -n __fish_use_subcommand -a {} -d '{}'\n",
+            name,
+            escape(help)
+        ));
+    }
+    script.push_str(&format!(
+        "complete -c rustfin -n '__fish_seen_subcommand_from completions' -x -a '{}'\n",
+        names(Shell::all().iter().map(Shell::name))
+    ));
+
+    for flag in BATCH_FLAGS {
+        let value = match flag.value {
+            Value::None => String::new(),
+            Value::File(_) => " -r -F".to_string(),
+            Value::Dir(_) => " -x -a '(__fish_complete_directories)'".to_string(),
+            Value::Number(_) => " -x".to_string(),
+            Value::Choice(_, choices) => format!(" -x -a '{}'", choices.join(" ")),
+        };
+        script.push_str(&format!(
+            "complete -c rustfin -n '__fish_seen_subcommand_from batch' -l {}{} -d '{}'\n",
+            flag.name.trim_start_matches("--"),
+            value,
+            escape(flag.help)
+        ));
+    }
+
+    script
+}
+
+pub fn completions(shell: Shell) -> String {
+    match shell {
+        Shell::Bash => bash(),
+        Shell::Zsh => zsh(),
+        Shell::Fish => fish(),
+    }
+}
+
+fn roff(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('-', "\\-")
+}
+
+// Página de manual (seção 1) em roff: rustfin man > rustfin.1
+pub fn man_page() -> String {
+    let mut page = format!(
+        ".TH RUSTFIN 1 \"\" \"rustfin {}\" \"Comandos do usuário\"\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    page.push_str(".SH NOME\nrustfin \\- análise e previsão de séries históricas\n");
+    page.push_str(".SH SINOPSE\n.B rustfin\n.br\n");
+    page.push_str(".B rustfin batch\n\\fB\\-\\-countries\\fR \\fIarquivo\\fR [\\fIopções\\fR]\n.br\n");
+    page.push_str(&format!(
+        ".B rustfin completions\n{}\n.br\n.B rustfin man\n",
+        Shell::all()
+            .iter()
+            .map(|shell| format!("\\fI{}\\fR", shell.name()))
+            .collect::<Vec<_>>()
+            .join("|")
+    ));
+    page.push_str(".SH DESCRIÇÃO\nSem argumentos, abre a interface gráfica. ");
+    page.push_str("A configuração (token e provedor) é a mesma nos dois modos.\n");
+
+    page.push_str(".SH COMANDOS\n");
+    for (name, help) in SUBCOMMANDS {
+        page.push_str(&format!(".TP\n.B {}\n{}\n", name, roff(help)));
+    }
+
+    page.push_str(".SH OPÇÕES DE BATCH\n");
+    for flag in BATCH_FLAGS {
+        let value = flag
+            .value
+            .placeholder()
+            .map(|name| format!(" \\fI{}\\fR", roff(name)))
+            .unwrap_or_default();
+        page.push_str(&format!(".TP\n\\fB{}\\fR{}\n{}\n", roff(flag.name), value, roff(flag.help)));
+    }
+
+    page.push_str(".SH CÓDIGOS DE SAÍDA\n.TP\n.B 0\nsucesso\n");
+    for kind in ErrorKind::all() {
+        page.push_str(&format!(".TP\n.B {}\n{}\n", kind.exit_code(), roff(kind.description())));
+    }
+
+    page
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch;
+
+    #[test]
+    fn usage_lists_every_batch_flag() {
+        for flag in BATCH_FLAGS {
+            assert!(batch::USAGE.contains(flag.name), "{} ausente do uso", flag.name);
+        }
+    }
+
+    #[test]
+    fn completions_cover_subcommands_and_flags() {
+        for shell in Shell::all() {
+            let script = completions(shell);
+            for (name, _) in SUBCOMMANDS {
+                assert!(script.contains(name), "{} ausente em {}", name, shell.name());
+            }
+            assert!(script.contains("inflation unemployment"));
+            assert!(script.contains("countries"));
+        }
+        assert_eq!(Shell::parse("zsh"), Some(Shell::Zsh));
+        assert_eq!(Shell::parse("powershell"), None);
+    }
+
+    #[test]
+    fn man_page_escapes_dashes_and_lists_exit_codes() {
+        let page = man_page();
+
+        assert!(page.starts_with(".TH RUSTFIN 1"));
+        assert!(page.contains("\\fB\\-\\-horizon\\fR \\fIpassos\\fR"));
+        assert!(page.contains(".B 4\nnenhum modelo convergiu para algum país"));
+    }
+}
@@ -0,0 +1,273 @@
+use serde::de::DeserializeOwned;
+use reqwest::header::{HeaderMap, HeaderName, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::json_stream::ArrayStream;
+use crate::quota;
+
+// Cliente HTTP compartilhado pelas buscas. Recebido por parâmetro para que testes
+// possam usar um cliente próprio (e `config.url_base` apontando para um servidor simulado).
+// Aceita respostas compactadas (gzip e brotli), descompactadas pelo reqwest.
+#[derive(Debug, Clone, Default)]
+pub struct ApiClient {
+    http: reqwest::Client,
+    // Erro da configuração de rede (proxy ou certificados); com ele nenhuma requisição é enviada,
+    // para não contornar um proxy obrigatório com uma conexão direta
+    refused: Option<String>,
+}
+
+static SHARED: OnceLock<ApiClient> = OnceLock::new();
+
+// Resposta maior que `Config::max_response_bytes`, interrompida antes de ocupar mais memória
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadTooLarge {
+    pub limit: u64,
+}
+
+impl fmt::Display for PayloadTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Resposta do provedor maior que o limite de {} bytes (max_response_bytes no config.json)",
+            self.limit
+        )
+    }
+}
+
+impl std::error::Error for PayloadTooLarge {}
+
+// Validadores HTTP de uma resposta (ETag e Last-Modified), reenviados na busca seguinte para que
+// o provedor responda 304 quando nada mudou
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name: HeaderName| headers.get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        Self {
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+// Resultado de uma busca condicional
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conditional<T> {
+    // 304: a cópia local continua válida
+    NotModified,
+    Modified { body: T, validators: Validators },
+}
+
+// Lê o corpo em partes, desistindo assim que passar de `limit` bytes (0 sem limite)
+async fn read_limited(mut response: reqwest::Response, limit: u64) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let too_large = |size: u64| limit > 0 && size > limit;
+    if response.content_length().is_some_and(too_large) {
+        return Err(PayloadTooLarge { limit }.into());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if too_large(body.len() as u64) {
+            return Err(PayloadTooLarge { limit }.into());
+        }
+    }
+    Ok(body)
+}
+
+// Tempo máximo de um envio para fora do provedor, para que um destino lento não prenda a execução
+const POST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Elementos do array `field`, desserializados à medida que o corpo chega
+async fn read_series<T: DeserializeOwned>(
+    mut response: reqwest::Response,
+    limit: u64,
+    field: &str,
+) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+    if response.content_length().is_some_and(|size| limit > 0 && size > limit) {
+        return Err(PayloadTooLarge { limit }.into());
+    }
+
+    let mut stream = ArrayStream::new(field);
+    let mut received = 0;
+    while let Some(chunk) = response.chunk().await? {
+        received += chunk.len() as u64;
+        if limit > 0 && received > limit {
+            return Err(PayloadTooLarge { limit }.into());
+        }
+        stream.feed(&chunk)?;
+        if stream.is_done() {
+            break;
+        }
+    }
+
+    Ok(stream.finish()?)
+}
+
+impl ApiClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_client(http: reqwest::Client) -> Self {
+        Self { http, refused: None }
+    }
+
+    fn ensure_usable(&self) -> Result<(), Box<dyn std::error::Error>> {
+        match &self.refused {
+            Some(error) => Err(format!("Requisição não enviada: {}", error).into()),
+            None => Ok(()),
+        }
+    }
+
+    // Cliente com o proxy e os certificados raiz adicionais da configuração
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(proxy) = config.proxy.as_deref().filter(|proxy| !proxy.is_empty()) {
+            let proxy = reqwest::Proxy::all(proxy).map_err(|e| format!("Proxy inválido ({}): {}", proxy, e))?;
+            builder = builder.proxy(proxy);
+        }
+        for path in &config.ca_certificates {
+            let pem = std::fs::read(path).map_err(|e| format!("Erro ao ler o certificado {}: {}", path, e))?;
+            let certificate =
+                reqwest::Certificate::from_pem(&pem).map_err(|e| format!("Certificado inválido em {}: {}", path, e))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+
+        let http = builder.build().map_err(|e| format!("Erro ao criar o cliente HTTP: {}", e))?;
+        Ok(Self::with_client(http))
+    }
+
+    // Cria a instância compartilhada a partir da configuração; chamada na inicialização, antes
+    // da primeira busca. Se a instância já existir, ela é mantida. Com proxy ou certificado
+    // inválido, a instância criada recusa todas as requisições com o mesmo erro.
+    pub fn configure(config: &Config) -> Result<(), String> {
+        if SHARED.get().is_some() {
+            return Ok(());
+        }
+        let client = Self::from_config(config);
+        let refused = client.as_ref().err().cloned();
+        let _ = SHARED.set(client.unwrap_or_else(|error| Self {
+            refused: Some(error),
+            ..Self::default()
+        }));
+        refused.map_or(Ok(()), Err)
+    }
+
+    // Instância usada pela aplicação, reaproveitando conexões entre requisições
+    pub fn shared() -> &'static ApiClient {
+        SHARED.get_or_init(ApiClient::new)
+    }
+
+    // GET em `endpoint` (relativo a config.url_base) com o token da API; conta na cota diária
+    pub async fn get(
+        &self,
+        config: &Config,
+        endpoint: &str,
+        query: &[(&str, &str)],
+    ) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        self.send(config, endpoint, query, None).await
+    }
+
+    // Como `get`, enviando If-None-Match e If-Modified-Since com os validadores informados
+    async fn send(
+        &self,
+        config: &Config,
+        endpoint: &str,
+        query: &[(&str, &str)],
+        validators: Option<&Validators>,
+    ) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        self.ensure_usable()?;
+        quota::reserve(config)?;
+
+        let mut request = self
+            .http
+            .get(format!("{}{}", config.url_base, endpoint))
+            .query(query)
+            .query(&[("token", config.api_token.as_str())]);
+        if let Some(validators) = validators {
+            if let Some(etag) = &validators.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &validators.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = request.send().await?.error_for_status()?;
+
+        Ok(response)
+    }
+
+    pub async fn get_json<T: DeserializeOwned>(
+        &self,
+        config: &Config,
+        endpoint: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T, Box<dyn std::error::Error>> {
+        let response = self.get(config, endpoint, query).await?;
+        let body = read_limited(response, config.max_response_bytes).await?;
+
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    // Array `field` da resposta JSON (ex.: {"inflation": [...]}), lido à medida que o corpo chega;
+    // para séries longas, evita manter o corpo inteiro em memória junto dos valores
+    pub async fn get_series<T: DeserializeOwned>(
+        &self,
+        config: &Config,
+        endpoint: &str,
+        query: &[(&str, &str)],
+        field: &str,
+    ) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+        let response = self.get(config, endpoint, query).await?;
+        read_series(response, config.max_response_bytes, field).await
+    }
+
+    // Como `get_series`, mas condicional: com `validators` da busca anterior, o provedor pode
+    // responder 304 sem corpo
+    pub async fn get_series_if_modified<T: DeserializeOwned>(
+        &self,
+        config: &Config,
+        endpoint: &str,
+        query: &[(&str, &str)],
+        field: &str,
+        validators: Option<&Validators>,
+    ) -> Result<Conditional<Vec<T>>, Box<dyn std::error::Error>> {
+        let response = self.send(config, endpoint, query, validators).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(Conditional::NotModified);
+        }
+
+        let validators = Validators::from_headers(response.headers());
+        let body = read_series(response, config.max_response_bytes, field).await?;
+        Ok(Conditional::Modified { body, validators })
+    }
+
+    // POST de `body` em JSON para uma URL externa (ex.: webhooks); não envia o token nem conta na cota
+    pub async fn post_json<T: Serialize>(&self, url: &str, body: &T) -> Result<(), Box<dyn std::error::Error>> {
+        self.ensure_usable()?;
+        self.http
+            .post(url)
+            .timeout(POST_TIMEOUT)
+            .json(body)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
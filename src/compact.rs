@@ -0,0 +1,196 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::cache::CachedObservation;
+use crate::dates::{self, DateFormat};
+
+// Armazenamento compacto do cache (`Config::compact_storage`), para quem acompanha muitas séries
+// diárias longas. As datas viram dias com codificação delta, os valores são guardados em f32 e
+// comprimidos por XOR com o anterior (como no Gorilla); séries que mudam pouco ocupam poucos bits
+// por observação. A perda de precisão do f32 (~7 dígitos) não afeta séries com poucas casas.
+const MAGIC: &[u8; 4] = b"RFC1";
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    // Bits já usados no último byte
+    used: u8,
+}
+
+impl BitWriter {
+    fn push(&mut self, bit: bool) {
+        if self.used == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            if let Some(last) = self.bytes.last_mut() {
+                *last |= 0x80 >> self.used;
+            }
+        }
+        self.used = (self.used + 1) % 8;
+    }
+
+    fn write(&mut self, value: u64, bits: u32) {
+        for i in (0..bits).rev() {
+            self.push((value >> i) & 1 == 1);
+        }
+    }
+
+    // Inteiro com sinal em zigzag + varint: deltas pequenos, positivos ou negativos, ocupam um byte
+    fn write_signed(&mut self, value: i64) {
+        let mut zigzag = ((value << 1) ^ (value >> 63)) as u64;
+        loop {
+            let byte = zigzag & 0x7f;
+            zigzag >>= 7;
+            self.write(byte | if zigzag == 0 { 0 } else { 0x80 }, 8);
+            if zigzag == 0 {
+                break;
+            }
+        }
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    // Posição em bits
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, bits: u32) -> Option<u64> {
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.bytes.get(self.position / 8)?;
+            value = (value << 1) | u64::from((byte >> (7 - self.position % 8)) & 1);
+            self.position += 1;
+        }
+        Some(value)
+    }
+
+    fn read_signed(&mut self) -> Option<i64> {
+        let mut zigzag = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read(8)?;
+            zigzag |= (byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Some((zigzag >> 1) as i64 ^ -((zigzag & 1) as i64));
+            }
+        }
+        None
+    }
+}
+
+pub fn encode(observations: &[CachedObservation]) -> Result<Vec<u8>, String> {
+    let mut writer = BitWriter::default();
+    writer.write_signed(observations.len() as i64);
+
+    let (mut day, mut seen, mut bits) = (0i64, 0i64, 0u32);
+    for observation in observations {
+        let date = dates::parse(&observation.date, DateFormat::DayMonthYear)?;
+        let current = i64::from(date.num_days_from_ce());
+        writer.write_signed(current - day);
+        writer.write_signed(observation.first_seen as i64 - seen);
+        (day, seen) = (current, observation.first_seen as i64);
+
+        // Valor igual ao anterior: um bit. Senão, só os bits entre os zeros à esquerda e à
+        // direita do XOR, com a posição e o tamanho do trecho
+        let value = (observation.value as f32).to_bits();
+        let xor = value ^ bits;
+        if xor == 0 {
+            writer.push(false);
+        } else {
+            let (leading, trailing) = (xor.leading_zeros(), xor.trailing_zeros());
+            let meaningful = 32 - leading - trailing;
+            writer.push(true);
+            writer.write(u64::from(leading), 5);
+            writer.write(u64::from(meaningful - 1), 5);
+            writer.write(u64::from(xor >> trailing), meaningful);
+        }
+        bits = value;
+    }
+
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend(writer.bytes);
+    Ok(bytes)
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Vec<CachedObservation>, String> {
+    let body = bytes.strip_prefix(MAGIC).ok_or("Arquivo compacto inválido")?;
+    let mut reader = BitReader { bytes: body, position: 0 };
+    let truncated = || "Arquivo compacto truncado".to_string();
+
+    let len = reader.read_signed().ok_or_else(truncated)?;
+    let mut observations = Vec::with_capacity(len.clamp(0, 1 << 20) as usize);
+    let (mut day, mut seen, mut bits) = (0i64, 0i64, 0u32);
+    for _ in 0..len {
+        day += reader.read_signed().ok_or_else(truncated)?;
+        seen += reader.read_signed().ok_or_else(truncated)?;
+        if reader.read(1).ok_or_else(truncated)? == 1 {
+            let leading = reader.read(5).ok_or_else(truncated)? as u32;
+            let meaningful = reader.read(5).ok_or_else(truncated)? as u32 + 1;
+            let trailing = 32u32.checked_sub(leading + meaningful).ok_or("Arquivo compacto inválido")?;
+            bits ^= (reader.read(meaningful).ok_or_else(truncated)? as u32) << trailing;
+        }
+
+        let date = i32::try_from(day)
+            .ok()
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .ok_or("Data inválida no arquivo compacto")?;
+        observations.push(CachedObservation {
+            date: dates::canonical(date),
+            value: f64::from(f32::from_bits(bits)),
+            first_seen: seen as u64,
+        });
+    }
+
+    Ok(observations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(date: &str, value: f64, first_seen: u64) -> CachedObservation {
+        CachedObservation {
+            date: date.to_string(),
+            value,
+            first_seen,
+        }
+    }
+
+    #[test]
+    fn round_trips_with_f32_precision() {
+        let observations = vec![
+            observation("31/12/2023", 4.62, 1_704_100_000),
+            observation("30/11/2023", 4.68, 1_704_100_000),
+            observation("31/10/2023", 4.68, 1_704_100_000),
+            observation("30/09/2023", -0.5, 1_700_000_000),
+            observation("01/01/1990", 1_234.5, 1_600_000_000),
+        ];
+
+        let decoded = decode(&encode(&observations).unwrap()).unwrap();
+        assert_eq!(decoded.len(), observations.len());
+        for (decoded, original) in decoded.iter().zip(&observations) {
+            assert_eq!(decoded.date, original.date);
+            assert_eq!(decoded.first_seen, original.first_seen);
+            assert!((decoded.value - original.value).abs() < 1e-4, "{}", decoded.value);
+        }
+    }
+
+    #[test]
+    fn is_smaller_than_json_and_rejects_invalid_input() {
+        let observations: Vec<CachedObservation> = (0..1000)
+            .map(|day| {
+                let date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap() + chrono::Duration::days(day);
+                observation(&dates::canonical(date), 5.25 + (day / 30) as f64 * 0.25, 1_700_000_000)
+            })
+            .collect();
+
+        let encoded = encode(&observations).unwrap();
+        let json = serde_json::to_vec(&observations).unwrap();
+        assert!(encoded.len() * 10 < json.len(), "{} bytes", encoded.len());
+
+        assert!(decode(b"JSON").is_err());
+        assert!(decode(&encoded[..encoded.len() / 2]).is_err());
+        assert!(encode(&[observation("2023-12-31", 1.0, 0)]).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{date_sort_key, InflationData};
+
+// Subíndice do IPC (alimentação, habitação, transportes...) com seu peso na cesta, em %
+#[derive(Debug, Clone, Deserialize)]
+pub struct CpiComponent {
+    pub name: String,
+    pub weight: f64,
+    pub values: Vec<InflationData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComponentsRaw {
+    pub components: Vec<CpiComponent>,
+}
+
+// Requer um provedor que exponha `inflation/components`; a brapi não o oferece
+pub async fn get_cpi_components(
+    client: &ApiClient,
+    config: &Config,
+    country: &str,
+) -> Result<Vec<CpiComponent>, Box<dyn std::error::Error>> {
+    let query = [("country", country), ("historical", "true")];
+    let mut data: ComponentsRaw = client.get_json(config, "inflation/components", &query).await?;
+    for component in &mut data.components {
+        component.values.sort_by_key(|obs| date_sort_key(&obs.date));
+    }
+
+    Ok(data.components)
+}
+
+// Contribuição de cada componente para o índice cheio em uma data
+#[derive(Debug, Clone)]
+pub struct Contribution {
+    pub date: String,
+    // Na mesma ordem dos componentes; contribuição = peso normalizado × variação
+    pub by_component: Vec<f64>,
+}
+
+impl Contribution {
+    pub fn headline(&self) -> f64 {
+        self.by_component.iter().sum()
+    }
+}
+
+pub fn normalized_weights(components: &[CpiComponent]) -> Vec<f64> {
+    let weights: Vec<f64> = components.iter().map(|c| c.weight).collect();
+    normalize(&weights)
+}
+
+fn normalize(weights: &[f64]) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return vec![0.0; weights.len()];
+    }
+
+    weights.iter().map(|weight| weight / total).collect()
+}
+
+// Contribuições nas datas presentes em todos os componentes, em ordem cronológica
+pub fn contributions(components: &[CpiComponent]) -> Vec<Contribution> {
+    contributions_with(components, &normalized_weights(components))
+}
+
+// Como `contributions`, com pesos já normalizados na ordem dos componentes
+fn contributions_with(components: &[CpiComponent], weights: &[f64]) -> Vec<Contribution> {
+    let Some(first) = components.first() else {
+        return vec![];
+    };
+
+    first
+        .values
+        .iter()
+        .filter_map(|obs| {
+            let by_component = components
+                .iter()
+                .zip(weights)
+                .map(|(component, weight)| {
+                    component
+                        .values
+                        .iter()
+                        .find(|other| other.date == obs.date)
+                        .map(|other| other.value * weight)
+                })
+                .collect::<Option<Vec<f64>>>()?;
+
+            Some(Contribution {
+                date: obs.date.clone(),
+                by_component,
+            })
+        })
+        .collect()
+}
+
+// Gastos mensais do usuário por componente (pelo nome), que definem os pesos da inflação pessoal
+pub fn spending_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("spending.json"))
+}
+
+pub fn load_spending() -> BTreeMap<String, f64> {
+    spending_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_spending(spending: &BTreeMap<String, f64>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = spending_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(spending)?)?;
+
+    Ok(())
+}
+
+// Pesos da cesta pessoal, na ordem dos componentes: os gastos informados, normalizados; um
+// componente sem gasto informado fica com o peso oficial, na proporção do orçamento total
+pub fn personal_weights(components: &[CpiComponent], spending: &BTreeMap<String, f64>) -> Vec<f64> {
+    let official = normalized_weights(components);
+    let informed: f64 = components.iter().filter_map(|c| spending.get(&c.name)).sum();
+    let uninformed: f64 = components
+        .iter()
+        .zip(&official)
+        .filter(|(c, _)| !spending.contains_key(&c.name))
+        .map(|(_, weight)| weight)
+        .sum();
+    // Sem nenhum gasto informado, a cesta pessoal é a oficial
+    let budget = if uninformed < 1.0 && informed > 0.0 {
+        informed / (1.0 - uninformed)
+    } else {
+        1.0
+    };
+
+    let weights: Vec<f64> = components
+        .iter()
+        .zip(&official)
+        .map(|(c, weight)| spending.get(&c.name).map(|v| v.max(0.0)).unwrap_or(weight * budget))
+        .collect();
+    normalize(&weights)
+}
+
+// Inflação pessoal: a variação de cada componente ponderada pelos gastos do usuário, nas datas
+// presentes em todos os componentes
+pub fn personal_inflation(components: &[CpiComponent], spending: &BTreeMap<String, f64>) -> Vec<InflationData> {
+    contributions_with(components, &personal_weights(components, spending))
+        .into_iter()
+        .map(|contribution| InflationData {
+            value: contribution.headline(),
+            date: contribution.date,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(name: &str, weight: f64, values: &[f64]) -> CpiComponent {
+        CpiComponent {
+            name: name.to_string(),
+            weight,
+            values: values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| InflationData {
+                    date: format!("01/{:02}/2024", i + 1),
+                    value: *value,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn weights_inflation_by_personal_spending() {
+        let components = vec![
+            component("Alimentação", 25.0, &[1.0, 2.0]),
+            component("Transportes", 75.0, &[0.0, 4.0]),
+        ];
+
+        // Sem gastos informados, a inflação pessoal é a oficial
+        let official = personal_inflation(&components, &BTreeMap::new());
+        assert_eq!(official[1].value, 0.25 * 2.0 + 0.75 * 4.0);
+
+        let spending = BTreeMap::from([("Alimentação".to_string(), 3000.0), ("Transportes".to_string(), 1000.0)]);
+        let personal = personal_inflation(&components, &spending);
+        assert_eq!(personal.len(), 2);
+        assert_eq!(personal[0].value, 0.75);
+        assert_eq!(personal[1].value, 0.75 * 2.0 + 0.25 * 4.0);
+    }
+
+    #[test]
+    fn uninformed_components_keep_their_official_share() {
+        let components = vec![
+            component("Alimentação", 20.0, &[1.0]),
+            component("Habitação", 30.0, &[1.0]),
+            component("Transportes", 50.0, &[1.0]),
+        ];
+        let spending = BTreeMap::from([("Alimentação".to_string(), 1000.0), ("Habitação".to_string(), 3000.0)]);
+
+        // Alimentação e habitação somam 50% do orçamento, como na cesta oficial
+        let weights = personal_weights(&components, &spending);
+        assert!((weights[0] - 0.125).abs() < 1e-12);
+        assert!((weights[1] - 0.375).abs() < 1e-12);
+        assert!((weights[2] - 0.5).abs() < 1e-12);
+    }
+}
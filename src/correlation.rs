@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::stats;
+
+// Versão das séries usada na correlação
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    Levels,
+    Differences,
+    Returns,
+}
+
+impl Transform {
+    pub fn all() -> [Transform; 3] {
+        [Transform::Levels, Transform::Differences, Transform::Returns]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Transform::Levels => "Níveis",
+            Transform::Differences => "Diferenças",
+            Transform::Returns => "Retornos",
+        }
+    }
+
+    // Séries diferenciadas ficam datadas pela observação mais recente de cada par;
+    // retornos exigem valores positivos, como em `returns`
+    pub fn apply(&self, series: &[(String, f64)]) -> Vec<(String, f64)> {
+        match self {
+            Transform::Levels => series.to_vec(),
+            Transform::Differences => series
+                .windows(2)
+                .map(|w| (w[1].0.clone(), w[1].1 - w[0].1))
+                .collect(),
+            Transform::Returns => series
+                .windows(2)
+                .filter(|w| w[0].1 > 0.0 && w[1].1 > 0.0)
+                .map(|w| (w[1].0.clone(), w[1].1 / w[0].1 - 1.0))
+                .collect(),
+        }
+    }
+}
+
+// Mínimo de datas em comum para que a correlação de um par seja calculada
+const MIN_OVERLAP: usize = 3;
+
+pub fn pearson(x: &[f64], y: &[f64]) -> Option<f64> {
+    if x.len() != y.len() || x.len() < MIN_OVERLAP {
+        return None;
+    }
+
+    let (mean_x, mean_y) = (stats::mean(x), stats::mean(y));
+    let sxy: f64 = x.iter().zip(y).map(|(a, b)| (a - mean_x) * (b - mean_y)).sum();
+    let sxx: f64 = x.iter().map(|a| (a - mean_x).powi(2)).sum();
+    let syy: f64 = y.iter().map(|b| (b - mean_y).powi(2)).sum();
+
+    if sxx == 0.0 || syy == 0.0 {
+        return None;
+    }
+    Some(sxy / (sxx * syy).sqrt())
+}
+
+#[derive(Debug, Clone)]
+pub struct CorrelationMatrix {
+    pub names: Vec<String>,
+    // values[i][j]: correlação entre names[i] e names[j], None sem datas suficientes em comum
+    pub values: Vec<Vec<Option<f64>>>,
+    // Datas em comum de cada par
+    pub overlap: Vec<Vec<usize>>,
+}
+
+// Correlações par a par, cada uma sobre as datas comuns às duas séries
+pub fn matrix(series: &BTreeMap<String, Vec<(String, f64)>>, transform: Transform) -> CorrelationMatrix {
+    let names: Vec<String> = series.keys().cloned().collect();
+    let transformed: Vec<HashMap<String, f64>> = series
+        .values()
+        .map(|values| transform.apply(values).into_iter().collect())
+        .collect();
+
+    let n = names.len();
+    let mut values = vec![vec![None; n]; n];
+    let mut overlap = vec![vec![0; n]; n];
+
+    for i in 0..n {
+        for j in i..n {
+            let (x, y): (Vec<f64>, Vec<f64>) = transformed[i]
+                .iter()
+                .filter_map(|(date, a)| transformed[j].get(date).map(|b| (*a, *b)))
+                .unzip();
+
+            let correlation = if i == j && x.len() >= MIN_OVERLAP { Some(1.0) } else { pearson(&x, &y) };
+            values[i][j] = correlation;
+            values[j][i] = correlation;
+            overlap[i][j] = x.len();
+            overlap[j][i] = x.len();
+        }
+    }
+
+    CorrelationMatrix { names, values, overlap }
+}
+
+impl CorrelationMatrix {
+    // CSV com as séries nas linhas e colunas; pares sem correlação ficam vazios
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("series");
+        for name in &self.names {
+            csv.push(',');
+            csv.push_str(name);
+        }
+        csv.push('\n');
+
+        for (name, row) in self.names.iter().zip(&self.values) {
+            csv.push_str(name);
+            for value in row {
+                csv.push(',');
+                if let Some(value) = value {
+                    csv.push_str(&format!("{:.4}", value));
+                }
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(values: &[f64]) -> Vec<(String, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (format!("01/{:02}/2023", i + 1), *value))
+            .collect()
+    }
+
+    #[test]
+    fn detects_positive_and_negative_correlation() {
+        let mut panel = BTreeMap::new();
+        panel.insert("a".to_string(), series(&[1.0, 2.0, 4.0, 8.0]));
+        panel.insert("b".to_string(), series(&[2.0, 4.0, 8.0, 16.0]));
+        panel.insert("c".to_string(), series(&[8.0, 4.0, 2.0, 1.0]));
+
+        let result = matrix(&panel, Transform::Levels);
+
+        assert!((result.values[0][1].unwrap() - 1.0).abs() < 1e-12);
+        assert!(result.values[0][2].unwrap() < -0.8);
+        assert_eq!(result.values[1][0], result.values[0][1]);
+    }
+
+    #[test]
+    fn uses_only_common_dates_and_exports_csv() {
+        let mut panel = BTreeMap::new();
+        panel.insert("a".to_string(), series(&[1.0, 2.0, 3.0, 4.0, 5.0]));
+        panel.insert("b".to_string(), series(&[1.0, 2.0]));
+
+        let result = matrix(&panel, Transform::Differences);
+
+        assert_eq!(result.overlap[0][1], 1);
+        assert_eq!(result.values[0][1], None);
+        assert_eq!(result.to_csv(), "series,a,b\na,1.0000,\nb,,\n");
+    }
+}
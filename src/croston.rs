@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+// Mínimo de observações para escolher o parâmetro de suavização
+const MIN_OBSERVATIONS: usize = 3;
+// Valores do parâmetro de suavização avaliados: 0.01, 0.02, ..., 0.30 (faixa usual para demanda
+// intermitente)
+const ALPHA_GRID: usize = 30;
+// Intervalo médio entre valores não nulos a partir do qual a série é intermitente
+// (Syntetos, Boylan e Croston, 2005)
+const INTERMITTENT_INTERVAL: f64 = 1.32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Variant {
+    // Croston (1972): razão entre o tamanho e o intervalo suavizados
+    Classic,
+    // Syntetos e Boylan (2005): corrige o viés positivo da razão com o fator 1 − α/2
+    Sba,
+}
+
+// Método de Croston para séries intermitentes (muitos zeros): suaviza separadamente os valores
+// não nulos e os intervalos entre eles; a previsão é constante
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Croston {
+    pub alpha: f64,
+    pub variant: Variant,
+    // Valor não nulo suavizado
+    pub size: f64,
+    // Intervalo suavizado entre valores não nulos, em períodos
+    pub interval: f64,
+    // Períodos desde o último valor não nulo
+    pub since: usize,
+    // Se já houve algum valor não nulo; antes disso a previsão é zero
+    pub started: bool,
+    // Variância dos erros de um passo na série usada no ajuste
+    pub sigma2: f64,
+}
+
+// Intervalo médio entre valores não nulos, contado desde o início da série; None sem nenhum
+pub fn mean_interval(values: &[f64]) -> Option<f64> {
+    let demands = values.iter().filter(|value| **value != 0.0).count();
+    let last = values.iter().rposition(|value| *value != 0.0)?;
+    Some((last + 1) as f64 / demands as f64)
+}
+
+// Série com zeros frequentes, em que a suavização usual subestima os picos e superestima os zeros
+pub fn is_intermittent(values: &[f64]) -> bool {
+    mean_interval(values).is_some_and(|interval| interval >= INTERMITTENT_INTERVAL)
+}
+
+impl Croston {
+    fn empty(alpha: f64, variant: Variant) -> Self {
+        Self {
+            alpha,
+            variant,
+            size: 0.0,
+            interval: 1.0,
+            since: 0,
+            started: false,
+            sigma2: 0.0,
+        }
+    }
+
+    // α pelo menor erro de um passo na grade
+    pub fn fit(values: &[f64], variant: Variant) -> Option<Self> {
+        if values.len() < MIN_OBSERVATIONS || values.iter().any(|v| !v.is_finite()) {
+            return None;
+        }
+
+        (1..=ALPHA_GRID)
+            .map(|i| i as f64 / 100.0)
+            .map(|alpha| Self::with_params(values, alpha, variant))
+            .min_by(|a, b| a.sigma2.total_cmp(&b.sigma2))
+    }
+
+    // Aplica o parâmetro já escolhido a uma série (ex.: com observações novas)
+    pub fn with_params(values: &[f64], alpha: f64, variant: Variant) -> Self {
+        let mut croston = Self::empty(alpha, variant);
+        let mut sse = 0.0;
+        let mut errors = 0;
+        for value in values {
+            // Os períodos antes do primeiro valor não nulo não têm previsão
+            if croston.started {
+                let error = value - croston.rate();
+                sse += error * error;
+                errors += 1;
+            }
+            croston.update(*value);
+        }
+        croston.sigma2 = sse / errors.max(1) as f64;
+        croston
+    }
+
+    // Valor esperado por período
+    fn rate(&self) -> f64 {
+        if !self.started {
+            return 0.0;
+        }
+        let rate = self.size / self.interval;
+        match self.variant {
+            Variant::Classic => rate,
+            Variant::Sba => rate * (1.0 - self.alpha / 2.0),
+        }
+    }
+
+    // Incorpora uma observação nova, sem reescolher α; zeros só aumentam o intervalo corrente
+    pub fn update(&mut self, value: f64) {
+        self.since += 1;
+        if value == 0.0 {
+            return;
+        }
+
+        if self.started {
+            self.size += self.alpha * (value - self.size);
+            self.interval += self.alpha * (self.since as f64 - self.interval);
+        } else {
+            self.size = value;
+            self.interval = self.since as f64;
+            self.started = true;
+        }
+        self.since = 0;
+    }
+
+    pub fn forecast(&self, steps: usize) -> Vec<f64> {
+        vec![self.rate(); steps]
+    }
+}
+
+pub fn run_croston_model(values: &[f64], steps: usize) -> Result<Vec<f64>, String> {
+    let croston = Croston::fit(values, Variant::Sba)
+        .ok_or_else(|| format!("Série inválida para o método de Croston (mínimo de {} pontos)", MIN_OBSERVATIONS))?;
+    Ok(croston.forecast(steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regular_demand_forecasts_size_over_interval() {
+        // Um valor 6 a cada três períodos: taxa de 2 por período
+        let values: Vec<f64> = (0..30).map(|t| if t % 3 == 2 { 6.0 } else { 0.0 }).collect();
+
+        let classic = Croston::fit(&values, Variant::Classic).unwrap();
+        assert!((classic.forecast(1)[0] - 2.0).abs() < 1e-9);
+
+        let sba = Croston::fit(&values, Variant::Sba).unwrap();
+        let expected = 2.0 * (1.0 - sba.alpha / 2.0);
+        assert_eq!(sba.forecast(2), vec![expected; 2]);
+    }
+
+    #[test]
+    fn series_without_demand_forecasts_zero() {
+        assert_eq!(run_croston_model(&[0.0; 6], 3).unwrap(), vec![0.0; 3]);
+        assert!(run_croston_model(&[0.0, 1.0], 3).is_err());
+    }
+
+    #[test]
+    fn intermittency_uses_the_mean_interval() {
+        assert!(is_intermittent(&[0.0, 0.0, 3.0, 0.0, 2.0, 0.0]));
+        assert!(!is_intermittent(&[1.0, 2.0, 0.0, 3.0, 4.0]));
+        assert!(!is_intermittent(&[0.0; 4]));
+    }
+
+    #[test]
+    fn update_matches_smoothing_the_longer_series() {
+        let values = [0.0, 4.0, 0.0, 0.0, 5.0, 0.0, 3.0, 0.0, 0.0];
+        let mut croston = Croston::with_params(&values[..5], 0.2, Variant::Sba);
+
+        values[5..].iter().for_each(|value| croston.update(*value));
+
+        let full = Croston::with_params(&values, 0.2, Variant::Sba);
+        assert_eq!((croston.size, croston.interval, croston.since), (full.size, full.interval, full.since));
+        assert_eq!(croston.forecast(2), full.forecast(2));
+    }
+}
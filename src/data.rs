@@ -0,0 +1,430 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::future::Future;
+
+use crate::cache::{self, CachedObservation};
+use crate::client::{ApiClient, Conditional};
+use crate::config::Config;
+use crate::dates;
+use crate::quota::QuotaExceeded;
+
+// Países oferecidos na escolha do país (assistente e modo terminal)
+pub const COUNTRIES: [&str; 4] = ["brazil", "usa", "argentina", "chile"];
+
+pub(crate) fn string_to_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = Deserialize::deserialize(deserializer)?;
+    s.parse::<f64>().map_err(serde::de::Error::custom)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InflationData {
+    #[serde(deserialize_with = "dates::raw_date")]
+    pub date: String,
+    #[serde(deserialize_with = "string_to_f64")]
+    pub value: f64
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InflationRaw {
+    pub inflation: Vec<InflationData>
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnemploymentRaw {
+    pub unemployment: Vec<InflationData>
+}
+
+// Chave de ordenação cronológica: a brapi retorna datas como "DD/MM/AAAA"
+pub fn date_sort_key(date: &str) -> String {
+    let parts: Vec<&str> = date.split('/').collect();
+    match parts.as_slice() {
+        [day, month, year] => format!("{}-{}-{}", year, month, day),
+        _ => date.to_string(),
+    }
+}
+
+// Datas da resposta na forma canônica (DD/MM/AAAA), lidas no formato do provedor
+fn normalize_dates(config: &Config, data: Vec<InflationData>) -> Result<Vec<InflationData>, String> {
+    data.into_iter()
+        .map(|item| {
+            let date = dates::normalize(&item.date, config.date_format)?;
+            Ok(InflationData { date, ..item })
+        })
+        .collect()
+}
+
+// Parâmetros das séries históricas completas, da mais recente para a mais antiga
+const HISTORICAL_QUERY: [(&str, &str); 3] = [("historical", "true"), ("sortBy", "date"), ("sortOrder", "desc")];
+
+// Consulta da série histórica de `country`; com `since` (já no formato do provedor) e um provedor
+// que aceite a data inicial (`since_param`), só as observações a partir dela
+fn historical_query<'a>(config: &'a Config, country: &'a str, since: Option<&'a str>) -> Vec<(&'a str, &'a str)> {
+    let mut query = vec![("country", country)];
+    query.extend(HISTORICAL_QUERY);
+    if let (Some(param), Some(since)) = (config.since_param.as_deref(), since) {
+        query.push((param, since));
+    }
+    query
+}
+
+struct RustFin<'a> {
+    client: &'a ApiClient,
+    config: Config,
+    country: String,
+}
+
+pub struct HistoricalSeriesDates {
+    pub date: String,
+}
+
+pub struct HistoricalSeriesValues {
+    pub date: String,
+    pub value: f64,
+}
+
+pub async fn get_historical_inflation(
+    client: &ApiClient,
+    config: &Config,
+    country: &str,
+) -> Result<InflationRaw, Box<dyn std::error::Error>> {
+    let query = historical_query(config, country, None);
+    let inflation = normalize_dates(config, client.get_series(config, "inflation", &query, "inflation").await?)?;
+
+    Ok(InflationRaw { inflation })
+}
+
+// Taxa de desemprego; requer um provedor que exponha o endpoint `unemployment`
+// no mesmo formato do de inflação (a brapi não o oferece)
+pub async fn get_historical_unemployment(
+    client: &ApiClient,
+    config: &Config,
+    country: &str,
+) -> Result<UnemploymentRaw, Box<dyn std::error::Error>> {
+    let query = historical_query(config, country, None);
+    let unemployment = client.get_series(config, "unemployment", &query, "unemployment").await?;
+    let unemployment = normalize_dates(config, unemployment)?;
+
+    Ok(UnemploymentRaw { unemployment })
+}
+
+// Indicadores que podem ser buscados no provedor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Indicator {
+    Inflation,
+    Unemployment,
+}
+
+impl Indicator {
+    pub fn all() -> [Indicator; 2] {
+        [Indicator::Inflation, Indicator::Unemployment]
+    }
+
+    // Nome usado em arquivos e séries, ex.: inflation_brazil
+    pub fn key(&self) -> &'static str {
+        match self {
+            Indicator::Inflation => "inflation",
+            Indicator::Unemployment => "unemployment",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Indicator::Inflation => "Inflação",
+            Indicator::Unemployment => "Desemprego",
+        }
+    }
+
+    // Indicador de uma série nomeada como "<indicador>_<país>"
+    pub fn from_series_name(name: &str) -> Option<Indicator> {
+        Indicator::all()
+            .into_iter()
+            .find(|indicator| name.starts_with(&format!("{}_", indicator.key())))
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Indicator::Inflation => "% em 12 meses",
+            Indicator::Unemployment => "% da força de trabalho",
+        }
+    }
+
+    // Faixa de valores plausíveis, em %; fora dela a série é considerada corrompida
+    pub fn plausible_range(&self) -> (f64, f64) {
+        match self {
+            Indicator::Inflation => (-50.0, 10_000.0),
+            Indicator::Unemployment => (0.0, 100.0),
+        }
+    }
+}
+
+// Provedor que atendeu uma busca e as falhas dos que vinham antes dele na cadeia. A biblioteca não
+// as imprime: quem buscou decide como informá-las (barra de status, saída do modo em lote...).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Source {
+    pub provider: String,
+    pub failures: Vec<String>,
+    // Falhas locais que não impediram a busca (ex.: ao gravar o cache)
+    pub warnings: Vec<String>,
+}
+
+impl Source {
+    // Os dados vieram de um provedor reserva
+    pub fn is_fallback(&self) -> bool {
+        !self.failures.is_empty()
+    }
+}
+
+// Executa `fetch` em cada provedor da cadeia do indicador até que um responda. O limite diário
+// vale para todos, então atingi-lo interrompe a cadeia; com um só provedor o erro é o original.
+async fn with_failover<T, F, Fut>(
+    config: &Config,
+    indicator: Indicator,
+    country: &str,
+    fetch: F,
+) -> Result<(T, Source), Box<dyn std::error::Error>>
+where
+    F: Fn(Config) -> Fut,
+    Fut: Future<Output = Result<T, Box<dyn std::error::Error>>>,
+{
+    let chain = config.provider_chain(indicator.key());
+    let mut failures = Vec::new();
+
+    for provider in &chain {
+        let result = fetch(config.with_provider(provider)).await;
+        match result {
+            Ok(data) => {
+                let source = Source {
+                    provider: provider.name.clone(),
+                    failures,
+                    warnings: Vec::new(),
+                };
+                return Ok((data, source));
+            }
+            Err(e) if chain.len() == 1 || e.is::<QuotaExceeded>() => return Err(e),
+            Err(e) => failures.push(format!("{}: {}", provider.name, e)),
+        }
+    }
+
+    let series = format!("{}_{}", indicator.key(), country);
+    Err(format!("Todos os provedores falharam ao buscar {} ({})", series, failures.join("; ")).into())
+}
+
+// Data inicial canônica no formato em que o provedor da configuração a espera
+fn provider_date(config: &Config, since: Option<&str>) -> Result<Option<String>, String> {
+    since.map(|date| dates::format(date, config.date_format)).transpose()
+}
+
+async fn fetch_indicator(
+    client: &ApiClient,
+    config: Config,
+    indicator: Indicator,
+    country: &str,
+    since: Option<&str>,
+) -> Result<Vec<InflationData>, Box<dyn std::error::Error>> {
+    let since = provider_date(&config, since)?;
+    let query = historical_query(&config, country, since.as_deref());
+    let endpoint = indicator.key();
+    let data = client.get_series(&config, endpoint, &query, endpoint).await?;
+    Ok(normalize_dates(&config, data)?)
+}
+
+// Observações de um indicador a partir de `since` (DD/MM/AAAA, inclusive; cada provedor a recebe
+// no seu `date_format`), em ordem cronológica, e o provedor que as forneceu. Provedores sem
+// `since_param` devolvem a série inteira.
+pub async fn get_indicator_since(
+    client: &ApiClient,
+    config: &Config,
+    indicator: Indicator,
+    country: &str,
+    since: Option<&str>,
+) -> Result<(Vec<InflationData>, Source), Box<dyn std::error::Error>> {
+    let (mut data, source) = with_failover(config, indicator, country, |config| {
+        fetch_indicator(client, config, indicator, country, since)
+    })
+    .await?;
+
+    data.sort_by_key(|item| date_sort_key(&item.date));
+    Ok((data, source))
+}
+
+// Série de um indicador em ordem cronológica e o provedor que a forneceu
+pub async fn get_indicator_with_source(
+    client: &ApiClient,
+    config: &Config,
+    indicator: Indicator,
+    country: &str,
+) -> Result<(Vec<InflationData>, Source), Box<dyn std::error::Error>> {
+    get_indicator_since(client, config, indicator, country, None).await
+}
+
+// Série completa a partir do cache e das observações buscadas desde a última data dele; as
+// buscadas prevalecem nas datas em comum (a última do cache pode ter sido revisada)
+pub fn merge_since(cached: &[CachedObservation], fresh: Vec<InflationData>) -> Vec<InflationData> {
+    let fetched: HashSet<&str> = fresh.iter().map(|item| item.date.as_str()).collect();
+    let mut merged: Vec<InflationData> = cached
+        .iter()
+        .filter(|obs| !fetched.contains(obs.date.as_str()))
+        .map(|obs| InflationData {
+            date: obs.date.clone(),
+            value: obs.value,
+        })
+        .collect();
+    merged.extend(fresh);
+    merged.sort_by_key(|item| date_sort_key(&item.date));
+    merged
+}
+
+// Série de um indicador em ordem cronológica
+pub async fn get_indicator(
+    client: &ApiClient,
+    config: &Config,
+    indicator: Indicator,
+    country: &str,
+) -> Result<Vec<InflationData>, Box<dyn std::error::Error>> {
+    Ok(get_indicator_with_source(client, config, indicator, country).await?.0)
+}
+
+impl<'a> RustFin<'a> {
+    fn new(client: &'a ApiClient, config: &Config, country: &str) -> Self {
+        Self {
+            client,
+            config: config.clone(),
+            country: country.to_string(),
+        }
+    }
+
+    // Com cache, pede só as observações desde a última data dele e as completa com as guardadas;
+    // a busca é condicional (ETag/Last-Modified), e um 304 mantém o cache como está
+    async fn make_historical_array(&self) -> Result<(Vec<InflationData>, Source), Box<dyn std::error::Error>> {
+        let cached = cache::load(&self.country);
+        let since = cached
+            .iter()
+            .map(|obs| obs.date.as_str())
+            .max_by_key(|date| date_sort_key(date));
+        let series = format!("{}_{}", Indicator::Inflation.key(), self.country);
+        let mut validators = cache::load_validators();
+
+        let ((url_base, response), mut source) =
+            with_failover(&self.config, Indicator::Inflation, &self.country, |config| {
+                // Sem cache não há cópia local a reaproveitar
+                let known = validators
+                    .get(&cache::validators_key(&series, &config.url_base))
+                    .filter(|_| !cached.is_empty())
+                    .cloned();
+                async move {
+                    let since = provider_date(&config, since)?;
+                    let query = historical_query(&config, &self.country, since.as_deref());
+                    let response = self
+                        .client
+                        .get_series_if_modified(&config, "inflation", &query, "inflation", known.as_ref())
+                        .await?;
+                    let response = match response {
+                        Conditional::Modified { body, validators } => Conditional::Modified {
+                            body: normalize_dates(&config, body)?,
+                            validators,
+                        },
+                        Conditional::NotModified => Conditional::NotModified,
+                    };
+                    Ok::<_, Box<dyn std::error::Error>>((config.url_base, response))
+                }
+            })
+            .await?;
+
+        let fresh = match response {
+            Conditional::NotModified => Vec::new(),
+            Conditional::Modified { body, validators: received } => {
+                let key = cache::validators_key(&series, &url_base);
+                let changed = if received.is_empty() {
+                    validators.remove(&key).is_some()
+                } else {
+                    validators.insert(key, received.clone()) != Some(received)
+                };
+                if changed {
+                    if let Err(e) = cache::save_validators(&validators) {
+                        source.warnings.push(format!("Erro ao salvar os validadores de {}: {}", series, e));
+                    }
+                }
+                body
+            }
+        };
+
+        Ok((merge_since(&cached, fresh), source))
+    }
+}
+
+pub async fn get_historical_data(
+    client: &ApiClient,
+    config: &Config,
+    country: &str,
+) -> Result<Vec<HistoricalSeriesValues>, Box<dyn std::error::Error>> {
+    Ok(get_historical_data_with_source(client, config, country).await?.0)
+}
+
+// Como `get_historical_data`, informando também o provedor que atendeu
+pub async fn get_historical_data_with_source(
+    client: &ApiClient,
+    config: &Config,
+    country: &str,
+) -> Result<(Vec<HistoricalSeriesValues>, Source), Box<dyn std::error::Error>> {
+    let rust_fin = RustFin::new(client, config, country);
+    let (mut inflation_data, mut source) = rust_fin.make_historical_array().await?;
+
+    // Falhas no cache local não impedem o uso dos dados recém-buscados
+    if let Err(e) = cache::update(config, country, &inflation_data) {
+        source.warnings.push(format!("Erro ao atualizar o cache de {}: {}", country, e));
+    }
+
+    // O provedor retorna da mais recente para a mais antiga; os modelos esperam ordem cronológica
+    inflation_data.sort_by_key(|item| date_sort_key(&item.date));
+
+    let mut values: Vec<HistoricalSeriesValues> = Vec::new();
+    let mut dates: Vec<HistoricalSeriesDates> = Vec::new();
+
+    for item in inflation_data {
+        let date = HistoricalSeriesDates {
+            date: item.date.to_string(),
+        };
+
+        let value = HistoricalSeriesValues {
+            date: item.date.to_string(),
+            value: item.value,
+        };
+
+        values.push(value);
+        dates.push(date);
+    }
+
+    Ok((values, source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(date: &str, value: f64) -> InflationData {
+        InflationData {
+            date: date.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn merge_since_prefers_fetched_observations() {
+        let cached: Vec<CachedObservation> = [("01/01/2024", 4.5), ("01/02/2024", 4.4)]
+            .into_iter()
+            .map(|(date, value)| CachedObservation {
+                date: date.to_string(),
+                value,
+                first_seen: 0,
+            })
+            .collect();
+
+        let merged = merge_since(&cached, vec![observation("01/03/2024", 4.2), observation("01/02/2024", 4.3)]);
+
+        let merged: Vec<(&str, f64)> = merged.iter().map(|obs| (obs.date.as_str(), obs.value)).collect();
+        assert_eq!(merged, vec![("01/01/2024", 4.5), ("01/02/2024", 4.3), ("01/03/2024", 4.2)]);
+    }
+}
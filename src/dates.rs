@@ -0,0 +1,152 @@
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+// Formato das datas de um provedor. A brapi usa DD/MM/AAAA, que é também a forma em que as datas
+// são guardadas no cache e exibidas; as dos demais são convertidas para ela ao chegar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateFormat {
+    // Reconhece qualquer um dos formatos abaixo
+    #[default]
+    Auto,
+    // 31/12/2023
+    DayMonthYear,
+    // 2023-12-31
+    Iso,
+    // 12/2023, lido como o primeiro dia do mês
+    MonthYear,
+    // Milissegundos desde 1970-01-01 (UTC)
+    EpochMillis,
+    // 2023Q4, lido como o primeiro dia do trimestre
+    Quarter,
+}
+
+impl DateFormat {
+    fn explicit() -> [DateFormat; 5] {
+        [
+            DateFormat::DayMonthYear,
+            DateFormat::Iso,
+            DateFormat::MonthYear,
+            DateFormat::Quarter,
+            DateFormat::EpochMillis,
+        ]
+    }
+}
+
+fn parse_with(raw: &str, format: DateFormat) -> Option<NaiveDate> {
+    match format {
+        // Sem a dica, só números longos são milissegundos (um ano como "2023" seria 1970)
+        DateFormat::Auto => DateFormat::explicit()
+            .into_iter()
+            .filter(|format| *format != DateFormat::EpochMillis || raw.len() >= 10)
+            .find_map(|format| parse_with(raw, format)),
+        DateFormat::DayMonthYear => NaiveDate::parse_from_str(raw, "%d/%m/%Y").ok(),
+        DateFormat::Iso => NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok(),
+        DateFormat::MonthYear => {
+            let (month, year) = raw.split_once('/')?;
+            NaiveDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, 1)
+        }
+        DateFormat::EpochMillis => {
+            let millis: i64 = raw.parse().ok()?;
+            DateTime::from_timestamp_millis(millis).map(|moment| moment.date_naive())
+        }
+        DateFormat::Quarter => {
+            let upper = raw.to_uppercase();
+            let (year, quarter) = upper.split_once('Q')?;
+            let quarter: u32 = quarter.parse().ok().filter(|quarter| (1..=4).contains(quarter))?;
+            NaiveDate::from_ymd_opt(year.parse().ok()?, 3 * (quarter - 1) + 1, 1)
+        }
+    }
+}
+
+pub fn parse(raw: &str, format: DateFormat) -> Result<NaiveDate, String> {
+    parse_with(raw.trim(), format).ok_or_else(|| format!("Data em formato não reconhecido: {}", raw))
+}
+
+// Forma canônica (DD/MM/AAAA) usada no restante da aplicação
+pub fn canonical(date: NaiveDate) -> String {
+    format!("{:02}/{:02}/{:04}", date.day(), date.month(), date.year())
+}
+
+pub fn normalize(raw: &str, format: DateFormat) -> Result<String, String> {
+    parse(raw, format).map(canonical)
+}
+
+// Inverso de `normalize`: data canônica no formato do provedor, para enviá-la numa consulta.
+// Sem dica (`Auto`) vale a forma canônica.
+pub fn format(date: &str, format: DateFormat) -> Result<String, String> {
+    let date = parse(date, DateFormat::DayMonthYear)?;
+    Ok(match format {
+        DateFormat::Auto | DateFormat::DayMonthYear => canonical(date),
+        DateFormat::Iso => date.format("%Y-%m-%d").to_string(),
+        DateFormat::MonthYear => format!("{:02}/{:04}", date.month(), date.year()),
+        DateFormat::EpochMillis => date.and_time(NaiveTime::MIN).and_utc().timestamp_millis().to_string(),
+        DateFormat::Quarter => format!("{}Q{}", date.year(), date.month0() / 3 + 1),
+    })
+}
+
+// Aceita a data como texto ou como número (milissegundos), mantendo-a como texto até a
+// normalização
+pub(crate) fn raw_date<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Text(String),
+        Number(i64),
+    }
+
+    Ok(match Raw::deserialize(deserializer)? {
+        Raw::Text(text) => text,
+        Raw::Number(number) => number.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_provider_formats() {
+        let cases = [
+            ("31/12/2023", "31/12/2023"),
+            ("2023-12-31", "31/12/2023"),
+            ("12/2023", "01/12/2023"),
+            ("2023Q3", "01/07/2023"),
+            ("2023q1", "01/01/2023"),
+            ("1704067200000", "01/01/2024"),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(normalize(raw, DateFormat::Auto).unwrap(), expected, "{}", raw);
+        }
+    }
+
+    #[test]
+    fn hints_restrict_the_format() {
+        assert_eq!(normalize("2023-12-31", DateFormat::Iso).unwrap(), "31/12/2023");
+        assert!(normalize("31/12/2023", DateFormat::Iso).is_err());
+        assert!(normalize("2023Q5", DateFormat::Auto).unwrap_err().contains("2023Q5"));
+        assert!(normalize("13/2023", DateFormat::MonthYear).is_err());
+        assert_eq!(normalize("86400000", DateFormat::EpochMillis).unwrap(), "02/01/1970");
+        assert!(normalize("2023", DateFormat::Auto).is_err());
+    }
+
+    #[test]
+    fn formats_back_to_the_provider_format() {
+        let cases = [
+            (DateFormat::Auto, "01/07/2023"),
+            (DateFormat::DayMonthYear, "01/07/2023"),
+            (DateFormat::Iso, "2023-07-01"),
+            (DateFormat::MonthYear, "07/2023"),
+            (DateFormat::Quarter, "2023Q3"),
+            (DateFormat::EpochMillis, "1688169600000"),
+        ];
+        for (hint, expected) in cases {
+            assert_eq!(format("01/07/2023", hint).unwrap(), expected, "{:?}", hint);
+            assert_eq!(normalize(expected, hint).unwrap(), "01/07/2023");
+        }
+        assert!(format("2023-07-01", DateFormat::Iso).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+// Redução de pontos para desenhar séries longas, restrita à faixa visível: ao aproximar o zoom,
+// menos pontos caem nela e a resolução completa volta. Além dos pontos escolhidos, ficam o
+// primeiro e o último ponto visíveis, o vizinho de cada lado (para a linha chegar à borda) e os
+// extremos da série (para o ajuste automático dos eixos continuar enxergando a série inteira).
+
+// Aplica `reduce` aos pontos visíveis quando são mais que `limit`; `points` em ordem crescente de x
+fn within_view(
+    points: &[[f64; 2]],
+    visible: (f64, f64),
+    limit: usize,
+    reduce: impl FnOnce(&[[f64; 2]], &mut Vec<[f64; 2]>),
+) -> Vec<[f64; 2]> {
+    let (start, end) = visible;
+    if points.len() <= limit || !(start.is_finite() && end.is_finite() && start < end) {
+        return points.to_vec();
+    }
+
+    let first = points.partition_point(|point| point[0] < start);
+    let last = points.partition_point(|point| point[0] <= end);
+    let mut decimated = Vec::with_capacity(limit + 6);
+    for i in [0, first.saturating_sub(1), first] {
+        push(&mut decimated, points.get(i));
+    }
+    reduce(&points[first..last], &mut decimated);
+    for i in [last.saturating_sub(1), last, points.len() - 1] {
+        push(&mut decimated, points.get(i));
+    }
+    decimated
+}
+
+// Em cada coluna de pixels (`columns` é a largura do gráfico), o menor e o maior valor dos pontos
+// que caem nela, o que preserva picos e vales
+pub fn min_max(points: &[[f64; 2]], visible: (f64, f64), columns: usize) -> Vec<[f64; 2]> {
+    let columns = columns.max(1);
+    within_view(points, visible, 2 * columns, |shown, decimated| {
+        let width = (visible.1 - visible.0) / columns as f64;
+        let mut bucket: Option<(usize, [f64; 2], [f64; 2])> = None;
+        for point in shown {
+            let column = (((point[0] - visible.0) / width) as usize).min(columns - 1);
+            bucket = match bucket {
+                Some((current, low, high)) if current == column => Some((
+                    column,
+                    if point[1] < low[1] { *point } else { low },
+                    if point[1] > high[1] { *point } else { high },
+                )),
+                previous => {
+                    push_bucket(decimated, previous);
+                    Some((column, *point, *point))
+                }
+            };
+        }
+        push_bucket(decimated, bucket);
+    })
+}
+
+// Largest-Triangle-Three-Buckets (Steinarsson, 2013): divide os pontos visíveis em `threshold`
+// grupos e escolhe, em cada um, o ponto que forma o maior triângulo com o escolhido no grupo
+// anterior e a média do seguinte. Mantém a forma da série (tendência, picos, quebras) com um
+// ponto por grupo, mais suave que o mínimo e o máximo por coluna.
+pub fn lttb(points: &[[f64; 2]], visible: (f64, f64), threshold: usize) -> Vec<[f64; 2]> {
+    let threshold = threshold.max(3);
+    within_view(points, visible, threshold, |shown, decimated| {
+        if shown.len() <= threshold {
+            for point in shown {
+                push(decimated, Some(point));
+            }
+            return;
+        }
+
+        let every = (shown.len() - 2) as f64 / (threshold - 2) as f64;
+        let bucket = |i: usize| ((i as f64 * every) as usize + 1).min(shown.len() - 1);
+        let mut chosen = shown[0];
+        push(decimated, Some(&chosen));
+        for i in 0..threshold - 2 {
+            let next = &shown[bucket(i + 1)..bucket(i + 2).max(bucket(i + 1) + 1)];
+            let average = next.iter().fold([0.0, 0.0], |sum, point| [sum[0] + point[0], sum[1] + point[1]]);
+            let average = [average[0] / next.len() as f64, average[1] / next.len() as f64];
+
+            let area = |point: &[f64; 2]| {
+                ((chosen[0] - average[0]) * (point[1] - chosen[1]) - (chosen[0] - point[0]) * (average[1] - chosen[1]))
+                    .abs()
+            };
+            let candidates = &shown[bucket(i)..bucket(i + 1)];
+            if let Some(best) = candidates.iter().max_by(|a, b| area(a).total_cmp(&area(b))) {
+                chosen = *best;
+                push(decimated, Some(&chosen));
+            }
+        }
+        push(decimated, shown.last());
+    })
+}
+
+// Acrescenta o ponto se estiver à direita do último, evitando repetições
+fn push(decimated: &mut Vec<[f64; 2]>, point: Option<&[f64; 2]>) {
+    if let Some(point) = point {
+        if decimated.last().filter(|last| last[0] >= point[0]).is_none() {
+            decimated.push(*point);
+        }
+    }
+}
+
+// Menor e maior valor da coluna, na ordem em que aparecem na série
+fn push_bucket(decimated: &mut Vec<[f64; 2]>, bucket: Option<(usize, [f64; 2], [f64; 2])>) {
+    if let Some((_, low, high)) = bucket {
+        let (left, right) = if low[0] <= high[0] { (low, high) } else { (high, low) };
+        push(decimated, Some(&left));
+        push(decimated, Some(&right));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(len: usize) -> Vec<[f64; 2]> {
+        (0..len).map(|x| [x as f64, (x as f64 * 0.1).sin()]).collect()
+    }
+
+    #[test]
+    fn keeps_peaks_within_each_column() {
+        let mut points = series(100_000);
+        points[50_000][1] = 10.0;
+        points[70_000][1] = -10.0;
+
+        let decimated = min_max(&points, (0.0, 99_999.0), 800);
+        assert!(decimated.len() <= 2 * 800 + 6);
+        assert!(decimated.contains(&[50_000.0, 10.0]));
+        assert!(decimated.contains(&[70_000.0, -10.0]));
+        assert!(decimated.windows(2).all(|pair| pair[0][0] < pair[1][0]));
+        assert_eq!(decimated.first(), points.first());
+        assert_eq!(decimated.last(), points.last());
+    }
+
+    #[test]
+    fn restricts_to_the_visible_range() {
+        let points = series(100_000);
+
+        let decimated = min_max(&points, (1_000.0, 2_000.0), 100);
+        // Extremos da série, vizinhos e pontos das bordas e até dois pontos por coluna
+        assert!(decimated.len() <= 2 * 100 + 6);
+        assert_eq!(decimated[0], points[0]);
+        assert_eq!(decimated[1], points[999]);
+        assert_eq!(decimated[decimated.len() - 2], points[2_001]);
+
+        assert_eq!(min_max(&points[..150], (0.0, 149.0), 100).len(), 150);
+        assert_eq!(min_max(&points, (f64::NEG_INFINITY, 0.0), 100).len(), 100_000);
+    }
+
+    #[test]
+    fn lttb_keeps_the_shape_with_one_point_per_bucket() {
+        let mut points: Vec<[f64; 2]> = (0..30_000).map(|x| [x as f64, 0.0]).collect();
+        points[12_345][1] = 5.0;
+
+        let decimated = lttb(&points, (0.0, 29_999.0), 500);
+        assert!(decimated.len() <= 500 + 6);
+        assert!(decimated.contains(&[12_345.0, 5.0]));
+        assert!(decimated.windows(2).all(|pair| pair[0][0] < pair[1][0]));
+        assert_eq!(decimated.first(), points.first());
+        assert_eq!(decimated.last(), points.last());
+
+        // Com zoom, a faixa visível tem menos pontos que o limite e volta inteira
+        let zoomed = lttb(&points, (12_000.0, 12_400.0), 500);
+        assert!(zoomed.iter().filter(|point| (12_000.0..=12_400.0).contains(&point[0])).count() == 401);
+    }
+}
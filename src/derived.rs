@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use crate::cache;
+use crate::data::date_sort_key;
+use crate::expr;
+
+// Série definida pelo usuário a partir de outras, ex.: "spread = inflation_usa - inflation_brazil"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DerivedSeries {
+    pub name: String,
+    pub expression: String,
+}
+
+pub fn derived_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("derived.json"))
+}
+
+pub fn load() -> Vec<DerivedSeries> {
+    derived_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(definitions: &[DerivedSeries]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = derived_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(definitions)?)?;
+
+    Ok(())
+}
+
+// Séries disponíveis no cache local, nomeadas pelo arquivo (ex.: inflation_brazil),
+// como pares (data, valor)
+pub fn cached_series() -> BTreeMap<String, Vec<(String, f64)>> {
+    let mut series = BTreeMap::new();
+    let entries = match cache::cache_dir().and_then(|dir| std::fs::read_dir(dir).ok()) {
+        Some(entries) => entries,
+        None => return series,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let Some(country) = stem.strip_prefix("inflation-") else {
+            continue;
+        };
+
+        let observations = cache::load_shared(country)
+            .iter()
+            .map(|obs| (obs.date.clone(), obs.value))
+            .collect();
+        series.insert(format!("inflation_{}", country), observations);
+    }
+
+    series
+}
+
+// Avalia a definição nas datas presentes em todas as séries referenciadas,
+// em ordem cronológica. Derivadas definidas antes podem ser usadas nas seguintes.
+pub fn evaluate(
+    definition: &DerivedSeries,
+    series: &BTreeMap<String, Vec<(String, f64)>>,
+) -> Result<Vec<(String, f64)>, String> {
+    let expr = expr::parse(&definition.expression)?;
+    let names = expr.series();
+
+    for name in &names {
+        if !series.contains_key(name) {
+            return Err(format!("Série desconhecida: {}", name));
+        }
+    }
+
+    // Sem séries referenciadas a expressão é constante e não há datas para avaliar
+    let Some(first) = names.first() else {
+        return Err("A expressão não referencia nenhuma série".to_string());
+    };
+
+    let mut result: Vec<(String, f64)> = series[first]
+        .iter()
+        .filter_map(|(date, _)| {
+            let values: HashMap<String, f64> = names
+                .iter()
+                .map(|name| {
+                    series[name]
+                        .iter()
+                        .find(|(other, _)| other == date)
+                        .map(|(_, value)| (name.clone(), *value))
+                })
+                .collect::<Option<_>>()?;
+
+            expr.eval(&values).map(|value| (date.clone(), value))
+        })
+        .collect();
+
+    result.sort_by_key(|(date, _)| date_sort_key(date));
+    Ok(result)
+}
+
+// Séries em cache mais todas as derivadas que puderam ser avaliadas
+pub fn all_series(definitions: &[DerivedSeries]) -> BTreeMap<String, Vec<(String, f64)>> {
+    let mut series = cached_series();
+    for definition in definitions {
+        if let Ok(values) = evaluate(definition, &series) {
+            series.insert(definition.name.clone(), values);
+        }
+    }
+    series
+}
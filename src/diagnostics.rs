@@ -0,0 +1,218 @@
+use crate::stats::{autocorrelation, mean};
+
+// Valor crítico de 5% da estatística t de Dickey-Fuller com constante (assintótico, MacKinnon)
+pub const DICKEY_FULLER_CRITICAL_5: f64 = -2.86;
+// Maior ordem sugerida para p e q; ordens altas raramente melhoram a previsão
+pub const MAX_SUGGESTED_ORDER: u32 = 5;
+// Defasagens examinadas no correlograma (limitadas a um quarto da série)
+const MAX_LAG: usize = 12;
+
+// Autocorrelações (ACF) e autocorrelações parciais (PACF) nas defasagens 1, 2, ...; `band` é o
+// limite de significância de 95% (1,96 / raiz de n)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Correlogram {
+    pub acf: Vec<f64>,
+    pub pacf: Vec<f64>,
+    pub band: f64,
+}
+
+pub fn difference(values: &[f64]) -> Vec<f64> {
+    values.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+pub fn correlogram(values: &[f64], max_lag: usize) -> Option<Correlogram> {
+    let max_lag = max_lag.min(values.len() / 4);
+    if max_lag == 0 {
+        return None;
+    }
+
+    let acf: Vec<f64> = (1..=max_lag)
+        .map(|lag| autocorrelation(values, lag))
+        .collect::<Option<_>>()?;
+
+    // Recursão de Durbin-Levinson: phi[j] são os coeficientes do AR(k) ajustado às autocorrelações
+    let mut pacf = Vec::with_capacity(max_lag);
+    let mut phi: Vec<f64> = Vec::new();
+    for k in 1..=max_lag {
+        let numerator = acf[k - 1] - (1..k).map(|j| phi[j - 1] * acf[k - j - 1]).sum::<f64>();
+        let denominator = 1.0 - (1..k).map(|j| phi[j - 1] * acf[j - 1]).sum::<f64>();
+        if denominator.abs() < f64::EPSILON {
+            break;
+        }
+        let partial = numerator / denominator;
+        phi = (1..k).map(|j| phi[j - 1] - partial * phi[k - j - 1]).chain([partial]).collect();
+        pacf.push(partial);
+    }
+
+    Some(Correlogram {
+        acf,
+        pacf,
+        band: 1.96 / (values.len() as f64).sqrt(),
+    })
+}
+
+// Estatística t do teste de Dickey-Fuller com constante (Δy_t = a + γ·y_{t-1} + e_t). Abaixo de
+// DICKEY_FULLER_CRITICAL_5 rejeita a raiz unitária: a série é estacionária.
+pub fn dickey_fuller(values: &[f64]) -> Option<f64> {
+    if values.len() < 10 {
+        return None;
+    }
+
+    let lagged = &values[..values.len() - 1];
+    let changes = difference(values);
+    let (x_mean, y_mean) = (mean(lagged), mean(&changes));
+    let sxx: f64 = lagged.iter().map(|x| (x - x_mean).powi(2)).sum();
+    if sxx == 0.0 {
+        return None;
+    }
+    let sxy: f64 = lagged.iter().zip(&changes).map(|(x, y)| (x - x_mean) * (y - y_mean)).sum();
+    let gamma = sxy / sxx;
+    let intercept = y_mean - gamma * x_mean;
+
+    let residuals: f64 = lagged
+        .iter()
+        .zip(&changes)
+        .map(|(x, y)| (y - intercept - gamma * x).powi(2))
+        .sum();
+    let variance = residuals / (changes.len() - 2) as f64;
+    if variance == 0.0 {
+        return None;
+    }
+    Some(gamma / (variance / sxx).sqrt())
+}
+
+// Valor sugerido para um parâmetro e o motivo, em linguagem simples
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub value: u32,
+    pub reason: String,
+}
+
+// Ordem (p, d, q) sugerida pelos diagnósticos
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guidance {
+    pub p: Suggestion,
+    pub d: Suggestion,
+    pub q: Suggestion,
+}
+
+impl Guidance {
+    pub fn order(&self) -> (u32, u32, u32) {
+        (self.p.value, self.d.value, self.q.value)
+    }
+}
+
+// Defasagens significativas seguidas a partir da primeira: onde a função "corta"
+fn cutoff(coefficients: &[f64], band: f64) -> u32 {
+    coefficients.iter().take_while(|c| c.abs() > band).count().min(MAX_SUGGESTED_ORDER as usize) as u32
+}
+
+// d pelo teste de Dickey-Fuller no nível e nas diferenças; p e q pelo corte da PACF e da ACF da
+// série diferenciada, como no método de Box-Jenkins
+pub fn guidance(values: &[f64]) -> Option<Guidance> {
+    let stationary = |values: &[f64]| dickey_fuller(values).map(|t| t < DICKEY_FULLER_CRITICAL_5);
+
+    let (d, reason) = if stationary(values)? {
+        (0, "Dickey-Fuller: série estacionária, use d = 0".to_string())
+    } else if stationary(&difference(values))? {
+        (1, "Dickey-Fuller: raiz unitária, experimente d = 1".to_string())
+    } else {
+        (2, "Dickey-Fuller: raiz unitária mesmo após uma diferença, experimente d = 2".to_string())
+    };
+    let d = Suggestion { value: d, reason };
+
+    let mut differenced = values.to_vec();
+    for _ in 0..d.value {
+        differenced = difference(&differenced);
+    }
+    let correlogram = correlogram(&differenced, MAX_LAG)?;
+    let (ar, ma) = (cutoff(&correlogram.pacf, correlogram.band), cutoff(&correlogram.acf, correlogram.band));
+
+    let suggest = |value: u32, reason: String| Suggestion { value, reason };
+    let (p, q) = if ar == 0 && ma == 0 {
+        // A série (diferenciada) parece ruído
+        (
+            suggest(0, "PACF sem defasagens significativas: p = 0".to_string()),
+            suggest(0, "ACF sem defasagens significativas: q = 0".to_string()),
+        )
+    } else if ma > ar {
+        // A ACF decai devagar e a PACF corta: processo autorregressivo
+        (
+            suggest(ar, format!("PACF corta na defasagem {}: experimente p = {}", ar, ar)),
+            suggest(0, "ACF decai aos poucos: q = 0".to_string()),
+        )
+    } else if ar > ma {
+        // A PACF decai devagar e a ACF corta: média móvel
+        (
+            suggest(0, "PACF decai aos poucos: p = 0".to_string()),
+            suggest(ma, format!("ACF corta na defasagem {}: experimente q = {}", ma, ma)),
+        )
+    } else {
+        // Cortam juntas: modelo misto
+        (
+            suggest(1, "ACF e PACF cortam juntas: experimente p = 1".to_string()),
+            suggest(1, "ACF e PACF cortam juntas: experimente q = 1".to_string()),
+        )
+    };
+
+    Some(Guidance { p, d, q })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SeededRng;
+
+    fn noise(n: usize, seed: u64) -> Vec<f64> {
+        let mut rng = SeededRng::new(seed);
+        (0..n).map(|_| rng.normal()).collect()
+    }
+
+    #[test]
+    fn random_walk_needs_one_difference() {
+        let walk: Vec<f64> = noise(400, 7)
+            .into_iter()
+            .scan(0.0, |level, step| {
+                *level += step;
+                Some(*level)
+            })
+            .collect();
+
+        let guidance = guidance(&walk).unwrap();
+
+        assert_eq!(guidance.order(), (0, 1, 0));
+        assert!(guidance.d.reason.contains("d = 1"));
+    }
+
+    #[test]
+    fn pacf_cut_off_suggests_the_ar_order() {
+        let shocks = noise(600, 11);
+        let mut series = vec![0.0, 0.0];
+        for shock in &shocks {
+            let n = series.len();
+            series.push(0.5 * series[n - 1] + 0.3 * series[n - 2] + shock);
+        }
+
+        let guidance = guidance(&series).unwrap();
+
+        assert_eq!(guidance.order(), (2, 0, 0));
+        assert!(guidance.p.reason.contains("PACF corta na defasagem 2"));
+    }
+
+    #[test]
+    fn pacf_of_an_ar1_is_its_coefficient_then_zero() {
+        let mut series = vec![0.0];
+        for shock in noise(5000, 3) {
+            let last = *series.last().unwrap();
+            series.push(0.5 * last + shock);
+        }
+
+        let correlogram = correlogram(&series, 3).unwrap();
+
+        for (lag, estimated) in correlogram.acf.iter().enumerate() {
+            assert!((estimated - 0.5f64.powi(lag as i32 + 1)).abs() < 0.05, "{:?}", correlogram.acf);
+        }
+        assert!((correlogram.pacf[0] - 0.5).abs() < 0.05);
+        assert!(correlogram.pacf[1].abs() < 0.05 && correlogram.pacf[2].abs() < 0.05, "{:?}", correlogram.pacf);
+    }
+}
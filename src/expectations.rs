@@ -0,0 +1,101 @@
+use chrono::{Datelike, Months, NaiveDate};
+use serde::Deserialize;
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{date_sort_key, InflationData};
+use crate::dates::{self, DateFormat};
+
+// Distância, em pontos percentuais, a partir da qual a previsão é destacada como divergente
+pub const DIVERGENCE_THRESHOLD: f64 = 0.5;
+
+#[derive(Debug, Deserialize)]
+struct ExpectationsRaw {
+    // Mediana das expectativas por período de referência
+    expectations: Vec<InflationData>,
+}
+
+// Expectativas de mercado para a inflação dos próximos períodos (no Brasil, a pesquisa Focus), em
+// ordem cronológica. Requer um provedor que exponha `inflation/expectations`; a brapi não o oferece.
+pub async fn get_expectations(
+    client: &ApiClient,
+    config: &Config,
+    country: &str,
+) -> Result<Vec<InflationData>, Box<dyn std::error::Error>> {
+    let query = [("country", country)];
+    let data: ExpectationsRaw = client.get_json(config, "inflation/expectations", &query).await?;
+
+    let mut expectations = data
+        .expectations
+        .into_iter()
+        .map(|item| {
+            let date = dates::normalize(&item.date, config.date_format)?;
+            Ok(InflationData { date, ..item })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    expectations.sort_by_key(|obs| date_sort_key(&obs.date));
+
+    Ok(expectations)
+}
+
+fn month(date: NaiveDate) -> (i32, u32) {
+    (date.year(), date.month())
+}
+
+// Expectativa para cada passo de uma previsão mensal que começa no mês seguinte a `last_date`
+pub fn align(last_date: &str, expectations: &[InflationData], steps: usize) -> Vec<Option<f64>> {
+    let Ok(last) = dates::parse(last_date, DateFormat::Auto) else {
+        return vec![None; steps];
+    };
+    let by_month: Vec<((i32, u32), f64)> = expectations
+        .iter()
+        .filter_map(|obs| Some((month(dates::parse(&obs.date, DateFormat::Auto).ok()?), obs.value)))
+        .collect();
+
+    (1..=steps as u32)
+        .map(|step| {
+            let target = month(last.checked_add_months(Months::new(step))?);
+            by_month.iter().find(|(other, _)| *other == target).map(|(_, value)| *value)
+        })
+        .collect()
+}
+
+// Passos em que a previsão se afasta da expectativa por mais de `threshold`
+pub fn divergences(forecast: &[f64], expected: &[Option<f64>], threshold: f64) -> Vec<usize> {
+    forecast
+        .iter()
+        .zip(expected)
+        .enumerate()
+        .filter(|(_, (forecast, expected))| expected.is_some_and(|expected| (*forecast - expected).abs() > threshold))
+        .map(|(step, _)| step)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expectation(date: &str, value: f64) -> InflationData {
+        InflationData {
+            date: date.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn aligns_expectations_with_forecast_months() {
+        let expectations = vec![expectation("01/02/2025", 4.2), expectation("01/12/2024", 4.5)];
+
+        let aligned = align("15/11/2024", &expectations, 4);
+        assert_eq!(aligned, vec![Some(4.5), None, Some(4.2), None]);
+        assert_eq!(align("data inválida", &expectations, 2), vec![None, None]);
+    }
+
+    #[test]
+    fn flags_steps_far_from_expectations() {
+        let forecast = [4.4, 5.3, 3.0];
+        let expected = [Some(4.5), Some(4.6), None];
+
+        assert_eq!(divergences(&forecast, &expected, DIVERGENCE_THRESHOLD), vec![1]);
+    }
+}
@@ -0,0 +1,338 @@
+use crate::artifact::{ModelArtifact, Parameters};
+use crate::config::Config;
+
+// Horizonte máximo da resposta ao impulso em que se procura a meia-vida
+const HALF_LIFE_HORIZON: usize = 1200;
+
+// Coeficiente estimado com o seu significado em linguagem simples
+#[derive(Debug, Clone, PartialEq)]
+pub struct Term {
+    pub name: String,
+    pub value: f64,
+    pub meaning: String,
+}
+
+// O que o modelo aprendeu: os coeficientes e frases que resumem o comportamento implícito
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub model: String,
+    pub terms: Vec<Term>,
+    pub summary: Vec<String>,
+}
+
+// Propriedades implícitas nos coeficientes de um ARIMA
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArmaSummary {
+    // Soma dos coeficientes AR: quanto de um desvio passa para o período seguinte
+    pub persistence: f64,
+    // Períodos para que metade de um choque desapareça; None sem termos AR ou se ele não se dissipa
+    pub half_life: Option<f64>,
+    // Média incondicional, para a qual a previsão converge; só em nível (d = 0) e com constante,
+    // que no statsmodels já é a média do processo
+    pub long_run_mean: Option<f64>,
+}
+
+// Coeficientes AR ou MA do statsmodels (`prefix` "ar.L" ou "ma.L") ordenados pela defasagem; as
+// defasagens ausentes valem 0
+fn lag_coefficients(coefficients: &[(String, f64)], prefix: &str) -> Vec<f64> {
+    let lags: Vec<(usize, f64)> = coefficients
+        .iter()
+        .filter_map(|(name, value)| Some((name.strip_prefix(prefix)?.parse().ok()?, *value)))
+        .filter(|(lag, _)| *lag > 0)
+        .collect();
+    let mut ar = vec![0.0; lags.iter().map(|(lag, _)| *lag).max().unwrap_or(0)];
+    for (lag, value) in lags {
+        ar[lag - 1] = value;
+    }
+    ar
+}
+
+// Resposta da parte AR a um choque unitário em t = 0: ψ_0 = 1 e ψ_h = Σ φ_i ψ_{h−i}
+pub fn impulse_response(ar: &[f64], horizon: usize) -> Vec<f64> {
+    arma_impulse_response(ar, &[], horizon)
+}
+
+// Resposta de um ARMA a um choque unitário em t = 0: ψ_h = θ_h + Σ φ_i ψ_{h−i}, com θ_h = 0 além
+// da ordem MA
+pub fn arma_impulse_response(ar: &[f64], ma: &[f64], horizon: usize) -> Vec<f64> {
+    let mut psi = vec![1.0];
+    for h in 1..=horizon {
+        let dynamics: f64 = ar.iter().take(h).enumerate().map(|(i, phi)| phi * psi[h - 1 - i]).sum();
+        psi.push(ma.get(h - 1).copied().unwrap_or(0.0) + dynamics);
+    }
+    psi
+}
+
+// Resposta do nível da série a um choque de um desvio padrão (√sigma2) em um ARIMA: a do ARMA
+// das diferenças, acumulada d vezes. Com d ≥ 1 o choque muda o nível de forma permanente.
+pub fn arima_response(order: (u32, u32, u32), coefficients: &[(String, f64)], sigma2: f64, horizon: usize) -> Vec<f64> {
+    let ar = lag_coefficients(coefficients, "ar.L");
+    let ma = lag_coefficients(coefficients, "ma.L");
+    let mut response = arma_impulse_response(&ar, &ma, horizon);
+    for _ in 0..order.1 {
+        response = response
+            .iter()
+            .scan(0.0, |total, value| {
+                *total += value;
+                Some(*total)
+            })
+            .collect();
+    }
+    response.iter().map(|value| value * sigma2.sqrt()).collect()
+}
+
+// Resposta a um choque do ARIMA ajustado; None para os demais modelos
+pub fn response_of(artifact: &ModelArtifact, horizon: usize) -> Option<Vec<f64>> {
+    match &artifact.parameters {
+        Parameters::Arima {
+            order,
+            coefficients,
+            sigma2,
+        } => Some(arima_response(*order, coefficients, *sigma2, horizon)),
+        _ => None,
+    }
+}
+
+// Meia-vida de um choque: períodos até a resposta ao impulso cair, em módulo, à metade pela
+// primeira vez, interpolando em log entre os dois períodos em volta. Com um único termo AR é a
+// fórmula usual ln 0,5 / ln φ; com mais termos, não depende só da soma dos coeficientes.
+pub fn half_life(ar: &[f64]) -> Option<f64> {
+    if ar.is_empty() {
+        return None;
+    }
+    let psi = impulse_response(ar, HALF_LIFE_HORIZON);
+    let h = psi.iter().position(|value| value.abs() <= 0.5)?;
+    let (before, after) = (psi[h - 1].abs(), psi[h].abs());
+    if after == 0.0 {
+        return Some(h as f64);
+    }
+    Some((h - 1) as f64 + (before / 0.5).ln() / (before / after).ln())
+}
+
+pub fn arma_summary(order: (u32, u32, u32), coefficients: &[(String, f64)]) -> ArmaSummary {
+    let ar = lag_coefficients(coefficients, "ar.L");
+    let persistence: f64 = ar.iter().sum();
+    let half_life = half_life(&ar);
+    let long_run_mean = if order.1 == 0 {
+        coefficients.iter().find(|(name, _)| name == "const").map(|(_, value)| *value)
+    } else {
+        None
+    };
+
+    ArmaSummary {
+        persistence,
+        half_life,
+        long_run_mean,
+    }
+}
+
+fn describe_persistence(persistence: f64) -> &'static str {
+    if persistence >= 1.0 {
+        "igual ou acima de 1: os desvios não se dissipam e o processo não volta à média"
+    } else if persistence >= 0.9 {
+        "muito alta: desvios em relação à média se dissipam devagar"
+    } else if persistence >= 0.5 {
+        "moderada: desvios duram alguns períodos"
+    } else if persistence >= 0.0 {
+        "baixa: a série volta rápido ao seu comportamento normal"
+    } else {
+        "negativa: um período acima da média tende a ser seguido de um abaixo"
+    }
+}
+
+fn explain_arima(
+    order: (u32, u32, u32),
+    coefficients: &[(String, f64)],
+    sigma2: f64,
+    config: &Config,
+) -> (Vec<Term>, Vec<String>) {
+    let (p, d, q) = order;
+    let subject = match d {
+        0 => "série",
+        1 => "variação da série",
+        _ => "variação da variação da série",
+    };
+
+    let mut terms: Vec<Term> = coefficients
+        .iter()
+        .map(|(name, value)| {
+            let meaning = if name == "const" {
+                "Média de longo prazo: a previsão converge para este valor".to_string()
+            } else if let Some(lag) = name.strip_prefix("ar.L") {
+                let direction = if *value >= 0.0 { "puxa na mesma direção" } else { "puxa na direção oposta" };
+                format!("Peso do valor de {} período(s) atrás: um desvio nele {} a previsão", lag, direction)
+            } else if let Some(lag) = name.strip_prefix("ma.L") {
+                format!("Fração do erro de previsão de {} período(s) atrás incorporada à previsão", lag)
+            } else {
+                "Termo do statsmodels".to_string()
+            };
+            Term {
+                name: name.clone(),
+                value: *value,
+                meaning,
+            }
+        })
+        .collect();
+    terms.push(Term {
+        name: "sigma2".to_string(),
+        value: sigma2,
+        meaning: "Variância dos choques: quanto maior, mais largo o intervalo da previsão".to_string(),
+    });
+
+    let arma = arma_summary(order, coefficients);
+    let mut summary = vec![match d {
+        0 => "O modelo trabalha com a série em nível (d = 0).".to_string(),
+        _ => format!(
+            "O modelo trabalha com a {} (d = {}): choques mudam o nível de forma permanente e a previsão não volta a uma média fixa.",
+            subject, d
+        ),
+    }];
+    if p > 0 {
+        summary.push(format!(
+            "Persistência de {} (soma dos coeficientes AR) — {}.",
+            config.format_value(arma.persistence),
+            describe_persistence(arma.persistence)
+        ));
+    }
+    if let Some(half_life) = arma.half_life {
+        summary.push(format!(
+            "Velocidade de reversão: metade de um desvio da {} some em cerca de {} período(s) (meia-vida).",
+            subject,
+            config.format_value(half_life)
+        ));
+    }
+    if let Some(mean) = arma.long_run_mean {
+        summary.push(format!("Média de longo prazo implícita: {}.", config.format_value(mean)));
+    }
+    if q > 0 {
+        summary.push(format!(
+            "Os termos MA fazem um choque pontual afetar a previsão por mais {} período(s) e depois sumir.",
+            q
+        ));
+    }
+
+    (terms, summary)
+}
+
+// Resumo do ARIMA ajustado; None para os demais modelos
+pub fn arma_of(artifact: &ModelArtifact) -> Option<ArmaSummary> {
+    match &artifact.parameters {
+        Parameters::Arima { order, coefficients, .. } => Some(arma_summary(*order, coefficients)),
+        _ => None,
+    }
+}
+
+pub fn explain(artifact: &ModelArtifact, config: &Config) -> Explanation {
+    let (terms, summary) = match &artifact.parameters {
+        Parameters::Naive => (vec![], vec!["O modelo ingênuo repete o último valor observado; não há coeficientes.".to_string()]),
+        Parameters::Arima {
+            order,
+            coefficients,
+            sigma2,
+        } => explain_arima(*order, coefficients, *sigma2, config),
+        Parameters::Mlp(_) => (
+            vec![],
+            vec!["A rede neural tem milhares de pesos sem interpretação individual; avalie-a pelos erros fora da amostra.".to_string()],
+        ),
+        Parameters::Theta { alpha, slope, sigma2 } => (
+            vec![
+                Term {
+                    name: "alpha".to_string(),
+                    value: *alpha,
+                    meaning: "Peso da observação mais recente no nível: perto de 1 segue os últimos dados, perto de 0 suaviza".to_string(),
+                },
+                Term {
+                    name: "slope".to_string(),
+                    value: *slope,
+                    meaning: "Inclinação da tendência linear da série; a previsão avança metade dela por período".to_string(),
+                },
+                Term {
+                    name: "sigma2".to_string(),
+                    value: *sigma2,
+                    meaning: "Variância dos erros de um passo".to_string(),
+                },
+            ],
+            vec![format!(
+                "A previsão parte do nível suavizado e muda {} por período.",
+                config.format_value(slope / 2.0)
+            )],
+        ),
+        Parameters::Croston { alpha, sigma2, .. } => (
+            vec![
+                Term {
+                    name: "alpha".to_string(),
+                    value: *alpha,
+                    meaning: "Peso dos dados recentes na suavização dos valores não nulos e dos intervalos".to_string(),
+                },
+                Term {
+                    name: "sigma2".to_string(),
+                    value: *sigma2,
+                    meaning: "Variância dos erros de um passo".to_string(),
+                },
+            ],
+            vec!["A previsão é constante: o tamanho típico dos valores não nulos dividido pelo intervalo típico entre eles.".to_string()],
+        ),
+    };
+
+    Explanation {
+        model: artifact.model.clone(),
+        terms,
+        summary,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coefficients(pairs: &[(&str, f64)]) -> Vec<(String, f64)> {
+        pairs.iter().map(|(name, value)| (name.to_string(), *value)).collect()
+    }
+
+    #[test]
+    fn ar_coefficients_give_persistence_half_life_and_mean() {
+        let summary = arma_summary(
+            (2, 0, 1),
+            &coefficients(&[("const", 4.2), ("ar.L2", 0.25), ("ar.L1", 0.25), ("ma.L1", 0.3)]),
+        );
+
+        assert_eq!(summary.persistence, 0.5);
+        // A resposta cai de 1 para 0,25 já no primeiro período
+        assert!((summary.half_life.unwrap() - 0.5).abs() < 1e-12);
+        assert_eq!(summary.long_run_mean, Some(4.2));
+    }
+
+    #[test]
+    fn half_life_follows_the_impulse_response() {
+        // AR(1): a fórmula usual
+        assert!((half_life(&[0.9]).unwrap() - 0.5f64.ln() / 0.9f64.ln()).abs() < 1e-9);
+        assert_eq!(impulse_response(&[0.5, 0.25], 2), vec![1.0, 0.5, 0.5]);
+        // Choques que oscilam de sinal também se dissipam
+        assert!((half_life(&[-0.5]).unwrap() - 1.0).abs() < 1e-12);
+        // Só o segundo termo: a resposta é nula no primeiro período
+        assert_eq!(half_life(&[0.0, 0.8]), Some(1.0));
+        assert_eq!(half_life(&[1.0]), None);
+        assert_eq!(half_life(&[]), None);
+    }
+
+    #[test]
+    fn arima_response_adds_the_ma_terms_and_integrates() {
+        assert_eq!(arma_impulse_response(&[0.5], &[0.25], 3), vec![1.0, 0.75, 0.375, 0.1875]);
+
+        let coefficients = coefficients(&[("ar.L1", 0.5), ("ma.L1", 0.25), ("sigma2", 4.0)]);
+        // Choque de um desvio padrão (2) no nível de um ARIMA(1,1,1): o efeito se acumula
+        assert_eq!(arima_response((1, 1, 1), &coefficients, 4.0, 2), vec![2.0, 3.5, 4.25]);
+        assert_eq!(arima_response((0, 0, 0), &[], 1.0, 2), vec![1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn differenced_or_explosive_models_do_not_revert() {
+        let differenced = arma_summary((1, 1, 0), &coefficients(&[("ar.L1", 0.5)]));
+        assert_eq!(differenced.long_run_mean, None);
+        assert!(differenced.half_life.is_some());
+
+        let explosive = arma_summary((1, 0, 0), &coefficients(&[("const", 1.0), ("ar.L1", 1.02)]));
+        assert_eq!(explosive.half_life, None);
+        assert!(describe_persistence(explosive.persistence).contains("não volta"));
+    }
+}
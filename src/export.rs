@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use crate::annotations::Annotation;
+
+// Diretório padrão das exportações (Documentos do usuário, ou o diretório atual)
+pub fn export_dir() -> PathBuf {
+    dirs::document_dir().unwrap_or_else(|| PathBuf::from("."))
+}
+
+pub fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// CSV "date,value" com a coluna "annotation" opcional
+pub fn series_csv(dates: &[String], values: &[f64], annotations: Option<&[Annotation]>) -> String {
+    let mut csv = String::from(if annotations.is_some() { "date,value,annotation\n" } else { "date,value\n" });
+
+    for (date, value) in dates.iter().zip(values) {
+        csv.push_str(&format!("{},{}", escape(date), value));
+
+        if let Some(annotations) = annotations {
+            let labels: Vec<&str> = annotations
+                .iter()
+                .filter(|annotation| annotation.date == *date)
+                .map(|annotation| annotation.label.as_str())
+                .collect();
+            csv.push(',');
+            csv.push_str(&escape(&labels.join("; ")));
+        }
+
+        csv.push('\n');
+    }
+
+    csv
+}
+
+// Grava `rustfin-<name>.csv` no diretório de exportação
+pub fn write_csv(name: &str, csv: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = export_dir();
+    std::fs::create_dir_all(&dir)?;
+
+    let path = dir.join(format!("rustfin-{}.csv", name));
+    std::fs::write(&path, csv)?;
+
+    Ok(path)
+}
+
+pub fn write_series_csv(
+    name: &str,
+    dates: &[String],
+    values: &[f64],
+    annotations: Option<&[Annotation]>,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    write_csv(name, &series_csv(dates, values, annotations))
+}
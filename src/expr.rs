@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+// Expressão aritmética sobre séries: identificadores, números, + - * /, parênteses e menos unário
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Series(String),
+    Neg(Box<Expr>),
+    Binary(Box<Expr>, Op, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(Op),
+    LParen,
+    RParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = input.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let single = match c {
+            '+' => Some(Token::Op(Op::Add)),
+            '-' => Some(Token::Op(Op::Sub)),
+            '*' => Some(Token::Op(Op::Mul)),
+            '/' => Some(Token::Op(Op::Div)),
+            '(' => Some(Token::LParen),
+            ')' => Some(Token::RParen),
+            _ => None,
+        };
+        if let Some(token) = single {
+            tokens.push(token);
+            i += 1;
+            continue;
+        }
+
+        match c {
+            c if c.is_whitespace() => i += 1,
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let number = text.parse().map_err(|_| format!("Número inválido: {}", text))?;
+                tokens.push(Token::Number(number));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            c => return Err(format!("Caractere inesperado: '{}'", c)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    // expr := term (('+' | '-') term)*
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.term()?;
+        while let Some(Token::Op(op @ (Op::Add | Op::Sub))) = self.peek().cloned() {
+            self.pos += 1;
+            left = Expr::Binary(Box::new(left), op, Box::new(self.term()?));
+        }
+        Ok(left)
+    }
+
+    // term := factor (('*' | '/') factor)*
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut left = self.factor()?;
+        while let Some(Token::Op(op @ (Op::Mul | Op::Div))) = self.peek().cloned() {
+            self.pos += 1;
+            left = Expr::Binary(Box::new(left), op, Box::new(self.factor()?));
+        }
+        Ok(left)
+    }
+
+    // factor := número | série | '-' factor | '(' expr ')'
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.advance() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::Ident(name)) => Ok(Expr::Series(name)),
+            Some(Token::Op(Op::Sub)) => Ok(Expr::Neg(Box::new(self.factor()?))),
+            Some(Token::LParen) => {
+                let inner = self.expr()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err("Parêntese não fechado".to_string()),
+                }
+            }
+            Some(token) => Err(format!("Token inesperado: {:?}", token)),
+            None => Err("Expressão incompleta".to_string()),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Expr, String> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+    };
+
+    let expr = parser.expr()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(format!("Token inesperado: {:?}", parser.tokens[parser.pos]));
+    }
+
+    Ok(expr)
+}
+
+// Separa uma definição "nome = expressão"
+pub fn parse_definition(input: &str) -> Result<(String, Expr), String> {
+    let (name, body) = input.split_once('=').ok_or("Use o formato nome = expressão")?;
+    let name = name.trim();
+
+    let valid_name = !name.is_empty()
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit());
+    if !valid_name {
+        return Err(format!("Nome inválido: '{}'", name));
+    }
+
+    Ok((name.to_string(), parse(body)?))
+}
+
+impl Expr {
+    // Séries referenciadas, sem repetição
+    pub fn series(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.collect_series(&mut names);
+        names
+    }
+
+    fn collect_series(&self, names: &mut Vec<String>) {
+        match self {
+            Expr::Number(_) => {}
+            Expr::Series(name) => {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+            Expr::Neg(inner) => inner.collect_series(names),
+            Expr::Binary(left, _, right) => {
+                left.collect_series(names);
+                right.collect_series(names);
+            }
+        }
+    }
+
+    pub fn eval(&self, values: &HashMap<String, f64>) -> Option<f64> {
+        match self {
+            Expr::Number(n) => Some(*n),
+            Expr::Series(name) => values.get(name).copied(),
+            Expr::Neg(inner) => inner.eval(values).map(|v| -v),
+            Expr::Binary(left, op, right) => {
+                let (l, r) = (left.eval(values)?, right.eval(values)?);
+                match op {
+                    Op::Add => Some(l + r),
+                    Op::Sub => Some(l - r),
+                    Op::Mul => Some(l * r),
+                    Op::Div if r == 0.0 => None,
+                    Op::Div => Some(l / r),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn respects_precedence_and_parentheses() {
+        let values = HashMap::from([("a".to_string(), 2.0), ("b".to_string(), 3.0)]);
+
+        assert_eq!(parse("a + b * 2").unwrap().eval(&values), Some(8.0));
+        assert_eq!(parse("(a + b) * 2").unwrap().eval(&values), Some(10.0));
+        assert_eq!(parse("-a - -b").unwrap().eval(&values), Some(1.0));
+    }
+
+    #[test]
+    fn division_by_zero_has_no_value() {
+        let values = HashMap::from([("a".to_string(), 0.0)]);
+
+        assert_eq!(parse("1 / a").unwrap().eval(&values), None);
+    }
+
+    #[test]
+    fn parses_definitions_and_lists_series() {
+        let (name, expr) = parse_definition("spread = inflation_usa - inflation_brazil").unwrap();
+
+        assert_eq!(name, "spread");
+        assert_eq!(expr.series(), vec!["inflation_usa", "inflation_brazil"]);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(parse("a +").is_err());
+        assert!(parse("(a + b").is_err());
+        assert!(parse("a $ b").is_err());
+        assert!(parse_definition("1x = a").is_err());
+        assert!(parse_definition("a + b").is_err());
+    }
+}
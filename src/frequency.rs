@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::cache::{self, cache_dir};
+use crate::catalog::frequency_label;
+use crate::fx::{infer_frequency, Frequency};
+
+// Metadados de uma série buscada, gravados junto com o cache
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeriesMetadata {
+    // Inferida pelo intervalo entre as datas a cada busca
+    pub frequency: Frequency,
+    pub observations: usize,
+    // Momento (segundos Unix) da detecção
+    pub detected_at: u64,
+}
+
+fn metadata_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("metadata.json"))
+}
+
+// Metadados por nome de série (ex.: inflation_brazil)
+pub fn load() -> BTreeMap<String, SeriesMetadata> {
+    metadata_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(metadata: &BTreeMap<String, SeriesMetadata>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = metadata_path().ok_or("Diretório de dados não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(metadata)?)?;
+
+    Ok(())
+}
+
+// Detecta a frequência das datas buscadas de `name` e a grava nos metadados
+pub fn record(name: &str, dates: &[String]) -> Result<Frequency, Box<dyn std::error::Error>> {
+    let frequency = infer_frequency(dates);
+    let mut metadata = load();
+    metadata.insert(
+        name.to_string(),
+        SeriesMetadata {
+            frequency,
+            observations: dates.len(),
+            detected_at: cache::now_secs(),
+        },
+    );
+    save(&metadata)?;
+
+    Ok(frequency)
+}
+
+// Período sazonal natural da frequência; séries diárias e anuais não têm um único período
+pub fn seasonal_period(frequency: Frequency) -> Option<usize> {
+    match frequency {
+        Frequency::Monthly => Some(12),
+        Frequency::Quarterly => Some(4),
+        Frequency::Daily | Frequency::Annual => None,
+    }
+}
+
+fn frequency_of(series: &[(String, f64)]) -> Frequency {
+    let dates: Vec<String> = series.iter().map(|(date, _)| date.clone()).collect();
+    infer_frequency(&dates)
+}
+
+// Aviso quando séries combinadas (em uma expressão, cesta, emenda ou painel) têm frequências
+// diferentes: só as datas em comum entram, e o resultado pode não ter a frequência esperada
+pub fn mismatch<'a>(series: impl IntoIterator<Item = (&'a str, &'a [(String, f64)])>) -> Option<String> {
+    let frequencies: Vec<(&str, Frequency)> =
+        series.into_iter().map(|(name, values)| (name, frequency_of(values))).collect();
+    let first = frequencies.first()?.1;
+    if frequencies.iter().all(|(_, frequency)| *frequency == first) {
+        return None;
+    }
+
+    let described: Vec<String> = frequencies
+        .iter()
+        .map(|(name, frequency)| format!("{} ({})", name, frequency_label(*frequency).to_lowercase()))
+        .collect();
+    Some(format!("Frequências diferentes: {}; só as datas em comum são combinadas", described.join(", ")))
+}
+
+// Aviso quando o período sazonal usado não corresponde à frequência detectada das datas
+pub fn period_warning(dates: &[String], period: usize) -> Option<String> {
+    let frequency = infer_frequency(dates);
+    let label = frequency_label(frequency).to_lowercase();
+    match seasonal_period(frequency) {
+        Some(expected) if expected == period => None,
+        Some(expected) => Some(format!(
+            "Período sazonal {} para uma série {} (o esperado é {})",
+            period, label, expected
+        )),
+        None => Some(format!(
+            "Período sazonal {} para uma série {}, que não tem um ciclo de {} observações",
+            period, label, period
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(dates: &[&str]) -> Vec<(String, f64)> {
+        dates.iter().map(|date| (date.to_string(), 1.0)).collect()
+    }
+
+    #[test]
+    fn warns_when_combining_different_frequencies() {
+        let monthly = series(&["01/01/2024", "01/02/2024", "01/03/2024"]);
+        let daily = series(&["01/01/2024", "02/01/2024", "03/01/2024"]);
+        let quarterly = series(&["01/01/2024", "01/04/2024", "01/07/2024"]);
+
+        assert_eq!(mismatch([("a", monthly.as_slice()), ("b", monthly.as_slice())]), None);
+        let warning = mismatch([("ipca", monthly.as_slice()), ("selic", daily.as_slice())]).unwrap();
+        assert!(warning.contains("ipca (mensal)") && warning.contains("selic (diária)"));
+        assert!(mismatch([("a", monthly.as_slice()), ("b", quarterly.as_slice())]).is_some());
+    }
+
+    #[test]
+    fn warns_about_inconsistent_seasonal_periods() {
+        let monthly: Vec<String> = ["01/01/2024", "01/02/2024", "01/03/2024"].map(String::from).to_vec();
+        let quarterly: Vec<String> = ["01/01/2024", "01/04/2024", "01/07/2024"].map(String::from).to_vec();
+
+        assert_eq!(period_warning(&monthly, 12), None);
+        assert!(period_warning(&quarterly, 12).unwrap().contains("esperado é 4"));
+        assert_eq!(seasonal_period(Frequency::Daily), None);
+    }
+}
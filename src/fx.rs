@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{date_sort_key, string_to_f64, InflationData};
+use crate::stats;
+use crate::vintage;
+
+// Moedas de destino oferecidas na conversão
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Currency {
+    Usd,
+    Eur,
+}
+
+impl Currency {
+    pub fn all() -> [Currency; 2] {
+        [Currency::Usd, Currency::Eur]
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FxRaw {
+    pub currency: Vec<InflationData>,
+}
+
+// Cotações históricas de `to` em unidades de `from` (ex.: BRL por USD), no formato do par
+// da brapi ("USD-BRL"). A brapi só expõe cotações correntes; o histórico requer um provedor
+// com o endpoint `currency/historical` no mesmo formato das demais séries.
+pub async fn get_fx_history(
+    client: &ApiClient,
+    config: &Config,
+    from: &str,
+    to: Currency,
+) -> Result<Vec<InflationData>, Box<dyn std::error::Error>> {
+    let pair = format!("{}-{}", to.code(), from.trim().to_uppercase());
+    let query = [("currency", pair.as_str()), ("historical", "true")];
+    let mut data: FxRaw = client.get_json(config, "currency/historical", &query).await?;
+    data.currency.sort_by_key(|obs| date_sort_key(&obs.date));
+
+    Ok(data.currency)
+}
+
+// Cotação corrente de um par, no formato do endpoint `currency` da brapi
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FxQuote {
+    pub from_currency: String,
+    pub to_currency: String,
+    #[serde(deserialize_with = "string_to_f64")]
+    pub bid_price: f64,
+    // Variação percentual desde a abertura do dia
+    #[serde(deserialize_with = "string_to_f64")]
+    pub percentage_change: f64,
+    pub updated_at_date: String,
+}
+
+impl FxQuote {
+    pub fn pair(&self) -> String {
+        format!("{}-{}", self.from_currency, self.to_currency)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuotesRaw {
+    pub currency: Vec<FxQuote>,
+}
+
+// Últimas cotações dos pares ("USD-BRL"...) em uma única requisição
+pub async fn get_latest_quotes(
+    client: &ApiClient,
+    config: &Config,
+    pairs: &[String],
+) -> Result<Vec<FxQuote>, Box<dyn std::error::Error>> {
+    let pairs = pairs.join(",");
+    let data: QuotesRaw = client.get_json(config, "currency", &[("currency", pairs.as_str())]).await?;
+
+    Ok(data.currency)
+}
+
+// Frequência de uma série, inferida pelo intervalo mediano entre as datas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Frequency {
+    Daily,
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+fn day_number(date: &str) -> Option<u64> {
+    vintage::parse_date(&date_sort_key(date)).map(|secs| secs / 86400)
+}
+
+pub fn infer_frequency(dates: &[String]) -> Frequency {
+    let mut days: Vec<u64> = dates.iter().filter_map(|date| day_number(date)).collect();
+    days.sort_unstable();
+
+    let mut gaps: Vec<u64> = days.windows(2).map(|w| w[1] - w[0]).filter(|gap| *gap > 0).collect();
+    gaps.sort_unstable();
+
+    match gaps.get(gaps.len() / 2) {
+        Some(gap) if *gap <= 7 => Frequency::Daily,
+        Some(gap) if *gap <= 31 => Frequency::Monthly,
+        Some(gap) if *gap <= 92 => Frequency::Quarterly,
+        Some(_) => Frequency::Annual,
+        // Com uma única observação, assume a frequência das séries do provedor
+        None => Frequency::Monthly,
+    }
+}
+
+// (ano, subperíodo)
+type Period = (u64, u64);
+
+// Período que contém a data na frequência dada
+fn period(date: &str, frequency: Frequency) -> Option<Period> {
+    let key = date_sort_key(date);
+    let mut parts = key.splitn(3, '-');
+    let year: u64 = parts.next()?.parse().ok()?;
+    let month: u64 = parts.next()?.parse().ok()?;
+
+    match frequency {
+        Frequency::Daily => Some((year, day_number(date)?)),
+        Frequency::Monthly => Some((year, month)),
+        Frequency::Quarterly => Some((year, (month.max(1) - 1) / 3)),
+        Frequency::Annual => Some((year, 0)),
+    }
+}
+
+// Cotação para cada data da série: média das cotações do mesmo período (mês, trimestre, ano),
+// adequada a séries de fluxo como o PIB, ou a última cotação anterior quando o período não tem
+// nenhuma. Datas anteriores a todo o histórico de câmbio ficam sem cotação.
+pub fn align_rates(dates: &[String], rates: &[InflationData]) -> Vec<Option<f64>> {
+    let frequency = infer_frequency(dates);
+
+    // (chave cronológica, período, cotação), em ordem cronológica
+    let mut valid: Vec<(String, Option<Period>, f64)> = rates
+        .iter()
+        .filter(|rate| rate.value.is_finite() && rate.value > 0.0)
+        .map(|rate| (date_sort_key(&rate.date), period(&rate.date, frequency), rate.value))
+        .collect();
+    valid.sort_by(|a, b| a.0.cmp(&b.0));
+
+    dates
+        .iter()
+        .map(|date| {
+            let target = period(date, frequency);
+            let same: Vec<f64> = valid
+                .iter()
+                .filter(|(_, p, _)| target.is_some() && *p == target)
+                .map(|(_, _, value)| *value)
+                .collect();
+            if !same.is_empty() {
+                return Some(stats::mean(&same));
+            }
+
+            let key = date_sort_key(date);
+            valid.iter().rev().find(|(rate_key, _, _)| *rate_key <= key).map(|(_, _, value)| *value)
+        })
+        .collect()
+}
+
+// Converte a série dividindo cada valor pela cotação (moeda de origem por unidade de destino);
+// observações sem cotação são descartadas
+pub fn convert(dates: &[String], values: &[f64], rates: &[InflationData]) -> (Vec<String>, Vec<f64>) {
+    align_rates(dates, rates)
+        .into_iter()
+        .zip(dates.iter().zip(values))
+        .filter_map(|(rate, (date, value))| rate.map(|rate| (date.clone(), value / rate)))
+        .unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate(date: &str, value: f64) -> InflationData {
+        InflationData {
+            date: date.to_string(),
+            value,
+        }
+    }
+
+    fn dates(list: &[&str]) -> Vec<String> {
+        list.iter().map(|date| date.to_string()).collect()
+    }
+
+    #[test]
+    fn infers_frequency_from_gaps() {
+        assert_eq!(infer_frequency(&dates(&["01/01/2023", "01/02/2023", "01/03/2023"])), Frequency::Monthly);
+        assert_eq!(infer_frequency(&dates(&["01/01/2023", "01/04/2023", "01/07/2023"])), Frequency::Quarterly);
+        assert_eq!(infer_frequency(&dates(&["02/01/2023", "03/01/2023", "04/01/2023"])), Frequency::Daily);
+    }
+
+    #[test]
+    fn monthly_series_uses_average_of_daily_rates() {
+        let series = dates(&["01/01/2023", "01/02/2023"]);
+        let rates = [
+            rate("02/01/2023", 5.0),
+            rate("16/01/2023", 5.4),
+            rate("01/02/2023", 5.0),
+        ];
+
+        let (converted_dates, converted) = convert(&series, &[52.0, 100.0], &rates);
+
+        assert_eq!(converted_dates, series);
+        assert!((converted[0] - 10.0).abs() < 1e-9);
+        assert!((converted[1] - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn falls_back_to_last_rate_and_drops_dates_before_history() {
+        let series = dates(&["01/12/2022", "01/01/2023", "01/02/2023", "01/03/2023"]);
+        let rates = [rate("15/01/2023", 5.0), rate("20/02/2023", 4.0)];
+
+        let aligned = align_rates(&series, &rates);
+
+        assert_eq!(aligned, vec![None, Some(5.0), Some(4.0), Some(4.0)]);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::stats;
+
+// Mínimo de retornos para estimar o modelo
+const MIN_OBSERVATIONS: usize = 50;
+
+// GARCH(1,1) gaussiano: σ²[t] = ω + α·ε²[t-1] + β·σ²[t-1], com ε = r - média
+#[derive(Debug, Clone, Copy)]
+pub struct Garch {
+    pub mean: f64,
+    pub omega: f64,
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+impl Garch {
+    pub fn persistence(&self) -> f64 {
+        self.alpha + self.beta
+    }
+
+    // Variância condicional de cada retorno, usando só a informação anterior a ele,
+    // seguida da previsão para o próximo período (n + 1 elementos)
+    pub fn variances(&self, returns: &[f64]) -> Vec<f64> {
+        let mut current = self.omega / (1.0 - self.persistence());
+        let mut variances = Vec::with_capacity(returns.len() + 1);
+        variances.push(current);
+
+        for r in returns {
+            let e = r - self.mean;
+            current = self.omega + self.alpha * e * e + self.beta * current;
+            variances.push(current);
+        }
+
+        variances
+    }
+
+    pub fn forecast_variance(&self, returns: &[f64]) -> f64 {
+        *self.variances(returns).last().unwrap()
+    }
+
+    fn log_likelihood(&self, returns: &[f64]) -> f64 {
+        self.variances(returns)
+            .iter()
+            .zip(returns)
+            .map(|(v, r)| -0.5 * ((2.0 * std::f64::consts::PI).ln() + v.ln() + (r - self.mean).powi(2) / v))
+            .sum()
+    }
+}
+
+// Máxima verossimilhança com alvo de variância (ω fixado pela variância amostral):
+// busca em grade de α e β, refinada em torno do melhor ponto
+pub fn fit(returns: &[f64]) -> Option<Garch> {
+    if returns.len() < MIN_OBSERVATIONS {
+        return None;
+    }
+
+    let mean = stats::mean(returns);
+    let variance = stats::std_dev(returns).powi(2);
+    if variance == 0.0 {
+        return None;
+    }
+
+    let candidate = |alpha: f64, beta: f64| Garch {
+        mean,
+        omega: variance * (1.0 - alpha - beta),
+        alpha,
+        beta,
+    };
+    let consider = |best: &mut Option<(f64, Garch)>, alpha: f64, beta: f64| {
+        if alpha <= 0.0 || beta < 0.0 || alpha + beta >= 0.999 {
+            return;
+        }
+        let model = candidate(alpha, beta);
+        let likelihood = model.log_likelihood(returns);
+        let better = match best {
+            Some((l, _)) => likelihood > *l,
+            None => true,
+        };
+        if better {
+            *best = Some((likelihood, model));
+        }
+    };
+
+    let mut best = None;
+    for i in 1..=30 {
+        for j in 50..=98 {
+            consider(&mut best, i as f64 * 0.01, j as f64 * 0.01);
+        }
+    }
+
+    let (_, coarse) = best?;
+    for i in -5..=5 {
+        for j in -5..=5 {
+            consider(&mut best, coarse.alpha + i as f64 * 0.002, coarse.beta + j as f64 * 0.002);
+        }
+    }
+
+    best.map(|(_, model)| model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SeededRng;
+
+    #[test]
+    fn recovers_simulated_parameters() {
+        let truth = Garch {
+            mean: 0.0,
+            omega: 1e-5,
+            alpha: 0.1,
+            beta: 0.85,
+        };
+        let mut rng = SeededRng::new(11);
+        let mut variance = truth.omega / (1.0 - truth.persistence());
+        let returns: Vec<f64> = (0..2000)
+            .map(|_| {
+                let r = variance.sqrt() * rng.normal();
+                variance = truth.omega + truth.alpha * r * r + truth.beta * variance;
+                r
+            })
+            .collect();
+
+        let model = fit(&returns).unwrap();
+
+        assert!((0.04..=0.2).contains(&model.alpha));
+        assert!((0.88..0.999).contains(&model.persistence()));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::diagnostics::difference;
+use crate::stats::{mean, normal_cdf, normal_quantile, std_dev};
+
+// Passos mensais da previsão em um ano
+const STEPS_PER_YEAR: f64 = 12.0;
+
+// Taxa real, pela equação de Fisher, a partir da nominal e da inflação (todas em %)
+pub fn real_return(nominal: f64, inflation: f64) -> f64 {
+    ((1.0 + nominal / 100.0) / (1.0 + inflation / 100.0) - 1.0) * 100.0
+}
+
+// Cálculo inverso: a maior inflação com a qual o retorno nominal ainda entrega a meta real
+pub fn max_inflation(nominal: f64, target_real: f64) -> f64 {
+    ((1.0 + nominal / 100.0) / (1.0 + target_real / 100.0) - 1.0) * 100.0
+}
+
+// Taxa mensal equivalente a uma taxa em 12 meses (em %)
+pub fn monthly_rate(annual: f64) -> f64 {
+    ((1.0 + annual / 100.0).powf(1.0 / STEPS_PER_YEAR) - 1.0) * 100.0
+}
+
+// Desvio padrão da previsão em cada passo a partir dos intervalos de 95%
+pub fn interval_std_devs(intervals: &[(f64, f64)]) -> Vec<f64> {
+    let z = normal_quantile(0.975);
+    intervals.iter().map(|(lower, upper)| (upper - lower) / (2.0 * z)).collect()
+}
+
+// Sem intervalos do modelo, aproxima a incerteza por um passeio aleatório: o desvio padrão das
+// variações da série cresce com a raiz do passo
+pub fn random_walk_std_devs(values: &[f64], horizon: usize) -> Vec<f64> {
+    let changes = difference(values);
+    if changes.len() < 2 {
+        return vec![];
+    }
+    let sd = std_dev(&changes);
+    (1..=horizon).map(|step| sd * (step as f64).sqrt()).collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalSeek {
+    // Inflação máxima tolerável (% a.a.), constante no horizonte
+    pub ceiling: f64,
+    // Teto em cada passo (% a.a.): a inflação anualizada máxima nos passos restantes, supondo que
+    // os anteriores tenham a inflação prevista. Sobe quando a previsão fica abaixo do teto e desce
+    // quando passa.
+    pub path: Vec<f64>,
+    // Passos (a partir de 0) em que a previsão passa do teto constante
+    pub breaches: Vec<usize>,
+    // Retorno real (% a.a.) com a inflação prevista, anualizada no horizonte
+    pub expected_real_return: f64,
+    // Probabilidade de o retorno real ficar abaixo da meta; None sem medida de incerteza
+    pub shortfall_probability: Option<f64>,
+}
+
+impl GoalSeek {
+    pub fn misses_target(&self, target_real: f64) -> bool {
+        self.expected_real_return < target_real
+    }
+}
+
+// Compara a previsão de inflação com o teto que garante a meta real. Taxas em % a.a.: cada passo
+// (mensal) da previsão é uma inflação em 12 meses, acumulada no horizonte pela taxa mensal
+// equivalente. `std_devs` é a incerteza da previsão em cada passo; vazio quando não há.
+pub fn goal_seek(nominal: f64, target_real: f64, forecast: &[f64], std_devs: &[f64]) -> Option<GoalSeek> {
+    if forecast.is_empty() {
+        return None;
+    }
+
+    let ceiling = max_inflation(nominal, target_real);
+    let horizon = forecast.len() as f64;
+    let growth = |monthly: f64| 1.0 + monthly / 100.0;
+    // Taxa anual equivalente a um fator acumulado em `steps` meses
+    let annualize = |factor: f64, steps: f64| (factor.powf(STEPS_PER_YEAR / steps) - 1.0) * 100.0;
+
+    let budget = growth(monthly_rate(ceiling)).powf(horizon);
+    let mut realized = 1.0;
+    let mut path = Vec::with_capacity(forecast.len());
+    for (step, inflation) in forecast.iter().enumerate() {
+        path.push(annualize(budget / realized, horizon - step as f64));
+        realized *= growth(monthly_rate(*inflation));
+    }
+
+    let average = annualize(realized, horizon);
+    // Erros de previsão de uma trajetória de inflação são fortemente correlacionados: a incerteza
+    // da média fica perto da média das incertezas de cada passo
+    let spread = (!std_devs.is_empty()).then(|| mean(std_devs)).filter(|spread| *spread > 0.0);
+
+    Some(GoalSeek {
+        ceiling,
+        path,
+        breaches: forecast.iter().enumerate().filter(|(_, f)| **f > ceiling).map(|(step, _)| step).collect(),
+        expected_real_return: real_return(nominal, average),
+        shortfall_probability: spread.map(|spread| 1.0 - normal_cdf((ceiling - average) / spread)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ceiling_inverts_the_fisher_equation() {
+        let ceiling = max_inflation(10.0, 5.0);
+
+        assert!((ceiling - 4.761905).abs() < 1e-6);
+        assert!((real_return(10.0, ceiling) - 5.0).abs() < 1e-12);
+
+        let flat = goal_seek(10.0, 5.0, &[ceiling; 4], &[1.0; 4]).unwrap();
+        assert!(flat.path.iter().all(|limit| (limit - ceiling).abs() < 1e-9));
+        assert!(flat.breaches.is_empty());
+        assert!((flat.shortfall_probability.unwrap() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn low_early_inflation_leaves_room_but_late_spike_misses() {
+        let result = goal_seek(10.0, 5.0, &[2.0, 2.0, 8.0, 8.0], &[]).unwrap();
+
+        // Depois de dois passos a 2%, a média restante pode chegar a ~7,6%
+        assert!((result.path[2] - 7.598595).abs() < 1e-5);
+        assert_eq!(result.breaches, vec![2, 3]);
+        assert!(result.misses_target(5.0));
+        assert_eq!(result.shortfall_probability, None);
+    }
+
+    #[test]
+    fn twelve_month_forecasts_compound_as_monthly_rates() {
+        // 1% ao mês equivale a 12,682503% em 12 meses
+        assert!((monthly_rate(12.682503) - 1.0).abs() < 1e-6);
+
+        // Um ano de previsões a 6% em 12 meses acumula 6%, não 1,06^12
+        let result = goal_seek(10.0, 5.0, &[6.0; 12], &[]).unwrap();
+        assert!((result.expected_real_return - 3.773585).abs() < 1e-6);
+        // Depois de seis meses a 6% a.a., resta 1,0476190^2 / 1,06 - 1 para o semestre final
+        assert!((result.path[6] - 3.538271).abs() < 1e-6);
+        assert_eq!(result.breaches.len(), 12);
+    }
+}
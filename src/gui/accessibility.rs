@@ -0,0 +1,157 @@
+use eframe::egui;
+use egui::{Color32, FontId, Stroke};
+use std::collections::BTreeMap;
+
+use crate::config::{Config, DEFAULT_FONT_SIZE};
+
+// Limites da escala da interface e do tamanho da fonte
+const UI_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.75..=3.0;
+const FONT_SIZE_RANGE: std::ops::RangeInclusive<f32> = 9.0..=32.0;
+
+// Atalhos de teclado, listados no painel
+const SHORTCUTS: &[(&str, &str)] = &[
+    ("Tab / Shift+Tab", "próximo / anterior controle"),
+    ("Setas", "ajustam o slider em foco"),
+    ("Espaço / Enter", "acionam o botão ou a caixa em foco"),
+    ("Ctrl+PageDown / Ctrl+PageUp", "próxima / anterior visão"),
+    ("↑ / ↓ e Enter na busca", "escolhem e abrem um resultado"),
+    ("Ctrl+Z / Ctrl+Shift+Z", "desfazer / refazer"),
+];
+
+// Nome lido pelo leitor de tela (AccessKit) em controles sem rótulo visível, como campos que só
+// têm a dica ou botões de ícone
+pub fn named(response: egui::Response, kind: egui::WidgetType, name: &str) -> egui::Response {
+    response.widget_info(|| egui::WidgetInfo::labeled(kind, name));
+    response
+}
+
+// Texto branco sobre preto, bordas brancas nos controles e borda amarela e grossa no controle em
+// foco ou sob o mouse
+fn high_contrast_visuals() -> egui::Visuals {
+    let mut visuals = egui::Visuals::dark();
+    visuals.override_text_color = Some(Color32::WHITE);
+    visuals.panel_fill = Color32::BLACK;
+    visuals.window_fill = Color32::BLACK;
+    visuals.extreme_bg_color = Color32::BLACK;
+    visuals.faint_bg_color = Color32::from_gray(24);
+    visuals.hyperlink_color = Color32::from_rgb(0x66, 0xCC, 0xFF);
+    visuals.selection.bg_fill = Color32::from_rgb(0x00, 0x50, 0xC8);
+    visuals.selection.stroke = Stroke::new(2.0, Color32::WHITE);
+
+    let widgets = &mut visuals.widgets;
+    for style in [&mut widgets.noninteractive, &mut widgets.inactive] {
+        style.bg_stroke = Stroke::new(1.0, Color32::WHITE);
+        style.fg_stroke = Stroke::new(1.5, Color32::WHITE);
+    }
+    widgets.inactive.bg_fill = Color32::BLACK;
+    widgets.inactive.weak_bg_fill = Color32::BLACK;
+    for style in [&mut widgets.hovered, &mut widgets.active, &mut widgets.open] {
+        style.bg_fill = Color32::from_gray(40);
+        style.weak_bg_fill = Color32::from_gray(40);
+        style.bg_stroke = Stroke::new(2.5, Color32::YELLOW);
+        style.fg_stroke = Stroke::new(2.0, Color32::YELLOW);
+    }
+
+    visuals
+}
+
+// Estilos de texto do egui com o texto normal em `font_size` e os demais na mesma proporção
+fn scaled_text_styles(font_size: f32) -> BTreeMap<egui::TextStyle, FontId> {
+    let factor = font_size.clamp(*FONT_SIZE_RANGE.start(), *FONT_SIZE_RANGE.end()) / DEFAULT_FONT_SIZE;
+    egui::Style::default()
+        .text_styles
+        .into_iter()
+        .map(|(style, font)| (style, FontId::new(font.size * factor, font.family)))
+        .collect()
+}
+
+// Tema, escala e opções de acessibilidade, guardados na configuração
+pub struct AccessibilityPanel {
+    // Tema aplicado por último; None antes do primeiro quadro
+    applied: Option<bool>,
+    // Escala, tamanho da fonte e escala nativa do monitor aplicados por último
+    display: Option<(f32, f32, f32)>,
+    // Visual anterior ao alto contraste, restaurado ao desativá-lo
+    base: Option<egui::Visuals>,
+    error: Option<String>,
+}
+
+impl AccessibilityPanel {
+    pub fn new() -> Self {
+        Self {
+            applied: None,
+            display: None,
+            base: None,
+            error: None,
+        }
+    }
+
+    // `native_scale` é a escala do monitor informada pelo sistema (2.0 em telas HiDPI, por exemplo)
+    pub fn apply(&mut self, ctx: &egui::Context, config: &Config, native_scale: f32) {
+        // Durante o arraste de um slider, a troca de escala moveria o slider sob o mouse
+        let display = (config.ui_scale, config.font_size, native_scale);
+        if self.display != Some(display) && !ctx.input(|i| i.pointer.any_down()) {
+            ctx.set_pixels_per_point(native_scale * config.ui_scale.clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end()));
+            let mut style = (*ctx.style()).clone();
+            style.text_styles = scaled_text_styles(config.font_size);
+            ctx.set_style(style);
+            self.display = Some(display);
+        }
+
+        if self.applied == Some(config.high_contrast) {
+            return;
+        }
+        if config.high_contrast {
+            self.base.get_or_insert_with(|| ctx.style().visuals.clone());
+            ctx.set_visuals(high_contrast_visuals());
+        } else if let Some(base) = self.base.take() {
+            ctx.set_visuals(base);
+        }
+        self.applied = Some(config.high_contrast);
+    }
+
+    fn persist(&mut self, config: &Config) {
+        self.error = config.save().err().map(|e| format!("Erro ao salvar a configuração: {}", e));
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &mut Config) {
+        ui.collapsing("Acessibilidade", |ui| {
+            let toggled = ui
+                .checkbox(&mut config.high_contrast, "Alto contraste")
+                .on_hover_text("Texto branco sobre fundo preto e destaque amarelo no controle em foco")
+                .changed();
+
+            let scale = ui
+                .add(egui::Slider::new(&mut config.ui_scale, UI_SCALE_RANGE).step_by(0.05).text("Escala da interface"))
+                .on_hover_text("Multiplica a escala do monitor; aumente em telas 4K");
+            let font = ui.add(
+                egui::Slider::new(&mut config.font_size, FONT_SIZE_RANGE)
+                    .step_by(0.5)
+                    .text("Tamanho da fonte"),
+            );
+            // Aplicadas ao soltar o slider; grava a configuração uma vez por ajuste
+            let adjusted = [scale, font]
+                .iter()
+                .any(|response| response.drag_released() || (response.changed() && !response.dragged()));
+            if ui.button("Restaurar tamanho padrão").clicked() {
+                config.ui_scale = 1.0;
+                config.font_size = DEFAULT_FONT_SIZE;
+                self.persist(config);
+            } else if toggled || adjusted {
+                self.persist(config);
+            }
+            if let Some(error) = &self.error {
+                ui.colored_label(Color32::RED, error);
+            }
+
+            ui.label("Atalhos de teclado:");
+            egui::Grid::new("shortcuts").show(ui, |ui| {
+                for (keys, action) in SHORTCUTS {
+                    ui.strong(*keys);
+                    ui.label(*action);
+                    ui.end_row();
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,141 @@
+use eframe::egui;
+use egui::plot::{PlotPoint, PlotUi, Text, VLine};
+
+use crate::annotations::{self, Annotation};
+use crate::recessions::{self, Recession};
+
+use super::accessibility;
+
+const MARKER_COLOR: egui::Color32 = egui::Color32::from_rgb(200, 120, 0);
+const RECESSION_COLOR: egui::Color32 = egui::Color32::from_rgba_premultiplied(32, 32, 32, 40);
+
+// Eventos do país desenhados nos gráficos de séries temporais
+#[derive(Debug, Clone, Default)]
+pub struct Markers {
+    pub annotations: Vec<Annotation>,
+    pub recessions: Vec<Recession>,
+}
+
+// Anotações do país atual e formulário para editá-las
+pub struct AnnotationsPanel {
+    country: String,
+    pub annotations: Vec<Annotation>,
+    date: String,
+    label: String,
+    error: Option<String>,
+}
+
+impl AnnotationsPanel {
+    pub fn new() -> Self {
+        Self {
+            country: String::new(),
+            annotations: vec![],
+            date: String::new(),
+            label: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn set_country(&mut self, country: &str) {
+        self.country = country.to_string();
+        self.annotations = annotations::load(country);
+    }
+
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    // Troca as anotações do país atual (ao desfazer ou refazer) e as grava
+    pub fn replace(&mut self, annotations: Vec<Annotation>) {
+        self.annotations = annotations;
+        self.persist();
+    }
+
+    fn persist(&mut self) {
+        self.error = annotations::save(&self.country, &self.annotations)
+            .err()
+            .map(|e| e.to_string());
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new(format!("Anotações ({})", self.annotations.len()))
+            .id_source("annotations")
+            .show(ui, |ui| {
+                let mut removed = None;
+                for (i, annotation) in self.annotations.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}: {}", annotation.date, annotation.label));
+                        if ui.small_button("Remover").clicked() {
+                            removed = Some(i);
+                        }
+                    });
+                }
+                if let Some(i) = removed {
+                    self.annotations.remove(i);
+                    self.persist();
+                }
+
+                ui.horizontal(|ui| {
+                    let date = ui.add(egui::TextEdit::singleline(&mut self.date).hint_text("data (DD/MM/AAAA)").desired_width(110.0));
+                    accessibility::named(date, egui::WidgetType::TextEdit, "Data da anotação (DD/MM/AAAA)");
+                    let label = ui.add(egui::TextEdit::singleline(&mut self.label).hint_text("evento").desired_width(200.0));
+                    accessibility::named(label, egui::WidgetType::TextEdit, "Evento da anotação");
+
+                    let ready = !self.date.trim().is_empty() && !self.label.trim().is_empty();
+                    if ui.add_enabled(ready, egui::Button::new("Adicionar")).clicked() {
+                        self.annotations.push(Annotation {
+                            date: self.date.trim().to_string(),
+                            label: self.label.trim().to_string(),
+                        });
+                        self.date.clear();
+                        self.label.clear();
+                        self.persist();
+                    }
+                });
+
+                if let Some(error) = &self.error {
+                    ui.colored_label(egui::Color32::RED, format!("Erro ao salvar as anotações: {}", error));
+                }
+            });
+    }
+}
+
+// Desenha as recessões como faixas sombreadas em um gráfico cujo eixo x é o índice em `dates`
+pub fn draw_recessions(plot_ui: &mut PlotUi, recessions: &[Recession], dates: &[String]) {
+    for recession in recessions {
+        let Some((first, last)) = recessions::span(recession, dates) else {
+            continue;
+        };
+        // Uma linha vertical da largura da recessão, ao contrário de um polígono, não interfere
+        // nos limites automáticos do eixo y
+        let (start, end) = (first as f64 - 0.5, last as f64 + 0.5);
+        let width = plot_ui.screen_from_plot(PlotPoint::new(end, 0.0)).x
+            - plot_ui.screen_from_plot(PlotPoint::new(start, 0.0)).x;
+        plot_ui.vline(
+            VLine::new((start + end) / 2.0)
+                .width(width.max(1.0))
+                .color(RECESSION_COLOR)
+                .name("Recessão"),
+        );
+    }
+}
+
+// Desenha as recessões e as anotações, como linhas verticais rotuladas, em um gráfico cujo eixo x
+// é o índice em `dates` (datas em ordem cronológica)
+pub fn draw_markers(plot_ui: &mut PlotUi, markers: &Markers, dates: &[String]) {
+    draw_recessions(plot_ui, &markers.recessions, dates);
+
+    let top = plot_ui.plot_bounds().max()[1];
+    for annotation in &markers.annotations {
+        let Some(x) = annotations::position(annotation, dates) else {
+            continue;
+        };
+
+        plot_ui.vline(VLine::new(x as f64).color(MARKER_COLOR));
+        plot_ui.text(
+            Text::new(PlotPoint::new(x as f64, top), &annotation.label)
+                .color(MARKER_COLOR)
+                .anchor(egui::Align2::LEFT_TOP),
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use eframe::egui;
+use std::collections::BTreeMap;
+
+use crate::calendar::{self, MONTHS};
+use crate::config::Config;
+use crate::data::Indicator;
+
+const CELL_SIZE: egui::Vec2 = egui::vec2(52.0, 24.0);
+
+// Do verde (menor valor da série) ao vermelho (maior), passando pelo amarelo
+fn cell_color(value: Option<f64>, (min, max): (f64, f64)) -> egui::Color32 {
+    let Some(value) = value else {
+        return egui::Color32::from_gray(90);
+    };
+
+    let position = if max > min { ((value - min) / (max - min)).clamp(0.0, 1.0) } else { 0.5 };
+    if position < 0.5 {
+        egui::Color32::from_rgb((510.0 * position) as u8, 200, 80)
+    } else {
+        egui::Color32::from_rgb(255, (200.0 * 2.0 * (1.0 - position)) as u8, 80)
+    }
+}
+
+// Visão de análise: inflação de um país em ano × mês, com cores pela magnitude, para ler a
+// sazonalidade e os períodos de crise
+pub struct CalendarView {
+    // None para a inflação do país configurado
+    series: Option<String>,
+}
+
+impl CalendarView {
+    pub fn new() -> Self {
+        Self { series: None }
+    }
+
+    fn cell(ui: &mut egui::Ui, config: &Config, value: Option<f64>, range: (f64, f64), hover: String) {
+        let (rect, response) = ui.allocate_exact_size(CELL_SIZE, egui::Sense::hover());
+        ui.painter().rect_filled(rect, 2.0, cell_color(value, range));
+        ui.painter().text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            value.map(|v| config.format_value(v)).unwrap_or_else(|| "-".to_string()),
+            egui::FontId::monospace(11.0),
+            egui::Color32::BLACK,
+        );
+        response.on_hover_text(hover);
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, series: &BTreeMap<String, Vec<(String, f64)>>) {
+        let default = format!("{}_{}", Indicator::Inflation.key(), config.country);
+        let selected = self.series.clone().unwrap_or(default);
+        egui::ComboBox::from_label("País")
+            .selected_text(&selected)
+            .show_ui(ui, |ui| {
+                let inflation = series.keys().filter(|name| Indicator::from_series_name(name) == Some(Indicator::Inflation));
+                for name in inflation {
+                    ui.selectable_value(&mut self.series, Some(name.clone()), name);
+                }
+            });
+
+        let Some(values) = series.get(&selected) else {
+            ui.label(format!("Série {} não carregada; busque-a na visão de previsão.", selected));
+            return;
+        };
+        let calendar = calendar::calendar(values);
+        let Some(range) = calendar.range() else {
+            ui.label("A série não tem datas no formato DD/MM/AAAA.");
+            return;
+        };
+        ui.horizontal(|ui| {
+            ui.label("Escala:");
+            ui.colored_label(cell_color(Some(range.0), range), config.format_value(range.0));
+            ui.label("→");
+            ui.colored_label(cell_color(Some(range.1), range), config.format_value(range.1));
+        });
+
+        egui::ScrollArea::both().show(ui, |ui| {
+            egui::Grid::new("inflation_calendar").spacing([2.0, 2.0]).show(ui, |ui| {
+                ui.label("");
+                for month in MONTHS {
+                    ui.strong(month);
+                }
+                ui.end_row();
+
+                for (year, row) in calendar.years.iter().zip(&calendar.values) {
+                    ui.strong(year.to_string());
+                    for (month, value) in row.iter().enumerate() {
+                        let hover = match value {
+                            Some(value) => format!("{}/{}: {}", MONTHS[month], year, config.format_value(*value)),
+                            None => format!("{}/{}: sem observação", MONTHS[month], year),
+                        };
+                        Self::cell(ui, config, *value, range, hover);
+                    }
+                    ui.end_row();
+                }
+
+                ui.strong("Média");
+                for (month, mean) in calendar.monthly_means().iter().enumerate() {
+                    let hover = format!("Média de {} entre os anos", MONTHS[month]);
+                    Self::cell(ui, config, *mean, range, hover);
+                }
+                ui.end_row();
+            });
+        });
+    }
+}
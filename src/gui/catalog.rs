@@ -0,0 +1,168 @@
+use eframe::egui;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::catalog::{self, frequency_label, Coverage, Entry};
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{get_indicator, Indicator};
+use crate::watchlist::WatchItem;
+
+type Series = Vec<(String, f64)>;
+// None enquanto a busca não termina
+type State = Option<Result<(Coverage, Series), String>>;
+type Slot = Arc<Mutex<State>>;
+
+// Catálogo dos indicadores do provedor: busca por nome, unidade ou país, cobertura de cada país
+// (primeira e última observação) e abertura da série na previsão
+pub struct CatalogView {
+    entries: Vec<Entry>,
+    query: String,
+    coverage: HashMap<(Indicator, String), Slot>,
+    // Série a abrir assim que a busca terminar
+    pending_open: Option<(Indicator, String)>,
+}
+
+impl CatalogView {
+    pub fn new() -> Self {
+        Self {
+            entries: catalog::entries(),
+            query: String::new(),
+            coverage: HashMap::new(),
+            pending_open: None,
+        }
+    }
+
+    fn fetch(&mut self, config: &Config, indicator: Indicator, country: &str) {
+        let slot: Slot = Arc::new(Mutex::new(None));
+        self.coverage.insert((indicator, country.to_string()), slot.clone());
+
+        let config = config.clone();
+        let country = country.to_string();
+        tokio::spawn(async move {
+            let result = get_indicator(ApiClient::shared(), &config, indicator, &country)
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|data| {
+                    let series: Series = data.into_iter().map(|obs| (obs.date, obs.value)).collect();
+                    let dates: Vec<String> = series.iter().map(|(date, _)| date.clone()).collect();
+                    let coverage = Coverage::of(&dates).ok_or("Série vazia no provedor")?;
+                    Ok((coverage, series))
+                });
+
+            *slot.lock().unwrap() = Some(result);
+        });
+    }
+
+    fn state(&self, indicator: Indicator, country: &str) -> Option<State> {
+        self.coverage
+            .get(&(indicator, country.to_string()))
+            .map(|slot| slot.lock().unwrap().clone())
+    }
+
+    // Retorna a série escolhida para abrir na previsão
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config) -> Option<(WatchItem, Series)> {
+        ui.horizontal(|ui| {
+            let label = ui.label("Buscar:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text("indicador, unidade ou país")
+                    .desired_width(220.0),
+            )
+            .labelled_by(label.id);
+        });
+        ui.separator();
+
+        if let Some((indicator, country)) = self.pending_open.clone() {
+            match self.state(indicator, &country).flatten() {
+                Some(Ok((_, series))) => {
+                    self.pending_open = None;
+                    return Some((WatchItem { country, indicator }, series));
+                }
+                Some(Err(_)) => self.pending_open = None,
+                None => {}
+            }
+        }
+
+        let mut fetch = Vec::new();
+        let mut open = None;
+        let entries: Vec<Entry> = self.entries.iter().filter(|entry| entry.matches(&self.query)).cloned().collect();
+        if entries.is_empty() {
+            ui.label("Nenhum indicador encontrado");
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for entry in &entries {
+                let indicator = entry.indicator;
+                ui.group(|ui| {
+                    ui.horizontal(|ui| {
+                        ui.strong(indicator.label());
+                        ui.weak(format!("({}, {})", indicator.key(), indicator.unit()));
+                        if ui.small_button("Verificar cobertura").clicked() {
+                            fetch.extend(entry.countries.iter().map(|country| (indicator, country.clone())));
+                        }
+                    });
+                    ui.label(entry.description);
+
+                    egui::Grid::new(("catalog", indicator.key())).striped(true).show(ui, |ui| {
+                        for header in ["País", "Frequência", "Primeira", "Última", "Observações", ""] {
+                            ui.strong(header);
+                        }
+                        ui.end_row();
+
+                        for country in &entry.countries {
+                            ui.label(country);
+                            match self.state(indicator, country) {
+                                None => {
+                                    ui.weak("não verificado");
+                                    ui.label("");
+                                    ui.label("");
+                                    ui.label("");
+                                }
+                                Some(None) => {
+                                    ui.spinner();
+                                    ui.label("");
+                                    ui.label("");
+                                    ui.label("");
+                                }
+                                Some(Some(Err(e))) => {
+                                    ui.colored_label(egui::Color32::RED, "indisponível").on_hover_text(e);
+                                    ui.label("");
+                                    ui.label("");
+                                    ui.label("");
+                                }
+                                Some(Some(Ok((coverage, _)))) => {
+                                    ui.label(frequency_label(coverage.frequency));
+                                    ui.label(&coverage.first);
+                                    ui.label(&coverage.last);
+                                    ui.label(coverage.observations.to_string());
+                                }
+                            }
+                            if ui.button("Carregar").clicked() {
+                                open = Some((indicator, country.clone()));
+                            }
+                            ui.end_row();
+                        }
+                    });
+                });
+            }
+        });
+
+        for (indicator, country) in fetch {
+            self.fetch(config, indicator, &country);
+        }
+
+        let (indicator, country) = open?;
+        match self.state(indicator, &country).flatten() {
+            Some(Ok((_, series))) => return Some((WatchItem { country, indicator }, series)),
+            // Ainda não buscada (ou com erro): busca e abre quando chegar
+            Some(Err(_)) | None => {
+                if !matches!(self.state(indicator, &country), Some(None)) {
+                    self.fetch(config, indicator, &country);
+                }
+                self.pending_open = Some((indicator, country));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,195 @@
+use eframe::egui;
+use egui::plot::{
+    Bar, BarChart, BoxElem, BoxPlot, BoxSpread, HLine, Legend, Line, Plot, PlotPoints, PlotUi, Points, Polygon,
+};
+use std::ops::RangeInclusive;
+
+use crate::decimate;
+use crate::market::Candle;
+use crate::targets::InflationTarget;
+
+use super::annotations::{draw_markers, Markers};
+
+// Formata o eixo x de gráficos indexados por posição, exibindo a data nos valores inteiros
+pub fn date_axis(dates: Vec<String>) -> impl Fn(f64, &RangeInclusive<f64>) -> String {
+    move |x, _| {
+        let i = x.round();
+        if (x - i).abs() < 0.01 && i >= 0.0 {
+            dates.get(i as usize).cloned().unwrap_or_default()
+        } else {
+            String::new()
+        }
+    }
+}
+
+// Pontos de uma linha reduzidos por LTTB a cerca de um por pixel da faixa visível, para séries com
+// dezenas de milhares de pontos não pesarem a cada quadro
+fn visible_points(plot_ui: &PlotUi, points: &[[f64; 2]]) -> PlotPoints {
+    let bounds = plot_ui.plot_bounds();
+    let columns = plot_ui.transform().frame().width() as usize;
+    PlotPoints::new(decimate::lttb(points, (bounds.min()[0], bounds.max()[0]), columns))
+}
+
+fn indexed(values: &[f64]) -> Vec<[f64; 2]> {
+    values.iter().enumerate().map(|(x, y)| [x as f64, *y]).collect()
+}
+
+// Linha sobreposta à série (ex.: média móvel), sem pontos no período de aquecimento
+pub struct Overlay {
+    pub name: String,
+    pub values: Vec<Option<f64>>,
+    pub color: egui::Color32,
+}
+
+// Forma de exibir a série atual: linhas (padrão, séries macro) ou candles com volume,
+// quando há cotações OHLCV
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartType {
+    #[default]
+    Line,
+    Candlestick,
+}
+
+impl ChartType {
+    pub fn for_series(candles: Option<&[Candle]>) -> Self {
+        match candles {
+            Some(candles) if !candles.is_empty() => ChartType::Candlestick,
+            _ => ChartType::Line,
+        }
+    }
+}
+
+// Sobreposições e previsão, comuns aos dois tipos de gráfico
+fn draw_overlays_and_forecast(plot_ui: &mut PlotUi, values: &[f64], forecast: &[f64], overlays: &[Overlay]) {
+    for overlay in overlays {
+        let points: Vec<[f64; 2]> = overlay
+            .values
+            .iter()
+            .enumerate()
+            .filter_map(|(x, y)| y.map(|y| [x as f64, y]))
+            .collect();
+        let points = visible_points(plot_ui, &points);
+        plot_ui.line(Line::new(points).name(&overlay.name).color(overlay.color));
+    }
+
+    if !forecast.is_empty() {
+        // A previsão começa no último ponto observado para a linha ficar contínua
+        let start = values.len().saturating_sub(1);
+        let points: PlotPoints = values
+            .last()
+            .into_iter()
+            .chain(forecast)
+            .enumerate()
+            .map(|(i, y)| [(start + i) as f64, *y])
+            .collect();
+        plot_ui.line(Line::new(points).name("Previsão").style(egui::plot::LineStyle::dashed_loose()));
+    }
+}
+
+// Meta de inflação com o intervalo de tolerância em faixa, da primeira observação ao fim da previsão
+fn draw_target_band(plot_ui: &mut PlotUi, target: &InflationTarget, end: f64) {
+    let color = egui::Color32::from_rgb(44, 160, 44);
+    let (start, lower, upper) = (-0.5, target.lower(), target.upper());
+    let band = PlotPoints::new(vec![[start, lower], [end, lower], [end, upper], [start, upper]]);
+    plot_ui.polygon(Polygon::new(band).color(color).name("Intervalo da meta"));
+    plot_ui.hline(HLine::new(target.center).color(color).style(egui::plot::LineStyle::dashed_loose()).name("Meta"));
+}
+
+// Série histórica seguida da previsão, com as anotações do país e, opcionalmente,
+// uma série de referência nas mesmas datas (ex.: a original, sem ajuste sazonal), a faixa da
+// meta de inflação e os pontos da previsão destacados por divergirem das expectativas de mercado
+#[allow(clippy::too_many_arguments)]
+pub fn show_series_chart(
+    ui: &mut egui::Ui,
+    dates: &[String],
+    values: &[f64],
+    reference: Option<(&str, &[f64])>,
+    forecast: &[f64],
+    overlays: &[Overlay],
+    divergences: &[[f64; 2]],
+    target: Option<&InflationTarget>,
+    markers: &Markers,
+) {
+    Plot::new("series_chart")
+        .legend(Legend::default())
+        .height(300.0)
+        .x_axis_formatter(date_axis(dates.to_vec()))
+        .show(ui, |plot_ui| {
+            if let Some(target) = target {
+                draw_target_band(plot_ui, target, (values.len() + forecast.len()) as f64);
+            }
+
+            let history = visible_points(plot_ui, &indexed(values));
+            plot_ui.line(Line::new(history).name("Histórico"));
+
+            if let Some((name, reference)) = reference {
+                let points = visible_points(plot_ui, &indexed(reference));
+                plot_ui.line(Line::new(points).name(name).color(egui::Color32::GRAY));
+            }
+
+            draw_overlays_and_forecast(plot_ui, values, forecast, overlays);
+            if !divergences.is_empty() {
+                let points = Points::new(divergences.to_vec()).radius(4.0).color(egui::Color32::from_rgb(214, 39, 40));
+                plot_ui.points(points.name("Divergência das expectativas"));
+            }
+
+            draw_markers(plot_ui, markers, dates);
+        });
+}
+
+// Candles (alta em verde, baixa em vermelho) com o volume em um gráfico abaixo; a previsão
+// parte do último fechamento
+pub fn show_candlestick_chart(
+    ui: &mut egui::Ui,
+    candles: &[Candle],
+    forecast: &[f64],
+    overlays: &[Overlay],
+    markers: &Markers,
+) {
+    let dates: Vec<String> = candles.iter().map(|candle| candle.date.clone()).collect();
+    let closes: Vec<f64> = candles.iter().map(|candle| candle.close).collect();
+    let color = |candle: &Candle| {
+        if candle.close >= candle.open {
+            egui::Color32::from_rgb(44, 160, 44)
+        } else {
+            egui::Color32::from_rgb(214, 39, 40)
+        }
+    };
+
+    Plot::new("candlestick_chart")
+        .legend(Legend::default())
+        .height(300.0)
+        .x_axis_formatter(date_axis(dates.clone()))
+        .show(ui, |plot_ui| {
+            let boxes = candles
+                .iter()
+                .enumerate()
+                .map(|(x, candle)| {
+                    let body_low = candle.open.min(candle.close);
+                    let body_high = candle.open.max(candle.close);
+                    BoxElem::new(x as f64, BoxSpread::new(candle.low, body_low, candle.close, body_high, candle.high))
+                        .box_width(0.7)
+                        .whisker_width(0.0)
+                        .fill(color(candle))
+                        .stroke(egui::Stroke::new(1.0, color(candle)))
+                })
+                .collect();
+            plot_ui.box_plot(BoxPlot::new(boxes).name("Cotações"));
+
+            draw_overlays_and_forecast(plot_ui, &closes, forecast, overlays);
+            draw_markers(plot_ui, markers, &dates);
+        });
+
+    Plot::new("volume_chart")
+        .height(80.0)
+        .include_y(0.0)
+        .x_axis_formatter(date_axis(dates))
+        .show(ui, |plot_ui| {
+            let bars = candles
+                .iter()
+                .enumerate()
+                .map(|(x, candle)| Bar::new(x as f64, candle.volume).fill(color(candle)).width(0.7))
+                .collect();
+            plot_ui.bar_chart(BarChart::new(bars).name("Volume"));
+        });
+}
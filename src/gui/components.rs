@@ -0,0 +1,306 @@
+use eframe::egui;
+use egui::plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use crate::components::{self, get_cpi_components, CpiComponent};
+use crate::client::ApiClient;
+use crate::config::Config;
+
+use super::annotations::{draw_markers, Markers};
+use super::chart::date_axis;
+
+// Quantidade de datas recentes exibidas no gráfico de contribuições
+const CHART_DATES: usize = 24;
+
+const PALETTE: [egui::Color32; 8] = [
+    egui::Color32::from_rgb(31, 119, 180),
+    egui::Color32::from_rgb(255, 127, 14),
+    egui::Color32::from_rgb(44, 160, 44),
+    egui::Color32::from_rgb(214, 39, 40),
+    egui::Color32::from_rgb(148, 103, 189),
+    egui::Color32::from_rgb(140, 86, 75),
+    egui::Color32::from_rgb(227, 119, 194),
+    egui::Color32::from_rgb(127, 127, 127),
+];
+
+// None enquanto a busca não termina
+type State = Option<Result<Vec<CpiComponent>, String>>;
+
+// Visão de análise: subíndices do IPC e sua contribuição para a inflação cheia
+pub struct ComponentsView {
+    components: Arc<Mutex<State>>,
+    requested: bool,
+    // Próximo valor previsto por componente, na ordem de `components`
+    forecasts: Vec<Option<f64>>,
+    // Gastos mensais do usuário por componente, para a inflação pessoal
+    spending: BTreeMap<String, f64>,
+    spending_error: Option<String>,
+}
+
+impl ComponentsView {
+    pub fn new() -> Self {
+        Self {
+            components: Arc::new(Mutex::new(None)),
+            requested: false,
+            forecasts: vec![],
+            spending: components::load_spending(),
+            spending_error: None,
+        }
+    }
+
+    fn load(&mut self, config: &Config) {
+        let config = config.clone();
+        let components = self.components.clone();
+
+        self.requested = true;
+        self.forecasts.clear();
+        *components.lock().unwrap() = None;
+
+        tokio::spawn(async move {
+            let result = get_cpi_components(ApiClient::shared(), &config, &config.country)
+                .await
+                .map_err(|e| e.to_string());
+
+            *components.lock().unwrap() = Some(result);
+        });
+    }
+
+    // `forecast` ajusta o modelo selecionado na janela principal e prevê o próximo passo. Retorna
+    // a série da inflação pessoal quando o usuário pede para abri-la na previsão.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        config: &Config,
+        markers: &Markers,
+        forecast: &dyn Fn(&[f64]) -> Vec<f64>,
+    ) -> Option<Vec<(String, f64)>> {
+        if !self.requested {
+            self.load(config);
+        }
+
+        // A cópia é feita antes do match para liberar o lock antes de uma nova busca
+        let snapshot = self.components.lock().unwrap().clone();
+        let components = match snapshot {
+            None => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Buscando os componentes do IPC...");
+                });
+                return None;
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao buscar os componentes: {}", e));
+                if ui.button("Tentar novamente").clicked() {
+                    self.load(config);
+                }
+                return None;
+            }
+            Some(Ok(components)) if components.is_empty() => {
+                ui.label("O provedor não retornou componentes para este país.");
+                return None;
+            }
+            Some(Ok(components)) => components,
+        };
+
+        ui.horizontal(|ui| {
+            if ui.button("Atualizar").clicked() {
+                self.load(config);
+            }
+            if ui.button("Prever componentes").clicked() {
+                self.forecasts = components
+                    .iter()
+                    .map(|component| {
+                        let values: Vec<f64> = component.values.iter().map(|obs| obs.value).collect();
+                        forecast(&values).first().copied()
+                    })
+                    .collect();
+            }
+        });
+
+        let weights = components::normalized_weights(&components);
+        let contributions = components::contributions(&components);
+        let latest = contributions.last();
+
+        egui::Grid::new("cpi_components").striped(true).show(ui, |ui| {
+            ui.strong("Componente");
+            ui.strong("Peso");
+            ui.strong("Última variação");
+            ui.strong("Contribuição");
+            ui.strong("Previsão");
+            ui.strong("Contribuição prevista");
+            ui.end_row();
+
+            for (i, component) in components.iter().enumerate() {
+                let last = component.values.last().map(|obs| obs.value);
+                let predicted = self.forecasts.get(i).copied().flatten();
+
+                ui.colored_label(PALETTE[i % PALETTE.len()], &component.name);
+                ui.label(format!("{}%", config.format_value(weights[i] * 100.0)));
+                ui.label(last.map(|v| config.format_value(v)).unwrap_or_else(|| "-".to_string()));
+                ui.label(
+                    latest
+                        .map(|c| config.format_value(c.by_component[i]))
+                        .unwrap_or_else(|| "-".to_string()),
+                );
+                ui.label(predicted.map(|v| config.format_value(v)).unwrap_or_else(|| "-".to_string()));
+                ui.label(
+                    predicted
+                        .map(|v| config.format_value(v * weights[i]))
+                        .unwrap_or_else(|| "-".to_string()),
+                );
+                ui.end_row();
+            }
+
+            if let Some(latest) = latest {
+                ui.strong("Índice cheio");
+                ui.label("100%");
+                ui.label("");
+                ui.strong(config.format_value(latest.headline()));
+                ui.label("");
+                let predicted: Option<f64> = if self.forecasts.is_empty() {
+                    None
+                } else {
+                    self.forecasts.iter().zip(&weights).map(|(v, w)| v.map(|v| v * w)).sum()
+                };
+                ui.strong(predicted.map(|v| config.format_value(v)).unwrap_or_else(|| "-".to_string()));
+                ui.end_row();
+            }
+        });
+
+        // Barras empilhadas com a contribuição de cada componente nas datas mais recentes
+        let recent = &contributions[contributions.len().saturating_sub(CHART_DATES)..];
+        let dates: Vec<String> = recent.iter().map(|c| c.date.clone()).collect();
+
+        Plot::new("cpi_contributions")
+            .legend(Legend::default())
+            .x_axis_formatter(date_axis(dates.clone()))
+            .show(ui, |plot_ui| {
+                let mut charts: Vec<BarChart> = Vec::with_capacity(components.len());
+                for (i, component) in components.iter().enumerate() {
+                    let bars = recent
+                        .iter()
+                        .enumerate()
+                        .map(|(x, c)| Bar::new(x as f64, c.by_component[i]).width(0.7))
+                        .collect();
+
+                    let others: Vec<&BarChart> = charts.iter().collect();
+                    let chart = BarChart::new(bars)
+                        .name(&component.name)
+                        .color(PALETTE[i % PALETTE.len()])
+                        .stack_on(&others);
+                    charts.push(chart);
+                }
+
+                for chart in charts {
+                    plot_ui.bar_chart(chart);
+                }
+
+                draw_markers(plot_ui, markers, &dates);
+            });
+
+        self.show_personal(ui, config, &components, &contributions, markers)
+    }
+
+    // Inflação com os pesos dos gastos do próprio usuário, comparada com a oficial
+    fn show_personal(
+        &mut self,
+        ui: &mut egui::Ui,
+        config: &Config,
+        components: &[CpiComponent],
+        contributions: &[components::Contribution],
+        markers: &Markers,
+    ) -> Option<Vec<(String, f64)>> {
+        let mut open = None;
+
+        ui.collapsing("Minha inflação", |ui| {
+            ui.label("Informe quanto gasta por mês em cada grupo; os não informados ficam com o peso oficial.");
+
+            let official = components::normalized_weights(components);
+            let weights = components::personal_weights(components, &self.spending);
+            egui::Grid::new("personal_spending").striped(true).show(ui, |ui| {
+                ui.strong("Componente");
+                ui.strong("Gasto mensal");
+                ui.strong("Seu peso");
+                ui.strong("Peso oficial");
+                ui.end_row();
+
+                for (i, component) in components.iter().enumerate() {
+                    ui.colored_label(PALETTE[i % PALETTE.len()], &component.name);
+                    ui.horizontal(|ui| {
+                        let mut informed = self.spending.contains_key(&component.name);
+                        if ui.checkbox(&mut informed, "").changed() {
+                            if informed {
+                                self.spending.insert(component.name.clone(), 0.0);
+                            } else {
+                                self.spending.remove(&component.name);
+                            }
+                        }
+                        if let Some(amount) = self.spending.get_mut(&component.name) {
+                            ui.add(egui::DragValue::new(amount).speed(10.0).clamp_range(0.0..=f64::MAX));
+                        }
+                    });
+                    ui.label(format!("{}%", config.format_value(weights[i] * 100.0)));
+                    ui.label(format!("{}%", config.format_value(official[i] * 100.0)));
+                    ui.end_row();
+                }
+            });
+
+            ui.horizontal(|ui| {
+                if ui.button("Salvar gastos").clicked() {
+                    self.spending_error = components::save_spending(&self.spending)
+                        .err()
+                        .map(|e| format!("Erro ao salvar os gastos: {}", e));
+                }
+                if ui.button("Limpar").clicked() {
+                    self.spending.clear();
+                }
+            });
+            if let Some(error) = &self.spending_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+
+            let personal = components::personal_inflation(components, &self.spending);
+            let (Some(latest), Some(headline)) = (personal.last(), contributions.last()) else {
+                return;
+            };
+            ui.label(format!(
+                "Sua inflação em {}: {} (oficial: {})",
+                latest.date,
+                config.format_value(latest.value),
+                config.format_value(headline.headline())
+            ));
+            if !self.forecasts.is_empty() {
+                let predicted: Option<f64> = self.forecasts.iter().zip(&weights).map(|(v, w)| v.map(|v| v * w)).sum();
+                if let Some(predicted) = predicted {
+                    ui.label(format!("Previsão da sua inflação: {}", config.format_value(predicted)));
+                }
+            }
+
+            let dates: Vec<String> = personal.iter().map(|obs| obs.date.clone()).collect();
+            Plot::new("personal_inflation")
+                .legend(Legend::default())
+                .height(200.0)
+                .x_axis_formatter(date_axis(dates.clone()))
+                .show(ui, |plot_ui| {
+                    draw_markers(plot_ui, markers, &dates);
+                    let line = |values: Vec<f64>| -> PlotPoints {
+                        values.into_iter().enumerate().map(|(x, y)| [x as f64, y]).collect()
+                    };
+                    let values = personal.iter().map(|obs| obs.value).collect();
+                    plot_ui.line(Line::new(line(values)).name("Sua inflação"));
+                    plot_ui.line(
+                        Line::new(line(contributions.iter().map(|c| c.headline()).collect()))
+                            .name("Oficial")
+                            .color(egui::Color32::GRAY),
+                    );
+                });
+
+            if ui.button("Abrir na previsão").clicked() {
+                open = Some(personal.into_iter().map(|obs| (obs.date, obs.value)).collect());
+            }
+        });
+
+        open
+    }
+}
@@ -0,0 +1,112 @@
+use eframe::egui;
+use std::collections::BTreeMap;
+
+use crate::correlation::{self, CorrelationMatrix, Transform};
+use crate::export;
+
+const CELL_SIZE: egui::Vec2 = egui::vec2(64.0, 24.0);
+
+// Séries de um cálculo, por nome e tamanho
+type Selection = Vec<(String, usize)>;
+
+// Vermelho para correlação positiva, azul para negativa, mais intenso quanto maior o módulo
+fn cell_color(value: Option<f64>) -> egui::Color32 {
+    let Some(value) = value else {
+        return egui::Color32::from_gray(90);
+    };
+
+    let fade = (255.0 * (1.0 - value.abs().min(1.0))) as u8;
+    if value >= 0.0 {
+        egui::Color32::from_rgb(255, fade, fade)
+    } else {
+        egui::Color32::from_rgb(fade, fade, 255)
+    }
+}
+
+// Visão de análise: matriz de correlação entre as séries carregadas, como mapa de calor
+pub struct CorrelationView {
+    transform: Transform,
+    // Matriz da última combinação de séries e transformação
+    matrix: Option<(Transform, Selection, CorrelationMatrix)>,
+    export_result: Option<Result<String, String>>,
+}
+
+impl CorrelationView {
+    pub fn new() -> Self {
+        Self {
+            transform: Transform::Levels,
+            matrix: None,
+            export_result: None,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, series: &BTreeMap<String, Vec<(String, f64)>>) {
+        ui.horizontal(|ui| {
+            for transform in Transform::all() {
+                ui.radio_value(&mut self.transform, transform, transform.label());
+            }
+        });
+
+        if series.len() < 2 {
+            ui.label("Carregue ao menos duas séries (em cache, derivadas ou de ativos) para comparar.");
+            return;
+        }
+
+        let key: Selection = series.iter().map(|(name, values)| (name.clone(), values.len())).collect();
+        let stale = !matches!(&self.matrix, Some((transform, cached, _)) if *transform == self.transform && *cached == key);
+        if stale {
+            self.matrix = Some((self.transform, key, correlation::matrix(series, self.transform)));
+        }
+        let Some((_, _, matrix)) = &self.matrix else {
+            return;
+        };
+
+        if ui.button("Exportar CSV").clicked() {
+            let name = format!("correlacoes-{:?}", self.transform).to_lowercase();
+            self.export_result = Some(
+                export::write_csv(&name, &matrix.to_csv())
+                    .map(|path| path.display().to_string())
+                    .map_err(|e| e.to_string()),
+            );
+        }
+        match &self.export_result {
+            Some(Ok(path)) => {
+                ui.label(format!("Arquivo gerado: {}", path));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao exportar: {}", e));
+            }
+            None => {}
+        }
+
+        egui::ScrollArea::both().show(ui, |ui| {
+            egui::Grid::new("correlation_heatmap").spacing([2.0, 2.0]).show(ui, |ui| {
+                ui.label("");
+                for name in &matrix.names {
+                    ui.strong(name);
+                }
+                ui.end_row();
+
+                for (i, row) in matrix.values.iter().enumerate() {
+                    ui.strong(&matrix.names[i]);
+                    for (j, value) in row.iter().enumerate() {
+                        let (rect, response) = ui.allocate_exact_size(CELL_SIZE, egui::Sense::hover());
+                        ui.painter().rect_filled(rect, 2.0, cell_color(*value));
+                        ui.painter().text(
+                            rect.center(),
+                            egui::Align2::CENTER_CENTER,
+                            value.map(|v| format!("{:.2}", v)).unwrap_or_else(|| "-".to_string()),
+                            egui::FontId::monospace(12.0),
+                            egui::Color32::BLACK,
+                        );
+                        response.on_hover_text(format!(
+                            "{} × {}: {} datas em comum",
+                            matrix.names[i], matrix.names[j], matrix.overlap[i][j]
+                        ));
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,315 @@
+use eframe::egui;
+use egui::plot::{Line, Plot, PlotPoints};
+use std::sync::{Arc, Mutex};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{get_indicator, Indicator};
+use crate::prefetch;
+use crate::report::{self, DashboardCard};
+use crate::tags::Tags;
+use crate::watchlist::{self, WatchItem};
+
+use super::accessibility;
+use super::state::{Message, Outbox};
+
+type Series = Vec<(String, f64)>;
+
+struct Card {
+    item: WatchItem,
+    data: Arc<Mutex<Option<Result<Series, String>>>>,
+    // Próximo ponto previsto, calculado uma vez quando os dados chegam
+    next: Option<Option<f64>>,
+}
+
+impl Card {
+    fn new(item: WatchItem, config: &Config) -> Self {
+        let card = Self {
+            item,
+            data: Arc::new(Mutex::new(None)),
+            next: None,
+        };
+        card.fetch(config, true);
+        card
+    }
+
+    // Com `prefetched`, uma série buscada antecipadamente há pouco é usada sem nova requisição
+    fn fetch(&self, config: &Config, prefetched: bool) {
+        let config = config.clone();
+        let item = self.item.clone();
+        let data = self.data.clone();
+
+        *data.lock().unwrap() = None;
+
+        tokio::spawn(async move {
+            let client = ApiClient::shared();
+            let result = if prefetched {
+                prefetch::get_indicator(client, &config, item.indicator, &item.country).await
+            } else {
+                get_indicator(client, &config, item.indicator, &item.country).await
+            };
+            let result = result
+                .map(|series| series.into_iter().map(|obs| (obs.date, obs.value)).collect())
+                .map_err(|e| e.to_string());
+
+            *data.lock().unwrap() = Some(result);
+        });
+    }
+}
+
+// Demais indicadores do país de um cartão recém-adicionado, para acompanhá-los sem espera
+fn prefetch_others(config: &Config, item: &WatchItem, outbox: Outbox) {
+    if !prefetch::allowed(config) {
+        return;
+    }
+    let config = config.clone();
+    let item = item.clone();
+    tokio::spawn(async move {
+        let failures = prefetch::prefetch_country(ApiClient::shared(), &config, &item.country, item.indicator).await;
+        if !failures.is_empty() {
+            outbox.send(Message::Warnings(failures));
+        }
+    });
+}
+
+// Painel inicial: um cartão com minigráfico para cada par (país, indicador) acompanhado
+pub struct Dashboard {
+    cards: Vec<Card>,
+    loaded: bool,
+    new_country: String,
+    new_indicator: Indicator,
+    error: Option<String>,
+    // Só os cartões das séries com esta etiqueta; None mostra todos
+    tag: Option<String>,
+    // Caminho do último retrato em HTML, ou o erro ao gravá-lo
+    export: Option<Result<String, String>>,
+}
+
+impl Dashboard {
+    pub fn new() -> Self {
+        Self {
+            cards: vec![],
+            loaded: false,
+            new_country: String::new(),
+            new_indicator: Indicator::Inflation,
+            error: None,
+            tag: None,
+            export: None,
+        }
+    }
+
+    fn persist(&mut self) {
+        let items: Vec<WatchItem> = self.cards.iter().map(|card| card.item.clone()).collect();
+        self.error = watchlist::save(&items).err().map(|e| e.to_string());
+    }
+
+    fn visible(&self, card: &Card, tags: &Tags) -> bool {
+        match &self.tag {
+            Some(tag) => tags.has(&card.item.name(), tag),
+            None => true,
+        }
+    }
+
+    // Cartões visíveis já carregados; os com erro ou ainda buscando ficam de fora
+    fn export_html(&mut self, config: &Config, tags: &Tags) {
+        let loaded: Vec<(String, Vec<String>, Series, Option<f64>)> = self
+            .cards
+            .iter()
+            .filter(|card| self.visible(card, tags))
+            .filter_map(|card| {
+                let series = card.data.lock().unwrap().clone()?.ok()?;
+                let title = format!("{} · {}", card.item.indicator.label(), card.item.country);
+                Some((title, tags.of(&card.item.name()), series, card.next.flatten()))
+            })
+            .collect();
+        let columns: Vec<(Vec<String>, Vec<f64>)> =
+            loaded.iter().map(|(_, _, series, _)| series.iter().cloned().unzip()).collect();
+        let cards: Vec<DashboardCard> = loaded
+            .iter()
+            .zip(&columns)
+            .map(|((title, tags, _, next), (dates, values))| DashboardCard {
+                title: title.clone(),
+                dates,
+                values,
+                next: *next,
+                tags: tags.clone(),
+            })
+            .collect();
+
+        self.export = Some(
+            report::write_dashboard_html(&cards, config)
+                .map(|path| path.display().to_string())
+                .map_err(|e| e.to_string()),
+        );
+    }
+
+    // Retorna o item clicado e sua série, para abrir a análise completa
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        config: &Config,
+        forecast: &dyn Fn(&[f64]) -> Vec<f64>,
+        tags: &Tags,
+        outbox: Outbox,
+    ) -> Option<(WatchItem, Series)> {
+        if !self.loaded {
+            self.loaded = true;
+            self.cards = watchlist::load(&config.country)
+                .into_iter()
+                .map(|item| Card::new(item, config))
+                .collect();
+        }
+
+        ui.horizontal(|ui| {
+            let country = ui.add(egui::TextEdit::singleline(&mut self.new_country).hint_text("país").desired_width(120.0));
+            accessibility::named(country, egui::WidgetType::TextEdit, "País a acompanhar");
+            egui::ComboBox::from_id_source("watch_indicator")
+                .selected_text(self.new_indicator.label())
+                .show_ui(ui, |ui| {
+                    for indicator in Indicator::all() {
+                        ui.selectable_value(&mut self.new_indicator, indicator, indicator.label());
+                    }
+                });
+
+            let item = WatchItem {
+                country: self.new_country.trim().to_lowercase(),
+                indicator: self.new_indicator,
+            };
+            let ready = !item.country.is_empty() && !self.cards.iter().any(|card| card.item == item);
+            if ui.add_enabled(ready, egui::Button::new("Acompanhar")).clicked() {
+                prefetch_others(config, &item, outbox);
+                self.cards.push(Card::new(item, config));
+                self.new_country.clear();
+                self.persist();
+            }
+
+            if ui.button("Atualizar todos").clicked() {
+                for card in &mut self.cards {
+                    card.next = None;
+                    card.fetch(config, false);
+                }
+            }
+
+            let export = ui
+                .button("Exportar HTML")
+                .on_hover_text("Gráficos em SVG e tabelas em um único arquivo, que abre em qualquer navegador")
+                .clicked();
+            if export {
+                self.export_html(config, tags);
+            }
+        });
+
+        let all = tags.all();
+        if self.tag.as_ref().is_some_and(|tag| !all.contains(tag)) {
+            self.tag = None;
+        }
+        if !all.is_empty() {
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_label("Etiqueta")
+                    .selected_text(self.tag.clone().unwrap_or_else(|| "Todas".to_string()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.tag, None, "Todas");
+                        for tag in &all {
+                            ui.selectable_value(&mut self.tag, Some(tag.clone()), tag);
+                        }
+                    });
+            });
+        }
+
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::RED, format!("Erro ao salvar a lista: {}", error));
+        }
+        match &self.export {
+            Some(Ok(path)) => {
+                ui.label(format!("Painel exportado: {}", path));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao exportar: {}", e));
+            }
+            None => {}
+        }
+        ui.separator();
+
+        let mut opened = None;
+        let mut removed = None;
+
+        let tag = self.tag.clone();
+        ui.horizontal_wrapped(|ui| {
+            for (i, card) in self.cards.iter_mut().enumerate() {
+                let name = card.item.name();
+                if tag.as_ref().is_some_and(|tag| !tags.has(&name, tag)) {
+                    continue;
+                }
+                let data = card.data.lock().unwrap().clone();
+
+                ui.group(|ui| {
+                    ui.set_width(220.0);
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.strong(format!("{} · {}", card.item.indicator.label(), card.item.country));
+                            let remove = ui.small_button("✕").on_hover_text("Deixar de acompanhar");
+                            if accessibility::named(remove, egui::WidgetType::Button, "Deixar de acompanhar").clicked() {
+                                removed = Some(i);
+                            }
+                        });
+                        let card_tags = tags.of(&name);
+                        if !card_tags.is_empty() {
+                            ui.weak(card_tags.join(", "));
+                        }
+
+                        let series = match data {
+                            None => {
+                                ui.spinner();
+                                return;
+                            }
+                            Some(Err(e)) => {
+                                ui.colored_label(egui::Color32::RED, "Erro ao buscar").on_hover_text(e);
+                                return;
+                            }
+                            Some(Ok(series)) => series,
+                        };
+
+                        let values: Vec<f64> = series.iter().map(|(_, value)| *value).collect();
+                        let next = *card.next.get_or_insert_with(|| forecast(&values).first().copied());
+
+                        let points: PlotPoints = values.iter().enumerate().map(|(x, y)| [x as f64, *y]).collect();
+                        Plot::new(("sparkline", i))
+                            .height(50.0)
+                            .show_axes([false, false])
+                            .show_x(false)
+                            .show_y(false)
+                            .allow_drag(false)
+                            .allow_zoom(false)
+                            .allow_scroll(false)
+                            .allow_boxed_zoom(false)
+                            .show(ui, |plot_ui| plot_ui.line(Line::new(points)));
+
+                        if let Some((date, last)) = series.last() {
+                            ui.label(format!("Último: {} ({})", config.format_value(*last), date));
+                        }
+                        if let [.., previous, last] = values.as_slice() {
+                            let change = last - previous;
+                            let color = if change > 0.0 { egui::Color32::RED } else { egui::Color32::GREEN };
+                            ui.colored_label(color, format!("Variação: {}", config.format_value(change)));
+                        }
+                        match next {
+                            Some(next) => ui.label(format!("Próxima previsão: {}", config.format_value(next))),
+                            None => ui.weak("Próxima previsão: -"),
+                        };
+
+                        if ui.button("Abrir análise").clicked() {
+                            opened = Some((card.item.clone(), series));
+                        }
+                    });
+                });
+            }
+        });
+
+        if let Some(i) = removed {
+            self.cards.remove(i);
+            self.persist();
+        }
+
+        opened
+    }
+}
@@ -0,0 +1,418 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints};
+use std::collections::BTreeMap;
+
+use crate::basket::{self, Basket, Component, Method};
+use crate::derived::{self, DerivedSeries};
+use crate::expr;
+use crate::frequency;
+use crate::splice::{self, Splice};
+
+use super::accessibility;
+
+// Visão de análise: construtor de séries derivadas ("nome = expressão") e de cestas ponderadas
+pub struct DerivedView {
+    pub definitions: Vec<DerivedSeries>,
+    input: String,
+    error: Option<String>,
+    baskets: Vec<Basket>,
+    // Cesta em edição
+    draft: Basket,
+    basket_error: Option<String>,
+    splices: Vec<Splice>,
+    // Emenda em edição
+    splice_draft: Splice,
+    splice_error: Option<String>,
+    // Séries avaliadas, recalculadas apenas quando as definições mudam, e quantas vezes já foram
+    series: BTreeMap<String, Vec<(String, f64)>>,
+    generation: u64,
+    // Aviso de frequências diferentes entre as séries combinadas, por série definida
+    warnings: BTreeMap<String, String>,
+}
+
+impl DerivedView {
+    pub fn new() -> Self {
+        let definitions = derived::load();
+        let baskets = basket::load();
+        let splices = splice::load();
+        let mut series = derived::all_series(&definitions);
+        splice::extend(&mut series, &splices);
+        basket::extend(&mut series, &baskets);
+
+        let mut view = Self {
+            series,
+            generation: 0,
+            warnings: BTreeMap::new(),
+            definitions,
+            input: String::new(),
+            error: None,
+            baskets,
+            draft: Basket {
+                name: String::new(),
+                components: vec![],
+                method: Method::default(),
+            },
+            basket_error: None,
+            splices,
+            splice_draft: Splice {
+                name: String::new(),
+                old: String::new(),
+                new: String::new(),
+                date: None,
+                method: splice::Method::default(),
+            },
+            splice_error: None,
+        };
+        view.warnings = view.frequency_warnings();
+        view
+    }
+
+    pub fn series(&self) -> &BTreeMap<String, Vec<(String, f64)>> {
+        &self.series
+    }
+
+    // Muda a cada reavaliação, para quem guarda cópias de `series`
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    // Reavalia derivadas, emendas e cestas a partir das séries em cache, nessa ordem: cada etapa
+    // pode usar as séries das anteriores
+    pub fn refresh(&mut self) {
+        self.series = derived::all_series(&self.definitions);
+        splice::extend(&mut self.series, &self.splices);
+        basket::extend(&mut self.series, &self.baskets);
+        self.warnings = self.frequency_warnings();
+        self.generation += 1;
+    }
+
+    // Séries referenciadas por cada definição, expressão, emenda ou cesta, comparadas pela
+    // frequência detectada
+    fn frequency_warnings(&self) -> BTreeMap<String, String> {
+        let expressions = self.definitions.iter().map(|definition| {
+            let used = expr::parse(&definition.expression).map(|expr| expr.series()).unwrap_or_default();
+            (&definition.name, used)
+        });
+        let splices = self.splices.iter().map(|splice| (&splice.name, vec![splice.old.clone(), splice.new.clone()]));
+        let baskets = self.baskets.iter().map(|basket| {
+            (&basket.name, basket.components.iter().map(|component| component.series.clone()).collect())
+        });
+
+        expressions
+            .chain(splices)
+            .chain(baskets)
+            .filter_map(|(name, used): (&String, Vec<String>)| {
+                let combined = used
+                    .iter()
+                    .filter_map(|other| self.series.get(other).map(|values| (other.as_str(), values.as_slice())));
+                frequency::mismatch(combined).map(|warning| (name.clone(), warning))
+            })
+            .collect()
+    }
+
+    fn frequency_warning(&self, ui: &mut egui::Ui, name: &str) {
+        if let Some(warning) = self.warnings.get(name) {
+            ui.colored_label(egui::Color32::YELLOW, "⚠").on_hover_text(warning);
+        }
+    }
+
+    fn add(&mut self) {
+        let name = match expr::parse_definition(&self.input) {
+            Ok((name, _)) => name,
+            Err(e) => {
+                self.error = Some(e);
+                return;
+            }
+        };
+        if self.series.contains_key(&name) {
+            self.error = Some(format!("Já existe uma série chamada {}", name));
+            return;
+        }
+
+        let expression = self.input.split_once('=').map(|(_, body)| body.trim().to_string()).unwrap_or_default();
+        let definition = DerivedSeries { name, expression };
+
+        if let Err(e) = derived::evaluate(&definition, &self.series) {
+            self.error = Some(e);
+            return;
+        }
+
+        self.definitions.push(definition);
+        self.persist();
+        self.input.clear();
+    }
+
+    fn persist(&mut self) {
+        self.error = derived::save(&self.definitions).err().map(|e| e.to_string());
+        self.refresh();
+    }
+
+    fn add_basket(&mut self) {
+        let name = self.draft.name.trim().to_string();
+        if name.is_empty() {
+            self.basket_error = Some("Dê um nome à cesta".to_string());
+            return;
+        }
+        if self.series.contains_key(&name) {
+            self.basket_error = Some(format!("Já existe uma série chamada {}", name));
+            return;
+        }
+        let basket = Basket { name, ..self.draft.clone() };
+        if let Err(e) = basket::evaluate(&basket, &self.series) {
+            self.basket_error = Some(e);
+            return;
+        }
+
+        self.baskets.push(basket);
+        self.draft.name.clear();
+        self.draft.components.clear();
+        self.persist_baskets();
+    }
+
+    fn persist_baskets(&mut self) {
+        self.basket_error = basket::save(&self.baskets).err().map(|e| e.to_string());
+        self.refresh();
+    }
+
+    fn add_splice(&mut self) {
+        let name = self.splice_draft.name.trim().to_string();
+        if name.is_empty() {
+            self.splice_error = Some("Dê um nome à série emendada".to_string());
+            return;
+        }
+        if self.series.contains_key(&name) {
+            self.splice_error = Some(format!("Já existe uma série chamada {}", name));
+            return;
+        }
+        let date = self.splice_draft.date.as_deref().map(str::trim).filter(|date| !date.is_empty()).map(str::to_string);
+        let splice = Splice {
+            name,
+            date,
+            ..self.splice_draft.clone()
+        };
+        if let Err(e) = splice::evaluate(&splice, &self.series) {
+            self.splice_error = Some(e);
+            return;
+        }
+
+        self.splices.push(splice);
+        self.splice_draft.name.clear();
+        self.splice_draft.date = None;
+        self.persist_splices();
+    }
+
+    fn persist_splices(&mut self) {
+        self.splice_error = splice::save(&self.splices).err().map(|e| e.to_string());
+        self.refresh();
+    }
+
+    fn show_splices(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing("Emendas de séries descontinuadas", |ui| {
+            ui.label(
+                "Encadeie uma série descontinuada à que a substituiu (ex.: um índice rebaseado) em uma série contínua. \
+                 A antiga é levada ao nível da nova na data de emenda, pela razão ou pela diferença entre as duas.",
+            );
+            ui.horizontal(|ui| {
+                let label = ui.label("Nome:");
+                ui.add(egui::TextEdit::singleline(&mut self.splice_draft.name).desired_width(160.0))
+                    .labelled_by(label.id);
+                for method in splice::Method::all() {
+                    ui.radio_value(&mut self.splice_draft.method, method, method.label());
+                }
+            });
+            ui.horizontal(|ui| {
+                for (label, id, selected) in [
+                    ("Antiga", "splice_old", &mut self.splice_draft.old),
+                    ("Nova", "splice_new", &mut self.splice_draft.new),
+                ] {
+                    egui::ComboBox::new(id, label).selected_text(selected.as_str()).show_ui(ui, |ui| {
+                        for name in self.series.keys() {
+                            ui.selectable_value(selected, name.clone(), name);
+                        }
+                    });
+                }
+                let date = self.splice_draft.date.get_or_insert_with(String::new);
+                let response =
+                    ui.add(egui::TextEdit::singleline(date).hint_text("última data em comum").desired_width(140.0));
+                accessibility::named(response, egui::WidgetType::TextEdit, "Data de emenda (DD/MM/AAAA)");
+                let ready = !self.splice_draft.old.is_empty() && !self.splice_draft.new.is_empty();
+                if ui.add_enabled(ready, egui::Button::new("Criar emenda")).clicked() {
+                    self.add_splice();
+                }
+            });
+            if let Some(error) = &self.splice_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+
+            let mut removed = None;
+            for (i, splice) in self.splices.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    let date = splice.date.as_deref().unwrap_or("última data em comum");
+                    ui.monospace(format!(
+                        "{} = {} → {} em {} ({})",
+                        splice.name,
+                        splice.old,
+                        splice.new,
+                        date,
+                        splice.method.label()
+                    ));
+                    match self.series.get(&splice.name) {
+                        Some(values) => ui.weak(format!("{} observações", values.len())),
+                        None => ui.colored_label(egui::Color32::RED, "não avaliada"),
+                    };
+                    self.frequency_warning(ui, &splice.name);
+                    if ui.small_button("Remover").clicked() {
+                        removed = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = removed {
+                self.splices.remove(i);
+                self.persist_splices();
+            }
+        });
+    }
+
+    fn show_baskets(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing("Cestas e índices ponderados", |ui| {
+            ui.label(
+                "Combine séries com pesos em um índice (ex.: cesta de moedas ponderada pelo comércio ou cesta de preços \
+                 própria). A cesta é recalculada quando os componentes são atualizados e pode ser prevista como qualquer série.",
+            );
+            ui.horizontal(|ui| {
+                let label = ui.label("Nome:");
+                ui.add(egui::TextEdit::singleline(&mut self.draft.name).desired_width(160.0)).labelled_by(label.id);
+                for method in Method::all() {
+                    ui.radio_value(&mut self.draft.method, method, method.label());
+                }
+            });
+
+            let mut removed = None;
+            for (i, component) in self.draft.components.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source(("basket_component", i))
+                        .selected_text(&component.series)
+                        .show_ui(ui, |ui| {
+                            for name in self.series.keys() {
+                                ui.selectable_value(&mut component.series, name.clone(), name);
+                            }
+                        });
+                    let label = ui.label("Peso:");
+                    ui.add(egui::DragValue::new(&mut component.weight).speed(0.01).clamp_range(0.0..=f64::MAX))
+                        .labelled_by(label.id);
+                    if ui.small_button("Remover").clicked() {
+                        removed = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = removed {
+                self.draft.components.remove(i);
+            }
+
+            ui.horizontal(|ui| {
+                let first = self.series.keys().next().cloned();
+                if ui.add_enabled(first.is_some(), egui::Button::new("Adicionar componente")).clicked() {
+                    if let Some(series) = first {
+                        self.draft.components.push(Component { series, weight: 1.0 });
+                    }
+                }
+                if ui.add_enabled(!self.draft.components.is_empty(), egui::Button::new("Criar cesta")).clicked() {
+                    self.add_basket();
+                }
+            });
+            if let Some(error) = &self.basket_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+
+            let mut removed = None;
+            for (i, basket) in self.baskets.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    let components: Vec<String> = basket
+                        .components
+                        .iter()
+                        .map(|component| format!("{} × {}", component.weight, component.series))
+                        .collect();
+                    ui.monospace(format!("{} = {} ({})", basket.name, components.join(" + "), basket.method.label()));
+                    match self.series.get(&basket.name) {
+                        Some(values) => ui.weak(format!("{} observações", values.len())),
+                        None => ui.colored_label(egui::Color32::RED, "não avaliada"),
+                    };
+                    self.frequency_warning(ui, &basket.name);
+                    if ui.small_button("Remover").clicked() {
+                        removed = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = removed {
+                self.baskets.remove(i);
+                self.persist_baskets();
+            }
+        });
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.label("Defina novas séries a partir das carregadas, ex.: spread = inflation_usa - inflation_brazil");
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.input)
+                    .hint_text("nome = expressão")
+                    .desired_width(400.0),
+            );
+            let response = accessibility::named(response, egui::WidgetType::TextEdit, "Nova série derivada (nome = expressão)");
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if ui.button("Adicionar").clicked() || submitted {
+                self.add();
+            }
+            if ui.button("Recarregar séries").clicked() {
+                self.refresh();
+            }
+        });
+
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        ui.collapsing("Séries disponíveis", |ui| {
+            for (name, values) in &self.series {
+                ui.label(format!("{} ({} observações)", name, values.len()));
+            }
+        });
+
+        let mut removed = None;
+        for (i, definition) in self.definitions.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.monospace(format!("{} = {}", definition.name, definition.expression));
+                match self.series.get(&definition.name) {
+                    Some(values) => ui.weak(format!("{} observações", values.len())),
+                    None => ui.colored_label(egui::Color32::RED, "não avaliada"),
+                };
+                self.frequency_warning(ui, &definition.name);
+                if ui.small_button("Remover").clicked() {
+                    removed = Some(i);
+                }
+            });
+        }
+        if let Some(i) = removed {
+            self.definitions.remove(i);
+            self.persist();
+        }
+
+        self.show_splices(ui);
+        self.show_baskets(ui);
+
+        Plot::new("derived_series").legend(Legend::default()).show(ui, |plot_ui| {
+            let names = self
+                .definitions
+                .iter()
+                .map(|definition| &definition.name)
+                .chain(self.splices.iter().map(|splice| &splice.name))
+                .chain(self.baskets.iter().map(|basket| &basket.name));
+            for name in names {
+                if let Some(values) = self.series.get(name) {
+                    let points: PlotPoints = values.iter().enumerate().map(|(x, (_, y))| [x as f64, *y]).collect();
+                    plot_ui.line(Line::new(points).name(name));
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,159 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints, Polygon, VLine};
+use std::collections::BTreeMap;
+
+use crate::analytics::{self, EventStudy};
+use crate::annotations::Annotation;
+use crate::config::Config;
+
+const MEAN_COLOR: egui::Color32 = egui::Color32::from_rgb(31, 119, 180);
+const PATH_COLOR: egui::Color32 = egui::Color32::from_rgba_premultiplied(100, 100, 100, 100);
+
+// Visão de análise: trajetória média de uma série em torno de datas de eventos (decisões de juros,
+// eleições...), alinhadas em tempo de evento
+pub struct EventStudyView {
+    series: Option<String>,
+    // Datas dos eventos, uma por linha (DD/MM/AAAA)
+    events: String,
+    window: usize,
+    relative: bool,
+}
+
+impl EventStudyView {
+    pub fn new() -> Self {
+        Self {
+            series: None,
+            events: String::new(),
+            window: 12,
+            relative: true,
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        config: &Config,
+        series: &BTreeMap<String, Vec<(String, f64)>>,
+        annotations: &[Annotation],
+    ) {
+        if self.series.as_ref().filter(|name| series.contains_key(*name)).is_none() {
+            self.series = series.keys().next().cloned();
+        }
+        let Some(name) = self.series.clone() else {
+            ui.label("Carregue uma série para estudar eventos.");
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_label("Série").selected_text(&name).show_ui(ui, |ui| {
+                for other in series.keys() {
+                    ui.selectable_value(&mut self.series, Some(other.clone()), other);
+                }
+            });
+            let label = ui.label("Janela (observações):");
+            ui.add(egui::DragValue::new(&mut self.window).clamp_range(1..=60)).labelled_by(label.id);
+            ui.checkbox(&mut self.relative, "Relativo a t=0")
+                .on_hover_text("Cada trajetória é a diferença para o valor na data do evento");
+        });
+
+        ui.horizontal(|ui| {
+            let label = ui.label("Eventos (uma data por linha):");
+            ui.add(egui::TextEdit::multiline(&mut self.events).hint_text("DD/MM/AAAA").desired_rows(4))
+                .labelled_by(label.id);
+            if ui
+                .add_enabled(!annotations.is_empty(), egui::Button::new("Usar as anotações do país"))
+                .clicked()
+            {
+                let dates: Vec<&str> = annotations.iter().map(|annotation| annotation.date.as_str()).collect();
+                self.events = dates.join("\n");
+            }
+        });
+
+        let events: Vec<String> = self
+            .events
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+        if events.is_empty() {
+            ui.label("Informe as datas dos eventos.");
+            return;
+        }
+
+        let (dates, values): (Vec<String>, Vec<f64>) = series[&name].iter().cloned().unzip();
+        match analytics::event_study(&dates, &values, &events, self.window, self.relative) {
+            Ok(study) => self.show_study(ui, config, &study, events.len()),
+            Err(e) => {
+                ui.colored_label(egui::Color32::YELLOW, e);
+            }
+        }
+    }
+
+    fn show_study(&self, ui: &mut egui::Ui, config: &Config, study: &EventStudy, requested: usize) {
+        if study.paths.len() < requested {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!("{} de {} eventos fora do período da série", requested - study.paths.len(), requested),
+            );
+        }
+
+        let points = |values: &[Option<f64>]| -> PlotPoints {
+            study
+                .offsets
+                .iter()
+                .zip(values)
+                .filter_map(|(offset, value)| value.map(|value| [*offset as f64, value]))
+                .collect()
+        };
+
+        Plot::new("event_study_chart")
+            .legend(Legend::default())
+            .height(300.0)
+            .x_axis_formatter(|x, _| format!("t{:+}", x.round() as i64))
+            .show(ui, |plot_ui| {
+                plot_ui.vline(VLine::new(0.0).color(egui::Color32::GRAY));
+
+                // Faixa de 95%: o limite superior e, de volta, o inferior
+                let limits: Vec<(f64, (f64, f64))> = study
+                    .offsets
+                    .iter()
+                    .zip(&study.bands)
+                    .filter_map(|(offset, band)| band.map(|band| (*offset as f64, band)))
+                    .collect();
+                let mut band: Vec<[f64; 2]> = limits.iter().map(|(x, (_, upper))| [*x, *upper]).collect();
+                band.extend(limits.iter().rev().map(|(x, (lower, _))| [*x, *lower]));
+                if !band.is_empty() {
+                    plot_ui.polygon(Polygon::new(PlotPoints::new(band)).color(MEAN_COLOR).name("Intervalo de 95%"));
+                }
+
+                for (_, path) in &study.paths {
+                    plot_ui.line(Line::new(points(path)).color(PATH_COLOR).name("Eventos"));
+                }
+                plot_ui.line(Line::new(points(&study.mean)).color(MEAN_COLOR).width(2.0).name("Média"));
+            });
+
+        // Média nas defasagens principais
+        egui::Grid::new("event_study_summary").striped(true).show(ui, |ui| {
+            ui.strong("Defasagem");
+            ui.strong("Média");
+            ui.strong("Intervalo de 95%");
+            ui.end_row();
+
+            for (k, offset) in study.offsets.iter().enumerate().filter(|(_, offset)| **offset >= 0) {
+                let Some(mean) = study.mean[k] else {
+                    continue;
+                };
+                ui.label(format!("t{:+}", offset));
+                ui.label(config.format_value(mean));
+                match study.bands[k] {
+                    Some((lower, upper)) => {
+                        ui.label(format!("{} a {}", config.format_value(lower), config.format_value(upper)))
+                    }
+                    None => ui.label("-"),
+                };
+                ui.end_row();
+            }
+        });
+    }
+}
@@ -0,0 +1,130 @@
+use eframe::egui;
+
+use crate::config::Config;
+use crate::data::InflationData;
+use crate::expectations::{self, DIVERGENCE_THRESHOLD};
+
+use super::chart::Overlay;
+
+const EXPECTATIONS_COLOR: egui::Color32 = egui::Color32::from_rgb(148, 103, 189);
+const DIVERGENCE_COLOR: egui::Color32 = egui::Color32::from_rgb(214, 39, 40);
+
+// Expectativas de mercado (pesquisa Focus, no Brasil) comparadas com a previsão do modelo
+pub struct ExpectationsPanel {
+    pub show_on_chart: bool,
+    threshold: f64,
+    requested: bool,
+}
+
+impl ExpectationsPanel {
+    pub fn new() -> Self {
+        Self {
+            show_on_chart: true,
+            threshold: DIVERGENCE_THRESHOLD,
+            requested: false,
+        }
+    }
+
+    // Passos em que a previsão diverge da expectativa pelo limite escolhido
+    pub fn divergences(&self, forecast: &[f64], expected: &[Option<f64>]) -> Vec<usize> {
+        expectations::divergences(forecast, expected, self.threshold)
+    }
+
+    // Linha das expectativas nos passos da previsão, que começam depois das `known` observações
+    pub fn overlay(&self, known: usize, expected: &[Option<f64>]) -> Option<Overlay> {
+        if !self.show_on_chart || expected.iter().all(Option::is_none) {
+            return None;
+        }
+
+        let mut values = vec![None; known];
+        values.extend_from_slice(expected);
+        Some(Overlay {
+            name: "Expectativa de mercado".to_string(),
+            values,
+            color: EXPECTATIONS_COLOR,
+        })
+    }
+
+    // Retorna true quando o usuário pede a busca das expectativas
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        config: &Config,
+        fetched: Option<&Result<Vec<InflationData>, String>>,
+        forecast: &[f64],
+        expected: &[Option<f64>],
+    ) -> bool {
+        let mut fetch = false;
+
+        ui.collapsing("Expectativas de mercado", |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Buscar expectativas").clicked() {
+                    self.requested = true;
+                    fetch = true;
+                }
+                ui.checkbox(&mut self.show_on_chart, "Mostrar no gráfico");
+                let label = ui.label("Divergência a partir de (p.p.):");
+                ui.add(egui::DragValue::new(&mut self.threshold).speed(0.05).clamp_range(0.0..=100.0))
+                    .labelled_by(label.id);
+            });
+
+            match fetched {
+                None if self.requested => {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label("Buscando as expectativas...");
+                    });
+                }
+                None => {
+                    ui.weak("Requer um provedor que exponha as expectativas (ex.: a pesquisa Focus, no Brasil).");
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, format!("Erro ao buscar as expectativas: {}", e));
+                }
+                Some(Ok(_)) if forecast.is_empty() => {
+                    ui.weak("Calcule uma previsão para compará-la com as expectativas");
+                }
+                Some(Ok(_)) if expected.iter().all(Option::is_none) => {
+                    ui.weak("Não há expectativas para os períodos da previsão");
+                }
+                Some(Ok(_)) => self.show_comparison(ui, config, forecast, expected),
+            }
+        });
+
+        fetch
+    }
+
+    fn show_comparison(&self, ui: &mut egui::Ui, config: &Config, forecast: &[f64], expected: &[Option<f64>]) {
+        let divergences = self.divergences(forecast, expected);
+        if !divergences.is_empty() {
+            ui.colored_label(
+                DIVERGENCE_COLOR,
+                format!("A previsão diverge das expectativas em {} períodos", divergences.len()),
+            );
+        }
+
+        egui::Grid::new("expectations_comparison").striped(true).show(ui, |ui| {
+            ui.strong("Passo");
+            ui.strong("Previsão");
+            ui.strong("Expectativa");
+            ui.strong("Diferença");
+            ui.end_row();
+
+            for (step, (value, expected)) in forecast.iter().zip(expected).enumerate() {
+                let Some(expected) = expected else {
+                    continue;
+                };
+                ui.label(format!("t+{}", step + 1));
+                ui.label(config.format_value(*value));
+                ui.colored_label(EXPECTATIONS_COLOR, config.format_value(*expected));
+                let gap = config.format_value(value - expected);
+                if divergences.contains(&step) {
+                    ui.colored_label(DIVERGENCE_COLOR, gap);
+                } else {
+                    ui.label(gap);
+                }
+                ui.end_row();
+            }
+        });
+    }
+}
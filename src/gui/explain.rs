@@ -0,0 +1,98 @@
+use eframe::egui;
+use egui::plot::{HLine, Line, Plot, PlotPoints};
+
+use crate::artifact::ModelArtifact;
+use crate::config::Config;
+use crate::explain::{explain, response_of, Explanation};
+use crate::models::Candidate;
+use crate::plugins::Plugin;
+use crate::scale::Scale;
+
+// Períodos da resposta a um choque no gráfico
+const RESPONSE_HORIZON: usize = 24;
+
+// Coeficientes do modelo ajustado na série atual, com a interpretação de cada um
+pub struct ExplanationPanel {
+    // Explicação e a escala em que o modelo foi ajustado
+    explanation: Option<Result<(Explanation, Scale), String>>,
+    // Resposta da série a um choque, nos modelos que a têm (ARIMA)
+    response: Option<Vec<f64>>,
+}
+
+impl ExplanationPanel {
+    pub fn new() -> Self {
+        Self {
+            explanation: None,
+            response: None,
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        config: &Config,
+        plugins: &[Plugin],
+        candidate: Option<Candidate>,
+        scale: Scale,
+        values: &[f64],
+    ) {
+        ui.collapsing("Explicação do modelo", |ui| {
+            let explainable = candidate.filter(|candidate| !matches!(candidate, Candidate::Plugin(_)));
+            let clicked = ui
+                .add_enabled(explainable.is_some(), egui::Button::new("Explicar o ajuste"))
+                .on_hover_text("Ajusta o modelo escolhido na série atual e interpreta os coeficientes estimados")
+                .on_disabled_hover_text("Combinações e modelos de plugins não têm coeficientes a explicar")
+                .clicked();
+            if let Some(candidate) = explainable.filter(|_| clicked) {
+                // O registro do treino (série e datas) não entra na explicação; o ajuste é na escala do modelo,
+                // a mesma das previsões
+                let artifact = scale
+                    .transform(values)
+                    .and_then(|scaled| ModelArtifact::fit(candidate, plugins, "", &[], &scaled));
+                self.response = artifact.as_ref().ok().and_then(|artifact| response_of(artifact, RESPONSE_HORIZON));
+                self.explanation = Some(artifact.map(|artifact| (explain(&artifact, config), scale)));
+            }
+
+            match &self.explanation {
+                None => {}
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, format!("Erro ao ajustar o modelo: {}", e));
+                }
+                Some(Ok((explanation, scale))) => {
+                    ui.strong(format!("{} ({})", explanation.model, scale.label().to_lowercase()));
+                    if !explanation.terms.is_empty() {
+                        egui::Grid::new("explanation_terms").striped(true).show(ui, |ui| {
+                            for header in ["Coeficiente", "Valor", "Significado"] {
+                                ui.strong(header);
+                            }
+                            ui.end_row();
+                            for term in &explanation.terms {
+                                ui.monospace(&term.name);
+                                ui.label(format!("{:.4}", term.value));
+                                ui.label(&term.meaning);
+                                ui.end_row();
+                            }
+                        });
+                    }
+                    for line in &explanation.summary {
+                        ui.label(format!("• {}", line));
+                    }
+                    ui.label(format!("• {}", scale.interpretation()));
+
+                    if let Some(response) = &self.response {
+                        ui.label(format!(
+                            "Resposta da série ({}) a um choque de um desvio padrão, período a período:",
+                            scale.label().to_lowercase()
+                        ));
+                        let points: PlotPoints =
+                            response.iter().enumerate().map(|(h, value)| [h as f64, *value]).collect();
+                        Plot::new("explanation_response").height(160.0).allow_drag(false).show(ui, |plot_ui| {
+                            plot_ui.hline(HLine::new(0.0).color(egui::Color32::GRAY));
+                            plot_ui.line(Line::new(points).name("Resposta"));
+                        });
+                    }
+                }
+            }
+        });
+    }
+}
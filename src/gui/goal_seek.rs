@@ -0,0 +1,89 @@
+use eframe::egui;
+use egui::plot::{HLine, Legend, Line, LineStyle, Plot, PlotPoints, Points};
+
+use crate::config::Config;
+use crate::goal_seek::{self, goal_seek};
+
+const SHORTFALL_COLOR: egui::Color32 = egui::Color32::from_rgb(214, 39, 40);
+
+// Cálculo inverso: dado o retorno nominal de um investimento e a meta de retorno real, a inflação
+// máxima tolerável comparada com a previsão do modelo
+pub struct GoalSeekPanel {
+    nominal: f64,
+    target_real: f64,
+}
+
+impl GoalSeekPanel {
+    pub fn new() -> Self {
+        Self {
+            nominal: 10.0,
+            target_real: 4.0,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, values: &[f64], forecast: &[f64], intervals: &[(f64, f64)]) {
+        ui.collapsing("Meta de retorno real", |ui| {
+            ui.horizontal(|ui| {
+                let label = ui.label("Retorno nominal (% a.a.):");
+                ui.add(egui::DragValue::new(&mut self.nominal).speed(0.05)).labelled_by(label.id);
+                let label = ui.label("Meta real (% a.a.):");
+                ui.add(egui::DragValue::new(&mut self.target_real).speed(0.05)).labelled_by(label.id);
+            });
+
+            // Incerteza dos intervalos do modelo quando existem; senão, a de um passeio aleatório
+            let std_devs = if intervals.len() == forecast.len() {
+                goal_seek::interval_std_devs(intervals)
+            } else {
+                goal_seek::random_walk_std_devs(values, forecast.len())
+            };
+            let Some(result) = goal_seek(self.nominal, self.target_real, forecast, &std_devs) else {
+                ui.weak("Calcule uma previsão de inflação para compará-la com a meta");
+                return;
+            };
+
+            ui.label(format!(
+                "Inflação máxima tolerável: {}% a.a.",
+                config.format_value(result.ceiling)
+            ));
+            let expected = format!(
+                "Retorno real com a inflação prevista: {}% a.a.",
+                config.format_value(result.expected_real_return)
+            );
+            if result.misses_target(self.target_real) {
+                ui.colored_label(SHORTFALL_COLOR, format!("{} — abaixo da meta", expected));
+            } else {
+                ui.label(expected);
+            }
+            if let Some(probability) = result.shortfall_probability {
+                let text = format!("Risco de não atingir a meta: {}%", config.format_value(probability * 100.0));
+                if probability > 0.5 {
+                    ui.colored_label(SHORTFALL_COLOR, text);
+                } else {
+                    ui.label(text);
+                }
+            }
+            if !result.breaches.is_empty() {
+                let steps: Vec<String> = result.breaches.iter().map(|step| (step + 1).to_string()).collect();
+                ui.colored_label(
+                    SHORTFALL_COLOR,
+                    format!("A previsão passa do teto nos passos {}", steps.join(", ")),
+                );
+            }
+
+            Plot::new("goal_seek_chart").legend(Legend::default()).height(200.0).show(ui, |plot_ui| {
+                let line = |values: &[f64]| -> PlotPoints {
+                    values.iter().enumerate().map(|(i, y)| [(i + 1) as f64, *y]).collect()
+                };
+                plot_ui.line(Line::new(line(forecast)).name("Previsão"));
+                plot_ui.line(
+                    Line::new(line(&result.path))
+                        .name("Teto restante (anualizado nos próximos passos)")
+                        .style(LineStyle::dashed_loose()),
+                );
+                plot_ui.hline(HLine::new(result.ceiling).name("Inflação máxima tolerável").color(egui::Color32::GRAY));
+                let breaches: PlotPoints = result.breaches.iter().map(|step| [(step + 1) as f64, forecast[*step]]).collect();
+                plot_ui.points(Points::new(breaches).radius(4.0).color(SHORTFALL_COLOR).name("Acima do teto"));
+            });
+        });
+    }
+}
@@ -0,0 +1,175 @@
+use eframe::egui;
+use egui::plot::{Bar, BarChart, HLine, Legend, Line, Plot, PlotPoints};
+
+use crate::indicators;
+use crate::recessions::Recession;
+
+use super::annotations::draw_recessions;
+use super::chart::{date_axis, Overlay};
+
+const SMA_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 127, 14);
+const EMA_COLOR: egui::Color32 = egui::Color32::from_rgb(148, 103, 189);
+const BOLLINGER_COLOR: egui::Color32 = egui::Color32::from_rgb(127, 127, 127);
+
+fn points(values: &[Option<f64>]) -> PlotPoints {
+    values
+        .iter()
+        .enumerate()
+        .filter_map(|(x, y)| y.map(|y| [x as f64, y]))
+        .collect()
+}
+
+fn period(value: &mut usize) -> egui::DragValue<'_> {
+    egui::DragValue::new(value).clamp_range(1..=500).prefix("n = ")
+}
+
+// Indicadores técnicos da série atual: médias e bandas sobrepostas ao gráfico principal,
+// osciladores (RSI, MACD) em gráficos próprios abaixo dele
+pub struct IndicatorsPanel {
+    sma: bool,
+    sma_period: usize,
+    ema: bool,
+    ema_period: usize,
+    bollinger: bool,
+    bollinger_period: usize,
+    bollinger_k: f64,
+    rsi: bool,
+    rsi_period: usize,
+    macd: bool,
+    macd_fast: usize,
+    macd_slow: usize,
+    macd_signal: usize,
+}
+
+impl IndicatorsPanel {
+    pub fn new() -> Self {
+        Self {
+            sma: false,
+            sma_period: 20,
+            ema: false,
+            ema_period: 20,
+            bollinger: false,
+            bollinger_period: 20,
+            bollinger_k: 2.0,
+            rsi: false,
+            rsi_period: 14,
+            macd: false,
+            macd_fast: 12,
+            macd_slow: 26,
+            macd_signal: 9,
+        }
+    }
+
+    pub fn show_controls(&mut self, ui: &mut egui::Ui) {
+        ui.collapsing("Indicadores técnicos", |ui| {
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.sma, "Média móvel simples");
+                ui.add(period(&mut self.sma_period));
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.ema, "Média móvel exponencial");
+                ui.add(period(&mut self.ema_period));
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.bollinger, "Bandas de Bollinger");
+                ui.add(period(&mut self.bollinger_period));
+                ui.add(
+                    egui::DragValue::new(&mut self.bollinger_k)
+                        .clamp_range(0.5..=5.0)
+                        .speed(0.1)
+                        .prefix("k = "),
+                );
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.rsi, "RSI");
+                ui.add(period(&mut self.rsi_period));
+            });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.macd, "MACD");
+                ui.add(period(&mut self.macd_fast).prefix("rápida = "));
+                ui.add(period(&mut self.macd_slow).prefix("lenta = "));
+                ui.add(period(&mut self.macd_signal).prefix("sinal = "));
+            });
+        });
+    }
+
+    // Linhas na escala da própria série
+    pub fn overlays(&self, values: &[f64]) -> Vec<Overlay> {
+        let mut overlays = Vec::new();
+
+        if self.sma {
+            overlays.push(Overlay {
+                name: format!("MMS({})", self.sma_period),
+                values: indicators::sma(values, self.sma_period),
+                color: SMA_COLOR,
+            });
+        }
+        if self.ema {
+            overlays.push(Overlay {
+                name: format!("MME({})", self.ema_period),
+                values: indicators::ema(values, self.ema_period),
+                color: EMA_COLOR,
+            });
+        }
+        if self.bollinger {
+            let bands = indicators::bollinger(values, self.bollinger_period, self.bollinger_k);
+            let name = format!("Bollinger({}, {})", self.bollinger_period, self.bollinger_k);
+            overlays.push(Overlay {
+                name: name.clone(),
+                values: bands.upper,
+                color: BOLLINGER_COLOR,
+            });
+            overlays.push(Overlay {
+                name: name.clone(),
+                values: bands.middle,
+                color: BOLLINGER_COLOR,
+            });
+            overlays.push(Overlay {
+                name,
+                values: bands.lower,
+                color: BOLLINGER_COLOR,
+            });
+        }
+
+        overlays
+    }
+
+    pub fn show_oscillators(&self, ui: &mut egui::Ui, dates: &[String], values: &[f64], recessions: &[Recession]) {
+        if self.rsi {
+            let rsi = indicators::rsi(values, self.rsi_period);
+            Plot::new("rsi_chart")
+                .height(120.0)
+                .include_y(0.0)
+                .include_y(100.0)
+                .x_axis_formatter(date_axis(dates.to_vec()))
+                .show(ui, |plot_ui| {
+                    draw_recessions(plot_ui, recessions, dates);
+                    // Faixas usuais de sobrecompra e sobrevenda
+                    plot_ui.hline(HLine::new(70.0).color(egui::Color32::RED));
+                    plot_ui.hline(HLine::new(30.0).color(egui::Color32::GREEN));
+                    plot_ui.line(Line::new(points(&rsi)).name(format!("RSI({})", self.rsi_period)));
+                });
+        }
+
+        if self.macd {
+            let macd = indicators::macd(values, self.macd_fast, self.macd_slow, self.macd_signal);
+            let bars = macd
+                .histogram
+                .iter()
+                .enumerate()
+                .filter_map(|(x, y)| y.map(|y| Bar::new(x as f64, y)))
+                .collect();
+
+            Plot::new("macd_chart")
+                .height(120.0)
+                .legend(Legend::default())
+                .x_axis_formatter(date_axis(dates.to_vec()))
+                .show(ui, |plot_ui| {
+                    draw_recessions(plot_ui, recessions, dates);
+                    plot_ui.bar_chart(BarChart::new(bars).name("Histograma").color(egui::Color32::GRAY));
+                    plot_ui.line(Line::new(points(&macd.macd)).name("MACD"));
+                    plot_ui.line(Line::new(points(&macd.signal)).name("Sinal"));
+                });
+        }
+    }
+}
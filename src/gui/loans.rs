@@ -0,0 +1,144 @@
+use eframe::egui;
+use egui::plot::{Bar, BarChart, Legend, Plot};
+
+use crate::config::Config;
+use crate::loans::{self, Loan, System};
+
+// Visão de análise: cronograma de um financiamento (SAC ou Price), opcionalmente corrigido pela
+// inflação prevista na visão de previsão
+pub struct LoansView {
+    loan: Loan,
+    export_result: Option<Result<String, String>>,
+}
+
+impl LoansView {
+    pub fn new() -> Self {
+        Self {
+            loan: Loan {
+                principal: 300_000.0,
+                annual_rate: 10.0,
+                months: 360,
+                system: System::Sac,
+                indexed: false,
+            },
+            export_result: None,
+        }
+    }
+
+    // `forecast` é a previsão atual da série `series`, usada como trajetória do IPCA
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, series: &str, forecast: &[f64]) {
+        egui::Grid::new("loan_inputs").show(ui, |ui| {
+            let label = ui.label("Valor financiado:");
+            ui.add(egui::DragValue::new(&mut self.loan.principal).speed(1000.0).clamp_range(0.0..=f64::MAX))
+                .labelled_by(label.id);
+            ui.end_row();
+            let label = ui.label(if self.loan.indexed { "Juros acima do IPCA (% a.a.):" } else { "Juros (% a.a.):" });
+            ui.add(egui::DragValue::new(&mut self.loan.annual_rate).speed(0.05).clamp_range(0.0..=100.0))
+                .labelled_by(label.id);
+            ui.end_row();
+            let label = ui.label("Prazo (meses):");
+            ui.add(egui::DragValue::new(&mut self.loan.months).clamp_range(1..=600)).labelled_by(label.id);
+            ui.end_row();
+            ui.label("Sistema:");
+            ui.horizontal(|ui| {
+                for system in System::all() {
+                    ui.radio_value(&mut self.loan.system, system, system.label());
+                }
+            });
+            ui.end_row();
+        });
+
+        ui.checkbox(&mut self.loan.indexed, "Corrigir o saldo pelo IPCA previsto");
+        if self.loan.indexed {
+            if forecast.is_empty() {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    "Sem previsão: calcule a previsão da inflação na visão \"Previsão\"; até lá o saldo não é corrigido",
+                );
+            } else {
+                ui.weak(format!(
+                    "Inflação prevista de {} ({} passos; depois, o último valor previsto)",
+                    series,
+                    forecast.len()
+                ));
+            }
+        }
+
+        let schedule = loans::schedule(&self.loan, forecast);
+        let totals = loans::totals(&schedule);
+        ui.separator();
+        egui::Grid::new("loan_totals").show(ui, |ui| {
+            let rows = [
+                ("Primeira parcela", schedule.first().map(|installment| installment.payment)),
+                ("Última parcela", schedule.last().map(|installment| installment.payment)),
+                ("Total pago", Some(totals.paid)),
+                ("Total de juros", Some(totals.interest)),
+                ("Total de correção monetária", Some(totals.correction).filter(|_| self.loan.indexed)),
+            ];
+            for (label, value) in rows {
+                if let Some(value) = value {
+                    ui.label(label);
+                    ui.strong(config.format_value(value));
+                    ui.end_row();
+                }
+            }
+        });
+
+        if ui.button("Exportar CSV").clicked() {
+            self.export_result = Some(
+                loans::write_schedule_csv(&self.loan, &schedule)
+                    .map(|path| path.display().to_string())
+                    .map_err(|e| e.to_string()),
+            );
+        }
+        match &self.export_result {
+            Some(Ok(path)) => {
+                ui.label(format!("Arquivo gerado: {}", path));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao exportar: {}", e));
+            }
+            None => {}
+        }
+
+        // Composição de cada parcela: amortização e juros empilhados
+        Plot::new("loan_chart").legend(Legend::default()).height(200.0).show(ui, |plot_ui| {
+            let amortization = BarChart::new(
+                schedule
+                    .iter()
+                    .map(|installment| Bar::new(installment.month as f64, installment.amortization))
+                    .collect(),
+            )
+            .name("Amortização");
+            let interest = BarChart::new(
+                schedule.iter().map(|installment| Bar::new(installment.month as f64, installment.interest)).collect(),
+            )
+            .name("Juros")
+            .stack_on(&[&amortization]);
+            plot_ui.bar_chart(amortization);
+            plot_ui.bar_chart(interest);
+        });
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("loan_schedule").striped(true).show(ui, |ui| {
+                for header in ["Mês", "Correção", "Juros", "Amortização", "Parcela", "Saldo devedor"] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+                for installment in &schedule {
+                    ui.label(installment.month.to_string());
+                    for value in [
+                        installment.correction,
+                        installment.interest,
+                        installment.amortization,
+                        installment.payment,
+                        installment.balance,
+                    ] {
+                        ui.label(config.format_value(value));
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+    }
+}
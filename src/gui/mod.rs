@@ -0,0 +1,2085 @@
+use eframe::egui;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "python-models")]
+use crate::arima::{self, run_arima_model};
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{date_sort_key, get_historical_data_with_source, Indicator};
+use crate::diagnostics::{self, Guidance, Suggestion};
+use crate::history::History;
+use crate::incremental::IncrementalModel;
+#[cfg(feature = "ml")]
+use crate::ml;
+use crate::models::{self, Candidate, Component, Weighting};
+use crate::plugins::{self, Plugin};
+use crate::pinned::PinnedForecast;
+use crate::presets::Preset;
+use crate::rates::{self, Basis};
+use crate::project::{Layout, Project};
+use crate::quota::{self, QuotaExceeded, QuotaState};
+use crate::revisions;
+use crate::scale::{self, Scale};
+use crate::search::Target;
+use crate::staleness::{FitStamp, RefitPolicy, Staleness};
+use crate::targets::{self, InflationTarget};
+use crate::report::{self, Report};
+use crate::annotations::Annotation;
+use crate::validation::{self, Severity};
+use crate::fx::{self, Currency};
+use crate::market::{self, Candle};
+use crate::{boxcox, cache, croston, export, frequency, prefetch, rng, seasonal, stats, theta, vintage};
+use crate::FORECAST_STEPS;
+
+mod accessibility;
+mod annotations;
+mod calendar;
+mod catalog;
+mod chart;
+mod components;
+mod correlation;
+mod dashboard;
+mod derived;
+mod event_study;
+mod expectations;
+mod explain;
+mod goal_seek;
+mod indicators;
+mod loans;
+mod order_help;
+mod pca;
+mod phillips;
+mod pinned;
+mod prepared;
+mod presets;
+mod project;
+mod projection;
+#[cfg(feature = "python-models")]
+mod python_env;
+mod ranking;
+mod rebase;
+mod recessions;
+mod refresh;
+mod regions;
+mod returns;
+mod revisions_view;
+mod salary;
+mod saved_models;
+mod scenario;
+#[cfg(feature = "scripting")]
+mod scripts;
+mod search;
+mod state;
+mod status;
+mod tags;
+mod ticker;
+mod var;
+mod wizard;
+
+use accessibility::AccessibilityPanel;
+use annotations::{AnnotationsPanel, Markers};
+use calendar::CalendarView;
+use catalog::CatalogView;
+use chart::ChartType;
+use components::ComponentsView;
+use correlation::CorrelationView;
+use dashboard::Dashboard;
+use derived::DerivedView;
+use event_study::EventStudyView;
+use expectations::ExpectationsPanel;
+use explain::ExplanationPanel;
+use goal_seek::GoalSeekPanel;
+use indicators::IndicatorsPanel;
+use loans::LoansView;
+use pca::PcaView;
+use phillips::PhillipsView;
+use pinned::PinnedPanel;
+use prepared::{LoadedSeries, PreparedSeries};
+use presets::PresetsPanel;
+use project::ProjectPanel;
+use projection::ProjectionPanel;
+#[cfg(feature = "python-models")]
+use python_env::PythonPanel;
+use ranking::RankingView;
+use rebase::RebaseView;
+use recessions::RecessionsPanel;
+use refresh::{PendingRefresh, RefreshChoice};
+use regions::RegionsView;
+use returns::ReturnsView;
+use salary::SalaryView;
+use saved_models::SavedModelsPanel;
+use scenario::ScenarioPanel;
+#[cfg(feature = "scripting")]
+use scripts::ScriptsView;
+use search::SearchPanel;
+use state::{AppState, Message, Series, Task};
+use tags::TagsPanel;
+use ticker::FxTicker;
+use var::VarView;
+use wizard::Wizard;
+
+// Estados guardados para desfazer
+const UNDO_LIMIT: usize = 100;
+
+// Estado da análise coberto pelo desfazer/refazer: parâmetros e transformações, anotações do país
+// e previsões fixadas. A série e o país só identificam o contexto; trocá-los limpa o histórico.
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    series: String,
+    country: String,
+    settings: Preset,
+    detect_breaks: bool,
+    annotations: Vec<Annotation>,
+    pinned: Vec<PinnedForecast>,
+}
+
+// Visões de análise disponíveis na janela principal
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum View {
+    Dashboard,
+    Catalog,
+    Forecast,
+    Phillips,
+    Components,
+    Regions,
+    Derived,
+    Returns,
+    Correlation,
+    Pca,
+    Loans,
+    Salary,
+    Ranking,
+    Calendar,
+    Rebase,
+    EventStudy,
+    Var,
+    #[cfg(feature = "scripting")]
+    Scripts,
+}
+
+impl View {
+    // Identificador guardado nos projetos
+    fn key(&self) -> &'static str {
+        match self {
+            View::Dashboard => "dashboard",
+            View::Catalog => "catalog",
+            View::Forecast => "forecast",
+            View::Phillips => "phillips",
+            View::Components => "components",
+            View::Regions => "regions",
+            View::Derived => "derived",
+            View::Returns => "returns",
+            View::Correlation => "correlation",
+            View::Pca => "pca",
+            View::Loans => "loans",
+            View::Salary => "salary",
+            View::Ranking => "ranking",
+            View::Calendar => "calendar",
+            View::Rebase => "rebase",
+            View::EventStudy => "event_study",
+            View::Var => "var",
+            #[cfg(feature = "scripting")]
+            View::Scripts => "scripts",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<View> {
+        match key {
+            "dashboard" => Some(View::Dashboard),
+            "catalog" => Some(View::Catalog),
+            "forecast" => Some(View::Forecast),
+            "phillips" => Some(View::Phillips),
+            "components" => Some(View::Components),
+            "regions" => Some(View::Regions),
+            "derived" => Some(View::Derived),
+            "returns" => Some(View::Returns),
+            "correlation" => Some(View::Correlation),
+            "pca" => Some(View::Pca),
+            "loans" => Some(View::Loans),
+            "salary" => Some(View::Salary),
+            "ranking" => Some(View::Ranking),
+            "calendar" => Some(View::Calendar),
+            "rebase" => Some(View::Rebase),
+            "event_study" => Some(View::EventStudy),
+            "var" => Some(View::Var),
+            #[cfg(feature = "scripting")]
+            "scripts" => Some(View::Scripts),
+            _ => None,
+        }
+    }
+
+    // Na ordem das abas
+    fn all() -> Vec<View> {
+        vec![
+            View::Dashboard,
+            View::Catalog,
+            View::Forecast,
+            View::Phillips,
+            View::Components,
+            View::Regions,
+            View::Derived,
+            View::Returns,
+            View::Correlation,
+            View::Pca,
+            View::Loans,
+            View::Salary,
+            View::Ranking,
+            View::Calendar,
+            View::Rebase,
+            View::EventStudy,
+            View::Var,
+            #[cfg(feature = "scripting")]
+            View::Scripts,
+        ]
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            View::Dashboard => "Painel",
+            View::Catalog => "Catálogo",
+            View::Forecast => "Previsão",
+            View::Phillips => "Curva de Phillips",
+            View::Components => "Componentes do IPC",
+            View::Regions => "Inflação regional",
+            View::Derived => "Séries derivadas",
+            View::Returns => "Retornos",
+            View::Correlation => "Correlações",
+            View::Pca => "Fatores comuns",
+            View::Loans => "Financiamentos",
+            View::Salary => "Salário real",
+            View::Ranking => "Ranking de inflação",
+            View::Calendar => "Calendário de inflação",
+            View::Rebase => "Índices rebaseados",
+            View::EventStudy => "Estudo de evento",
+            View::Var => "VAR",
+            #[cfg(feature = "scripting")]
+            View::Scripts => "Scripts",
+        }
+    }
+
+    // Aba seguinte (`step` 1) ou anterior (-1), voltando ao início depois da última
+    fn cycle(&self, step: isize) -> View {
+        let views = View::all();
+        let current = views.iter().position(|view| view == self).unwrap_or(0) as isize;
+        views[(current + step).rem_euclid(views.len() as isize) as usize]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Model {
+    #[cfg(feature = "python-models")]
+    Arima,
+    #[cfg(feature = "ml")]
+    Mlp,
+    Theta,
+    Croston,
+    // Índice do plugin em MyApp::plugins
+    Plugin(usize),
+    // Combinação das previsões de todos os modelos
+    Ensemble(Weighting),
+}
+
+impl Model {
+    fn label(&self, plugins: &[Plugin]) -> String {
+        match self {
+            #[cfg(feature = "python-models")]
+            Model::Arima => "ARIMA (statsmodels)".to_string(),
+            #[cfg(feature = "ml")]
+            Model::Mlp => "Rede neural MLP (candle)".to_string(),
+            Model::Theta => "Método Theta".to_string(),
+            Model::Croston => "Croston (séries intermitentes)".to_string(),
+            Model::Plugin(i) => plugins[*i].name().to_string(),
+            Model::Ensemble(weighting) => format!("Combinação ({})", weighting.label()),
+        }
+    }
+
+    // O ARIMA só aparece se o Python encontrado tiver numpy e statsmodels
+    fn all(plugins: &[Plugin]) -> Vec<Model> {
+        let mut models = vec![];
+        #[cfg(feature = "python-models")]
+        if arima::available() {
+            models.push(Model::Arima);
+        }
+        #[cfg(feature = "ml")]
+        models.push(Model::Mlp);
+        models.push(Model::Theta);
+        models.push(Model::Croston);
+        models.extend((0..plugins.len()).map(Model::Plugin));
+        models.extend(Weighting::all().map(Model::Ensemble));
+        models
+    }
+
+    // Ajusta o modelo e prevê `steps` passos (até FORECAST_STEPS); falhas resultam em previsão vazia
+    #[cfg_attr(not(feature = "python-models"), allow(unused_variables))]
+    fn run(&self, plugins: &[Plugin], (p, d, q): (u32, u32, u32), values: &[f64], steps: usize) -> Vec<f64> {
+        let mut forecast = match self {
+            #[cfg(feature = "python-models")]
+            Model::Arima => run_arima_model(values, p, d, q).unwrap_or_else(|_| vec![]),
+            #[cfg(feature = "ml")]
+            Model::Mlp => ml::run_mlp_model(values, steps).unwrap_or_else(|_| vec![]),
+            Model::Theta => theta::run_theta_model(values, steps).unwrap_or_else(|_| vec![]),
+            Model::Croston => croston::run_croston_model(values, steps).unwrap_or_else(|_| vec![]),
+            Model::Plugin(i) => plugins[*i].run(values, steps).unwrap_or_else(|_| vec![]),
+            Model::Ensemble(weighting) => models::ensemble(plugins, values, steps, *weighting)
+                .map(|fit| fit.forecast)
+                .unwrap_or_else(|_| vec![]),
+        };
+        forecast.truncate(steps);
+        forecast
+    }
+
+    // Candidato equivalente, usado ao salvar o modelo ajustado; combinações não têm um
+    #[cfg_attr(not(feature = "python-models"), allow(unused_variables))]
+    fn candidate(&self, (p, d, q): (u32, u32, u32)) -> Option<Candidate> {
+        match self {
+            #[cfg(feature = "python-models")]
+            Model::Arima => Some(Candidate::Arima(p, d, q)),
+            #[cfg(feature = "ml")]
+            Model::Mlp => Some(Candidate::Mlp),
+            Model::Theta => Some(Candidate::Theta),
+            Model::Croston => Some(Candidate::Croston),
+            Model::Plugin(i) => Some(Candidate::Plugin(*i)),
+            Model::Ensemble(_) => None,
+        }
+    }
+
+    // Identificador guardado nas predefinições
+    fn key(&self, plugins: &[Plugin]) -> String {
+        match self {
+            #[cfg(feature = "python-models")]
+            Model::Arima => "arima".to_string(),
+            #[cfg(feature = "ml")]
+            Model::Mlp => "mlp".to_string(),
+            Model::Theta => "theta".to_string(),
+            Model::Croston => "croston".to_string(),
+            Model::Plugin(i) => plugins[*i].name().to_string(),
+            Model::Ensemble(weighting) => format!("ensemble-{}", weighting.key()),
+        }
+    }
+
+    // Só entre os modelos disponíveis: o ARIMA sem o Python completo ou um plugin removido não voltam
+    fn from_key(key: &str, plugins: &[Plugin]) -> Option<Model> {
+        Model::all(plugins).into_iter().find(|model| model.key(plugins) == key)
+    }
+
+    // Modelos que usam a ordem (p, d, q) definida nos sliders
+    fn has_order(&self) -> bool {
+        #[cfg(feature = "python-models")]
+        if *self == Model::Arima {
+            return true;
+        }
+        false
+    }
+}
+
+pub struct MyApp {
+    config: Option<Config>,
+    wizard: Option<Wizard>,
+    // Proxy ou certificado inválido na configuração; as buscas seguem com o cliente padrão
+    client_error: Option<String>,
+    // Série, previsão e resultados das buscas em segundo plano
+    state: AppState,
+    ticker: FxTicker,
+    view: View,
+    dashboard: Dashboard,
+    catalog: CatalogView,
+    search: SearchPanel,
+    phillips: PhillipsView,
+    components: ComponentsView,
+    regions: RegionsView,
+    derived: DerivedView,
+    returns: ReturnsView,
+    correlation: CorrelationView,
+    pca: PcaView,
+    loans: LoansView,
+    salary: SalaryView,
+    ranking: RankingView,
+    calendar: CalendarView,
+    rebase: RebaseView,
+    event_study: EventStudyView,
+    var: VarView,
+    #[cfg(feature = "scripting")]
+    scripts: ScriptsView,
+    // Série usada nos modelos: None para a série buscada do provedor
+    series: Option<String>,
+    // Cotações OHLCV do ativo carregado, com seu código; a série atual são os fechamentos
+    asset: String,
+    candles: Option<(String, Vec<Candle>)>,
+    candles_pending: bool,
+    candles_error: Option<String>,
+    // Conversão da série (na moeda `currency`) para outra moeda pelas cotações históricas
+    currency: String,
+    convert_to: Option<Currency>,
+    fx_pending: bool,
+    fx_error: Option<String>,
+    // Base das taxas exibidas e modeladas, calculada a partir das taxas do período publicadas
+    basis: Basis,
+    basis_error: Option<String>,
+    annotations: AnnotationsPanel,
+    recessions: RecessionsPanel,
+    export_include_annotations: bool,
+    export_result: Option<Result<String, String>>,
+    report_pdf: bool,
+    model: Option<Model>,
+    // Passos previstos, até FORECAST_STEPS
+    horizon: usize,
+    presets: PresetsPanel,
+    pinned: PinnedPanel,
+    history: History<Snapshot>,
+    // Último estado guardado no histórico; None recomeça o histórico no próximo quadro
+    committed: Option<Snapshot>,
+    tags: TagsPanel,
+    project: ProjectPanel,
+    accessibility: AccessibilityPanel,
+    // Modelo da última previsão, com o tamanho da série exibida já incorporado a ele;
+    // recebe as observações novas da atualização periódica sem ser reajustado
+    live: Option<(usize, IncrementalModel)>,
+    // Série com que a previsão exibida foi feita, para detectar quando fica desatualizada
+    fitted: Option<FitStamp>,
+    // Série dessazonalizada e quebras estruturais, refeitas só quando a série ou as opções mudam
+    prepared: PreparedSeries,
+    // Séries carregadas entregues às visões de análise
+    loaded: LoadedSeries,
+    last_refresh: Instant,
+    // Buscas já comparadas com a série exibida e a versão nova que aguarda a decisão do usuário
+    refresh_checked: u64,
+    pending_refresh: Option<PendingRefresh>,
+    // Tamanho da série do provedor na última reavaliação das derivadas e cestas
+    derived_source: usize,
+    // Pesos da última previsão combinada
+    weights: Vec<Component>,
+    explanation: ExplanationPanel,
+    // Ordem sugerida pelos diagnósticos (ACF, PACF e Dickey-Fuller) para os valores guardados
+    guidance: Option<(Vec<f64>, Option<Guidance>)>,
+    saved_models: SavedModelsPanel,
+    scenario: ScenarioPanel,
+    goal_seek: GoalSeekPanel,
+    expectations: ExpectationsPanel,
+    projection: ProjectionPanel,
+    // Modo vintage: ajusta os modelos com a série como era conhecida em vintage_date
+    vintage: bool,
+    vintage_date: String,
+    vintage_error: Option<String>,
+    // Ajuste sazonal (STL) antes da exibição e da modelagem
+    seasonal_adjust: bool,
+    compare_nsa: bool,
+    // Modela a série transformada por Box-Cox; guarda o λ escolhido (ou o erro) e os intervalos
+    // de 95% da última previsão
+    box_cox: bool,
+    box_cox_status: Option<Result<f64, String>>,
+    intervals: Vec<(f64, f64)>,
+    // Escala do modelo (nível, log ou variação percentual) e o erro da última transformação
+    scale: Scale,
+    scale_error: Option<String>,
+    // Quebras estruturais marcadas no gráfico; opcionalmente ajusta só no último regime
+    detect_breaks: bool,
+    latest_regime: bool,
+    // Faixa da meta de inflação do país no gráfico, quando conhecida
+    show_target: bool,
+    indicators: IndicatorsPanel,
+    #[cfg(feature = "python-models")]
+    python: PythonPanel,
+    plugins: Vec<Plugin>,
+    p: u32,
+    d: u32,
+    q: u32,
+}
+
+impl MyApp {
+    // Sem configuração (primeira execução) o assistente é exibido antes de carregar os dados
+    pub fn new(config: Option<Config>) -> Self {        
+        let plugins = plugins::discover_plugins(&plugins::plugins_dir());
+        // O venv configurado precisa valer antes de listar os modelos disponíveis
+        #[cfg(feature = "python-models")]
+        if let Some(config) = &config {
+            arima::configure(config);
+        }
+
+        let mut app = Self {
+            wizard: config.is_none().then(Wizard::new),
+            config: None,
+            client_error: None,
+            state: AppState::new(),
+            ticker: FxTicker::new(),
+            view: View::Dashboard,
+            dashboard: Dashboard::new(),
+            catalog: CatalogView::new(),
+            search: SearchPanel::new(),
+            phillips: PhillipsView::new(),
+            components: ComponentsView::new(),
+            regions: RegionsView::new(),
+            derived: DerivedView::new(),
+            returns: ReturnsView::new(),
+            correlation: CorrelationView::new(),
+            pca: PcaView::new(),
+            loans: LoansView::new(),
+            salary: SalaryView::new(),
+            ranking: RankingView::new(),
+            calendar: CalendarView::new(),
+            rebase: RebaseView::new(),
+            event_study: EventStudyView::new(),
+            var: VarView::new(),
+            #[cfg(feature = "scripting")]
+            scripts: ScriptsView::new(),
+            series: None,
+            asset: String::new(),
+            candles: None,
+            candles_pending: false,
+            candles_error: None,
+            currency: "BRL".to_string(),
+            convert_to: None,
+            fx_pending: false,
+            fx_error: None,
+            basis: Basis::Period,
+            basis_error: None,
+            annotations: AnnotationsPanel::new(),
+            recessions: RecessionsPanel::new(),
+            export_include_annotations: true,
+            export_result: None,
+            report_pdf: false,
+            model: Model::all(&plugins).into_iter().next(),
+            horizon: FORECAST_STEPS,
+            presets: PresetsPanel::new(),
+            pinned: PinnedPanel::new(),
+            history: History::new(UNDO_LIMIT),
+            committed: None,
+            tags: TagsPanel::new(),
+            project: ProjectPanel::new(),
+            accessibility: AccessibilityPanel::new(),
+            live: None,
+            fitted: None,
+            prepared: PreparedSeries::default(),
+            loaded: LoadedSeries::default(),
+            last_refresh: Instant::now(),
+            refresh_checked: 0,
+            pending_refresh: None,
+            derived_source: 0,
+            weights: vec![],
+            explanation: ExplanationPanel::new(),
+            guidance: None,
+            saved_models: SavedModelsPanel::new(),
+            scenario: ScenarioPanel::new(),
+            goal_seek: GoalSeekPanel::new(),
+            expectations: ExpectationsPanel::new(),
+            projection: ProjectionPanel::new(),
+            vintage: false,
+            vintage_date: String::new(),
+            vintage_error: None,
+            seasonal_adjust: false,
+            compare_nsa: false,
+            box_cox: false,
+            box_cox_status: None,
+            intervals: vec![],
+            scale: Scale::Levels,
+            scale_error: None,
+            detect_breaks: false,
+            latest_regime: false,
+            show_target: true,
+            indicators: IndicatorsPanel::new(),
+            #[cfg(feature = "python-models")]
+            python: PythonPanel::new(),
+            plugins,
+            p: 1,
+            d: 1,
+            q: 1,
+        };
+
+        if let Some(config) = config {
+            app.load_data(config);
+        }
+
+        app
+    }
+
+    // Inicia a busca dos dados e as verificações periódicas do provedor
+    fn load_data(&mut self, config: Config) {
+        // Vale tanto na inicialização quanto ao concluir o assistente, antes da primeira busca
+        self.client_error = ApiClient::configure(&config).err();
+        rng::set_global_seed(config.seed);
+        if self.client_error.is_none() {
+            status::spawn_health_checks(config.clone(), self.state.outbox());
+            self.ticker.spawn_polling(config.clone());
+        }
+        self.annotations.set_country(&config.country);
+        self.recessions.set_country(&config.country);
+        #[cfg(feature = "python-models")]
+        self.python.set_config(&config);
+        self.config = Some(config);
+        self.apply_default_preset();
+        self.fetch_data();
+    }
+
+    // Configuração para as buscas; nenhuma é feita com proxy ou certificado inválido, como no modo
+    // em lote, em vez de seguir por uma conexão direta
+    fn fetch_config(&self) -> Option<Config> {
+        self.config.clone().filter(|_| self.client_error.is_none())
+    }
+
+    // Busca a série histórica em segundo plano
+    fn fetch_data(&mut self) {
+        self.fetch(false);
+    }
+
+    // Na atualização periódica só a série do provedor é trocada; a exibida recebe as
+    // observações novas em `apply_refresh`
+    fn fetch(&mut self, refresh: bool) {
+        let Some(task_config) = self.fetch_config() else {
+            return;
+        };
+        let outbox = self.state.begin(Task::Fetch);
+
+        self.state.load_error = None;
+        self.state.quota_blocked = false;
+
+        tokio::spawn(async move {
+            // O erro vira texto já aqui: `Box<dyn Error>` não é `Send` e a tarefa ainda aguarda a pré-busca
+            let result = get_historical_data_with_source(ApiClient::shared(), &task_config, &task_config.country)
+                .await
+                .map_err(|e| (e.to_string(), e.is::<QuotaExceeded>()));
+
+            let loaded = match result {
+                Ok((data, source)) => {
+                    outbox.send(Message::Fetched {
+                        series: Series {
+                            dates: data.iter().map(|v| v.date.clone()).collect(),
+                            values: data.iter().map(|v| v.value).collect(),
+                        },
+                        source,
+                        revisions: revisions::history(&task_config.country),
+                        refresh,
+                    });
+                    true
+                }
+                Err((error, quota_blocked)) => {
+                    outbox.send(Message::FetchFailed { error, quota_blocked });
+                    false
+                }
+            };
+
+            // Com a série do país carregada, os demais indicadores dele vêm em seguida
+            if loaded && !refresh {
+                let country = &task_config.country;
+                let failures =
+                    prefetch::prefetch_country(ApiClient::shared(), &task_config, country, Indicator::Inflation).await;
+                if !failures.is_empty() {
+                    outbox.send(Message::Warnings(failures));
+                }
+            }
+        });
+    }
+
+    // Busca em segundo plano os demais indicadores do país de uma série aberta
+    fn prefetch_country(&self, country: &str, except: Indicator) {
+        let Some(config) = self.fetch_config().filter(prefetch::allowed) else {
+            return;
+        };
+        let country = country.to_string();
+        let outbox = self.state.outbox();
+        tokio::spawn(async move {
+            let failures = prefetch::prefetch_country(ApiClient::shared(), &config, &country, except).await;
+            if !failures.is_empty() {
+                outbox.send(Message::Warnings(failures));
+            }
+        });
+    }
+}
+
+// Intervalo entre as atualizações da série do provedor
+const REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+// Sem tarefas pendentes, a tela só é redesenhada a cada intervalo (ou a cada evento), o bastante
+// para as verificações periódicas e a cotação do câmbio
+const IDLE_REPAINT: Duration = Duration::from_secs(1);
+// Observações reservadas para medir o erro da previsão no relatório
+const REPORT_HOLDOUT: usize = 12;
+// Valor sugerido ao fixar a semente pela primeira vez
+const DEFAULT_SEED: u64 = 42;
+
+// Dica dos diagnósticos ao lado do slider; destacada enquanto o valor escolhido é outro
+fn show_suggestion(ui: &mut egui::Ui, suggestion: &Suggestion, current: u32) {
+    if suggestion.value == current {
+        ui.weak(format!("✔ {}", suggestion.reason));
+    } else {
+        ui.colored_label(egui::Color32::LIGHT_BLUE, &suggestion.reason);
+    }
+}
+
+impl MyApp {
+    // Gera o relatório HTML (e opcionalmente PDF) da série e previsão atuais
+    fn generate_report(&mut self, config: &Config) -> Result<String, String> {
+        let values = self.model_values();
+        let dates = self.state.current.dates.clone();
+        let forecast = self.state.predictions.clone();
+        let name = self.series.clone().unwrap_or_else(|| format!("inflation_{}", config.country));
+
+        let model = match self.model {
+            Some(model) if model.has_order() => {
+                format!("{}, ordem ({}, {}, {})", model.label(&self.plugins), self.p, self.d, self.q)
+            }
+            Some(model) => model.label(&self.plugins),
+            None => "Nenhum modelo".to_string(),
+        };
+
+        // Ajusta o modelo sem as últimas observações e compara a previsão com elas
+        let horizon = REPORT_HOLDOUT.min(values.len() / 5);
+        let holdout = self.model.filter(|_| horizon > 0).and_then(|model| {
+            let split = values.len() - horizon;
+            let predicted = model.run(&self.plugins, (self.p, self.d, self.q), &values[..split], horizon);
+            stats::forecast_metrics(&values[split..], &predicted).map(|metrics| (horizon, metrics))
+        });
+
+        let report = Report {
+            title: format!("RustFin: {}", name),
+            model,
+            dates: &dates,
+            values: &values,
+            forecast: &forecast,
+            annotations: &self.annotations.annotations,
+            holdout,
+        };
+
+        let html = report::write_html(&name, &report, config).map_err(|e| e.to_string())?;
+        if self.report_pdf {
+            let pdf = report::html_to_pdf(&html).map_err(|e| e.to_string())?;
+            return Ok(format!("{} e {}", html.display(), pdf.display()));
+        }
+
+        Ok(html.display().to_string())
+    }
+
+    // Refaz a série dessazonalizada, as quebras e a validação se a série atual ou as opções mudaram
+    fn prepare(&mut self) {
+        let indicator = match &self.series {
+            None => Some(Indicator::Inflation),
+            Some(name) => Indicator::from_series_name(name),
+        };
+        let current = &self.state.current;
+        self.prepared.update(&current.dates, &current.values, self.seasonal_adjust, self.detect_breaks, indicator);
+    }
+
+    // Série usada na exibição e nos modelos: a atual, dessazonalizada se o ajuste estiver ativo
+    fn model_values(&mut self) -> Vec<f64> {
+        self.prepare();
+        self.prepared.values.clone()
+    }
+
+    // Amostra de ajuste dos modelos: a série exibida ou, se pedido, apenas o último regime
+    fn fit_values(&mut self) -> Vec<f64> {
+        self.prepare();
+        let values = &self.prepared.values;
+        match self.prepared.breaks.last() {
+            Some(&start) if self.latest_regime => values[start..].to_vec(),
+            _ => values.clone(),
+        }
+    }
+
+    // Atualiza a série do provedor periodicamente; com o limite diário atingido, a busca é adiada
+    fn refresh_data(&mut self, config: &Config) {
+        if self.config.is_none() || self.last_refresh.elapsed() < REFRESH_INTERVAL {
+            return;
+        }
+        self.last_refresh = Instant::now();
+
+        if quota::current().state(config) != QuotaState::Exceeded {
+            self.fetch(true);
+        }
+    }
+
+    // Compara a série exibida, se ela for a do provedor, com a versão da atualização periódica;
+    // havendo diferença, ela só é trocada depois de o usuário ver o que mudou
+    fn check_refresh(&mut self) {
+        if self.state.fetched == self.refresh_checked {
+            return;
+        }
+        self.refresh_checked = self.state.fetched;
+        if self.series.is_some() || self.vintage || self.convert_to.is_some() || self.basis != Basis::Period {
+            return;
+        }
+
+        self.pending_refresh = PendingRefresh::compare(&self.state.current, &self.state.historical);
+    }
+
+    // Troca a série exibida pela versão nova; se ela só acrescenta observações, o modelo da
+    // previsão as incorpora, e com valores revisados a previsão precisa ser refeita
+    fn apply_refresh(&mut self, pending: PendingRefresh) {
+        let known = self.state.current.values.len();
+        self.state.current = pending.fresh;
+        if pending.diff.appends_only(known) {
+            self.update_live(known);
+        } else {
+            self.live = None;
+            self.state.predictions.clear();
+        }
+    }
+
+    // Cada busca grava a série no cache; derivadas e cestas que dependem dela são reavaliadas
+    fn refresh_derived(&mut self) {
+        let fetched = self.state.historical.values.len();
+        if fetched != self.derived_source {
+            self.derived_source = fetched;
+            self.derived.refresh();
+        }
+    }
+
+    // Registra a série com que a previsão exibida foi feita
+    fn stamp_forecast(&mut self) {
+        self.fitted = Some(FitStamp::of(&self.state.current.dates, &self.state.current.values));
+    }
+
+    // Por que a previsão exibida está desatualizada; None sem previsão ou com ela em dia
+    fn staleness(&self) -> Option<Staleness> {
+        if self.state.predictions.is_empty() {
+            return None;
+        }
+        let stamp = self.fitted.as_ref()?;
+        Some(stamp.staleness(&self.state.current.dates, &self.state.current.values)).filter(|s| s.is_stale())
+    }
+
+    fn update_live(&mut self, known: usize) {
+        let values = self.state.current.values.clone();
+        let name = format!("inflation_{}", self.config.clone().unwrap_or_default().country);
+        let Some((len, live)) = self.live.as_mut() else {
+            return;
+        };
+        // Com o ajuste sazonal a série ajustada muda por inteiro e só um novo ajuste serve
+        if self.seasonal_adjust || *len != known || live.artifact().training.series != name {
+            self.live = None;
+            return;
+        }
+
+        match live.update(&values[known..]).and_then(|_| live.forecast(self.horizon)) {
+            Ok(forecast) => {
+                *len = values.len();
+                self.state.predictions = forecast;
+                self.fitted = Some(FitStamp::of(&self.state.current.dates, &values));
+            }
+            Err(_) => self.live = None,
+        }
+    }
+
+    // Abre uma série do painel na visão de previsão
+    fn open_series(&mut self, country: &str, name: String, series: Vec<(String, f64)>) {
+        self.pending_refresh = None;
+        self.vintage = false;
+        self.convert_to = None;
+        self.basis = Basis::Period;
+        self.candles = None;
+        if let Some(indicator) = Indicator::from_series_name(&name) {
+            self.prefetch_country(country, indicator);
+        }
+        self.series = Some(name);
+        self.annotations.set_country(country);
+        self.recessions.set_country(country);
+        self.state.current = Series::from_pairs(series);
+        self.state.predictions.clear();
+        self.apply_default_preset();
+        self.view = View::Forecast;
+    }
+
+    // Nome da série atual, como nas predefinições e nos modelos salvos
+    fn series_name(&self) -> String {
+        self.series
+            .clone()
+            .unwrap_or_else(|| format!("inflation_{}", self.config.clone().unwrap_or_default().country))
+    }
+
+    // Configuração atual da tela, sem nome
+    fn current_preset(&self) -> Preset {
+        Preset {
+            name: String::new(),
+            model: self.model.map(|model| model.key(&self.plugins)).unwrap_or_default(),
+            order: (self.p, self.d, self.q),
+            horizon: self.horizon,
+            seasonal_adjust: self.seasonal_adjust,
+            box_cox: self.box_cox,
+            scale: self.scale,
+            latest_regime: self.detect_breaks && self.latest_regime,
+        }
+    }
+
+    // Modelos indisponíveis nesta execução são ignorados e o modelo atual é mantido
+    fn apply_preset(&mut self, preset: &Preset) {
+        if let Some(model) = Model::from_key(&preset.model, &self.plugins) {
+            self.model = Some(model);
+        }
+        (self.p, self.d, self.q) = preset.order;
+        self.horizon = preset.horizon.clamp(1, FORECAST_STEPS);
+        self.seasonal_adjust = preset.seasonal_adjust;
+        self.box_cox = preset.box_cox;
+        self.scale = preset.scale;
+        self.latest_regime = preset.latest_regime;
+        self.detect_breaks |= preset.latest_regime;
+        self.state.predictions.clear();
+    }
+
+    // Abre um resultado da busca: a série (a do país, para anotações) ou a predefinição
+    fn open_target(&mut self, target: Target) {
+        match target {
+            Target::Series(name) => {
+                let Some(series) = self.derived.series().get(&name).cloned() else {
+                    return;
+                };
+                let country = match Indicator::from_series_name(&name) {
+                    Some(indicator) => name[indicator.key().len() + 1..].to_string(),
+                    None => self.config.clone().unwrap_or_default().country,
+                };
+                self.open_series(&country, name, series);
+            }
+            Target::Preset(name) => {
+                if let Some(preset) = self.presets.get(&name) {
+                    self.apply_preset(&preset);
+                    self.view = View::Forecast;
+                }
+            }
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            series: self.series_name(),
+            country: self.annotations.country().to_string(),
+            settings: self.current_preset(),
+            detect_breaks: self.detect_breaks,
+            annotations: self.annotations.annotations.clone(),
+            pinned: self.pinned.all().to_vec(),
+        }
+    }
+
+    // Guarda no histórico o estado anterior quando algo mudou. Durante um arraste (`interacting`)
+    // espera o botão ser solto, para que mover um slider seja um único passo.
+    fn track_history(&mut self, interacting: bool) {
+        let current = self.snapshot();
+        match &self.committed {
+            Some(committed) if committed.series == current.series && committed.country == current.country => {
+                if *committed != current && !interacting {
+                    if let Some(before) = self.committed.replace(current) {
+                        self.history.record(before);
+                    }
+                }
+            }
+            _ => {
+                self.history.clear();
+                self.committed = Some(current);
+            }
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        if self.current_preset() != snapshot.settings {
+            self.apply_preset(&snapshot.settings);
+        }
+        self.detect_breaks = snapshot.detect_breaks;
+        if self.annotations.annotations != snapshot.annotations {
+            self.annotations.replace(snapshot.annotations);
+        }
+        if self.pinned.all() != snapshot.pinned.as_slice() {
+            self.pinned.replace(snapshot.pinned);
+        }
+        self.committed = Some(self.snapshot());
+    }
+
+    fn undo(&mut self) {
+        self.track_history(false);
+        if let Some(previous) = self.history.undo(self.snapshot()) {
+            self.restore(previous);
+        }
+    }
+
+    fn redo(&mut self) {
+        self.track_history(false);
+        if let Some(next) = self.history.redo(self.snapshot()) {
+            self.restore(next);
+        }
+    }
+
+    // Ctrl+Z desfaz; Ctrl+Shift+Z e Ctrl+Y refazem. Com um campo de texto em edição, os atalhos
+    // ficam com ele.
+    fn handle_undo_shortcuts(&mut self, ctx: &egui::Context) {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+        let (undo, redo) = ctx.input_mut(|i| {
+            let redo = i.consume_key(egui::Modifiers { shift: true, ..egui::Modifiers::COMMAND }, egui::Key::Z)
+                || i.consume_key(egui::Modifiers::COMMAND, egui::Key::Y);
+            (!redo && i.consume_key(egui::Modifiers::COMMAND, egui::Key::Z), redo)
+        });
+        if undo {
+            self.undo();
+        } else if redo {
+            self.redo();
+        }
+    }
+
+    // Ctrl+PageDown e Ctrl+PageUp trocam de visão, como as abas de um navegador
+    fn handle_view_shortcuts(&mut self, ctx: &egui::Context) {
+        let (next, previous) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::COMMAND, egui::Key::PageDown),
+                i.consume_key(egui::Modifiers::COMMAND, egui::Key::PageUp),
+            )
+        });
+        if next {
+            self.view = self.view.cycle(1);
+        } else if previous {
+            self.view = self.view.cycle(-1);
+        }
+    }
+
+    // Estado da tela salvo nos projetos
+    fn layout(&self) -> Layout {
+        Layout {
+            view: self.view.key().to_string(),
+            country: self.config.clone().unwrap_or_default().country,
+            series: self.series.clone(),
+            settings: self.current_preset(),
+        }
+    }
+
+    // O projeto já foi instalado no diretório de configuração: recarrega os painéis e aplica o
+    // estado da tela. O país vale só para a sessão; séries derivadas que não existem aqui são
+    // trocadas pela do provedor.
+    fn open_project(&mut self, project: Project) {
+        self.dashboard = Dashboard::new();
+        self.presets = PresetsPanel::new();
+        self.pinned = PinnedPanel::new();
+        self.tags = TagsPanel::new();
+        self.committed = None;
+
+        let layout = project.layout;
+        let country_changed = self.config.as_mut().is_some_and(|config| {
+            let changed = config.country != layout.country;
+            config.country = layout.country.clone();
+            changed
+        });
+        if country_changed {
+            self.fetch_data();
+        }
+
+        self.candles = None;
+        self.series = layout.series.filter(|name| self.derived.series().contains_key(name));
+        self.apply_series();
+        self.apply_preset(&layout.settings);
+        self.view = View::from_key(&layout.view).unwrap_or(View::Dashboard);
+    }
+
+    fn apply_default_preset(&mut self) {
+        if let Some(preset) = self.presets.default_for(&self.series_name()) {
+            self.apply_preset(&preset);
+        }
+    }
+
+    // Troca a série usada nos modelos pela escolhida (buscada, em cache ou derivada)
+    fn apply_series(&mut self) {
+        // Trocar de série descarta a versão pendente; a do provedor é recarregada já com ela
+        self.pending_refresh = None;
+        self.convert_to = None;
+        self.basis = Basis::Period;
+        match &self.series {
+            None => {
+                let country = self.config.clone().unwrap_or_default().country;
+                self.annotations.set_country(&country);
+                self.recessions.set_country(&country);
+                self.apply_vintage();
+            }
+            Some(name) => {
+                self.vintage = false;
+                if let Some((_, candles)) = self.candles.as_ref().filter(|(ticker, _)| ticker == name) {
+                    self.state.current.values = candles.iter().map(|candle| candle.close).collect();
+                    self.state.current.dates = candles.iter().map(|candle| candle.date.clone()).collect();
+                    return;
+                }
+
+                self.candles = None;
+                let series = self.derived.series().get(name).cloned().unwrap_or_default();
+                self.state.current = Series::from_pairs(series);
+            }
+        }
+    }
+
+    // Troca a série usada nos modelos entre a atual e a vintage
+    fn apply_vintage(&mut self) {
+        self.pending_refresh = None;
+        self.convert_to = None;
+        self.basis = Basis::Period;
+        self.candles = None;
+        self.vintage_error = None;
+        self.series = None;
+
+        if !self.vintage {
+            self.state.current = self.state.historical.clone();
+            return;
+        }
+
+        let Some(as_of) = vintage::parse_date(&self.vintage_date) else {
+            self.vintage_error = Some("Data inválida, use o formato AAAA-MM-DD".to_string());
+            return;
+        };
+        let country = self.config.clone().unwrap_or_default().country;
+
+        let mut series = vintage::as_of(&cache::load_shared(&country), &self.state.revisions, as_of);
+        if series.is_empty() {
+            self.vintage_error = Some("Nenhuma observação conhecida localmente nessa data".to_string());
+        }
+        series.sort_by_key(|obs| date_sort_key(&obs.date));
+
+        self.state.current.values = series.iter().map(|obs| obs.value).collect();
+        self.state.current.dates = series.into_iter().map(|obs| obs.date).collect();
+    }
+}
+
+impl MyApp {
+    // Busca as cotações diárias do ativo informado; a série é trocada quando elas chegam
+    fn request_prices(&mut self) {
+        let Some(config) = self.fetch_config() else {
+            return;
+        };
+        let ticker = self.asset.trim().to_uppercase();
+        let outbox = self.state.begin(Task::Prices);
+
+        self.candles_error = None;
+        self.candles_pending = true;
+        self.state.prices = None;
+
+        tokio::spawn(async move {
+            let result = market::get_price_history(ApiClient::shared(), &config, &ticker)
+                .await
+                .map_err(|e| e.to_string());
+
+            outbox.send(Message::Prices { ticker, result });
+        });
+    }
+
+    fn poll_prices(&mut self) {
+        if !self.candles_pending {
+            return;
+        }
+        let Some((ticker, result)) = self.state.prices.take() else {
+            return;
+        };
+        self.candles_pending = false;
+
+        match result {
+            Ok(candles) if candles.is_empty() => {
+                self.candles_error = Some(format!("Nenhuma cotação encontrada para {}", ticker));
+            }
+            Ok(candles) => {
+                self.vintage = false;
+                self.series = Some(ticker.clone());
+                self.candles = Some((ticker, candles));
+                self.state.predictions.clear();
+                self.apply_series();
+            }
+            Err(e) => self.candles_error = Some(format!("Erro ao buscar as cotações: {}", e)),
+        }
+    }
+
+    // Busca as expectativas de mercado para a inflação do país configurado
+    fn request_expectations(&mut self) {
+        let Some(config) = self.fetch_config() else {
+            return;
+        };
+        let outbox = self.state.begin(Task::Expectations);
+        self.state.expectations = None;
+
+        tokio::spawn(async move {
+            let country = config.country.clone();
+            let result = crate::expectations::get_expectations(ApiClient::shared(), &config, &country)
+                .await
+                .map_err(|e| e.to_string());
+
+            outbox.send(Message::Expectations { country, result });
+        });
+    }
+
+    // Anotações e recessões do país atual, para os gráficos
+    fn markers(&self) -> Markers {
+        Markers {
+            annotations: self.annotations.annotations.clone(),
+            recessions: self.recessions.shaded(),
+        }
+    }
+
+    // Meta de inflação do país da série atual, se ela for a inflação em 12 meses como publicada
+    fn inflation_target(&self, config: &Config) -> Option<InflationTarget> {
+        if self.convert_to.is_some() || self.basis != Basis::Period {
+            return None;
+        }
+        let name = self.series_name();
+        let country = name.strip_prefix("inflation_")?;
+        targets::for_country(config, country)
+    }
+
+    // Expectativas para os passos da previsão; só se aplicam à série de inflação do provedor
+    fn expected(&self, country: &str) -> Vec<Option<f64>> {
+        if self.series.is_some() || self.vintage || self.convert_to.is_some() || self.basis != Basis::Period {
+            return vec![];
+        }
+        let Some((fetched_for, Ok(expectations))) = &self.state.expectations else {
+            return vec![];
+        };
+        match self.state.current.dates.last() {
+            Some(last) if fetched_for == country => {
+                crate::expectations::align(last, expectations, self.state.predictions.len())
+            }
+            _ => vec![],
+        }
+    }
+
+    // Candles só para as cotações como vieram; séries convertidas ou dessazonalizadas são exibidas em linhas
+    fn chart_type(&self) -> ChartType {
+        if self.convert_to.is_some() || self.basis != Basis::Period || self.seasonal_adjust {
+            return ChartType::Line;
+        }
+
+        ChartType::for_series(self.candles.as_ref().map(|(_, candles)| candles.as_slice()))
+    }
+
+    // Séries em cache e derivadas, mais a atual (ex.: um ativo) se ainda não estiver entre elas
+    fn load_series(&mut self) {
+        let current = &self.state.current;
+        let (derived, generation) = (self.derived.series(), self.derived.generation());
+        self.loaded.update(derived, generation, self.series.as_ref(), &current.dates, &current.values);
+    }
+
+    // Busca as cotações da moeda escolhida; a série é convertida quando elas chegam
+    fn request_conversion(&mut self) {
+        let target = self.convert_to;
+        // A conversão parte sempre da série original
+        self.apply_series();
+        self.convert_to = target;
+        self.fx_error = None;
+        self.fx_pending = false;
+        self.state.predictions.clear();
+
+        let (Some(to), Some(config)) = (target, self.fetch_config()) else {
+            return;
+        };
+        let from = self.currency.clone();
+        let outbox = self.state.begin(Task::Conversion);
+
+        self.fx_pending = true;
+        self.state.conversion = None;
+
+        tokio::spawn(async move {
+            let result = fx::get_fx_history(ApiClient::shared(), &config, &from, to)
+                .await
+                .map_err(|e| e.to_string());
+
+            outbox.send(Message::Conversion { currency: to, result });
+        });
+    }
+
+    // Recalcula a série na base escolhida, sempre a partir das taxas do período
+    fn apply_basis(&mut self) {
+        let basis = self.basis;
+        self.apply_series();
+        self.basis = basis;
+        self.basis_error = None;
+        self.state.predictions.clear();
+        if basis == Basis::Period {
+            return;
+        }
+
+        let result = rates::rebase(&self.state.current.dates, &self.state.current.values, basis);
+        match result {
+            Ok((dates, values)) => {
+                self.state.current.values = values;
+                self.state.current.dates = dates;
+            }
+            Err(e) => {
+                self.basis_error = Some(e);
+                self.basis = Basis::Period;
+            }
+        }
+    }
+
+    // Aplica a conversão quando as cotações pedidas chegam; respostas de pedidos anteriores são descartadas
+    fn poll_conversion(&mut self) {
+        if !self.fx_pending {
+            return;
+        }
+        let Some((currency, result)) = self.state.conversion.take() else {
+            return;
+        };
+        if Some(currency) != self.convert_to {
+            return;
+        }
+        self.fx_pending = false;
+
+        let rates = match result {
+            Ok(rates) => rates,
+            Err(e) => {
+                self.fx_error = Some(format!("Erro ao buscar o câmbio: {}", e));
+                self.convert_to = None;
+                return;
+            }
+        };
+
+        let (dates, values) = fx::convert(&self.state.current.dates, &self.state.current.values, &rates);
+        if values.is_empty() {
+            self.fx_error = Some("Nenhuma cotação cobre o período da série".to_string());
+            self.convert_to = None;
+            return;
+        }
+
+        self.state.current.values = values;
+        self.state.current.dates = dates;
+    }
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        if let Some(wizard) = self.wizard.as_mut() {
+            if let Some(config) = wizard.show(ctx) {
+                self.wizard = None;
+                self.load_data(config);
+            }
+            return;
+        }
+
+        let config = self.config.clone().unwrap_or_default();
+        self.state.poll();
+        self.refresh_data(&config);
+        self.check_refresh();
+        self.refresh_derived();
+        status::show_status_bar(ctx, &self.state.status, &config);
+        self.ticker.show(ctx, &config);
+        self.accessibility.apply(ctx, &config, frame.info().native_pixels_per_point.unwrap_or(1.0));
+        self.track_history(ctx.input(|i| i.pointer.any_down()));
+        self.handle_undo_shortcuts(ctx);
+        self.handle_view_shortcuts(ctx);
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("ARIMA Model Visualization");            
+
+            if let Some(error) = &self.client_error {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    format!("Erro na configuração de rede: {}. Nenhuma busca é feita até corrigi-la no config.json.", error),
+                );
+            }
+
+            let load_error = self.state.load_error.clone();
+            if let Some(error) = load_error {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao carregar os dados: {}", error));
+
+                if self.state.quota_blocked && ui.button("Ignorar o limite e buscar").clicked() {
+                    if let Some(config) = self.config.as_mut() {
+                        config.ignore_quota = true;
+                    }
+                    self.fetch_data();
+                }
+            }
+
+            if let Some(choice) = self.pending_refresh.as_ref().and_then(|pending| pending.show(ui, &config)) {
+                let pending = self.pending_refresh.take();
+                if let (RefreshChoice::Apply, Some(pending)) = (choice, pending) {
+                    self.apply_refresh(pending);
+                }
+            }
+
+            let layout = self.layout();
+            if let Some(project) = self.project.show(ui, layout) {
+                self.open_project(project);
+            }
+            if let Some(target) = self.search.show(ui, self.derived.series()) {
+                self.open_target(target);
+            }
+            if let Some(config) = self.config.as_mut() {
+                self.accessibility.show(ui, config);
+            }
+
+            ui.horizontal(|ui| {
+                for view in View::all() {
+                    ui.selectable_value(&mut self.view, view, view.label());
+                }
+            });
+            ui.separator();
+
+            match self.view {
+                View::Dashboard => {
+                    let forecast = |values: &[f64]| {
+                        self.model
+                            .map(|model| model.run(&self.plugins, (self.p, self.d, self.q), values, 1))
+                            .unwrap_or_default()
+                    };
+                    if let Some((item, series)) = self.dashboard.show(ui, &config, &forecast, self.tags.tags(), self.state.outbox()) {
+                        self.open_series(&item.country, item.name(), series);
+                    }
+                    return;
+                }
+                View::Catalog => {
+                    if let Some((item, series)) = self.catalog.show(ui, &config) {
+                        self.open_series(&item.country, item.name(), series);
+                    }
+                    return;
+                }
+                View::Forecast => {}
+                View::Phillips => return self.phillips.show(ui, &config),
+                View::Components => {
+                    let forecast = |values: &[f64]| {
+                        self.model
+                            .map(|model| model.run(&self.plugins, (self.p, self.d, self.q), values, 1))
+                            .unwrap_or_default()
+                    };
+                    let markers = self.markers();
+                    let personal = self.components.show(ui, &config, &markers, &forecast);
+                    if let Some(series) = personal {
+                        self.open_series(&config.country, format!("personal_inflation_{}", config.country), series);
+                    }
+                    return;
+                }
+                View::Regions => {
+                    let forecast = |values: &[f64], steps: usize| {
+                        self.model
+                            .map(|model| model.run(&self.plugins, (self.p, self.d, self.q), values, steps))
+                            .unwrap_or_default()
+                    };
+                    return self.regions.show(ui, &config, &forecast);
+                }
+                View::Derived => return self.derived.show(ui),
+                View::Returns => return self.returns.show(ui, &config, &self.state.current.values),
+                View::Correlation => {
+                    self.load_series();
+                    return self.correlation.show(ui, &self.loaded.series);
+                }
+                View::Pca => {
+                    self.load_series();
+                    return self.pca.show(ui, &self.loaded.series);
+                }
+                View::Loans => {
+                    let name = self.series_name();
+                    let forecast = self.state.predictions.clone();
+                    return self.loans.show(ui, &config, &name, &forecast);
+                }
+                View::Salary => {
+                    self.load_series();
+                    return self.salary.show(ui, &config, &self.loaded.series);
+                }
+                View::Ranking => return self.ranking.show(ui, &config),
+                View::Calendar => {
+                    self.load_series();
+                    return self.calendar.show(ui, &config, &self.loaded.series);
+                }
+                View::Rebase => {
+                    self.load_series();
+                    return self.rebase.show(ui, &config, &self.loaded.series);
+                }
+                View::EventStudy => {
+                    self.load_series();
+                    return self.event_study.show(ui, &config, &self.loaded.series, &self.annotations.annotations);
+                }
+                View::Var => {
+                    self.load_series();
+                    return self.var.show(ui, &self.loaded.series);
+                }
+                #[cfg(feature = "scripting")]
+                View::Scripts => {
+                    self.load_series();
+                    return self.scripts.show(ui, &self.loaded.series);
+                }
+            }
+
+            let previous = self.series.clone();
+            egui::ComboBox::from_label("Série")
+                .selected_text(self.series.clone().unwrap_or_else(|| format!("Inflação ({})", config.country)))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.series, None, format!("Inflação ({})", config.country));
+                    for name in self.derived.series().keys() {
+                        ui.selectable_value(&mut self.series, Some(name.clone()), name);
+                    }
+                });
+            if self.series != previous {
+                self.apply_series();
+                self.apply_default_preset();
+            }
+            let name = self.series_name();
+            self.tags.show(ui, &name);
+
+            self.poll_prices();
+            ui.horizontal(|ui| {
+                let label = ui.label("Ativo:");
+                ui.add(egui::TextEdit::singleline(&mut self.asset).hint_text("PETR4").desired_width(80.0))
+                    .labelled_by(label.id);
+                let ready = !self.asset.trim().is_empty() && !self.candles_pending && self.client_error.is_none();
+                if ui.add_enabled(ready, egui::Button::new("Carregar cotações")).clicked() {
+                    self.request_prices();
+                }
+                if self.candles_pending {
+                    ui.spinner();
+                }
+            });
+            if let Some(error) = &self.candles_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+
+            self.poll_conversion();
+            ui.horizontal(|ui| {
+                ui.label("Moeda da série:");
+                let source = ui.add(egui::TextEdit::singleline(&mut self.currency).desired_width(50.0));
+
+                let previous = self.convert_to;
+                egui::ComboBox::from_label("Converter para")
+                    .selected_text(self.convert_to.map_or("Original", |currency| currency.code()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.convert_to, None, "Original");
+                        for currency in Currency::all() {
+                            ui.selectable_value(&mut self.convert_to, Some(currency), currency.code());
+                        }
+                    });
+
+                if self.convert_to != previous || (source.lost_focus() && self.convert_to.is_some()) {
+                    self.request_conversion();
+                }
+                if self.fx_pending {
+                    ui.spinner();
+                }
+            });
+            if let Some(error) = &self.fx_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+
+            let previous = self.basis;
+            egui::ComboBox::from_label("Base da taxa")
+                .selected_text(self.basis.label())
+                .show_ui(ui, |ui| {
+                    for basis in Basis::all() {
+                        ui.selectable_value(&mut self.basis, basis, basis.label());
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "A série deve ser de taxas do período (ex.: inflação mensal); \
+                     as outras bases são compostas a partir delas",
+                );
+            if self.basis != previous {
+                self.apply_basis();
+            }
+            if let Some(error) = &self.basis_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+
+            egui::ComboBox::from_label("Modelo")
+                .selected_text(
+                    self.model
+                        .map(|model| model.label(&self.plugins))
+                        .unwrap_or_else(|| "Nenhum modelo disponível".to_string()),
+                )
+                .show_ui(ui, |ui| {
+                    for model in Model::all(&self.plugins) {
+                        ui.selectable_value(&mut self.model, Some(model), model.label(&self.plugins));
+                    }
+                });
+            #[cfg(feature = "python-models")]
+            if let Err(e) = arima::environment() {
+                ui.colored_label(egui::Color32::YELLOW, "ARIMA indisponível: o Python não tem numpy e statsmodels.")
+                    .on_hover_text(e.as_str());
+                ui.label(format!(
+                    "Os demais modelos continuam disponíveis. Para habilitar o ARIMA, instale os pacotes ({}) e reinicie, ou escolha um ambiente abaixo.",
+                    arima::INSTALL_HINT
+                ));
+            }
+            #[cfg(feature = "python-models")]
+            if let Some(config) = self.config.as_mut() {
+                self.python.show(ui, config);
+            }
+
+            // Ajuste dos parâmetros
+            if self.model.is_some_and(|model| model.has_order()) {
+                let values = self.state.current.values.clone();
+                if !matches!(&self.guidance, Some((seen, _)) if *seen == values) {
+                    let guidance = diagnostics::guidance(&values);
+                    self.guidance = Some((values, guidance));
+                }
+                let guidance = self.guidance.as_ref().and_then(|(_, guidance)| guidance.clone());
+
+                // Descrições na dica e na ajuda, para que o rótulo não quebre em telas estreitas ou com fonte grande
+                let sliders = [
+                    (&mut self.p, "p (AR)", guidance.as_ref().map(|g| &g.p)),
+                    (&mut self.d, "d (I)", guidance.as_ref().map(|g| &g.d)),
+                    (&mut self.q, "q (MA)", guidance.as_ref().map(|g| &g.q)),
+                ];
+                for (i, (value, label, suggestion)) in sliders.into_iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.add(egui::Slider::new(&mut *value, 0..=10).text(label))
+                            .on_hover_text(order_help::hint(&config.locale, i));
+                        if let Some(suggestion) = suggestion {
+                            show_suggestion(ui, suggestion, *value);
+                        }
+                    });
+                }
+                if let Some(guidance) = guidance.filter(|guidance| guidance.order() != (self.p, self.d, self.q)) {
+                    let (p, d, q) = guidance.order();
+                    let apply = ui
+                        .button(format!("Usar a ordem sugerida ({}, {}, {})", p, d, q))
+                        .on_hover_text("Sugestão pelo método de Box-Jenkins; confira os erros fora da amostra")
+                        .clicked();
+                    if apply {
+                        (self.p, self.d, self.q) = (p, d, q);
+                    }
+                }
+                order_help::show(ui, &config.locale);
+            }
+            ui.add(egui::Slider::new(&mut self.horizon, 1..=FORECAST_STEPS).text("Horizonte (passos previstos)"));
+
+            ui.horizontal(|ui| {
+                let undo = ui
+                    .add_enabled(self.history.can_undo(), egui::Button::new("Desfazer"))
+                    .on_hover_text("Ctrl+Z: parâmetros, transformações, anotações e previsões fixadas")
+                    .clicked();
+                let redo = ui
+                    .add_enabled(self.history.can_redo(), egui::Button::new("Refazer"))
+                    .on_hover_text("Ctrl+Shift+Z ou Ctrl+Y")
+                    .clicked();
+                if undo {
+                    self.undo();
+                } else if redo {
+                    self.redo();
+                }
+            });
+
+            let (name, current) = (self.series_name(), self.current_preset());
+            if let Some(preset) = self.presets.show(ui, current, &name) {
+                self.apply_preset(&preset);
+            }
+
+            if let Some(config) = self.config.as_mut() {
+                ui.horizontal(|ui| {
+                    let mut fixed = config.seed.is_some();
+                    let mut seed = config.seed.unwrap_or(DEFAULT_SEED);
+
+                    let toggled = ui
+                        .checkbox(&mut fixed, "Semente fixa")
+                        .on_hover_text("Torna reprodutíveis os resultados dos componentes aleatórios (ex.: pesos iniciais da MLP)")
+                        .changed();
+                    let edited = ui
+                        .add_enabled(fixed, egui::DragValue::new(&mut seed).clamp_range(1..=u32::MAX))
+                        .changed();
+
+                    if toggled || edited {
+                        config.seed = fixed.then_some(seed);
+                        rng::set_global_seed(config.seed);
+                        // A semente vale para as próximas execuções; falha ao salvar não impede o uso na sessão
+                        let _ = config.save();
+                    }
+                });
+            }
+
+            ui.horizontal(|ui| {
+                let toggled = ui
+                    .checkbox(&mut self.vintage, "Modo vintage")
+                    .on_hover_text("Usa a série como era conhecida na data informada, sem revisões posteriores")
+                    .changed();
+                let date = ui.add_enabled(
+                    self.vintage,
+                    egui::TextEdit::singleline(&mut self.vintage_date).hint_text("AAAA-MM-DD"),
+                );
+                accessibility::named(date, egui::WidgetType::TextEdit, "Data do modo vintage (AAAA-MM-DD)");
+                let applied = ui.add_enabled(self.vintage, egui::Button::new("Aplicar")).clicked();
+
+                if toggled || applied {
+                    self.apply_vintage();
+                }
+            });
+            if let Some(error) = &self.vintage_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+
+            ui.horizontal(|ui| {
+                let adjusted = ui
+                    .checkbox(&mut self.seasonal_adjust, "Ajuste sazonal (STL)")
+                    .on_hover_text("Remove o componente sazonal antes da exibição e da modelagem")
+                    .changed();
+                ui.add_enabled(self.seasonal_adjust, egui::Checkbox::new(&mut self.compare_nsa, "Comparar com a série sem ajuste"));
+
+                // A previsão anterior foi feita sobre a outra versão da série
+                if adjusted {
+                    self.state.predictions.clear();
+                }
+            });
+            if self.seasonal_adjust && self.state.current.values.len() < 2 * seasonal::MONTHLY_PERIOD {
+                ui.colored_label(egui::Color32::YELLOW, "Série curta demais para o ajuste sazonal (mínimo de dois anos)");
+            }
+            // O ajuste sazonal e o λ de Box-Cox usam o período mensal
+            if self.seasonal_adjust || self.box_cox {
+                let warning = frequency::period_warning(&self.state.current.dates, seasonal::MONTHLY_PERIOD);
+                if let Some(warning) = warning {
+                    ui.colored_label(egui::Color32::YELLOW, warning);
+                }
+            }
+
+            ui.horizontal(|ui| {
+                let previous = self.scale;
+                egui::ComboBox::from_label("Escala do modelo")
+                    .selected_text(self.scale.label())
+                    .show_ui(ui, |ui| {
+                        for scale in Scale::all() {
+                            ui.selectable_value(&mut self.scale, scale, scale.label())
+                                .on_hover_text(scale.interpretation());
+                        }
+                    })
+                    .response
+                    .on_hover_text("O modelo é ajustado nesta escala; as previsões voltam ao nível da série");
+                // A previsão anterior foi feita em outra escala
+                if self.scale != previous {
+                    self.state.predictions.clear();
+                    self.intervals.clear();
+                    self.scale_error = None;
+                }
+                if let Some(error) = &self.scale_error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+            });
+
+            ui.horizontal(|ui| {
+                // Box-Cox já é uma escala; só se combina com o nível
+                let levels = self.scale == Scale::Levels;
+                ui.add_enabled(levels, egui::Checkbox::new(&mut self.box_cox, "Transformação de Box-Cox"))
+                    .on_hover_text(
+                        "Modela a série transformada, com λ escolhido pelo método de Guerrero, e volta as previsões à escala original com correção de viés",
+                    )
+                    .on_disabled_hover_text("Disponível apenas com a escala do modelo em nível");
+                let box_cox = self.box_cox && levels;
+                match &self.box_cox_status {
+                    Some(Ok(lambda)) if box_cox => {
+                        ui.label(format!("λ = {:.2}", lambda));
+                    }
+                    Some(Err(e)) if box_cox => {
+                        ui.colored_label(egui::Color32::RED, e);
+                    }
+                    _ => {}
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.detect_breaks, "Detectar quebras estruturais")
+                    .on_hover_text("Teste sup-F de Chow sobre uma tendência linear, com segmentação binária");
+                ui.add_enabled(
+                    self.detect_breaks,
+                    egui::Checkbox::new(&mut self.latest_regime, "Ajustar apenas no último regime"),
+                );
+            });
+
+            let target = self.inflation_target(&config);
+            if target.is_some() {
+                ui.checkbox(&mut self.show_target, "Mostrar a meta de inflação");
+            }
+
+            self.indicators.show_controls(ui);
+
+            // Validação da série atual; séries corrompidas não são modeladas
+            self.prepare();
+            let corrupt = validation::is_corrupt(&self.prepared.issues);
+            for issue in &self.prepared.issues {
+                let color = match issue.severity {
+                    Severity::Warning => egui::Color32::YELLOW,
+                    Severity::Error => egui::Color32::RED,
+                };
+                ui.colored_label(color, format!("⚠ {}", issue.message));
+            }
+
+            // Com a política "always", a previsão é refeita assim que a série muda depois do ajuste
+            let staleness = self.staleness();
+            let refit = !corrupt && config.refit_policy == RefitPolicy::Always && staleness.is_some();
+            let recalculate = ui
+                .horizontal(|ui| {
+                    let recalculate = ui
+                        .add_enabled(!corrupt, egui::Button::new("Recalcular Previsões"))
+                        .on_disabled_hover_text("A série tem erros de validação e não pode ser modelada");
+                    match (staleness, config.refit_policy) {
+                        (Some(staleness), RefitPolicy::Ask) => {
+                            ui.colored_label(egui::Color32::YELLOW, "⚠ Previsão desatualizada")
+                                .on_hover_text(staleness.describe());
+                        }
+                        (Some(staleness), RefitPolicy::Never) => {
+                            ui.weak(staleness.describe());
+                        }
+                        _ => {}
+                    }
+                    recalculate
+                })
+                .inner;
+            if recalculate.clicked() || refit {
+                if let Some(model) = self.model {
+                    // Recalcular previsões ao clicar
+                    let values = self.fit_values();
+                    let dates = self.state.current.dates.clone();
+                    let dates = &dates[dates.len().saturating_sub(values.len())..];
+                    let name = self.series.clone().unwrap_or_else(|| format!("inflation_{}", config.country));
+
+                    // Exceto plugins, combinações, com Box-Cox e fora do nível, o modelo ajustado é mantido para a
+                    // atualização periódica
+                    self.live = None;
+                    self.weights.clear();
+                    self.intervals.clear();
+                    self.box_cox_status = None;
+                    self.scale_error = None;
+                    let forecast = if self.scale != Scale::Levels {
+                        let order = (self.p, self.d, self.q);
+                        let scaled = scale::forecast(self.scale, &values, |transformed| {
+                            let forecast = model.run(&self.plugins, order, transformed, self.horizon);
+                            if forecast.is_empty() {
+                                return Err(format!("{} não produziu previsões", model.label(&self.plugins)));
+                            }
+                            Ok(forecast)
+                        });
+                        match scaled {
+                            Ok(bands) => {
+                                self.intervals = bands.iter().map(|band| (band.lower, band.upper)).collect();
+                                bands.iter().map(|band| band.mean).collect()
+                            }
+                            Err(e) => {
+                                self.scale_error = Some(e);
+                                vec![]
+                            }
+                        }
+                    } else if self.box_cox {
+                        let order = (self.p, self.d, self.q);
+                        let transformed = boxcox::forecast(&values, seasonal::MONTHLY_PERIOD, |transformed| {
+                            let forecast = model.run(&self.plugins, order, transformed, self.horizon);
+                            if forecast.is_empty() {
+                                return Err(format!("{} não produziu previsões", model.label(&self.plugins)));
+                            }
+                            Ok(forecast)
+                        });
+                        match transformed {
+                            Ok((transform, bands)) => {
+                                self.box_cox_status = Some(Ok(transform.lambda));
+                                self.intervals = bands.iter().map(|band| (band.lower, band.upper)).collect();
+                                bands.iter().map(|band| band.mean).collect()
+                            }
+                            Err(e) => {
+                                self.box_cox_status = Some(Err(e));
+                                vec![]
+                            }
+                        }
+                    } else {
+                        match (model, model.candidate((self.p, self.d, self.q))) {
+                            (Model::Ensemble(weighting), _) => {
+                                match models::ensemble(&self.plugins, &values, self.horizon, weighting) {
+                                    Ok(fit) => {
+                                        self.weights = fit.components;
+                                        fit.forecast
+                                    }
+                                    Err(_) => vec![],
+                                }
+                            }
+                            (_, None | Some(Candidate::Plugin(_))) => {
+                                model.run(&self.plugins, (self.p, self.d, self.q), &values, self.horizon)
+                            }
+                            (_, Some(candidate)) => IncrementalModel::fit(candidate, &self.plugins, &name, dates, &values)
+                                .and_then(|live| {
+                                    let forecast = live.forecast(self.horizon)?;
+                                    self.live = Some((self.state.current.values.len(), live));
+                                    Ok(forecast)
+                                })
+                                .unwrap_or_default(),
+                        }
+                    };
+                    self.state.predictions = forecast;
+                    self.stamp_forecast();
+                }
+            }
+
+            {
+                let values = self.fit_values();
+                let dates = self.state.current.dates.clone();
+                // Com o ajuste só no último regime, as datas correspondem ao fim da série
+                let dates = &dates[dates.len().saturating_sub(values.len())..];
+                let name = self.series.clone().unwrap_or_else(|| format!("inflation_{}", config.country));
+                let candidate = self.model.and_then(|model| model.candidate((self.p, self.d, self.q)));
+
+                self.explanation.show(ui, &config, &self.plugins, candidate, self.scale, &values);
+                let continued = self.saved_models.show(ui, &self.plugins, candidate, &name, dates, &values);
+                if let Some((live, mut forecast)) = continued {
+                    forecast.truncate(self.horizon);
+                    self.state.predictions = forecast;
+                    self.stamp_forecast();
+                    self.weights.clear();
+                    self.intervals.clear();
+                    self.live = Some((self.state.current.values.len(), live));
+                }
+            }
+            
+            {
+                let values = self.model_values();
+                let original = self.state.current.values.clone();
+                let reference = (self.seasonal_adjust && self.compare_nsa).then_some(("Sem ajuste (NSA)", original.as_slice()));
+                let dates = self.state.current.dates.clone();
+
+                let mut markers = self.markers();
+                if self.detect_breaks {
+                    markers.annotations.extend(self.prepared.breaks.iter().filter_map(|&i| {
+                        dates.get(i).map(|date| Annotation {
+                            date: date.clone(),
+                            label: "Quebra estrutural".to_string(),
+                        })
+                    }));
+                }
+
+                let mut overlays = self.indicators.overlays(&values);
+                let expected = self.expected(&config.country);
+                overlays.extend(self.expectations.overlay(values.len(), &expected));
+                let divergences: Vec<[f64; 2]> = if self.expectations.show_on_chart {
+                    let forecast = &self.state.predictions;
+                    let steps = self.expectations.divergences(forecast, &expected);
+                    steps.into_iter().map(|step| [(values.len() + step) as f64, forecast[step]]).collect()
+                } else {
+                    vec![]
+                };
+                match (self.chart_type(), &self.candles) {
+                    (ChartType::Candlestick, Some((_, candles))) => chart::show_candlestick_chart(
+                        ui,
+                        candles,
+                        &self.state.predictions,
+                        &overlays,
+                        &markers,
+                    ),
+                    _ => chart::show_series_chart(
+                        ui,
+                        &dates,
+                        &values,
+                        reference,
+                        &self.state.predictions,
+                        &overlays,
+                        &divergences,
+                        target.as_ref().filter(|_| self.show_target),
+                        &markers,
+                    ),
+                }
+                if let Some(target) = target.filter(|_| self.show_target) {
+                    let stats = targets::band_stats(&target, &values);
+                    ui.label(format!(
+                        "Fora do intervalo da meta ({}% a {}%): {} de {} meses ({} acima, {} abaixo)",
+                        config.format_value(target.lower()),
+                        config.format_value(target.upper()),
+                        stats.outside(),
+                        stats.total,
+                        stats.above,
+                        stats.below
+                    ));
+                    if stats.current_streak > 0 {
+                        let text = format!("Há {} meses seguidos fora do intervalo", stats.current_streak);
+                        ui.colored_label(egui::Color32::YELLOW, text);
+                    }
+                }
+                self.indicators.show_oscillators(ui, &dates, &values, &markers.recessions);
+
+                let name = self.series.clone().unwrap_or_else(|| format!("inflation_{}", config.country));
+                let forecast = self.state.predictions.clone();
+                self.scenario.show(ui, &name, &values, &forecast, &self.derived.definitions, self.derived.series());
+                self.goal_seek.show(ui, &config, &values, &forecast, &self.intervals);
+                self.projection.show(ui, &config, &values, &forecast);
+
+                let fetched = self.state.expectations.as_ref().filter(|(country, _)| *country == config.country);
+                if self.expectations.show(ui, &config, fetched.map(|(_, result)| result), &forecast, &expected) {
+                    self.request_expectations();
+                }
+            }
+
+            self.annotations.show(ui);
+            self.recessions.show(ui);
+
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.export_include_annotations, "Incluir anotações");
+                if ui.button("Exportar CSV").clicked() {
+                    let name = self.series.clone().unwrap_or_else(|| format!("inflation_{}", config.country));
+                    let values = self.model_values();
+                    let annotations = self.export_include_annotations.then_some(self.annotations.annotations.as_slice());
+                    self.export_result = Some(
+                        export::write_series_csv(
+                            &name,
+                            &self.state.current.dates,
+                            &values,
+                            annotations,
+                        )
+                        .map(|path| path.display().to_string())
+                        .map_err(|e| e.to_string()),
+                    );
+                }
+
+                ui.separator();
+                ui.checkbox(&mut self.report_pdf, "Também em PDF");
+                if ui.button("Gerar relatório").clicked() {
+                    self.export_result = Some(self.generate_report(&config));
+                }
+            });
+            match &self.export_result {
+                Some(Ok(path)) => {
+                    ui.label(format!("Arquivo gerado: {}", path));
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, format!("Erro ao exportar: {}", e));
+                }
+                None => {}
+            }
+
+            revisions_view::show_revisions(ui, &self.state.revisions, &config);
+
+            if !self.weights.is_empty() {
+                ui.label("Pesos da combinação:");
+                for component in &self.weights {
+                    ui.label(format!(
+                        "{}: {:.2} (RMSE fora da amostra {:.3})",
+                        component.model, component.weight, component.holdout_rmse
+                    ));
+                }
+            }
+
+            // Média incondicional e meia-vida dos choques implícitas no ARIMA ajustado
+            if let Some(arma) = self.live.as_ref().and_then(|(_, live)| crate::explain::arma_of(live.artifact())) {
+                let mut implied = vec![];
+                if let Some(mean) = arma.long_run_mean {
+                    implied.push(format!("média de longo prazo de {}", config.format_value(mean)));
+                }
+                match arma.half_life {
+                    Some(half_life) => {
+                        implied.push(format!("meia-vida dos choques de {} período(s)", config.format_value(half_life)))
+                    }
+                    None if arma.persistence >= 1.0 => implied.push("choques que não se dissipam".to_string()),
+                    None => {}
+                }
+                if !implied.is_empty() {
+                    ui.label(format!("O modelo implica {}.", implied.join(" e ")))
+                        .on_hover_text("Pela resposta da parte AR a um choque; detalhes em \"Explicação do modelo\"");
+                }
+            }
+
+            // Exibição de previsões
+            let name = self.series_name();
+            let forecast = self.state.predictions.clone();
+            let pin = ui
+                .add_enabled(!forecast.is_empty(), egui::Button::new("Fixar previsão"))
+                .on_hover_text("Guarda a previsão para compará-la com os dados que chegarem depois")
+                .clicked();
+            if pin {
+                let model = self.model.map(|model| model.label(&self.plugins)).unwrap_or_default();
+                let last_date = self.state.current.dates.last().cloned();
+                self.pinned.pin(&name, &model, last_date, &forecast, self.tags.tags().of(&name));
+            }
+            self.pinned.show(ui, &config, &name);
+
+            ui.label("Previsões:");
+            // Só as linhas visíveis são montadas a cada quadro, mesmo com horizontes longos
+            let row_height = ui.text_style_height(&egui::TextStyle::Body);
+            egui::ScrollArea::vertical().show_rows(ui, row_height, forecast.len(), |ui, rows| {
+                for i in rows {
+                    let interval = self
+                        .intervals
+                        .get(i)
+                        .map(|(lower, upper)| {
+                            format!(" (95%: {} a {})", config.format_value(*lower), config.format_value(*upper))
+                        })
+                        .unwrap_or_default();
+                    ui.label(format!("Passo {}: {}{}", i + 1, config.format_value(forecast[i]), interval));
+                }
+            });
+        });
+
+        // Atualiza continuamente só enquanto uma busca não responde
+        if self.state.pending() {
+            ctx.request_repaint();
+        } else {
+            ctx.request_repaint_after(IDLE_REPAINT);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use eframe::egui;
+
+// Textos de ajuda da ordem (p, d, q) do ARIMA em um idioma
+struct Help {
+    title: &'static str,
+    // Nome do parâmetro, resumo (dica do slider) e explicação
+    parameters: [(&'static str, &'static str, &'static str); 3],
+    tip: &'static str,
+}
+
+const PT_BR: Help = Help {
+    title: "O que significam p, d e q?",
+    parameters: [
+        (
+            "p (AR, autorregressivo)",
+            "Número de termos passados da série usados para prever o próximo valor",
+            "Quantos valores anteriores da série entram na previsão. Com p = 2, a inflação do próximo mês \
+             depende das duas últimas observações. Aumente quando a série tem inércia, isto é, meses altos \
+             costumam ser seguidos de meses altos.",
+        ),
+        (
+            "d (I, integração)",
+            "Número de diferenças aplicadas para tornar a série estacionária (sem tendência ou sazonalidade)",
+            "Quantas vezes a série é diferenciada (cada valor menos o anterior) antes do ajuste. Use d = 0 \
+             para séries que oscilam em torno de uma média estável e d = 1 para séries com tendência; \
+             raramente é preciso mais que 2.",
+        ),
+        (
+            "q (MA, média móvel)",
+            "Número de erros passados usados para ajustar a previsão atual",
+            "Quantos erros de previsão anteriores corrigem a próxima previsão. Ajuda quando choques \
+             pontuais (uma greve, uma mudança de imposto) afetam a série por alguns períodos e depois \
+             desaparecem.",
+        ),
+    ],
+    tip: "Comece com (1, 1, 1) e compare os erros fora da amostra ao mudar um parâmetro de cada vez; \
+          ordens altas raramente melhoram a previsão e deixam o ajuste instável.",
+};
+
+const EN_US: Help = Help {
+    title: "What do p, d and q mean?",
+    parameters: [
+        (
+            "p (AR, autoregressive)",
+            "Number of past values of the series used to predict the next one",
+            "How many previous values of the series feed the forecast. With p = 2, next month's inflation \
+             depends on the last two observations. Increase it when the series is persistent, i.e. high \
+             months tend to be followed by high months.",
+        ),
+        (
+            "d (I, integrated)",
+            "Number of differences applied to make the series stationary (no trend or seasonality)",
+            "How many times the series is differenced (each value minus the previous one) before fitting. \
+             Use d = 0 for series that fluctuate around a stable mean and d = 1 for trending series; more \
+             than 2 is rarely needed.",
+        ),
+        (
+            "q (MA, moving average)",
+            "Number of past forecast errors used to adjust the current forecast",
+            "How many previous forecast errors correct the next forecast. Useful when one-off shocks (a \
+             strike, a tax change) affect the series for a few periods and then fade away.",
+        ),
+    ],
+    tip: "Start with (1, 1, 1) and compare the out-of-sample errors while changing one parameter at a \
+          time; high orders rarely improve the forecast and make the fit unstable.",
+};
+
+fn help(locale: &str) -> &'static Help {
+    if locale.starts_with("en") {
+        &EN_US
+    } else {
+        &PT_BR
+    }
+}
+
+// Resumo de p (0), d (1) ou q (2), exibido ao passar o mouse sobre o slider
+pub fn hint(locale: &str, parameter: usize) -> &'static str {
+    help(locale).parameters[parameter].1
+}
+
+// Seção recolhível com a explicação de cada parâmetro
+pub fn show(ui: &mut egui::Ui, locale: &str) {
+    let help = help(locale);
+    egui::CollapsingHeader::new(help.title).id_source("order_help").show(ui, |ui| {
+        for (name, _, explanation) in help.parameters {
+            ui.strong(name);
+            ui.label(explanation);
+        }
+        ui.separator();
+        ui.weak(help.tip);
+    });
+}
@@ -0,0 +1,144 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::frequency;
+use crate::pca::{self, Pca};
+
+use super::chart::date_axis;
+
+// Fatores exibidos no gráfico, no máximo
+const MAX_PLOTTED: usize = 3;
+
+// Séries de um cálculo, por nome e tamanho
+type Selection = Vec<(String, usize)>;
+
+// Visão de análise: componentes principais de um painel de séries (ex.: inflação de vários
+// países), com o primeiro fator lido como um índice "global" comum a elas
+pub struct PcaView {
+    // Séries fora do painel; as demais carregadas entram
+    excluded: BTreeSet<String>,
+    plotted: usize,
+    // Resultado da última seleção de séries
+    result: Option<(Selection, Result<Pca, String>)>,
+    // Aviso de frequências diferentes no painel do último resultado
+    frequency_warning: Option<String>,
+}
+
+impl PcaView {
+    pub fn new() -> Self {
+        Self {
+            excluded: BTreeSet::new(),
+            plotted: 1,
+            result: None,
+            frequency_warning: None,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, series: &BTreeMap<String, Vec<(String, f64)>>) {
+        if series.len() < 2 {
+            ui.label("Carregue ao menos duas séries (em cache, derivadas ou de ativos) para formar o painel.");
+            return;
+        }
+
+        // Por padrão o painel é formado pelas séries de inflação
+        if self.result.is_none() && series.keys().any(|name| name.starts_with("inflation_")) {
+            self.excluded = series.keys().filter(|name| !name.starts_with("inflation_")).cloned().collect();
+        }
+
+        egui::CollapsingHeader::new("Séries do painel").show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for name in series.keys() {
+                    let mut included = !self.excluded.contains(name);
+                    if ui.checkbox(&mut included, name).changed() {
+                        if included {
+                            self.excluded.remove(name);
+                        } else {
+                            self.excluded.insert(name.clone());
+                        }
+                    }
+                }
+            });
+        });
+
+        let panel: BTreeMap<String, Vec<(String, f64)>> = series
+            .iter()
+            .filter(|(name, _)| !self.excluded.contains(*name))
+            .map(|(name, values)| (name.clone(), values.clone()))
+            .collect();
+        let key: Selection = panel.iter().map(|(name, values)| (name.clone(), values.len())).collect();
+        if !matches!(&self.result, Some((cached, _)) if *cached == key) {
+            self.result = Some((key, pca::pca(&panel)));
+            self.frequency_warning =
+                frequency::mismatch(panel.iter().map(|(name, values)| (name.as_str(), values.as_slice())));
+        }
+        if let Some(warning) = &self.frequency_warning {
+            ui.colored_label(egui::Color32::YELLOW, warning);
+        }
+        let result = match &self.result {
+            Some((_, Ok(result))) => result,
+            Some((_, Err(e))) => {
+                ui.colored_label(egui::Color32::RED, e);
+                return;
+            }
+            None => return,
+        };
+
+        ui.label(format!(
+            "{} séries, {} datas em comum ({} a {})",
+            result.names.len(),
+            result.dates.len(),
+            result.dates.first().map(String::as_str).unwrap_or_default(),
+            result.dates.last().map(String::as_str).unwrap_or_default()
+        ));
+
+        ui.columns(2, |columns| {
+            egui::Grid::new("pca_explained").striped(true).show(&mut columns[0], |ui| {
+                ui.strong("Fator");
+                ui.strong("Variância explicada");
+                ui.strong("Acumulada");
+                ui.end_row();
+
+                let mut cumulative = 0.0;
+                for (k, explained) in result.explained.iter().enumerate() {
+                    cumulative += explained;
+                    ui.label(format!("F{}", k + 1));
+                    ui.label(format!("{:.1}%", explained * 100.0));
+                    ui.label(format!("{:.1}%", cumulative * 100.0));
+                    ui.end_row();
+                }
+            });
+
+            egui::ScrollArea::both().id_source("pca_loadings_scroll").show(&mut columns[1], |ui| {
+                egui::Grid::new("pca_loadings").striped(true).show(ui, |ui| {
+                    ui.strong("Série");
+                    for k in 0..result.loadings.len().min(MAX_PLOTTED) {
+                        ui.strong(format!("F{}", k + 1));
+                    }
+                    ui.end_row();
+
+                    for (i, name) in result.names.iter().enumerate() {
+                        ui.label(name);
+                        for loading in result.loadings.iter().take(MAX_PLOTTED) {
+                            ui.monospace(format!("{:+.3}", loading[i]));
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+
+        ui.add(egui::Slider::new(&mut self.plotted, 1..=MAX_PLOTTED.min(result.scores.len())).text("Fatores no gráfico"));
+
+        Plot::new("pca_scores")
+            .legend(Legend::default())
+            .x_axis_formatter(date_axis(result.dates.clone()))
+            .show(ui, |plot_ui| {
+                for (k, scores) in result.scores.iter().take(self.plotted).enumerate() {
+                    let points: PlotPoints = scores.iter().enumerate().map(|(t, v)| [t as f64, *v]).collect();
+                    let name = if k == 0 { "F1 (fator comum)".to_string() } else { format!("F{}", k + 1) };
+                    plot_ui.line(Line::new(points).name(name));
+                }
+            });
+    }
+}
@@ -0,0 +1,127 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints, Points};
+use std::sync::{Arc, Mutex};
+
+use crate::cache;
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::get_historical_unemployment;
+use crate::phillips::{self, PhillipsPoint};
+use crate::stats;
+
+// None enquanto a busca não termina
+type State = Option<Result<Vec<PhillipsPoint>, String>>;
+
+// Visão de análise: inflação contra desemprego (curva de Phillips)
+pub struct PhillipsView {
+    points: Arc<Mutex<State>>,
+    requested: bool,
+    regression: bool,
+}
+
+impl PhillipsView {
+    pub fn new() -> Self {
+        Self {
+            points: Arc::new(Mutex::new(None)),
+            requested: false,
+            regression: true,
+        }
+    }
+
+    // A inflação vem do cache local; apenas o desemprego é buscado no provedor
+    fn load(&mut self, config: &Config) {
+        let config = config.clone();
+        let points = self.points.clone();
+
+        self.requested = true;
+        *points.lock().unwrap() = None;
+
+        tokio::spawn(async move {
+            let inflation: Vec<(String, f64)> = cache::load(&config.country)
+                .into_iter()
+                .map(|obs| (obs.date, obs.value))
+                .collect();
+
+            let result = get_historical_unemployment(ApiClient::shared(), &config, &config.country)
+                .await
+                .map(|raw| phillips::align(&inflation, &raw.unemployment))
+                .map_err(|e| e.to_string());
+
+            *points.lock().unwrap() = Some(result);
+        });
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config) {
+        if !self.requested {
+            self.load(config);
+        }
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.regression, "Reta de regressão");
+            if ui.button("Atualizar").clicked() {
+                self.load(config);
+            }
+        });
+
+        let points = match self.points.lock().unwrap().clone() {
+            None => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Buscando a taxa de desemprego...");
+                });
+                return;
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao buscar o desemprego: {}", e));
+                return;
+            }
+            Some(Ok(points)) if points.is_empty() => {
+                ui.label("Sem datas em comum entre inflação e desemprego.");
+                return;
+            }
+            Some(Ok(points)) => points,
+        };
+
+        let x: Vec<f64> = points.iter().map(|p| p.unemployment).collect();
+        let y: Vec<f64> = points.iter().map(|p| p.inflation).collect();
+        let fit = stats::linear_regression(&x, &y);
+
+        if let (true, Some(fit)) = (self.regression, fit) {
+            ui.label(format!(
+                "inflação = {} {} {} × desemprego (R² = {})",
+                config.format_value(fit.intercept),
+                if fit.slope < 0.0 { "-" } else { "+" },
+                config.format_value(fit.slope.abs()),
+                config.format_value(fit.r_squared),
+            ));
+        }
+
+        Plot::new("phillips_curve")
+            .legend(Legend::default())
+            .x_axis_formatter(|x, _| format!("{:.1}%", x))
+            .y_axis_formatter(|y, _| format!("{:.1}%", y))
+            .show(ui, |plot_ui| {
+                // Gradiente temporal: observações antigas em azul, recentes em vermelho
+                let last = (points.len() - 1).max(1) as f32;
+                for (i, point) in points.iter().enumerate() {
+                    let t = i as f32 / last;
+                    let color = egui::Color32::from_rgb((255.0 * t) as u8, 60, (255.0 * (1.0 - t)) as u8);
+
+                    plot_ui.points(
+                        Points::new(vec![[point.unemployment, point.inflation]])
+                            .radius(3.0)
+                            .color(color),
+                    );
+                }
+
+                if let (true, Some(fit)) = (self.regression, fit) {
+                    let min = x.iter().cloned().fold(f64::INFINITY, f64::min);
+                    let max = x.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                    plot_ui.line(
+                        Line::new(PlotPoints::new(vec![[min, fit.predict(min)], [max, fit.predict(max)]]))
+                            .name("Regressão"),
+                    );
+                }
+            });
+    }
+}
@@ -0,0 +1,115 @@
+use eframe::egui;
+
+use crate::cache::now_secs;
+use crate::config::Config;
+use crate::pinned::{self, PinnedForecast};
+use crate::tags;
+
+use super::accessibility;
+
+// Previsões fixadas, guardadas para comparação com os dados que chegarem depois
+pub struct PinnedPanel {
+    pinned: Vec<PinnedForecast>,
+    // Etiqueta a aplicar com "Etiquetar"
+    new_tag: String,
+    error: Option<String>,
+}
+
+impl PinnedPanel {
+    pub fn new() -> Self {
+        Self {
+            pinned: pinned::load(),
+            new_tag: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn all(&self) -> &[PinnedForecast] {
+        &self.pinned
+    }
+
+    // Troca a lista inteira (ao desfazer ou refazer) e a grava
+    pub fn replace(&mut self, pinned: Vec<PinnedForecast>) {
+        self.pinned = pinned;
+        self.persist();
+    }
+
+    fn persist(&mut self) {
+        self.error = pinned::save(&self.pinned).err().map(|e| e.to_string());
+    }
+
+    // A previsão herda as etiquetas que a série tem no momento
+    pub fn pin(&mut self, series: &str, model: &str, last_date: Option<String>, values: &[f64], tags: Vec<String>) {
+        self.pinned.push(PinnedForecast {
+            series: series.to_string(),
+            model: model.to_string(),
+            pinned_at: now_secs(),
+            last_date,
+            values: values.to_vec(),
+            tags,
+        });
+        self.persist();
+    }
+
+    // Fixadas da série atual
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, series: &str) {
+        let indices: Vec<usize> = (0..self.pinned.len()).filter(|i| self.pinned[*i].series == series).collect();
+
+        egui::CollapsingHeader::new(format!("Previsões fixadas ({})", indices.len()))
+            .id_source("pinned")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let label = ui.label("Etiqueta:");
+                    ui.add(egui::TextEdit::singleline(&mut self.new_tag).hint_text("ex.: q3-report").desired_width(100.0))
+                        .labelled_by(label.id);
+                });
+                let new_tag = tags::normalize(&self.new_tag);
+
+                let mut removed = None;
+                let mut tagged = None;
+                let mut untagged = None;
+                for i in indices {
+                    let pinned = &self.pinned[i];
+                    let steps: Vec<String> = pinned.values.iter().take(6).map(|value| config.format_value(*value)).collect();
+                    ui.horizontal(|ui| {
+                        ui.label(format!(
+                            "{} a partir de {}: {}{}",
+                            pinned.model,
+                            pinned.last_date.as_deref().unwrap_or("?"),
+                            steps.join("; "),
+                            if pinned.values.len() > steps.len() { "; ..." } else { "" }
+                        ));
+                        for tag in &pinned.tags {
+                            let chip = ui.small_button(format!("{} ✕", tag)).on_hover_text("Remover etiqueta");
+                            if accessibility::named(chip, egui::WidgetType::Button, &format!("Remover a etiqueta {}", tag)).clicked() {
+                                untagged = Some((i, tag.clone()));
+                            }
+                        }
+                        let tag = new_tag.clone().filter(|tag| !pinned.tags.contains(tag));
+                        if ui.add_enabled(tag.is_some(), egui::Button::new("Etiquetar").small()).clicked() {
+                            tagged = tag.map(|tag| (i, tag));
+                        }
+                        if ui.small_button("Remover").clicked() {
+                            removed = Some(i);
+                        }
+                    });
+                }
+                if let Some((i, tag)) = tagged {
+                    self.pinned[i].tags.push(tag);
+                    self.persist();
+                }
+                if let Some((i, tag)) = untagged {
+                    self.pinned[i].tags.retain(|existing| *existing != tag);
+                    self.persist();
+                }
+                if let Some(i) = removed {
+                    self.pinned.remove(i);
+                    self.persist();
+                }
+
+                if let Some(error) = &self.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+            });
+    }
+}
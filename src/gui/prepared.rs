@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use crate::data::Indicator;
+use crate::staleness::FitStamp;
+use crate::validation::{self, Issue};
+use crate::{breaks, seasonal};
+
+// Versões da série atual usadas na exibição e nos modelos, com a validação dela. O STL e o teste
+// de quebras (sup-F sobre todas as divisões candidatas) são caros demais para cada quadro: só são
+// refeitos quando a série ou as opções mudam.
+#[derive(Default)]
+pub struct PreparedSeries {
+    // Série, opções (ajuste sazonal, detecção de quebras) e indicador da última preparação
+    key: Option<(FitStamp, bool, bool, Option<Indicator>)>,
+    // Série exibida e modelada: a atual, dessazonalizada se o ajuste estiver ativo
+    pub values: Vec<f64>,
+    // Quebras estruturais de `values`; vazia com a detecção desligada
+    pub breaks: Vec<usize>,
+    // Problemas encontrados na série atual
+    pub issues: Vec<Issue>,
+}
+
+impl PreparedSeries {
+    pub fn update(
+        &mut self,
+        dates: &[String],
+        values: &[f64],
+        seasonal_adjust: bool,
+        detect_breaks: bool,
+        indicator: Option<Indicator>,
+    ) {
+        let key = (FitStamp::of(dates, values), seasonal_adjust, detect_breaks, indicator);
+        if self.key.as_ref() == Some(&key) {
+            return;
+        }
+
+        // Séries curtas demais para o STL seguem sem ajuste
+        self.values = if seasonal_adjust {
+            seasonal::seasonally_adjust(values, seasonal::MONTHLY_PERIOD).unwrap_or_else(|| values.to_vec())
+        } else {
+            values.to_vec()
+        };
+        self.breaks = if detect_breaks { breaks::detect_breaks(&self.values) } else { vec![] };
+        self.issues = validation::validate(dates, values, indicator);
+        self.key = Some(key);
+    }
+}
+
+// Séries carregadas (derivadas e a atual) oferecidas às visões de análise, copiadas só quando as
+// derivadas são reavaliadas ou a série atual muda
+#[derive(Default)]
+pub struct LoadedSeries {
+    // Reavaliação das derivadas, nome e conteúdo da série atual
+    key: Option<(u64, Option<String>, FitStamp)>,
+    pub series: BTreeMap<String, Vec<(String, f64)>>,
+}
+
+impl LoadedSeries {
+    pub fn update(
+        &mut self,
+        derived: &BTreeMap<String, Vec<(String, f64)>>,
+        generation: u64,
+        name: Option<&String>,
+        dates: &[String],
+        values: &[f64],
+    ) {
+        let key = (generation, name.cloned(), FitStamp::of(dates, values));
+        if self.key.as_ref() == Some(&key) {
+            return;
+        }
+
+        self.series = derived.clone();
+        if let Some(name) = name {
+            self.series
+                .entry(name.clone())
+                .or_insert_with(|| dates.iter().cloned().zip(values.iter().copied()).collect());
+        }
+        self.key = Some(key);
+    }
+}
@@ -0,0 +1,111 @@
+use eframe::egui;
+
+use crate::presets::{Preset, Presets};
+
+use super::accessibility;
+
+// Salva a configuração atual como predefinição e define a padrão de cada série
+pub struct PresetsPanel {
+    presets: Presets,
+    name: String,
+    selected: Option<String>,
+    message: Option<Result<String, String>>,
+}
+
+impl PresetsPanel {
+    pub fn new() -> Self {
+        Self {
+            presets: Presets::load(),
+            name: String::new(),
+            selected: None,
+            message: None,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<Preset> {
+        self.presets.get(name).cloned()
+    }
+
+    pub fn default_for(&self, series: &str) -> Option<Preset> {
+        self.presets.default_for(series).cloned()
+    }
+
+    fn persist(&mut self, done: String) {
+        self.message = Some(match self.presets.save() {
+            Ok(()) => Ok(done),
+            Err(e) => Err(format!("Erro ao salvar as predefinições: {}", e)),
+        });
+    }
+
+    // `current` é a configuração da tela (o nome é ignorado); retorna a predefinição a aplicar
+    pub fn show(&mut self, ui: &mut egui::Ui, current: Preset, series: &str) -> Option<Preset> {
+        let mut apply = None;
+
+        ui.collapsing("Predefinições", |ui| {
+            ui.horizontal(|ui| {
+                let field = ui.add(egui::TextEdit::singleline(&mut self.name).hint_text("Nome").desired_width(120.0));
+                accessibility::named(field, egui::WidgetType::TextEdit, "Nome da predefinição");
+                let name = self.name.trim().to_string();
+                let clicked = ui
+                    .add_enabled(!name.is_empty(), egui::Button::new("Salvar configuração atual"))
+                    .on_hover_text("Transformações, modelo, ordem e horizonte")
+                    .clicked();
+                if clicked {
+                    self.presets.upsert(Preset { name: name.clone(), ..current });
+                    self.selected = Some(name.clone());
+                    self.persist(format!("Predefinição {} salva", name));
+                }
+            });
+
+            egui::ComboBox::from_label("Predefinição")
+                .selected_text(self.selected.clone().unwrap_or_else(|| "Nenhuma".to_string()))
+                .show_ui(ui, |ui| {
+                    for preset in &self.presets.presets {
+                        ui.selectable_value(&mut self.selected, Some(preset.name.clone()), &preset.name);
+                    }
+                });
+
+            if let Some(preset) = self.selected.as_deref().and_then(|name| self.presets.get(name)).cloned() {
+                ui.horizontal(|ui| {
+                    if ui.button("Aplicar").clicked() {
+                        apply = Some(preset.clone());
+                    }
+                    if ui.button(format!("Padrão de {}", series)).clicked() {
+                        match self.presets.bind(series, &preset.name) {
+                            Ok(()) => self.persist(format!("{} abre com a predefinição {}", series, preset.name)),
+                            Err(e) => self.message = Some(Err(e)),
+                        }
+                    }
+                    if ui.button("Excluir").clicked() {
+                        self.presets.remove(&preset.name);
+                        self.selected = None;
+                        self.persist(format!("Predefinição {} excluída", preset.name));
+                    }
+                });
+            }
+
+            let bound = self.presets.default_for(series).map(|preset| preset.name.clone());
+            if let Some(name) = bound {
+                ui.horizontal(|ui| {
+                    ui.label(format!("Padrão de {}: {}", series, name));
+                    if ui.small_button("Remover padrão").clicked() {
+                        self.presets.unbind(series);
+                        self.persist(format!("{} não tem mais predefinição padrão", series));
+                    }
+                });
+            }
+
+            match &self.message {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                None => {}
+            }
+        });
+
+        apply
+    }
+}
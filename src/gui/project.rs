@@ -0,0 +1,68 @@
+use eframe::egui;
+use std::path::PathBuf;
+
+use crate::project::{self, Layout, Project};
+
+// Salva e abre projetos: a análise inteira em um único arquivo
+pub struct ProjectPanel {
+    path: String,
+    message: Option<Result<String, String>>,
+}
+
+impl ProjectPanel {
+    pub fn new() -> Self {
+        Self {
+            path: project::default_path().display().to_string(),
+            message: None,
+        }
+    }
+
+    // `layout` é o estado atual da tela; retorna o projeto aberto, já instalado no diretório de
+    // configuração, para a interface aplicar o estado da tela dele
+    pub fn show(&mut self, ui: &mut egui::Ui, layout: Layout) -> Option<Project> {
+        let mut opened = None;
+
+        ui.collapsing("Projeto", |ui| {
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut self.path).desired_width(320.0));
+                let path = PathBuf::from(self.path.trim());
+
+                if ui.button("Salvar projeto").clicked() {
+                    self.message = Some(
+                        Project::capture(layout)
+                            .save(&path)
+                            .map(|_| format!("Projeto salvo em {}", path.display()))
+                            .map_err(|e| e.to_string()),
+                    );
+                }
+
+                let open = ui
+                    .button("Abrir projeto")
+                    .on_hover_text("Substitui as séries acompanhadas, as previsões fixadas, as anotações e as predefinições pelas do projeto")
+                    .clicked();
+                if open {
+                    let loaded = Project::load(&path).and_then(|project| project.install().map(|_| project));
+                    match loaded {
+                        Ok(project) => {
+                            self.message = Some(Ok(format!("Projeto {} aberto", path.display())));
+                            opened = Some(project);
+                        }
+                        Err(e) => self.message = Some(Err(format!("Erro ao abrir o projeto: {}", e))),
+                    }
+                }
+            });
+
+            match &self.message {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                None => {}
+            }
+        });
+
+        opened
+    }
+}
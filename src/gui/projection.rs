@@ -0,0 +1,113 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, LineStyle, Plot, PlotPoints};
+
+use crate::config::Config;
+use crate::projection::{self, Band, Plan, Projection};
+use crate::rng::SeededRng;
+
+// Calculadora de poupança/aposentadoria: projeta o saldo com aportes mensais e o desconta por
+// trajetórias de inflação simuladas em torno da previsão
+pub struct ProjectionPanel {
+    plan: Plan,
+    years: usize,
+    projection: Option<Projection>,
+}
+
+impl ProjectionPanel {
+    pub fn new() -> Self {
+        Self {
+            plan: Plan {
+                initial: 10_000.0,
+                monthly_contribution: 500.0,
+                nominal_return: 10.0,
+                months: 0,
+                indexed: true,
+            },
+            years: 20,
+            projection: None,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, values: &[f64], forecast: &[f64]) {
+        ui.collapsing("Projeção de poupança", |ui| {
+            egui::Grid::new("projection_inputs").show(ui, |ui| {
+                let label = ui.label("Saldo inicial:");
+                ui.add(egui::DragValue::new(&mut self.plan.initial).speed(100.0).clamp_range(0.0..=f64::MAX))
+                    .labelled_by(label.id);
+                ui.end_row();
+                let label = ui.label("Aporte mensal:");
+                ui.add(
+                    egui::DragValue::new(&mut self.plan.monthly_contribution)
+                        .speed(10.0)
+                        .clamp_range(0.0..=f64::MAX),
+                )
+                .labelled_by(label.id);
+                ui.end_row();
+                let label = ui.label("Retorno nominal (% a.a.):");
+                ui.add(egui::DragValue::new(&mut self.plan.nominal_return).speed(0.05)).labelled_by(label.id);
+                ui.end_row();
+                let label = ui.label("Horizonte (anos):");
+                ui.add(egui::DragValue::new(&mut self.years).clamp_range(1..=60)).labelled_by(label.id);
+                ui.end_row();
+            });
+            ui.checkbox(&mut self.plan.indexed, "Corrigir os aportes pela inflação");
+
+            let simulate = ui
+                .add_enabled(!forecast.is_empty(), egui::Button::new("Simular"))
+                .on_hover_text(format!(
+                    "{} trajetórias de inflação em torno da previsão, com choques do tamanho das variações mensais da série",
+                    projection::SIMULATIONS
+                ))
+                .on_disabled_hover_text("Calcule uma previsão de inflação antes")
+                .clicked();
+            if simulate {
+                self.plan.months = self.years * 12;
+                let volatility = projection::volatility(values);
+                self.projection = projection::project(
+                    &self.plan,
+                    forecast,
+                    volatility,
+                    projection::SIMULATIONS,
+                    &mut SeededRng::from_global(),
+                );
+            }
+
+            let Some(projection) = &self.projection else {
+                return;
+            };
+            if let Some((nominal, real)) = projection.last() {
+                let months = projection.real.len();
+                ui.label(format!(
+                    "Em {} anos: saldo nominal mediano de {}",
+                    months / 12,
+                    config.format_value(nominal.median)
+                ));
+                ui.strong(format!(
+                    "Poder de compra (em dinheiro de hoje): {} na mediana, entre {} (p10) e {} (p90)",
+                    config.format_value(real.median),
+                    config.format_value(real.p10),
+                    config.format_value(real.p90)
+                ));
+                if !self.plan.indexed {
+                    let contributed = self.plan.initial + self.plan.monthly_contribution * months as f64;
+                    ui.label(format!("Total aportado (nominal): {}", config.format_value(contributed)));
+                }
+            }
+
+            Plot::new("projection_chart").legend(Legend::default()).height(220.0).show(ui, |plot_ui| {
+                // Eixo x em anos
+                let points = |bands: &[Band], value: fn(&Band) -> f64| -> PlotPoints {
+                    bands.iter().enumerate().map(|(month, band)| [(month + 1) as f64 / 12.0, value(band)]).collect()
+                };
+                plot_ui.line(Line::new(points(&projection.nominal, |band| band.median)).name("Nominal (mediana)"));
+                plot_ui.line(Line::new(points(&projection.real, |band| band.median)).name("Real (mediana)"));
+                plot_ui.line(
+                    Line::new(points(&projection.real, |band| band.p10)).name("Real (p10)").style(LineStyle::dashed_loose()),
+                );
+                plot_ui.line(
+                    Line::new(points(&projection.real, |band| band.p90)).name("Real (p90)").style(LineStyle::dashed_loose()),
+                );
+            });
+        });
+    }
+}
@@ -0,0 +1,123 @@
+use eframe::egui;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::arima;
+use crate::config::Config;
+
+use super::accessibility;
+
+// Caminho do ambiente criado ou o erro; None enquanto a criação não termina
+type Creation = Arc<Mutex<Option<Result<PathBuf, String>>>>;
+
+// Escolha do ambiente virtual usado pelo ARIMA e criação de um ambiente dedicado
+pub struct PythonPanel {
+    version: (u8, u8),
+    input: String,
+    message: Option<Result<String, String>>,
+    // Criação do ambiente dedicado em segundo plano
+    creating: Option<Creation>,
+}
+
+impl PythonPanel {
+    pub fn new() -> Self {
+        Self {
+            version: arima::python_version(),
+            input: String::new(),
+            message: None,
+            creating: None,
+        }
+    }
+
+    pub fn set_config(&mut self, config: &Config) {
+        self.input = config.python_env.clone().unwrap_or_default();
+    }
+
+    fn apply(&mut self, config: &mut Config) {
+        let dir = self.input.trim().to_string();
+        config.python_env = Some(dir.clone()).filter(|dir| !dir.is_empty());
+
+        self.message = Some(if dir.is_empty() {
+            // Pacotes de um venv já carregado continuam no sys.path até reiniciar
+            Ok("Python padrão selecionado; vale a partir da próxima execução.".to_string())
+        } else {
+            arima::use_venv(Path::new(&dir)).map(|_| "ARIMA habilitado com o ambiente selecionado.".to_string())
+        });
+        if let Err(e) = config.save() {
+            self.message = Some(Err(format!("Erro ao salvar a configuração: {}", e)));
+        }
+    }
+
+    fn create(&mut self) {
+        let Some(dir) = arima::dedicated_venv() else {
+            self.message = Some(Err("Diretório de configuração não encontrado".to_string()));
+            return;
+        };
+        let result = Arc::new(Mutex::new(None));
+        self.creating = Some(result.clone());
+        self.message = None;
+
+        tokio::task::spawn_blocking(move || {
+            let created = arima::create_venv(&dir).map(|_| dir);
+            *result.lock().unwrap() = Some(created);
+        });
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &mut Config) {
+        ui.collapsing("Ambiente Python", |ui| {
+            let (major, minor) = self.version;
+            ui.label(format!(
+                "Ambiente virtual (venv) do Python {}.{} com numpy e statsmodels; vazio usa o Python padrão.",
+                major, minor
+            ));
+            ui.horizontal(|ui| {
+                let field = ui.add(egui::TextEdit::singleline(&mut self.input).hint_text("/caminho/do/venv"));
+                accessibility::named(field, egui::WidgetType::TextEdit, "Caminho do ambiente virtual");
+                if ui.button("Aplicar").clicked() {
+                    self.apply(config);
+                }
+            });
+
+            if let Some(creating) = &self.creating {
+                let finished = creating.lock().unwrap().take();
+                match finished {
+                    None => {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Criando o ambiente e instalando numpy e statsmodels...");
+                        });
+                    }
+                    Some(Ok(dir)) => {
+                        self.creating = None;
+                        self.input = dir.display().to_string();
+                        self.apply(config);
+                    }
+                    Some(Err(e)) => {
+                        self.creating = None;
+                        self.message = Some(Err(e));
+                    }
+                }
+            } else if !arima::available() {
+                if let Some(dir) = arima::dedicated_venv() {
+                    let clicked = ui
+                        .button("Criar ambiente dedicado")
+                        .on_hover_text(format!("Cria um venv em {} e executa {}", dir.display(), arima::INSTALL_HINT))
+                        .clicked();
+                    if clicked {
+                        self.create();
+                    }
+                }
+            }
+
+            match &self.message {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                None => {}
+            }
+        });
+    }
+}
@@ -0,0 +1,213 @@
+use eframe::egui;
+use egui::plot::{Line, Plot, PlotPoints};
+use std::sync::{Arc, Mutex};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{get_indicator, Indicator};
+use crate::ranking::{self, Entry};
+
+use super::accessibility;
+
+type Series = Vec<(String, f64)>;
+
+struct Row {
+    country: String,
+    data: Arc<Mutex<Option<Result<Series, String>>>>,
+}
+
+impl Row {
+    fn new(country: String, config: &Config) -> Self {
+        let row = Self {
+            country,
+            data: Arc::new(Mutex::new(None)),
+        };
+        row.fetch(config);
+        row
+    }
+
+    fn fetch(&self, config: &Config) {
+        let config = config.clone();
+        let country = self.country.clone();
+        let data = self.data.clone();
+
+        *data.lock().unwrap() = None;
+
+        tokio::spawn(async move {
+            let result = get_indicator(ApiClient::shared(), &config, Indicator::Inflation, &country)
+                .await
+                .map(|series| series.into_iter().map(|obs| (obs.date, obs.value)).collect())
+                .map_err(|e| e.to_string());
+
+            *data.lock().unwrap() = Some(result);
+        });
+    }
+}
+
+// Visão de análise: última leitura da inflação de vários países, da maior para a menor, com a
+// variação em 12 meses
+pub struct RankingView {
+    rows: Vec<Row>,
+    loaded: bool,
+    new_country: String,
+    error: Option<String>,
+    export_result: Option<Result<String, String>>,
+}
+
+impl RankingView {
+    pub fn new() -> Self {
+        Self {
+            rows: vec![],
+            loaded: false,
+            new_country: String::new(),
+            error: None,
+            export_result: None,
+        }
+    }
+
+    fn persist(&mut self) {
+        let countries: Vec<String> = self.rows.iter().map(|row| row.country.clone()).collect();
+        self.error = ranking::save(&countries).err().map(|e| e.to_string());
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config) {
+        if !self.loaded {
+            self.loaded = true;
+            self.rows = ranking::load().into_iter().map(|country| Row::new(country, config)).collect();
+        }
+
+        // Países já carregados entram no ranking; os demais aparecem abaixo, buscando ou com erro
+        let mut entries: Vec<Entry> = vec![];
+        let mut pending: Vec<(String, Option<String>)> = vec![];
+        for row in &self.rows {
+            match row.data.lock().unwrap().clone() {
+                Some(Ok(series)) => match ranking::entry(&row.country, &series) {
+                    Some(entry) => entries.push(entry),
+                    None => pending.push((row.country.clone(), Some("Série vazia".to_string()))),
+                },
+                Some(Err(e)) => pending.push((row.country.clone(), Some(e))),
+                None => pending.push((row.country.clone(), None)),
+            }
+        }
+        ranking::rank(&mut entries);
+
+        ui.horizontal(|ui| {
+            let country =
+                ui.add(egui::TextEdit::singleline(&mut self.new_country).hint_text("país").desired_width(120.0));
+            accessibility::named(country, egui::WidgetType::TextEdit, "País a comparar");
+            let country = self.new_country.trim().to_lowercase();
+            let ready = !country.is_empty() && !self.rows.iter().any(|row| row.country == country);
+            if ui.add_enabled(ready, egui::Button::new("Adicionar")).clicked() {
+                self.rows.push(Row::new(country, config));
+                self.new_country.clear();
+                self.persist();
+            }
+            if ui.button("Atualizar todos").clicked() {
+                for row in &self.rows {
+                    row.fetch(config);
+                }
+            }
+            if ui.add_enabled(!entries.is_empty(), egui::Button::new("Exportar CSV")).clicked() {
+                self.export_result = Some(
+                    ranking::write_ranking_csv(&entries)
+                        .map(|path| path.display().to_string())
+                        .map_err(|e| e.to_string()),
+                );
+            }
+        });
+
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::RED, format!("Erro ao salvar a lista: {}", error));
+        }
+        match &self.export_result {
+            Some(Ok(path)) => {
+                ui.label(format!("Arquivo gerado: {}", path));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao exportar: {}", e));
+            }
+            None => {}
+        }
+        ui.separator();
+
+        let mut removed = None;
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("inflation_ranking").striped(true).show(ui, |ui| {
+                let headers = [
+                    "#",
+                    "País",
+                    "Data",
+                    "Inflação (%)",
+                    "Variação em 12 meses (p.p.)",
+                    "Últimos 12 meses",
+                    "",
+                ];
+                for header in headers {
+                    ui.strong(header);
+                }
+                ui.end_row();
+
+                for (i, entry) in entries.iter().enumerate() {
+                    ui.label((i + 1).to_string());
+                    ui.label(&entry.country);
+                    ui.label(&entry.date);
+                    ui.strong(config.format_value(entry.latest));
+                    match entry.change {
+                        Some(change) if change > 0.0 => {
+                            ui.colored_label(egui::Color32::RED, format!("+{}", config.format_value(change)));
+                        }
+                        Some(change) => {
+                            ui.colored_label(egui::Color32::GREEN, config.format_value(change));
+                        }
+                        None => {
+                            ui.weak("—").on_hover_text("A série não cobre um ano");
+                        }
+                    }
+
+                    let points: PlotPoints = entry.recent.iter().enumerate().map(|(x, y)| [x as f64, *y]).collect();
+                    Plot::new(("ranking_sparkline", &entry.country))
+                        .height(30.0)
+                        .width(140.0)
+                        .show_axes([false, false])
+                        .show_x(false)
+                        .show_y(false)
+                        .allow_drag(false)
+                        .allow_zoom(false)
+                        .allow_scroll(false)
+                        .allow_boxed_zoom(false)
+                        .show(ui, |plot_ui| plot_ui.line(Line::new(points)));
+
+                    if ui.small_button("Remover").clicked() {
+                        removed = Some(entry.country.clone());
+                    }
+                    ui.end_row();
+                }
+
+                for (country, error) in &pending {
+                    ui.label("");
+                    ui.label(country);
+                    match error {
+                        Some(e) => {
+                            ui.colored_label(egui::Color32::RED, "Erro ao buscar").on_hover_text(e);
+                        }
+                        None => {
+                            ui.spinner();
+                        }
+                    }
+                    for _ in 0..3 {
+                        ui.label("");
+                    }
+                    if ui.small_button("Remover").clicked() {
+                        removed = Some(country.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+
+        if let Some(country) = removed {
+            self.rows.retain(|row| row.country != country);
+            self.persist();
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use eframe::egui;
+use egui::plot::{HLine, Legend, Line, Plot, PlotPoints, VLine};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::config::Config;
+use crate::data::date_sort_key;
+use crate::rebase::{self, Reference};
+
+use super::chart::date_axis;
+
+// Visão de análise: índices (de preços, cotações...) rebaseados para 100 em um período de
+// referência e comparados no mesmo gráfico
+pub struct RebaseView {
+    selected: BTreeSet<String>,
+    // Posição da data de referência entre as datas das séries escolhidas
+    base: usize,
+    // Referência na média do ano da data escolhida, em vez da própria data
+    whole_year: bool,
+}
+
+impl RebaseView {
+    pub fn new() -> Self {
+        Self {
+            selected: BTreeSet::new(),
+            base: 0,
+            whole_year: false,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, series: &BTreeMap<String, Vec<(String, f64)>>) {
+        self.selected.retain(|name| series.contains_key(name));
+
+        egui::CollapsingHeader::new("Índices comparados").default_open(true).show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for name in series.keys() {
+                    let mut included = self.selected.contains(name);
+                    if ui.checkbox(&mut included, name).changed() {
+                        if included {
+                            self.selected.insert(name.clone());
+                        } else {
+                            self.selected.remove(name);
+                        }
+                    }
+                }
+            });
+        });
+
+        let chosen: BTreeMap<String, Vec<(String, f64)>> = series
+            .iter()
+            .filter(|(name, _)| self.selected.contains(*name))
+            .map(|(name, values)| (name.clone(), values.clone()))
+            .collect();
+        let dates = rebase::all_dates(&chosen);
+        if dates.is_empty() {
+            ui.label("Escolha as séries de nível (índices de preços, cotações...) a rebasear.");
+            return;
+        }
+        self.base = self.base.min(dates.len() - 1);
+
+        ui.horizontal(|ui| {
+            let label = ui.label("Referência:");
+            // O campo mostra a data e aceita digitar outra (DD/MM/AAAA) entre as das séries
+            let (shown, typed) = (dates.clone(), dates.clone());
+            ui.add(
+                egui::Slider::new(&mut self.base, 0..=dates.len() - 1)
+                    .custom_formatter(move |i, _| shown.get(i as usize).cloned().unwrap_or_default())
+                    .custom_parser(move |text| typed.iter().position(|date| date == text.trim()).map(|i| i as f64)),
+            )
+            .labelled_by(label.id)
+            .on_hover_text("Também é possível clicar no gráfico para escolher a data");
+            ui.checkbox(&mut self.whole_year, "Média do ano");
+        });
+
+        let base_date = &dates[self.base];
+        let year = date_sort_key(base_date).get(..4).and_then(|year| year.parse().ok());
+        let reference = match year.filter(|_| self.whole_year) {
+            Some(year) => Reference::Year(year),
+            None => Reference::Date(base_date.clone()),
+        };
+        ui.strong(reference.label());
+
+        let position: HashMap<&str, usize> = dates.iter().enumerate().map(|(i, date)| (date.as_str(), i)).collect();
+        let mut rebased = vec![];
+        for (name, values) in &chosen {
+            match rebase::rebase(values, &reference) {
+                Ok(values) => rebased.push((name, values)),
+                Err(e) => {
+                    ui.colored_label(egui::Color32::YELLOW, format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        let response = Plot::new("rebase_chart")
+            .legend(Legend::default())
+            .height(300.0)
+            .allow_drag(false)
+            .x_axis_formatter(date_axis(dates.clone()))
+            .show(ui, |plot_ui| {
+                plot_ui.hline(HLine::new(100.0).color(egui::Color32::GRAY));
+                plot_ui.vline(VLine::new(self.base as f64).color(egui::Color32::GRAY));
+                for (name, values) in &rebased {
+                    let points: PlotPoints =
+                        values.iter().map(|(date, value)| [position[date.as_str()] as f64, *value]).collect();
+                    plot_ui.line(Line::new(points).name(name));
+                }
+                plot_ui.pointer_coordinate()
+            });
+        if response.response.clicked() {
+            if let Some(pointer) = response.inner {
+                self.base = (pointer.x.round().max(0.0) as usize).min(dates.len() - 1);
+            }
+        }
+
+        egui::Grid::new("rebase_last").striped(true).show(ui, |ui| {
+            for header in ["Série", "Última data", "Último valor", "Variação desde a referência (%)"] {
+                ui.strong(header);
+            }
+            ui.end_row();
+            for (name, values) in &rebased {
+                if let Some((date, value)) = values.last() {
+                    ui.label(*name);
+                    ui.label(date);
+                    ui.label(config.format_value(*value));
+                    ui.label(config.format_value(value - 100.0));
+                    ui.end_row();
+                }
+            }
+        });
+    }
+}
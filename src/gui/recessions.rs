@@ -0,0 +1,97 @@
+use eframe::egui;
+
+use crate::recessions::{self, Recession};
+
+use super::accessibility;
+
+// Recessões do país atual, sombreadas nos gráficos, e formulário para editá-las
+pub struct RecessionsPanel {
+    country: String,
+    recessions: Vec<Recession>,
+    pub visible: bool,
+    start: String,
+    end: String,
+    error: Option<String>,
+}
+
+impl RecessionsPanel {
+    pub fn new() -> Self {
+        Self {
+            country: String::new(),
+            recessions: vec![],
+            visible: true,
+            start: String::new(),
+            end: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn set_country(&mut self, country: &str) {
+        self.country = country.to_string();
+        self.recessions = recessions::load(country);
+    }
+
+    // Recessões a sombrear; nenhuma com o sombreamento desligado
+    pub fn shaded(&self) -> Vec<Recession> {
+        if self.visible {
+            self.recessions.clone()
+        } else {
+            vec![]
+        }
+    }
+
+    fn persist(&mut self) {
+        self.error = recessions::save(&self.country, &self.recessions)
+            .err()
+            .map(|e| e.to_string());
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new(format!("Recessões ({})", self.recessions.len()))
+            .id_source("recessions")
+            .show(ui, |ui| {
+                ui.checkbox(&mut self.visible, "Sombrear as recessões nos gráficos");
+
+                let mut removed = None;
+                for (i, recession) in self.recessions.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{} a {}", recession.start, recession.end));
+                        if ui.small_button("Remover").clicked() {
+                            removed = Some(i);
+                        }
+                    });
+                }
+                if let Some(i) = removed {
+                    self.recessions.remove(i);
+                    self.persist();
+                }
+
+                ui.horizontal(|ui| {
+                    let start = ui.add(egui::TextEdit::singleline(&mut self.start).hint_text("início (DD/MM/AAAA)").desired_width(130.0));
+                    accessibility::named(start, egui::WidgetType::TextEdit, "Início da recessão (DD/MM/AAAA)");
+                    let end = ui.add(egui::TextEdit::singleline(&mut self.end).hint_text("fim (DD/MM/AAAA)").desired_width(130.0));
+                    accessibility::named(end, egui::WidgetType::TextEdit, "Fim da recessão (DD/MM/AAAA)");
+
+                    let ready = !self.start.trim().is_empty() && !self.end.trim().is_empty();
+                    if ui.add_enabled(ready, egui::Button::new("Adicionar")).clicked() {
+                        self.recessions.push(Recession {
+                            start: self.start.trim().to_string(),
+                            end: self.end.trim().to_string(),
+                        });
+                        self.start.clear();
+                        self.end.clear();
+                        self.persist();
+                    }
+                });
+
+                if ui.button("Restaurar a datação oficial").clicked() {
+                    self.recessions = recessions::bundled(&self.country);
+                    self.persist();
+                }
+
+                if let Some(error) = &self.error {
+                    ui.colored_label(egui::Color32::RED, format!("Erro ao salvar as recessões: {}", error));
+                }
+            });
+    }
+}
@@ -0,0 +1,114 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints, Points};
+
+use crate::config::Config;
+use crate::revisions::{self, SeriesDiff};
+
+use super::chart::date_axis;
+use super::state::Series;
+
+const ADDED_COLOR: egui::Color32 = egui::Color32::from_rgb(44, 160, 44);
+const REVISED_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 176, 0);
+
+// Decisão do usuário sobre a série da atualização periódica
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshChoice {
+    Apply,
+    Keep,
+}
+
+// Versão da série buscada na atualização periódica que difere da usada nos modelos; só substitui
+// a série em uso depois de o usuário ver o que mudou
+pub struct PendingRefresh {
+    pub fresh: Series,
+    pub diff: SeriesDiff,
+}
+
+impl PendingRefresh {
+    // Nada a decidir quando a versão buscada é igual à série em uso
+    pub fn compare(current: &Series, fresh: &Series) -> Option<Self> {
+        let diff = revisions::diff(&current.pairs(), &fresh.pairs());
+        (!diff.is_empty()).then(|| Self {
+            fresh: fresh.clone(),
+            diff,
+        })
+    }
+
+    // Resumo das mudanças com a série nova, as observações novas em verde e as revisadas em âmbar
+    pub fn show(&self, ui: &mut egui::Ui, config: &Config) -> Option<RefreshChoice> {
+        let mut choice = None;
+
+        ui.group(|ui| {
+            ui.strong("A série foi atualizada pelo provedor");
+            ui.label(format!(
+                "{} observações novas, {} revisadas e {} removidas. O modelo atual foi ajustado com a versão anterior.",
+                self.diff.added.len(),
+                self.diff.revised.len(),
+                self.diff.removed.len()
+            ));
+
+            if !self.diff.revised.is_empty() {
+                egui::CollapsingHeader::new("Valores revisados")
+                    .id_source("refresh_revised")
+                    .show(ui, |ui| {
+                        for (i, old_value) in &self.diff.revised {
+                            ui.colored_label(
+                                REVISED_COLOR,
+                                format!(
+                                    "{}: {} → {}",
+                                    self.fresh.dates[*i],
+                                    config.format_value(*old_value),
+                                    config.format_value(self.fresh.values[*i])
+                                ),
+                            );
+                        }
+                    });
+            }
+
+            if !self.diff.removed.is_empty() {
+                egui::CollapsingHeader::new("Observações removidas")
+                    .id_source("refresh_removed")
+                    .show(ui, |ui| {
+                        for (date, old_value) in &self.diff.removed {
+                            ui.colored_label(REVISED_COLOR, format!("{}: {}", date, config.format_value(*old_value)));
+                        }
+                    });
+            }
+
+            let values = &self.fresh.values;
+            Plot::new("refresh_diff_chart")
+                .legend(Legend::default())
+                .height(160.0)
+                .x_axis_formatter(date_axis(self.fresh.dates.clone()))
+                .show(ui, |plot_ui| {
+                    let line: PlotPoints = values.iter().enumerate().map(|(x, y)| [x as f64, *y]).collect();
+                    plot_ui.line(Line::new(line).name("Série nova").color(egui::Color32::GRAY));
+
+                    let added: PlotPoints = self.diff.added.iter().map(|i| [*i as f64, values[*i]]).collect();
+                    plot_ui.points(Points::new(added).radius(4.0).color(ADDED_COLOR).name("Novas"));
+
+                    let revised: PlotPoints = self.diff.revised.iter().map(|(i, _)| [*i as f64, values[*i]]).collect();
+                    plot_ui.points(Points::new(revised).radius(4.0).color(REVISED_COLOR).name("Revisadas"));
+                    let previous: PlotPoints = self.diff.revised.iter().map(|(i, old)| [*i as f64, *old]).collect();
+                    plot_ui.points(
+                        Points::new(previous)
+                            .radius(4.0)
+                            .filled(false)
+                            .color(REVISED_COLOR)
+                            .name("Valor anterior"),
+                    );
+                });
+
+            ui.horizontal(|ui| {
+                if ui.button("Usar a série nova").clicked() {
+                    choice = Some(RefreshChoice::Apply);
+                }
+                if ui.button("Manter a série atual").clicked() {
+                    choice = Some(RefreshChoice::Keep);
+                }
+            });
+        });
+
+        choice
+    }
+}
@@ -0,0 +1,180 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints};
+use std::sync::{Arc, Mutex};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::InflationData;
+use crate::hierarchy::{self, get_regional_inflation, Method, Reconciled, RegionalInflation};
+
+// Passos previstos para o nacional e cada região
+const HORIZON: usize = 12;
+
+// Previsões base (cada série ajustada isoladamente) e suas versões coerentes
+struct Forecasts {
+    national: Vec<f64>,
+    regional: Vec<Vec<f64>>,
+    bottom_up: Reconciled,
+    mint: Reconciled,
+}
+
+fn values(observations: &[InflationData]) -> Vec<f64> {
+    observations.iter().map(|obs| obs.value).collect()
+}
+
+// Visão de análise: previsão hierárquica da inflação regional, coerente com a nacional
+pub struct RegionsView {
+    data: Arc<Mutex<Option<Result<RegionalInflation, String>>>>,
+    requested: bool,
+    method: Method,
+    forecasts: Option<Forecasts>,
+}
+
+impl RegionsView {
+    pub fn new() -> Self {
+        Self {
+            data: Arc::new(Mutex::new(None)),
+            requested: false,
+            method: Method::MinT,
+            forecasts: None,
+        }
+    }
+
+    fn load(&mut self, config: &Config) {
+        let config = config.clone();
+        let data = self.data.clone();
+
+        self.requested = true;
+        self.forecasts = None;
+        *data.lock().unwrap() = None;
+
+        tokio::spawn(async move {
+            let result = get_regional_inflation(ApiClient::shared(), &config, &config.country)
+                .await
+                .map_err(|e| e.to_string());
+
+            *data.lock().unwrap() = Some(result);
+        });
+    }
+
+    fn run_forecasts(data: &RegionalInflation, forecast: &dyn Fn(&[f64], usize) -> Vec<f64>) -> Forecasts {
+        let weights = hierarchy::normalized_weights(&data.regions);
+
+        let national_values = values(&data.national);
+        let national = forecast(&national_values, HORIZON);
+        let regional: Vec<Vec<f64>> = data
+            .regions
+            .iter()
+            .map(|region| forecast(&values(&region.values), HORIZON))
+            .collect();
+
+        let regional_variances: Vec<f64> = data
+            .regions
+            .iter()
+            .map(|region| hierarchy::error_variance(&values(&region.values)).unwrap_or(0.0))
+            .collect();
+        let national_variance = hierarchy::error_variance(&national_values).unwrap_or(0.0);
+
+        Forecasts {
+            bottom_up: hierarchy::bottom_up(&weights, &regional),
+            mint: hierarchy::mint(&weights, &national, &regional, national_variance, &regional_variances),
+            national,
+            regional,
+        }
+    }
+
+    // `forecast` ajusta o modelo selecionado na janela principal e prevê os passos pedidos
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, forecast: &dyn Fn(&[f64], usize) -> Vec<f64>) {
+        if !self.requested {
+            self.load(config);
+        }
+
+        // A cópia é feita antes do match para liberar o lock antes de uma nova busca
+        let snapshot = self.data.lock().unwrap().clone();
+        let data = match snapshot {
+            None => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Buscando a inflação regional...");
+                });
+                return;
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Erro ao buscar a inflação regional: {}", e));
+                if ui.button("Tentar novamente").clicked() {
+                    self.load(config);
+                }
+                return;
+            }
+            Some(Ok(data)) if data.regions.is_empty() => {
+                ui.label("O provedor não retornou inflação regional para este país.");
+                return;
+            }
+            Some(Ok(data)) => data,
+        };
+
+        ui.horizontal(|ui| {
+            if ui.button("Atualizar").clicked() {
+                self.load(config);
+            }
+            if ui.button("Prever regiões").clicked() {
+                self.forecasts = Some(Self::run_forecasts(&data, forecast));
+            }
+            for method in Method::all() {
+                ui.radio_value(&mut self.method, method, method.label());
+            }
+        });
+
+        let Some(forecasts) = &self.forecasts else {
+            ui.label("Preveja as regiões para obter previsões coerentes com a nacional.");
+            return;
+        };
+        let coherent = match self.method {
+            Method::BottomUp => &forecasts.bottom_up,
+            Method::MinT => &forecasts.mint,
+        };
+        if coherent.national.is_empty() {
+            ui.label("O modelo selecionado não produziu previsões para todas as séries.");
+            return;
+        }
+
+        let weights = hierarchy::normalized_weights(&data.regions);
+        let first = |series: &[f64]| series.first().map(|v| config.format_value(*v)).unwrap_or_else(|| "-".to_string());
+
+        egui::Grid::new("regional_forecasts").striped(true).show(ui, |ui| {
+            ui.strong("Região");
+            ui.strong("Peso");
+            ui.strong("Última variação");
+            ui.strong("Previsão base");
+            ui.strong("Previsão coerente");
+            ui.end_row();
+
+            for (i, region) in data.regions.iter().enumerate() {
+                ui.label(&region.name);
+                ui.label(format!("{}%", config.format_value(weights[i] * 100.0)));
+                ui.label(region.values.last().map(|obs| config.format_value(obs.value)).unwrap_or_else(|| "-".to_string()));
+                ui.label(first(&forecasts.regional[i]));
+                ui.label(first(&coherent.regional[i]));
+                ui.end_row();
+            }
+
+            ui.strong("Nacional");
+            ui.label("100%");
+            ui.label(data.national.last().map(|obs| config.format_value(obs.value)).unwrap_or_else(|| "-".to_string()));
+            ui.strong(first(&forecasts.national));
+            ui.strong(first(&coherent.national));
+            ui.end_row();
+        });
+
+        Plot::new("regional_national_forecast")
+            .legend(Legend::default())
+            .show(ui, |plot_ui| {
+                let line = |values: &[f64]| -> PlotPoints {
+                    values.iter().enumerate().map(|(h, v)| [(h + 1) as f64, *v]).collect()
+                };
+                plot_ui.line(Line::new(line(&forecasts.national)).name("Nacional (base)"));
+                plot_ui.line(Line::new(line(&forecasts.bottom_up.national)).name(Method::BottomUp.label()));
+                plot_ui.line(Line::new(line(&forecasts.mint.national)).name(Method::MinT.label()));
+            });
+    }
+}
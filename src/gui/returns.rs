@@ -0,0 +1,243 @@
+use eframe::egui;
+use egui::plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints};
+use std::sync::{Arc, Mutex};
+
+use crate::config::Config;
+use crate::progress::{Progress, Reporter, Shared};
+use crate::returns;
+use crate::risk::{self, Backtest, Method, RiskEstimate};
+use crate::stats;
+
+type RiskRow = (Method, Option<RiskEstimate>, Option<Backtest>);
+
+// Estimativas de risco de uma combinação de retornos e parâmetros, calculadas em segundo
+// plano (o GARCH e os backtests são caros demais para serem refeitos a cada quadro)
+struct RiskJob {
+    key: Vec<u64>,
+    rows: Arc<Mutex<Option<Vec<RiskRow>>>>,
+    progress: Shared,
+}
+
+// Visão de análise: retornos da série atual (ex.: fechamentos de um ativo) e sua distribuição
+pub struct ReturnsView {
+    log: bool,
+    bins: usize,
+    // Nível de confiança do VaR/ES, em %
+    confidence: f64,
+    backtest_window: usize,
+    risk: Option<RiskJob>,
+}
+
+impl ReturnsView {
+    pub fn new() -> Self {
+        Self {
+            log: true,
+            bins: 30,
+            confidence: 99.0,
+            backtest_window: 250,
+            risk: None,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, prices: &[f64]) {
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.log, false, "Retornos simples");
+            ui.radio_value(&mut self.log, true, "Retornos logarítmicos");
+            ui.add(egui::Slider::new(&mut self.bins, 5..=100).text("Classes"));
+        });
+
+        let returns = if self.log {
+            returns::log_returns(prices)
+        } else {
+            returns::simple_returns(prices)
+        };
+
+        let Some(distribution) = returns::distribution(&returns) else {
+            ui.label("Série curta ou constante demais para analisar os retornos.");
+            return;
+        };
+
+        let percent = |value: f64| format!("{}%", config.format_value(value * 100.0));
+        egui::Grid::new("returns_stats").striped(true).show(ui, |ui| {
+            ui.label("Observações");
+            ui.label(distribution.observations.to_string());
+            ui.end_row();
+            ui.label("Média");
+            ui.label(percent(distribution.mean));
+            ui.end_row();
+            ui.label("Desvio padrão");
+            ui.label(percent(distribution.std_dev));
+            ui.end_row();
+            ui.label("Assimetria");
+            ui.label(config.format_value(distribution.skewness));
+            ui.end_row();
+            ui.label("Curtose em excesso");
+            ui.label(config.format_value(distribution.excess_kurtosis));
+            ui.end_row();
+            ui.label("Jarque-Bera");
+            ui.label(format!(
+                "{} (p = {})",
+                config.format_value(distribution.jarque_bera),
+                config.format_value(distribution.p_value)
+            ));
+            ui.end_row();
+        });
+
+        if distribution.is_normal() {
+            ui.label("Não se rejeita a normalidade dos retornos a 5%.");
+        } else {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                "Normalidade rejeitada a 5%: medidas de risco gaussianas tendem a subestimar as caudas.",
+            );
+        }
+
+        let histogram = returns::histogram(&returns, self.bins);
+        let n = returns.len() as f64;
+
+        Plot::new("returns_histogram")
+            .legend(Legend::default())
+            .height(260.0)
+            .x_axis_formatter(|x, _| format!("{:.1}%", x * 100.0))
+            .show(ui, |plot_ui| {
+                let bars = histogram
+                    .iter()
+                    .map(|(center, width, count)| Bar::new(*center, *count as f64).width(*width))
+                    .collect();
+                plot_ui.bar_chart(BarChart::new(bars).name("Frequência"));
+
+                // Densidade normal com a mesma média e desvio, na escala das contagens
+                if let (Some(first), Some(last)) = (histogram.first(), histogram.last()) {
+                    let width = first.1;
+                    let (start, end) = (first.0 - width, last.0 + width);
+                    let normal: PlotPoints = (0..=200)
+                        .map(|i| {
+                            let x = start + (end - start) * i as f64 / 200.0;
+                            let z = (x - distribution.mean) / distribution.std_dev;
+                            [x, stats::normal_pdf(z) / distribution.std_dev * n * width]
+                        })
+                        .collect();
+                    plot_ui.line(Line::new(normal).name("Normal").color(egui::Color32::RED));
+                }
+            });
+
+        self.show_risk(ui, config, &returns);
+    }
+
+    fn show_risk(&mut self, ui: &mut egui::Ui, config: &Config, returns: &[f64]) {
+        ui.separator();
+        ui.heading("Risco");
+        ui.horizontal(|ui| {
+            ui.label("Confiança:");
+            ui.add(
+                egui::DragValue::new(&mut self.confidence)
+                    .clamp_range(90.0..=99.9)
+                    .speed(0.1)
+                    .suffix("%"),
+            );
+            ui.add(egui::Slider::new(&mut self.backtest_window, 20..=1000).text("Janela do backtest"));
+        });
+
+        let confidence = self.confidence / 100.0;
+        let key: Vec<u64> = returns
+            .iter()
+            .chain([&confidence])
+            .map(|value| value.to_bits())
+            .chain([self.backtest_window as u64])
+            .collect();
+        let stale = !matches!(&self.risk, Some(job) if job.key == key);
+        if stale {
+            let job = RiskJob {
+                key,
+                rows: Arc::new(Mutex::new(None)),
+                progress: Shared::new(),
+            };
+            let (rows, progress) = (job.rows.clone(), job.progress.clone());
+            let (returns, window) = (returns.to_vec(), self.backtest_window);
+
+            tokio::task::spawn_blocking(move || {
+                let methods = Method::all();
+                let result = methods
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, method)| {
+                        // Um backtest por método, somados em uma única barra
+                        let overall = |step: &Progress| {
+                            progress.report(&Progress::new(
+                                step.stage.clone(),
+                                i * step.total + step.done,
+                                methods.len() * step.total,
+                            ))
+                        };
+                        (
+                            method,
+                            risk::estimate(&returns, confidence, method),
+                            risk::backtest_with_progress(&returns, confidence, window, method, &overall),
+                        )
+                    })
+                    .collect();
+                *rows.lock().unwrap() = Some(result);
+            });
+            self.risk = Some(job);
+        }
+        let Some(job) = &self.risk else {
+            return;
+        };
+        let Some(rows) = job.rows.lock().unwrap().clone() else {
+            let progress = job.progress.get();
+            let text = match &progress {
+                Some(step) => format!("Backtest: {} ({} de {})", step.stage, step.done, step.total),
+                None => "Estimando o risco...".to_string(),
+            };
+            ui.add(egui::ProgressBar::new(progress.map(|step| step.fraction()).unwrap_or(0.0)).text(text));
+            ui.ctx().request_repaint();
+            return;
+        };
+
+        let percent = |value: f64| format!("{}%", config.format_value(value * 100.0));
+        egui::Grid::new("risk_table").striped(true).show(ui, |ui| {
+            ui.strong("Método");
+            ui.strong("VaR");
+            ui.strong("ES");
+            ui.strong("Violações (esperadas)");
+            ui.strong("Kupiec (p)");
+            ui.end_row();
+
+            for (method, estimate, backtest) in rows {
+                ui.label(method.label());
+                match estimate {
+                    Some(estimate) => {
+                        ui.label(percent(estimate.var));
+                        ui.label(percent(estimate.es));
+                    }
+                    None => {
+                        ui.weak("-");
+                        ui.weak("-");
+                    }
+                }
+                match backtest {
+                    Some(backtest) => {
+                        ui.label(format!(
+                            "{} de {} ({})",
+                            backtest.exceptions,
+                            backtest.observations,
+                            config.format_value(backtest.expected)
+                        ));
+                        let text = config.format_value(backtest.p_value);
+                        if backtest.rejects() {
+                            ui.colored_label(egui::Color32::RED, format!("{} (rejeitado)", text));
+                        } else {
+                            ui.label(text);
+                        }
+                    }
+                    None => {
+                        ui.weak("série curta para a janela");
+                        ui.weak("-");
+                    }
+                }
+                ui.end_row();
+            }
+        });
+        ui.weak("VaR e ES do próximo período como perda, com os retornos da série; o backtest reestima o VaR a cada período com a janela anterior.");
+    }
+}
@@ -0,0 +1,40 @@
+use eframe::egui;
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::config::Config;
+use crate::data::date_sort_key;
+use crate::health::format_elapsed;
+use crate::revisions::{self, Revision};
+
+// Histórico de revisões agrupado por observação (data)
+pub fn show_revisions(ui: &mut egui::Ui, history: &[Revision], config: &Config) {
+    egui::CollapsingHeader::new(format!("Histórico de revisões ({})", history.len()))
+        .id_source("revision_history")
+        .show(ui, |ui| {
+            if history.is_empty() {
+                ui.weak("Nenhuma revisão detectada até o momento.");
+                return;
+            }
+
+            let mut dates: Vec<&str> = history.iter().map(|revision| revision.date.as_str()).collect();
+            dates.sort_unstable_by_key(|date| std::cmp::Reverse(date_sort_key(date)));
+            dates.dedup();
+
+            for date in dates {
+                let revisions = revisions::for_date(history, date);
+                egui::CollapsingHeader::new(format!("{} ({} revisões)", date, revisions.len()))
+                    .id_source(("revision", date))
+                    .show(ui, |ui| {
+                        for revision in revisions {
+                            let detected = UNIX_EPOCH + Duration::from_secs(revision.detected_at);
+                            ui.label(format!(
+                                "{} → {} (detectada {})",
+                                config.format_value(revision.old_value),
+                                config.format_value(revision.new_value),
+                                format_elapsed(detected),
+                            ));
+                        }
+                    });
+            }
+        });
+}
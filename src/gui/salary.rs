@@ -0,0 +1,176 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints};
+use std::collections::BTreeMap;
+
+use crate::config::Config;
+use crate::data::{date_sort_key, Indicator};
+use crate::salary::{self, SalaryRecord};
+use crate::vintage;
+
+use super::accessibility;
+use super::chart::date_axis;
+
+// Visão de análise: histórico de um salário (ex.: o mínimo) deflacionado por uma série de
+// inflação, com o reajuste que recupera o poder de compra de um ano-base
+pub struct SalaryView {
+    records: Vec<SalaryRecord>,
+    new_date: String,
+    new_value: f64,
+    base_year: String,
+    // Série de inflação usada como deflator; None para a do país configurado
+    inflation: Option<String>,
+    error: Option<String>,
+}
+
+impl SalaryView {
+    pub fn new() -> Self {
+        let records = salary::load();
+        let base_year = records
+            .iter()
+            .map(|record| date_sort_key(&record.date))
+            .min()
+            .map(|key| key.chars().take(4).collect())
+            .unwrap_or_default();
+
+        Self {
+            records,
+            new_date: String::new(),
+            new_value: 0.0,
+            base_year,
+            inflation: None,
+            error: None,
+        }
+    }
+
+    fn persist(&mut self) {
+        self.records.sort_by_key(|record| date_sort_key(&record.date));
+        self.error = salary::save(&self.records).err().map(|e| e.to_string());
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, config: &Config, series: &BTreeMap<String, Vec<(String, f64)>>) {
+        let default = format!("{}_{}", Indicator::Inflation.key(), config.country);
+        let deflator = self.inflation.clone().unwrap_or(default);
+        egui::ComboBox::from_label("Deflator")
+            .selected_text(&deflator)
+            .show_ui(ui, |ui| {
+                let inflation = series.keys().filter(|name| Indicator::from_series_name(name) == Some(Indicator::Inflation));
+                for name in inflation {
+                    ui.selectable_value(&mut self.inflation, Some(name.clone()), name);
+                }
+            });
+
+        ui.horizontal(|ui| {
+            let date = ui.add(egui::TextEdit::singleline(&mut self.new_date).hint_text("DD/MM/AAAA").desired_width(90.0));
+            accessibility::named(date, egui::WidgetType::TextEdit, "Data do salário (DD/MM/AAAA)");
+            let label = ui.label("Valor:");
+            ui.add(egui::DragValue::new(&mut self.new_value).speed(10.0).clamp_range(0.0..=f64::MAX))
+                .labelled_by(label.id);
+            if ui.button("Adicionar").clicked() {
+                let date = self.new_date.trim().to_string();
+                if vintage::parse_date(&date_sort_key(&date)).is_none() {
+                    self.error = Some(format!("Data inválida: {}", date));
+                } else {
+                    // Um valor por data: registrar de novo substitui o anterior
+                    self.records.retain(|record| record.date != date);
+                    self.records.push(SalaryRecord {
+                        date,
+                        value: self.new_value,
+                    });
+                    self.new_date.clear();
+                    self.persist();
+                }
+            }
+            if ui
+                .button("Salário mínimo (Brasil)")
+                .on_hover_text("Substitui o histórico pelo salário mínimo nacional desde 2015")
+                .clicked()
+            {
+                self.records = salary::brazil_minimum_wage();
+                self.base_year = "2015".to_string();
+                self.persist();
+            }
+        });
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        let Some(inflation) = series.get(&deflator) else {
+            ui.label(format!("Série de inflação {} não carregada; busque-a na visão de previsão.", deflator));
+            return;
+        };
+        if self.records.is_empty() {
+            ui.label("Adicione os valores do salário, cada um a partir da data em que passou a valer.");
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            let label = ui.label("Ano-base:");
+            ui.add(egui::TextEdit::singleline(&mut self.base_year).desired_width(50.0)).labelled_by(label.id);
+        });
+
+        let index = salary::price_index(inflation);
+        let real = match salary::deflate(&self.records, &index, &self.base_year) {
+            Ok(real) => real,
+            Err(e) => {
+                ui.colored_label(egui::Color32::RED, e);
+                return;
+            }
+        };
+        match salary::restore(&self.records, &index, &self.base_year) {
+            Ok(restoration) => {
+                ui.label(format!(
+                    "Para comprar hoje o mesmo que {} em {}, o salário precisa ser {}.",
+                    config.format_value(restoration.base_nominal),
+                    restoration.base_date,
+                    config.format_value(restoration.required_nominal)
+                ));
+                let raise = format!(
+                    "Reajuste necessário sobre o atual ({}): {}%",
+                    config.format_value(restoration.current_nominal),
+                    config.format_value(restoration.raise)
+                );
+                if restoration.raise > 0.0 {
+                    ui.colored_label(egui::Color32::YELLOW, raise);
+                } else {
+                    ui.label(format!("{} (o poder de compra está acima do ano-base)", raise));
+                }
+            }
+            Err(e) => {
+                ui.colored_label(egui::Color32::RED, e);
+            }
+        }
+
+        let dates: Vec<String> = real.iter().map(|income| income.date.clone()).collect();
+        Plot::new("salary_chart")
+            .legend(Legend::default())
+            .height(250.0)
+            .x_axis_formatter(date_axis(dates))
+            .show(ui, |plot_ui| {
+                let nominal: PlotPoints = real.iter().enumerate().map(|(x, income)| [x as f64, income.nominal]).collect();
+                plot_ui.line(Line::new(nominal).name("Nominal"));
+                let deflated: PlotPoints = real.iter().enumerate().map(|(x, income)| [x as f64, income.real]).collect();
+                plot_ui.line(Line::new(deflated).name(format!("Real (dinheiro de {})", self.base_year)));
+            });
+
+        let mut removed = None;
+        egui::Grid::new("salary_records").striped(true).show(ui, |ui| {
+            for header in ["Data", "Nominal", "Real", ""] {
+                ui.strong(header);
+            }
+            ui.end_row();
+            for income in &real {
+                ui.label(&income.date);
+                ui.label(config.format_value(income.nominal));
+                ui.label(config.format_value(income.real));
+                if ui.small_button("Remover").clicked() {
+                    removed = Some(income.date.clone());
+                }
+                ui.end_row();
+            }
+        });
+        if let Some(date) = removed {
+            self.records.retain(|record| record.date != date);
+            self.persist();
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use eframe::egui;
+use std::path::PathBuf;
+
+use crate::artifact::{self, ModelArtifact};
+use crate::incremental::IncrementalModel;
+use crate::models::Candidate;
+use crate::plugins::Plugin;
+use crate::FORECAST_STEPS;
+
+// Salva o modelo ajustado na série atual e prevê com modelos salvos, sem reajustá-los
+pub struct SavedModelsPanel {
+    saved: Vec<PathBuf>,
+    selected: Option<PathBuf>,
+    loaded: Option<ModelArtifact>,
+    message: Option<Result<String, String>>,
+}
+
+impl SavedModelsPanel {
+    pub fn new() -> Self {
+        let mut panel = Self {
+            saved: vec![],
+            selected: None,
+            loaded: None,
+            message: None,
+        };
+        panel.refresh();
+        panel
+    }
+
+    fn refresh(&mut self) {
+        self.saved = artifact::models_dir().map(|dir| artifact::list_models(&dir)).unwrap_or_default();
+    }
+
+    fn save(&mut self, candidate: Candidate, plugins: &[Plugin], series: &str, dates: &[String], values: &[f64]) {
+        let saved = ModelArtifact::fit(candidate, plugins, series, dates, values)
+            .and_then(|artifact| artifact.save_to_models_dir().map_err(|e| e.to_string()));
+
+        self.message = Some(saved.map(|path| format!("Modelo salvo em {}", path.display())));
+        self.refresh();
+    }
+
+    fn select(&mut self, path: PathBuf) {
+        self.message = None;
+        self.loaded = match ModelArtifact::load(&path) {
+            Ok(artifact) => Some(artifact),
+            Err(e) => {
+                self.message = Some(Err(e.to_string()));
+                None
+            }
+        };
+        self.selected = Some(path);
+    }
+
+    // Quando o usuário prevê com o modelo carregado, retorna o modelo continuado na série atual
+    // (para receber as observações novas) e a previsão
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        plugins: &[Plugin],
+        candidate: Option<Candidate>,
+        series: &str,
+        dates: &[String],
+        values: &[f64],
+    ) -> Option<(IncrementalModel, Vec<f64>)> {
+        let mut forecast = None;
+
+        ui.collapsing("Modelos salvos", |ui| {
+            let savable = candidate.filter(|candidate| !matches!(candidate, Candidate::Plugin(_)));
+            let clicked = ui
+                .add_enabled(savable.is_some(), egui::Button::new("Salvar modelo ajustado"))
+                .on_hover_text("Ajusta o modelo escolhido na série atual e salva os parâmetros estimados")
+                .on_disabled_hover_text("Combinações e modelos de plugins não podem ser salvos")
+                .clicked();
+            if let Some(candidate) = savable.filter(|_| clicked) {
+                self.save(candidate, plugins, series, dates, values);
+            }
+
+            let mut chosen = None;
+            egui::ComboBox::from_label("Modelo salvo")
+                .selected_text(
+                    self.selected
+                        .as_ref()
+                        .and_then(|path| path.file_stem())
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| "Nenhum".to_string()),
+                )
+                .show_ui(ui, |ui| {
+                    for path in &self.saved {
+                        let name = path
+                            .file_stem()
+                            .map(|stem| stem.to_string_lossy().into_owned())
+                            .unwrap_or_default();
+                        if ui.selectable_label(self.selected.as_ref() == Some(path), name).clicked() {
+                            chosen = Some(path.clone());
+                        }
+                    }
+                });
+            if let Some(path) = chosen {
+                self.select(path);
+            }
+
+            if let Some(loaded) = &self.loaded {
+                ui.label(loaded.describe());
+                if let Some(sigma2) = loaded.sigma2() {
+                    ui.label(format!("Variância dos resíduos: {:.4}", sigma2));
+                }
+                if ui.button("Prever com o modelo salvo").clicked() {
+                    let continued = IncrementalModel::from_artifact(loaded.clone(), values)
+                        .and_then(|model| model.forecast(FORECAST_STEPS).map(|values| (model, values)));
+                    match continued {
+                        Ok(continued) => forecast = Some(continued),
+                        Err(e) => self.message = Some(Err(e)),
+                    }
+                }
+            }
+
+            match &self.message {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(egui::Color32::RED, e);
+                }
+                None => {}
+            }
+        });
+
+        forecast
+    }
+}
@@ -0,0 +1,159 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, LineStyle, Plot, PlotPoints, Points};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::derived::DerivedSeries;
+use crate::scenario;
+
+// Observações recentes exibidas antes da região editável
+const CONTEXT: usize = 24;
+const SCENARIO_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 127, 14);
+
+// Eixo em passos: o último valor observado fica em 0 e os passos previstos em +1, +2, ...
+fn step_axis(x: f64, _: &RangeInclusive<f64>) -> String {
+    let i = x.round();
+    if (x - i).abs() > 0.01 {
+        String::new()
+    } else if i > 0.0 {
+        format!("+{}", i)
+    } else {
+        format!("{}", i)
+    }
+}
+
+// Teste de estresse: o usuário redesenha a trajetória futura da série atual arrastando os pontos
+// da previsão, e o cenário é propagado às séries derivadas que dependem dela
+pub struct ScenarioPanel {
+    // Série e previsão a partir das quais o cenário foi desenhado
+    series: String,
+    baseline: Vec<f64>,
+    path: Vec<f64>,
+    // Passo (1, 2, ...) editado pelo campo numérico
+    step: usize,
+}
+
+impl ScenarioPanel {
+    pub fn new() -> Self {
+        Self {
+            series: String::new(),
+            baseline: Vec::new(),
+            path: Vec::new(),
+            step: 1,
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        name: &str,
+        values: &[f64],
+        forecast: &[f64],
+        definitions: &[DerivedSeries],
+        series: &BTreeMap<String, Vec<(String, f64)>>,
+    ) {
+        ui.collapsing("Cenários", |ui| {
+            if forecast.is_empty() || values.is_empty() {
+                ui.weak("Calcule uma previsão para desenhar um cenário a partir dela");
+                return;
+            }
+            // Outra série ou nova previsão: o cenário recomeça da previsão
+            if self.series != name || self.baseline != forecast {
+                self.series = name.to_string();
+                self.baseline = forecast.to_vec();
+                self.path = forecast.to_vec();
+            }
+            self.step = self.step.clamp(1, self.path.len());
+
+            ui.label(
+                "Arraste sobre a região da previsão para redesenhar a trajetória (ex.: a inflação subindo a 10% no próximo trimestre).",
+            );
+            ui.horizontal(|ui| {
+                let label = ui.label("Passo:");
+                ui.add(egui::DragValue::new(&mut self.step).clamp_range(1..=self.path.len()).prefix("+"))
+                    .labelled_by(label.id);
+                let label = ui.label("Valor:");
+                ui.add(egui::DragValue::new(&mut self.path[self.step - 1]).speed(0.05))
+                    .labelled_by(label.id);
+                if ui.button("Restaurar a previsão").clicked() {
+                    self.path = self.baseline.clone();
+                }
+            });
+
+            let tail = &values[values.len().saturating_sub(CONTEXT)..];
+            let last = (tail.len() - 1) as f64;
+            // A trajetória parte do último ponto observado para a linha ficar contínua
+            let future = |path: &[f64]| -> PlotPoints {
+                tail.last().into_iter().chain(path).enumerate().map(|(i, y)| [i as f64, *y]).collect()
+            };
+            let response = Plot::new("scenario_chart")
+                .legend(Legend::default())
+                .height(250.0)
+                .allow_drag(false)
+                .x_axis_formatter(step_axis)
+                .show(ui, |plot_ui| {
+                    let history: PlotPoints = tail.iter().enumerate().map(|(i, y)| [i as f64 - last, *y]).collect();
+                    plot_ui.line(Line::new(history).name("Histórico"));
+                    plot_ui.line(Line::new(future(&self.baseline)).name("Previsão").style(LineStyle::dashed_loose()));
+                    plot_ui.line(Line::new(future(&self.path)).name("Cenário").color(SCENARIO_COLOR));
+                    let handles: PlotPoints = self.path.iter().enumerate().map(|(i, y)| [(i + 1) as f64, *y]).collect();
+                    plot_ui.points(Points::new(handles).radius(4.0).color(SCENARIO_COLOR));
+                    plot_ui.pointer_coordinate()
+                });
+
+            // Clicar ou arrastar leva o passo mais próximo do ponteiro até a sua altura
+            if response.response.clicked() || response.response.dragged() {
+                if let Some(pointer) = response.inner {
+                    let step = pointer.x.round();
+                    if step >= 1.0 && step <= self.path.len() as f64 {
+                        self.step = step as usize;
+                        self.path[self.step - 1] = pointer.y;
+                    }
+                }
+            }
+
+            let compared = scenario::compare(definitions, series, name, &self.baseline, &self.path);
+            if compared.is_empty() {
+                ui.weak(format!("Nenhuma série derivada depende de {}", name));
+                return;
+            }
+
+            ui.strong("Séries derivadas sob o cenário");
+            egui::Grid::new("scenario_derived_grid").striped(true).show(ui, |ui| {
+                for header in ["Série", "Último observado", "Fim da previsão", "Fim do cenário", "Diferença"] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+                for propagated in &compared {
+                    let observed = series.get(&propagated.name).and_then(|values| values.last()).map(|(_, value)| *value);
+                    let baseline = propagated.baseline.last().copied().unwrap_or(f64::NAN);
+                    let scenario = propagated.scenario.last().copied().unwrap_or(f64::NAN);
+                    ui.monospace(&propagated.name);
+                    ui.label(observed.map(|value| format!("{:.2}", value)).unwrap_or_else(|| "—".to_string()));
+                    ui.label(format!("{:.2}", baseline));
+                    ui.label(format!("{:.2}", scenario));
+                    ui.label(format!("{:+.2}", scenario - baseline));
+                    ui.end_row();
+                }
+            });
+
+            Plot::new("scenario_derived_chart")
+                .legend(Legend::default())
+                .height(200.0)
+                .x_axis_formatter(step_axis)
+                .show(ui, |plot_ui| {
+                    for propagated in &compared {
+                        let points = |path: &[f64]| -> PlotPoints {
+                            path.iter().enumerate().map(|(i, y)| [(i + 1) as f64, *y]).collect()
+                        };
+                        plot_ui.line(
+                            Line::new(points(&propagated.baseline))
+                                .name(format!("{} (previsão)", propagated.name))
+                                .style(LineStyle::dashed_loose()),
+                        );
+                        plot_ui.line(Line::new(points(&propagated.scenario)).name(format!("{} (cenário)", propagated.name)));
+                    }
+                });
+        });
+    }
+}
@@ -0,0 +1,104 @@
+use eframe::egui;
+use egui::plot::{Legend, Line, Plot, PlotPoints};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::plugins::{self, Plugin};
+use crate::scripting::{self, Output};
+
+const EXAMPLE: &str = r#"// Ex.: núcleo suavizado da inflação e previsão do modelo padrão
+let s = series(names()[0]);
+publish(s.sma(3), "suavizada");
+print(forecast(s, 6));
+"#;
+
+// Visão de análise: console de scripts rhai sobre as séries carregadas
+pub struct ScriptsView {
+    code: String,
+    scripts: Vec<PathBuf>,
+    // O motor de scripts precisa ser dono dos plugins, então a visão carrega os seus
+    plugins: Rc<Vec<Plugin>>,
+    output: Option<Result<Output, String>>,
+}
+
+impl ScriptsView {
+    pub fn new() -> Self {
+        let mut view = Self {
+            code: EXAMPLE.to_string(),
+            scripts: vec![],
+            plugins: Rc::new(plugins::discover_plugins(&plugins::plugins_dir())),
+            output: None,
+        };
+        view.refresh();
+        view
+    }
+
+    fn refresh(&mut self) {
+        self.scripts = scripting::scripts_dir()
+            .map(|dir| scripting::list_scripts(&dir))
+            .unwrap_or_default();
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, series: &BTreeMap<String, Vec<(String, f64)>>) {
+        ui.horizontal(|ui| {
+            ui.label("Scripts salvos:");
+            let mut opened = None;
+            for path in &self.scripts {
+                let name = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+                if ui.button(name).clicked() {
+                    opened = Some(path.clone());
+                }
+            }
+            if let Some(path) = opened {
+                match std::fs::read_to_string(&path) {
+                    Ok(code) => self.code = code,
+                    Err(e) => self.output = Some(Err(format!("Erro ao ler {}: {}", path.display(), e))),
+                }
+            }
+            if ui.small_button("Recarregar").clicked() {
+                self.refresh();
+            }
+        });
+        if self.scripts.is_empty() {
+            if let Some(dir) = scripting::scripts_dir() {
+                ui.weak(format!("Nenhum script em {}", dir.display()));
+            }
+        }
+
+        ui.add(
+            egui::TextEdit::multiline(&mut self.code)
+                .code_editor()
+                .desired_rows(10)
+                .desired_width(f32::INFINITY),
+        );
+        if ui.button("Executar").clicked() {
+            self.output = Some(scripting::run(&self.code, series.clone(), self.plugins.clone()));
+        }
+
+        let output = match &self.output {
+            None => return,
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, e);
+                return;
+            }
+            Some(Ok(output)) => output,
+        };
+
+        for line in &output.printed {
+            ui.monospace(line);
+        }
+        if let Some(result) = &output.result {
+            ui.strong(format!("= {}", result));
+        }
+
+        if !output.published.is_empty() {
+            Plot::new("script_series").legend(Legend::default()).show(ui, |plot_ui| {
+                for published in &output.published {
+                    let points: PlotPoints = published.values().iter().enumerate().map(|(x, y)| [x as f64, *y]).collect();
+                    plot_ui.line(Line::new(points).name(&published.name));
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use eframe::egui;
+use std::collections::BTreeMap;
+
+use crate::search::{Index, Target};
+
+use super::accessibility;
+
+// Resultados exibidos de uma vez
+const SHOWN: usize = 20;
+
+// Caixa de busca sobre as séries, anotações, previsões fixadas, predefinições e etiquetas
+pub struct SearchPanel {
+    query: String,
+    index: Index,
+    // Resultado destacado, escolhido com as setas e aberto com Enter
+    selected: usize,
+}
+
+impl SearchPanel {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            index: Index::default(),
+            selected: 0,
+        }
+    }
+
+    // `series` são as séries disponíveis (cache e derivadas); retorna o resultado escolhido
+    pub fn show(&mut self, ui: &mut egui::Ui, series: &BTreeMap<String, Vec<(String, f64)>>) -> Option<Target> {
+        let mut chosen = None;
+
+        ui.collapsing("Buscar", |ui| {
+            // As setas movem o destaque enquanto a caixa tem o foco, em vez do cursor do texto
+            let id = egui::Id::new("search_query");
+            if ui.memory(|memory| memory.has_focus(id)) {
+                let (down, up) = ui.input_mut(|i| {
+                    (
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                    )
+                });
+                if down {
+                    self.selected += 1;
+                }
+                if up {
+                    self.selected = self.selected.saturating_sub(1);
+                }
+            }
+
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .id(id)
+                    .hint_text("série, anotação, previsão, predefinição ou etiqueta")
+                    .desired_width(320.0),
+            );
+            let response = accessibility::named(response, egui::WidgetType::TextEdit, "Buscar");
+            // Os arquivos são relidos a cada alteração da busca, para incluir o que mudou na sessão
+            if response.changed() {
+                self.index = Index::load(series.keys());
+                self.selected = 0;
+            }
+            if self.query.trim().is_empty() {
+                return;
+            }
+
+            let hits = self.index.search(&self.query);
+            if hits.is_empty() {
+                ui.weak("Nenhum resultado");
+                return;
+            }
+            self.selected = self.selected.min(hits.len().min(SHOWN) - 1);
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                chosen = Some(hits[self.selected].target.clone());
+            }
+
+            for (i, hit) in hits.iter().take(SHOWN).enumerate() {
+                ui.horizontal(|ui| {
+                    ui.weak(hit.kind.label());
+                    if ui.selectable_label(i == self.selected, &hit.title).clicked() {
+                        chosen = Some(hit.target.clone());
+                    }
+                    ui.weak(&hit.detail);
+                });
+            }
+            if hits.len() > SHOWN {
+                ui.weak(format!("e mais {} resultados", hits.len() - SHOWN));
+            }
+        });
+
+        chosen
+    }
+}
@@ -0,0 +1,204 @@
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::data::{InflationData, Source};
+use crate::fx::Currency;
+use crate::health::ProviderStatus;
+use crate::market::Candle;
+use crate::revisions::Revision;
+
+// Datas e valores alinhados de uma série
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Series {
+    pub dates: Vec<String>,
+    pub values: Vec<f64>,
+}
+
+impl Series {
+    pub fn from_pairs(pairs: Vec<(String, f64)>) -> Self {
+        let (dates, values) = pairs.into_iter().unzip();
+        Self { dates, values }
+    }
+
+    pub fn pairs(&self) -> Vec<(String, f64)> {
+        self.dates.iter().cloned().zip(self.values.iter().copied()).collect()
+    }
+}
+
+// Tarefas em segundo plano cujas respostas substituem as anteriores
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Task {
+    Fetch,
+    Prices,
+    Conversion,
+    Expectations,
+}
+
+// Resultado de uma tarefa em segundo plano, entregue à interface
+pub enum Message {
+    // Série do provedor; na atualização periódica (`refresh`) a série exibida não é trocada
+    Fetched {
+        series: Series,
+        source: Source,
+        revisions: Vec<Revision>,
+        refresh: bool,
+    },
+    FetchFailed {
+        error: String,
+        quota_blocked: bool,
+    },
+    HealthChecked(Result<(Duration, Option<u64>), String>),
+    // Falhas que não impediram nenhuma busca pedida (ex.: na busca antecipada)
+    Warnings(Vec<String>),
+    Prices {
+        ticker: String,
+        result: Result<Vec<Candle>, String>,
+    },
+    Conversion {
+        currency: Currency,
+        result: Result<Vec<InflationData>, String>,
+    },
+    Expectations {
+        country: String,
+        result: Result<Vec<InflationData>, String>,
+    },
+}
+
+impl Message {
+    fn task(&self) -> Option<Task> {
+        match self {
+            Message::Fetched { .. } | Message::FetchFailed { .. } => Some(Task::Fetch),
+            Message::Prices { .. } => Some(Task::Prices),
+            Message::Conversion { .. } => Some(Task::Conversion),
+            Message::Expectations { .. } => Some(Task::Expectations),
+            Message::HealthChecked(_) | Message::Warnings(_) => None,
+        }
+    }
+}
+
+// Canal de uma tarefa para a interface, marcado com o número do pedido
+#[derive(Clone)]
+pub struct Outbox {
+    request: u64,
+    sender: UnboundedSender<(u64, Message)>,
+}
+
+impl Outbox {
+    // A interface pode ter sido fechada; nesse caso a mensagem é descartada
+    pub fn send(&self, message: Message) {
+        let _ = self.sender.send((self.request, message));
+    }
+}
+
+// Estado da série e da previsão, de posse exclusiva da interface. As tarefas em segundo plano não
+// o acessam: enviam mensagens, aplicadas no início de cada quadro, e as respostas de um pedido
+// já substituído por outro mais novo do mesmo tipo são descartadas.
+pub struct AppState {
+    // Série do provedor como veio da última busca
+    pub historical: Series,
+    // Série usada nos modelos e no gráfico: a buscada, uma derivada, convertida, rebaseada...
+    pub current: Series,
+    pub predictions: Vec<f64>,
+    pub load_error: Option<String>,
+    // Busca recusada pelo limite diário de requisições; o usuário pode ignorá-lo
+    pub quota_blocked: bool,
+    pub status: ProviderStatus,
+    pub revisions: Vec<Revision>,
+    // Número de buscas concluídas, para a interface notar uma série nova do provedor
+    pub fetched: u64,
+    // Respostas ainda não consumidas pela tela
+    pub prices: Option<(String, Result<Vec<Candle>, String>)>,
+    pub conversion: Option<(Currency, Result<Vec<InflationData>, String>)>,
+    // Expectativas de mercado do país, mantidas até a próxima busca
+    pub expectations: Option<(String, Result<Vec<InflationData>, String>)>,
+    // Último pedido de cada tarefa e o último já respondido
+    requests: [u64; 4],
+    answered: [u64; 4],
+    sender: UnboundedSender<(u64, Message)>,
+    receiver: UnboundedReceiver<(u64, Message)>,
+}
+
+impl AppState {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            historical: Series::default(),
+            current: Series::default(),
+            predictions: vec![],
+            load_error: None,
+            quota_blocked: false,
+            status: ProviderStatus::default(),
+            revisions: vec![],
+            fetched: 0,
+            prices: None,
+            conversion: None,
+            expectations: None,
+            requests: [0; 4],
+            answered: [0; 4],
+            sender,
+            receiver,
+        }
+    }
+
+    // Novo pedido de `task`; as respostas dos anteriores passam a ser ignoradas
+    pub fn begin(&mut self, task: Task) -> Outbox {
+        let request = &mut self.requests[task as usize];
+        *request += 1;
+        Outbox {
+            request: *request,
+            sender: self.sender.clone(),
+        }
+    }
+
+    // Canal para mensagens que não substituem umas às outras (verificações do provedor)
+    pub fn outbox(&self) -> Outbox {
+        Outbox {
+            request: 0,
+            sender: self.sender.clone(),
+        }
+    }
+
+    // Alguma tarefa ainda não respondeu ao último pedido
+    pub fn pending(&self) -> bool {
+        self.requests != self.answered
+    }
+
+    // Aplica as mensagens recebidas desde o último quadro
+    pub fn poll(&mut self) {
+        while let Ok((request, message)) = self.receiver.try_recv() {
+            if let Some(task) = message.task() {
+                if self.requests[task as usize] != request {
+                    continue;
+                }
+                self.answered[task as usize] = request;
+            }
+
+            match message {
+                Message::Fetched {
+                    series,
+                    source,
+                    revisions,
+                    refresh,
+                } => {
+                    if !refresh {
+                        self.current = series.clone();
+                    }
+                    self.historical = series;
+                    self.status.record_source(source);
+                    self.revisions = revisions;
+                    self.fetched += 1;
+                }
+                Message::FetchFailed { error, quota_blocked } => {
+                    self.quota_blocked = quota_blocked;
+                    self.load_error = Some(error);
+                }
+                Message::HealthChecked(result) => self.status.record_check(result),
+                Message::Warnings(warnings) => self.status.record_warnings(warnings),
+                Message::Prices { ticker, result } => self.prices = Some((ticker, result)),
+                Message::Conversion { currency, result } => self.conversion = Some((currency, result)),
+                Message::Expectations { country, result } => self.expectations = Some((country, result)),
+            }
+        }
+    }
+}
+
@@ -0,0 +1,100 @@
+use eframe::egui;
+use std::time::Duration;
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::Indicator;
+use crate::health::{check_provider, format_elapsed, ProviderStatus};
+use crate::quota::{self, QuotaState};
+
+use super::state::{Message, Outbox};
+
+// Intervalo entre verificações do provedor
+const CHECK_INTERVAL: Duration = Duration::from_secs(300);
+
+pub fn spawn_health_checks(config: Config, outbox: Outbox) {
+    tokio::spawn(async move {
+        loop {
+            // Com o limite diário atingido a verificação é adiada para não consumir a cota
+            if quota::current().state(&config) != QuotaState::Exceeded {
+                let result = check_provider(ApiClient::shared(), &config).await;
+                outbox.send(Message::HealthChecked(result));
+            }
+
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+pub fn show_status_bar(ctx: &egui::Context, status: &ProviderStatus, config: &Config) {
+    egui::TopBottomPanel::bottom("provider_status").show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            let (color, text) = match status.reachable {
+                None => (egui::Color32::GRAY, "Verificando provedor...".to_string()),
+                Some(true) => (
+                    egui::Color32::GREEN,
+                    match status.latency {
+                        Some(latency) => format!("Provedor online ({} ms)", latency.as_millis()),
+                        None => "Provedor online".to_string(),
+                    },
+                ),
+                Some(false) => (egui::Color32::RED, "Provedor indisponível".to_string()),
+            };
+
+            let label = ui.colored_label(color, format!("● {}", text));
+            if let Some(error) = &status.last_error {
+                label.on_hover_text(error);
+            }
+
+            ui.separator();
+            match status.last_success {
+                Some(time) => ui.label(format!("Última busca: {}", format_elapsed(time))),
+                None => ui.label("Última busca: nunca"),
+            };
+
+            // A fonte só é informativa quando há provedores reserva configurados
+            let chain = config.provider_chain(Indicator::Inflation.key());
+            if let Some(source) = status.source.as_ref().filter(|_| chain.len() > 1) {
+                ui.separator();
+                let text = format!("Fonte: {}", source.provider);
+                if source.is_fallback() {
+                    ui.colored_label(egui::Color32::YELLOW, text)
+                        .on_hover_text(format!("Provedor reserva; falharam antes:\n{}", source.failures.join("\n")));
+                } else {
+                    ui.label(text);
+                }
+            }
+
+            if !status.warnings.is_empty() {
+                ui.separator();
+                ui.colored_label(egui::Color32::YELLOW, format!("⚠ {} avisos", status.warnings.len()))
+                    .on_hover_text(status.warnings.join("\n"));
+            }
+
+            if let Some(quota) = status.quota_remaining {
+                ui.separator();
+                ui.label(format!("Cota restante: {}", quota));
+            }
+
+            let usage = quota::current();
+            if config.daily_request_limit > 0 {
+                ui.separator();
+                let text = format!("Requisições hoje: {}/{}", usage.requests, config.daily_request_limit);
+                match usage.state(config) {
+                    QuotaState::Ok => ui.label(text),
+                    QuotaState::Warning => ui
+                        .colored_label(egui::Color32::YELLOW, text)
+                        .on_hover_text("Próximo do limite diário de requisições"),
+                    QuotaState::Exceeded => ui
+                        .colored_label(egui::Color32::RED, text)
+                        .on_hover_text("Limite diário atingido: novas buscas serão recusadas"),
+                };
+            }
+
+            if let Some(time) = status.checked_at {
+                ui.separator();
+                ui.weak(format!("Verificado {}", format_elapsed(time)));
+            }
+        });
+    });
+}
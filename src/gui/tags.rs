@@ -0,0 +1,67 @@
+use eframe::egui;
+
+use crate::tags::Tags;
+
+use super::accessibility;
+
+// Etiquetas da série atual, usadas para filtrar o painel e encontradas pela busca
+pub struct TagsPanel {
+    tags: Tags,
+    new_tag: String,
+    error: Option<String>,
+}
+
+impl TagsPanel {
+    pub fn new() -> Self {
+        Self {
+            tags: Tags::load(),
+            new_tag: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn tags(&self) -> &Tags {
+        &self.tags
+    }
+
+    fn persist(&mut self) {
+        self.error = self.tags.save().err().map(|e| format!("Erro ao salvar as etiquetas: {}", e));
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, series: &str) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Etiquetas:");
+
+            let mut removed = None;
+            for tag in self.tags.of(series) {
+                let chip = ui.small_button(format!("{} ✕", tag)).on_hover_text("Remover etiqueta");
+                if accessibility::named(chip, egui::WidgetType::Button, &format!("Remover a etiqueta {}", tag)).clicked() {
+                    removed = Some(tag);
+                }
+            }
+            if let Some(tag) = removed {
+                self.tags.remove(series, &tag);
+                self.persist();
+            }
+
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.new_tag)
+                    .hint_text("nova etiqueta")
+                    .desired_width(100.0),
+            );
+            let response = accessibility::named(response, egui::WidgetType::TextEdit, "Nova etiqueta da série");
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let clicked = ui
+                .add_enabled(!self.new_tag.trim().is_empty(), egui::Button::new("Adicionar"))
+                .clicked();
+            if (submitted || clicked) && self.tags.add(series, &self.new_tag).is_some() {
+                self.new_tag.clear();
+                self.persist();
+            }
+        });
+
+        if let Some(error) = &self.error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use eframe::egui;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::fx::{get_latest_quotes, FxQuote};
+use crate::health::format_elapsed;
+use crate::quota::{self, QuotaState};
+
+// Intervalo entre atualizações das cotações; cada uma consome uma requisição da cota
+const POLL_INTERVAL: Duration = Duration::from_secs(300);
+
+#[derive(Default)]
+struct TickerState {
+    quotes: Vec<FxQuote>,
+    updated: Option<SystemTime>,
+    error: Option<String>,
+}
+
+// Faixa de cotações sempre visível, atualizada periodicamente
+#[derive(Default)]
+pub struct FxTicker {
+    state: Arc<Mutex<TickerState>>,
+}
+
+impl FxTicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn spawn_polling(&self, config: Config) {
+        if config.fx_pairs.is_empty() {
+            return;
+        }
+        let state = self.state.clone();
+
+        tokio::spawn(async move {
+            loop {
+                // Como nas verificações do provedor, nada é buscado com o limite diário atingido
+                if quota::current().state(&config) != QuotaState::Exceeded {
+                    let result = get_latest_quotes(ApiClient::shared(), &config, &config.fx_pairs).await;
+
+                    let mut state = state.lock().unwrap();
+                    match result {
+                        Ok(quotes) => {
+                            state.quotes = quotes;
+                            state.updated = Some(SystemTime::now());
+                            state.error = None;
+                        }
+                        // Mantém as últimas cotações conhecidas
+                        Err(e) => state.error = Some(e.to_string()),
+                    }
+                }
+
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        });
+    }
+
+    pub fn show(&self, ctx: &egui::Context, config: &Config) {
+        if config.fx_pairs.is_empty() {
+            return;
+        }
+        let state = self.state.lock().unwrap();
+
+        egui::TopBottomPanel::top("fx_ticker").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if state.quotes.is_empty() && state.error.is_none() {
+                    ui.spinner();
+                    ui.weak("Buscando cotações...");
+                }
+
+                for quote in &state.quotes {
+                    let change = quote.percentage_change;
+                    let color = if change > 0.0 {
+                        egui::Color32::GREEN
+                    } else if change < 0.0 {
+                        egui::Color32::RED
+                    } else {
+                        egui::Color32::GRAY
+                    };
+                    let arrow = if change > 0.0 { "▲" } else if change < 0.0 { "▼" } else { "■" };
+
+                    ui.strong(quote.pair());
+                    ui.label(config.format_value(quote.bid_price));
+                    ui.colored_label(color, format!("{} {}%", arrow, config.format_value(change)))
+                        .on_hover_text(format!("Variação desde a abertura; cotação de {}", quote.updated_at_date));
+                    ui.separator();
+                }
+
+                if let Some(error) = &state.error {
+                    ui.colored_label(egui::Color32::RED, "Erro ao atualizar as cotações").on_hover_text(error);
+                }
+                if let Some(time) = state.updated {
+                    ui.weak(format!("Atualizado {}", format_elapsed(time)));
+                }
+            });
+        });
+    }
+}
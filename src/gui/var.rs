@@ -0,0 +1,268 @@
+use eframe::egui;
+use egui::plot::{Bar, BarChart, HLine, Legend, Line, Plot, PlotPoints, Polygon};
+use std::collections::BTreeMap;
+
+use crate::correlation::Transform;
+use crate::rng::SeededRng;
+use crate::var::{self, Matrix, ResponseBands, Var};
+
+const RESPONSE_COLOR: egui::Color32 = egui::Color32::from_rgb(31, 119, 180);
+const CELL_HEIGHT: f32 = 140.0;
+
+// VAR estimado com as suas respostas ao impulso, a decomposição da variância e, com o bootstrap,
+// os intervalos
+struct Estimate {
+    var: Var,
+    responses: Vec<Matrix>,
+    shares: Vec<Matrix>,
+    bands: Option<Result<ResponseBands, String>>,
+}
+
+// Visão de análise: VAR entre séries carregadas, a propagação de um choque em cada uma para as
+// demais (respostas ao impulso ortogonalizadas) e a decomposição da variância do erro de previsão
+pub struct VarView {
+    // Séries do VAR, na ordem da identificação dos choques
+    order: Vec<String>,
+    transform: Transform,
+    lags: usize,
+    horizon: usize,
+    // Reamostragens do bootstrap; 0 dispensa os intervalos
+    draws: usize,
+    result: Option<Result<Estimate, String>>,
+    // Passos à frente da tabela da decomposição e série do gráfico dela
+    step: usize,
+    decomposed: usize,
+}
+
+impl VarView {
+    pub fn new() -> Self {
+        Self {
+            order: vec![],
+            transform: Transform::Differences,
+            lags: 2,
+            horizon: 12,
+            draws: 200,
+            result: None,
+            step: 12,
+            decomposed: 0,
+        }
+    }
+
+    fn estimate(&self, series: &BTreeMap<String, Vec<(String, f64)>>) -> Result<Estimate, String> {
+        let chosen: Vec<(String, Vec<(String, f64)>)> = self
+            .order
+            .iter()
+            .map(|name| (name.clone(), self.transform.apply(&series[name])))
+            .collect();
+        let var = var::fit(&chosen, self.lags)?;
+        let responses = var.impulse_responses(self.horizon)?;
+        let shares = var::variance_decomposition(&responses);
+        let bands = (self.draws > 0).then(|| var.bootstrap(self.horizon, self.draws, &mut SeededRng::from_global()));
+        Ok(Estimate {
+            var,
+            responses,
+            shares,
+            bands,
+        })
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, series: &BTreeMap<String, Vec<(String, f64)>>) {
+        self.order.retain(|name| series.contains_key(name));
+        if series.len() < 2 {
+            ui.label("Carregue ao menos duas séries (em cache, derivadas ou de ativos) para estimar um VAR.");
+            return;
+        }
+
+        egui::CollapsingHeader::new("Séries do VAR").default_open(true).show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for name in series.keys() {
+                    let mut included = self.order.contains(name);
+                    if ui.checkbox(&mut included, name).changed() {
+                        if included {
+                            self.order.push(name.clone());
+                        } else {
+                            self.order.retain(|other| other != name);
+                        }
+                    }
+                }
+            });
+
+            // Cada série só reage no mesmo período aos choques das que vêm antes dela
+            ui.label("Ordem da identificação (da mais exógena para a mais endógena):");
+            let mut raised = None;
+            for (i, name) in self.order.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(i > 0, egui::Button::new("↑").small()).clicked() {
+                        raised = Some(i);
+                    }
+                    ui.label(format!("{}. {}", i + 1, name));
+                });
+            }
+            if let Some(i) = raised {
+                self.order.swap(i - 1, i);
+            }
+        });
+
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_label("Versão das séries")
+                .selected_text(self.transform.label())
+                .show_ui(ui, |ui| {
+                    for transform in Transform::all() {
+                        ui.selectable_value(&mut self.transform, transform, transform.label());
+                    }
+                });
+            let label = ui.label("Defasagens:");
+            ui.add(egui::DragValue::new(&mut self.lags).clamp_range(1..=var::MAX_LAGS)).labelled_by(label.id);
+            let label = ui.label("Horizonte:");
+            ui.add(egui::DragValue::new(&mut self.horizon).clamp_range(1..=60)).labelled_by(label.id);
+            let label = ui.label("Reamostragens:");
+            ui.add(egui::DragValue::new(&mut self.draws).clamp_range(0..=2000))
+                .labelled_by(label.id)
+                .on_hover_text("Bootstrap dos resíduos para os intervalos de 95%; 0 mostra só as estimativas");
+        });
+
+        let ready = self.order.len() >= 2;
+        if ui
+            .add_enabled(ready, egui::Button::new("Estimar"))
+            .on_disabled_hover_text("Escolha ao menos duas séries")
+            .clicked()
+        {
+            self.result = Some(self.estimate(series));
+        }
+
+        let estimate = match &self.result {
+            None => return,
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, e);
+                return;
+            }
+            Some(Ok(estimate)) => estimate,
+        };
+        let var = &estimate.var;
+        ui.label(format!(
+            "VAR({}) com {} observações em comum ({} a {})",
+            var.lags,
+            var.dates.len(),
+            var.dates.first().map(String::as_str).unwrap_or_default(),
+            var.dates.last().map(String::as_str).unwrap_or_default()
+        ));
+        let bands = match &estimate.bands {
+            Some(Ok(bands)) => {
+                ui.label(format!("Intervalos de 95% por bootstrap ({} reamostragens)", bands.draws));
+                Some(bands)
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::YELLOW, e);
+                None
+            }
+            None => None,
+        };
+
+        show_responses(ui, &var.names, &estimate.responses, bands);
+        show_decomposition(ui, &var.names, &estimate.shares, &mut self.step, &mut self.decomposed);
+    }
+}
+
+// Grade de respostas: a linha é a série que responde e a coluna, a série do choque
+fn show_responses(ui: &mut egui::Ui, names: &[String], responses: &[Matrix], bands: Option<&ResponseBands>) {
+    let n = names.len();
+    let width = (ui.available_width() / n as f32 - 8.0).max(120.0);
+
+    egui::ScrollArea::horizontal().show(ui, |ui| {
+        egui::Grid::new("var_responses").spacing([8.0, 8.0]).show(ui, |ui| {
+            for i in 0..n {
+                for j in 0..n {
+                    ui.vertical(|ui| {
+                        ui.small(format!("{} → {}", names[j], names[i]));
+                        let line: PlotPoints =
+                            responses.iter().enumerate().map(|(h, matrix)| [h as f64, matrix[i][j]]).collect();
+                        Plot::new(format!("var_response_{}_{}", i, j))
+                            .width(width)
+                            .height(CELL_HEIGHT)
+                            .allow_drag(false)
+                            .allow_zoom(false)
+                            .show(ui, |plot_ui| {
+                                plot_ui.hline(HLine::new(0.0).color(egui::Color32::GRAY));
+                                if let Some(bands) = bands {
+                                    // Limite superior e, de volta, o inferior
+                                    let mut band: Vec<[f64; 2]> =
+                                        bands.upper.iter().enumerate().map(|(h, m)| [h as f64, m[i][j]]).collect();
+                                    band.extend(bands.lower.iter().enumerate().rev().map(|(h, m)| [h as f64, m[i][j]]));
+                                    plot_ui.polygon(Polygon::new(PlotPoints::new(band)).color(RESPONSE_COLOR));
+                                }
+                                plot_ui.line(Line::new(line).color(RESPONSE_COLOR).width(2.0));
+                            });
+                    });
+                }
+                ui.end_row();
+            }
+        });
+    });
+}
+
+// Decomposição da variância do erro de previsão: tabela em um horizonte e, para uma série, as
+// participações dos choques empilhadas ao longo dos horizontes
+fn show_decomposition(
+    ui: &mut egui::Ui,
+    names: &[String],
+    shares: &[Matrix],
+    step: &mut usize,
+    decomposed: &mut usize,
+) {
+    if shares.is_empty() {
+        return;
+    }
+    *step = (*step).clamp(1, shares.len());
+    *decomposed = (*decomposed).min(names.len() - 1);
+
+    egui::CollapsingHeader::new("Decomposição da variância do erro de previsão")
+        .default_open(true)
+        .show(ui, |ui| {
+            let label = ui.label("Passos à frente:");
+            ui.add(egui::Slider::new(step, 1..=shares.len())).labelled_by(label.id);
+
+            egui::Grid::new("var_decomposition").striped(true).show(ui, |ui| {
+                ui.strong("Série / choque");
+                for name in names {
+                    ui.strong(name);
+                }
+                ui.end_row();
+                for (name, row) in names.iter().zip(&shares[*step - 1]) {
+                    ui.label(name);
+                    for share in row {
+                        ui.label(format!("{:.1}%", 100.0 * share));
+                    }
+                    ui.end_row();
+                }
+            });
+
+            egui::ComboBox::from_label("Série decomposta")
+                .selected_text(&names[*decomposed])
+                .show_ui(ui, |ui| {
+                    for (i, name) in names.iter().enumerate() {
+                        ui.selectable_value(decomposed, i, name);
+                    }
+                });
+            let i = *decomposed;
+            Plot::new("var_decomposition_chart")
+                .legend(Legend::default())
+                .height(200.0)
+                .include_y(100.0)
+                .show(ui, |plot_ui| {
+                    let mut charts: Vec<BarChart> = vec![];
+                    for (j, name) in names.iter().enumerate() {
+                        let bars = shares
+                            .iter()
+                            .enumerate()
+                            .map(|(h, matrix)| Bar::new((h + 1) as f64, 100.0 * matrix[i][j]))
+                            .collect();
+                        let below: Vec<&BarChart> = charts.iter().collect();
+                        let chart = BarChart::new(bars).name(name).stack_on(&below);
+                        charts.push(chart);
+                    }
+                    for chart in charts {
+                        plot_ui.bar_chart(chart);
+                    }
+                });
+        });
+}
@@ -0,0 +1,149 @@
+use eframe::egui;
+use std::sync::{Arc, Mutex};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{get_historical_inflation, COUNTRIES};
+
+const LOCALES: [&str; 2] = ["pt-BR", "en-US"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Step {
+    Token,
+    Country,
+    Connection,
+}
+
+// Assistente da primeira execução: token da API, país/idioma padrão e teste de conexão
+pub struct Wizard {
+    step: Step,
+    config: Config,
+    // Resultado do teste de conexão: Ok(número de observações) ou a mensagem de erro
+    test_result: Arc<Mutex<Option<Result<usize, String>>>>,
+    save_error: Option<String>,
+}
+
+impl Wizard {
+    pub fn new() -> Self {
+        Self {
+            step: Step::Token,
+            config: Config::default(),
+            test_result: Arc::new(Mutex::new(None)),
+            save_error: None,
+        }
+    }
+
+    fn test_connection(&mut self) {
+        let config = self.config.clone();
+        let test_result = self.test_result.clone();
+
+        *test_result.lock().unwrap() = None;
+
+        // Cliente próprio do teste: o compartilhado só é criado com a configuração concluída
+        tokio::spawn(async move {
+            let result = match ApiClient::from_config(&config) {
+                Ok(client) => get_historical_inflation(&client, &config, &config.country)
+                    .await
+                    .map(|raw| raw.inflation.len())
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e),
+            };
+
+            *test_result.lock().unwrap() = Some(result);
+        });
+    }
+
+    // Retorna a configuração salva quando o usuário conclui o assistente
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<Config> {
+        let mut finished = None;
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Bem-vindo ao RustFin");
+            ui.label("Nenhuma configuração foi encontrada. Vamos configurar o acesso aos dados.");
+            ui.separator();
+
+            match self.step {
+                Step::Token => {
+                    ui.label("Token da API (obtenha o seu em https://brapi.dev):");
+                    ui.add(egui::TextEdit::singleline(&mut self.config.api_token).password(true));
+                    ui.label("URL base da API:");
+                    ui.text_edit_singleline(&mut self.config.url_base);
+
+                    let ready = !self.config.api_token.trim().is_empty() && !self.config.url_base.trim().is_empty();
+                    if ui.add_enabled(ready, egui::Button::new("Próximo")).clicked() {
+                        self.step = Step::Country;
+                    }
+                }
+                Step::Country => {
+                    egui::ComboBox::from_label("País padrão")
+                        .selected_text(self.config.country.clone())
+                        .show_ui(ui, |ui| {
+                            for country in COUNTRIES {
+                                ui.selectable_value(&mut self.config.country, country.to_string(), country);
+                            }
+                        });
+                    egui::ComboBox::from_label("Idioma / formato numérico")
+                        .selected_text(self.config.locale.clone())
+                        .show_ui(ui, |ui| {
+                            for locale in LOCALES {
+                                ui.selectable_value(&mut self.config.locale, locale.to_string(), locale);
+                            }
+                        });
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Voltar").clicked() {
+                            self.step = Step::Token;
+                        }
+                        if ui.button("Próximo").clicked() {
+                            self.step = Step::Connection;
+                            self.test_connection();
+                        }
+                    });
+                }
+                Step::Connection => {
+                    let result = self.test_result.lock().unwrap().clone();
+                    let connected = matches!(result, Some(Ok(_)));
+
+                    match result {
+                        None => {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label("Testando conexão...");
+                            });
+                        }
+                        Some(Ok(count)) => {
+                            ui.colored_label(
+                                egui::Color32::GREEN,
+                                format!("Conexão bem-sucedida: {} observações encontradas.", count),
+                            );
+                        }
+                        Some(Err(e)) => {
+                            ui.colored_label(egui::Color32::RED, format!("Falha na conexão: {}", e));
+                        }
+                    }
+
+                    if let Some(e) = &self.save_error {
+                        ui.colored_label(egui::Color32::RED, format!("Erro ao salvar a configuração: {}", e));
+                    }
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Voltar").clicked() {
+                            self.step = Step::Country;
+                        }
+                        if ui.button("Testar novamente").clicked() {
+                            self.test_connection();
+                        }
+                        if ui.add_enabled(connected, egui::Button::new("Concluir")).clicked() {
+                            match self.config.save() {
+                                Ok(()) => finished = Some(self.config.clone()),
+                                Err(e) => self.save_error = Some(e.to_string()),
+                            }
+                        }
+                    });
+                }
+            }
+        });
+
+        finished
+    }
+}
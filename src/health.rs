@@ -0,0 +1,98 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::Source;
+
+// Cabeçalhos comuns de limite de requisições; nem todo provedor os envia
+const QUOTA_HEADERS: [&str; 3] = ["x-ratelimit-remaining", "ratelimit-remaining", "x-rate-limit-remaining"];
+
+#[derive(Debug, Clone, Default)]
+pub struct ProviderStatus {
+    // None enquanto a primeira verificação não terminou
+    pub reachable: Option<bool>,
+    pub latency: Option<Duration>,
+    pub quota_remaining: Option<u64>,
+    pub last_success: Option<SystemTime>,
+    pub checked_at: Option<SystemTime>,
+    pub last_error: Option<String>,
+    // Provedor que forneceu a série na última busca
+    pub source: Option<Source>,
+    // Falhas recentes que não impediram as buscas (cache local, busca antecipada), mais novas no fim
+    pub warnings: Vec<String>,
+}
+
+// Avisos mantidos na barra de status
+const MAX_WARNINGS: usize = 20;
+
+impl ProviderStatus {
+    pub fn record_success(&mut self) {
+        self.last_success = Some(SystemTime::now());
+    }
+
+    pub fn record_source(&mut self, source: Source) {
+        self.record_success();
+        self.record_warnings(source.warnings.clone());
+        self.source = Some(source);
+    }
+
+    pub fn record_warnings(&mut self, warnings: Vec<String>) {
+        self.warnings.extend(warnings);
+        let excess = self.warnings.len().saturating_sub(MAX_WARNINGS);
+        self.warnings.drain(..excess);
+    }
+
+    pub fn record_check(&mut self, result: Result<(Duration, Option<u64>), String>) {
+        let now = SystemTime::now();
+        self.checked_at = Some(now);
+
+        match result {
+            Ok((latency, quota)) => {
+                self.reachable = Some(true);
+                self.latency = Some(latency);
+                self.last_error = None;
+                self.last_success = Some(now);
+                if quota.is_some() {
+                    self.quota_remaining = quota;
+                }
+            }
+            Err(e) => {
+                self.reachable = Some(false);
+                self.latency = None;
+                self.last_error = Some(e);
+            }
+        }
+    }
+}
+
+// Faz uma requisição leve (apenas o último valor) e mede a latência do provedor
+pub async fn check_provider(client: &ApiClient, config: &Config) -> Result<(Duration, Option<u64>), String> {
+    let started = Instant::now();
+    let response = client
+        .get(config, "inflation", &[("country", config.country.as_str())])
+        .await
+        .map_err(|e| e.to_string())?;
+    let latency = started.elapsed();
+
+    let quota = QUOTA_HEADERS.iter().find_map(|name| {
+        response
+            .headers()
+            .get(*name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+    });
+
+    Ok((latency, quota))
+}
+
+// Texto relativo para exibição, ex.: "há 5 min"
+pub fn format_elapsed(time: SystemTime) -> String {
+    let seconds = time.elapsed().map(|d| d.as_secs()).unwrap_or(0);
+
+    match seconds {
+        0..=59 => format!("há {} s", seconds),
+        60..=3599 => format!("há {} min", seconds / 60),
+        3600..=86399 => format!("há {} h", seconds / 3600),
+        _ => format!("há {} dias", seconds / 86400),
+    }
+}
@@ -0,0 +1,182 @@
+use serde::Deserialize;
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{date_sort_key, InflationData};
+
+// Inflação de uma região (estado, região metropolitana...) com seu peso no índice nacional, em %
+#[derive(Debug, Clone, Deserialize)]
+pub struct Region {
+    pub name: String,
+    pub weight: f64,
+    pub values: Vec<InflationData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RegionalInflation {
+    pub national: Vec<InflationData>,
+    pub regions: Vec<Region>,
+}
+
+// Requer um provedor que exponha `inflation/regional`; a brapi não o oferece
+pub async fn get_regional_inflation(
+    client: &ApiClient,
+    config: &Config,
+    country: &str,
+) -> Result<RegionalInflation, Box<dyn std::error::Error>> {
+    let query = [("country", country), ("historical", "true")];
+    let mut data: RegionalInflation = client.get_json(config, "inflation/regional", &query).await?;
+    data.national.sort_by_key(|obs| date_sort_key(&obs.date));
+    for region in &mut data.regions {
+        region.values.sort_by_key(|obs| date_sort_key(&obs.date));
+    }
+
+    Ok(data)
+}
+
+pub fn normalized_weights(regions: &[Region]) -> Vec<f64> {
+    let total: f64 = regions.iter().map(|r| r.weight).sum();
+    if total <= 0.0 {
+        return vec![0.0; regions.len()];
+    }
+
+    regions.iter().map(|r| r.weight / total).collect()
+}
+
+// Forma de tornar as previsões nacional e regionais coerentes (nacional = Σ peso × regional)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    // Nacional agregado das previsões regionais, que ficam como estão
+    BottomUp,
+    // Reconciliação de traço mínimo com matriz de erros diagonal
+    MinT,
+}
+
+impl Method {
+    pub fn all() -> [Method; 2] {
+        [Method::BottomUp, Method::MinT]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Method::BottomUp => "Bottom-up",
+            Method::MinT => "Reconciliação (MinT)",
+        }
+    }
+}
+
+// Variância do erro de previsão de um passo, aproximada pela do passeio aleatório
+// (média dos quadrados das primeiras diferenças); é o "lite" do MinT: não exige
+// os resíduos dentro da amostra de cada modelo
+pub fn error_variance(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+
+    let sum: f64 = values.windows(2).map(|w| (w[1] - w[0]).powi(2)).sum();
+    Some(sum / (values.len() - 1) as f64)
+}
+
+// Previsões coerentes: regional[i][h] para a região i no passo h
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reconciled {
+    pub national: Vec<f64>,
+    pub regional: Vec<Vec<f64>>,
+}
+
+// Passos previstos por todas as regiões
+fn horizon(regional: &[Vec<f64>]) -> usize {
+    regional.iter().map(Vec::len).min().unwrap_or(0)
+}
+
+pub fn bottom_up(weights: &[f64], regional: &[Vec<f64>]) -> Reconciled {
+    let steps = horizon(regional);
+    let regional: Vec<Vec<f64>> = regional.iter().map(|r| r[..steps].to_vec()).collect();
+    let national = (0..steps)
+        .map(|h| weights.iter().zip(&regional).map(|(w, r)| w * r[h]).sum())
+        .collect();
+
+    Reconciled { national, regional }
+}
+
+// MinT com W = diag(σ²_nacional, σ²_regiões). Pela fórmula de Sherman-Morrison a solução
+// tem forma fechada: cada região recebe a diferença entre a previsão nacional e a agregada
+// na proporção de peso × variância, e o nacional resultante é a média das duas ponderada
+// pela precisão.
+pub fn mint(
+    weights: &[f64],
+    national: &[f64],
+    regional: &[Vec<f64>],
+    national_variance: f64,
+    regional_variances: &[f64],
+) -> Reconciled {
+    let aggregated = bottom_up(weights, regional);
+    let steps = aggregated.national.len().min(national.len());
+
+    // w' D w
+    let spread: f64 = weights.iter().zip(regional_variances).map(|(w, v)| w * w * v).sum();
+    let denominator = national_variance + spread;
+    if denominator <= 0.0 {
+        return aggregated;
+    }
+
+    let mut regional = aggregated.regional;
+    for (h, base) in national.iter().take(steps).enumerate() {
+        let gap = (base - aggregated.national[h]) / denominator;
+        for ((values, w), v) in regional.iter_mut().zip(weights).zip(regional_variances) {
+            values[h] += v * w * gap;
+        }
+    }
+    for values in &mut regional {
+        values.truncate(steps);
+    }
+
+    let national = (0..steps)
+        .map(|h| weights.iter().zip(&regional).map(|(w, r)| w * r[h]).sum())
+        .collect();
+
+    Reconciled { national, regional }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEIGHTS: [f64; 2] = [0.75, 0.25];
+
+    #[test]
+    fn bottom_up_aggregates_regional_forecasts() {
+        let result = bottom_up(&WEIGHTS, &[vec![4.0, 6.0], vec![8.0, 8.0, 9.0]]);
+
+        assert_eq!(result.national, vec![5.0, 6.5]);
+        assert_eq!(result.regional[1], vec![8.0, 8.0]);
+    }
+
+    #[test]
+    fn mint_is_coherent_and_blends_national_and_aggregate() {
+        let regional = [vec![4.0], vec![8.0]];
+
+        let result = mint(&WEIGHTS, &[6.0], &regional, 1.0, &[1.0, 2.0]);
+
+        // Coerência: o nacional é exatamente o agregado das regiões reconciliadas
+        let aggregated = WEIGHTS[0] * result.regional[0][0] + WEIGHTS[1] * result.regional[1][0];
+        assert!((result.national[0] - aggregated).abs() < 1e-12);
+        // Entre o agregado (5,0) e a previsão nacional (6,0)
+        assert!(result.national[0] > 5.0 && result.national[0] < 6.0);
+    }
+
+    #[test]
+    fn noisy_national_forecast_falls_back_to_bottom_up() {
+        let regional = [vec![4.0], vec![8.0]];
+
+        let result = mint(&WEIGHTS, &[6.0], &regional, 1e12, &[1.0, 1.0]);
+
+        assert!((result.national[0] - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn error_variance_uses_first_differences() {
+        assert_eq!(error_variance(&[1.0, 2.0, 4.0]), Some(2.5));
+        assert_eq!(error_variance(&[1.0]), None);
+    }
+}
@@ -0,0 +1,90 @@
+// Pilhas de desfazer/refazer de estados completos. Quem usa guarda o estado atual e chama
+// `record` com o anterior a cada alteração; `undo` e `redo` recebem o atual e devolvem o estado
+// a restaurar.
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    undo: Vec<T>,
+    redo: Vec<T>,
+    // Estados guardados em cada pilha; os mais antigos são descartados
+    limit: usize,
+}
+
+impl<T> History<T> {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            limit: limit.max(1),
+        }
+    }
+
+    // Uma alteração nova invalida o que havia para refazer
+    pub fn record(&mut self, before: T) {
+        self.undo.push(before);
+        if self.undo.len() > self.limit {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_and_redo_walk_the_recorded_states() {
+        let mut history = History::new(10);
+        history.record(1);
+        history.record(2);
+        // Estado atual: 3
+
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), None);
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), Some(3));
+        assert!(!history.can_redo());
+
+        history.undo(3);
+        history.record(2);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn oldest_states_are_dropped_past_the_limit() {
+        let mut history = History::new(2);
+        for state in 1..=3 {
+            history.record(state);
+        }
+
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), None);
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(feature = "python-models")]
+use crate::arima::{self, ArimaState};
+use crate::artifact::{ModelArtifact, Parameters};
+use crate::croston::Croston;
+use crate::models::Candidate;
+use crate::plugins::Plugin;
+use crate::theta::Theta;
+
+// Estado que avança a cada observação nova
+enum State {
+    // Últimas observações: o ingênuo usa a última; a MLP, a janela de entrada da rede
+    Window(Vec<f64>),
+    // Nível da suavização do Theta: a suavização exponencial simples é o filtro de Kalman em
+    // regime do modelo de nível local
+    Theta(Theta),
+    // Tamanho e intervalo suavizados do Croston
+    Croston(Croston),
+    // Resultado do statsmodels, atualizado com `append` (filtro de Kalman)
+    #[cfg(feature = "python-models")]
+    Arima(ArimaState),
+}
+
+// Observações guardadas no estado de janela
+fn window_len(parameters: &Parameters) -> usize {
+    match parameters {
+        Parameters::Mlp(parameters) => parameters.lags,
+        _ => 1,
+    }
+}
+
+// Modelo ajustado que incorpora observações novas sem reestimar os parâmetros, para que a
+// previsão acompanhe a atualização periódica da série sem o custo de um novo ajuste.
+// Revisões de observações antigas só entram no próximo ajuste.
+pub struct IncrementalModel {
+    artifact: ModelArtifact,
+    state: State,
+    observations: usize,
+}
+
+impl IncrementalModel {
+    // Plugins não podem ser atualizados: seus parâmetros ficam na biblioteca
+    pub fn fit(
+        candidate: Candidate,
+        plugins: &[Plugin],
+        series: &str,
+        dates: &[String],
+        values: &[f64],
+    ) -> Result<Self, String> {
+        let artifact = ModelArtifact::fit(candidate, plugins, series, dates, values)?;
+        Self::from_artifact(artifact, values)
+    }
+
+    // Continua um modelo já ajustado (ex.: salvo em disco) a partir da série, sem reajustá-lo
+    pub fn from_artifact(artifact: ModelArtifact, values: &[f64]) -> Result<Self, String> {
+        let state = match &artifact.parameters {
+            #[cfg(feature = "python-models")]
+            Parameters::Arima {
+                order,
+                coefficients,
+                sigma2,
+            } => {
+                arima::environment()?;
+                let params = crate::artifact::arima_params(coefficients, *sigma2);
+                State::Arima(ArimaState::with_params(values, *order, &params).map_err(|e| e.to_string())?)
+            }
+            #[cfg(not(feature = "python-models"))]
+            Parameters::Arima { .. } => {
+                return Err(format!("Atualizar o modelo {} exige a feature python-models", artifact.model));
+            }
+            Parameters::Theta { alpha, slope, .. } => {
+                State::Theta(Theta::with_params(values, *alpha, *slope).ok_or("Série vazia")?)
+            }
+            Parameters::Croston { alpha, variant, .. } => State::Croston(Croston::with_params(values, *alpha, *variant)),
+            parameters => {
+                let start = values.len().saturating_sub(window_len(parameters));
+                State::Window(values[start..].to_vec())
+            }
+        };
+
+        Ok(Self {
+            artifact,
+            state,
+            observations: values.len(),
+        })
+    }
+
+    pub fn artifact(&self) -> &ModelArtifact {
+        &self.artifact
+    }
+
+    // Observações incorporadas até agora, incluindo as do ajuste
+    pub fn observations(&self) -> usize {
+        self.observations
+    }
+
+    // Acrescenta observações posteriores às já incorporadas
+    pub fn update(&mut self, new: &[f64]) -> Result<(), String> {
+        if new.is_empty() {
+            return Ok(());
+        }
+
+        match &mut self.state {
+            State::Window(window) => {
+                window.extend_from_slice(new);
+                let excess = window.len().saturating_sub(window_len(&self.artifact.parameters));
+                window.drain(..excess);
+            }
+            State::Theta(theta) => new.iter().for_each(|value| theta.update(*value)),
+            State::Croston(croston) => new.iter().for_each(|value| croston.update(*value)),
+            #[cfg(feature = "python-models")]
+            State::Arima(state) => state.append(new).map_err(|e| e.to_string())?,
+        }
+        self.observations += new.len();
+        Ok(())
+    }
+
+    // Atualiza a partir da série completa, incorporando só o que veio depois das observações já
+    // vistas; retorna quantas foram acrescentadas
+    pub fn update_series(&mut self, values: &[f64]) -> Result<usize, String> {
+        let new = values.get(self.observations..).ok_or_else(|| {
+            format!(
+                "A série tem {} observações, menos que as {} já incorporadas; é preciso reajustar o modelo",
+                values.len(),
+                self.observations
+            )
+        })?;
+        self.update(new)?;
+        Ok(new.len())
+    }
+
+    pub fn forecast(&self, steps: usize) -> Result<Vec<f64>, String> {
+        match &self.state {
+            State::Window(window) => self.artifact.forecast(window, steps),
+            State::Theta(theta) => Ok(theta.forecast(steps)),
+            State::Croston(croston) => Ok(croston.forecast(steps)),
+            #[cfg(feature = "python-models")]
+            State::Arima(state) => {
+                let mut forecast = state.forecast(steps).map_err(|e| e.to_string())?;
+                if forecast.len() < steps || forecast.iter().any(|v| !v.is_finite()) {
+                    return Err(format!("{} não produziu uma previsão válida", self.artifact.model));
+                }
+                forecast.truncate(steps);
+                Ok(forecast)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn naive_model_follows_new_observations() {
+        let mut model = IncrementalModel::fit(Candidate::Naive, &[], "inflation_brazil", &[], &[4.0, 4.5]).unwrap();
+
+        model.update(&[5.0, 5.5]).unwrap();
+
+        assert_eq!(model.observations(), 4);
+        assert_eq!(model.forecast(3).unwrap(), vec![5.5; 3]);
+        // O ajuste continua sendo o original
+        assert_eq!(model.artifact().training.observations, 2);
+    }
+
+    #[test]
+    fn update_series_appends_only_unseen_points() {
+        let mut model = IncrementalModel::fit(Candidate::Naive, &[], "inflation_chile", &[], &[3.0, 3.2]).unwrap();
+
+        assert_eq!(model.update_series(&[3.0, 3.2, 3.4]).unwrap(), 1);
+        assert_eq!(model.update_series(&[3.0, 3.2, 3.4]).unwrap(), 0);
+        assert_eq!(model.forecast(1).unwrap(), vec![3.4]);
+        assert!(model.update_series(&[3.0]).is_err());
+    }
+
+    #[test]
+    fn theta_update_matches_continuing_on_the_longer_series() {
+        let values: Vec<f64> = (0..30).map(|t| 4.0 + 0.1 * t as f64 + (t as f64).sin()).collect();
+        let mut model = IncrementalModel::fit(Candidate::Theta, &[], "inflation_brazil", &[], &values[..24]).unwrap();
+
+        model.update(&values[24..]).unwrap();
+
+        let continued = IncrementalModel::from_artifact(model.artifact().clone(), &values).unwrap();
+        assert_eq!(model.forecast(6).unwrap(), continued.forecast(6).unwrap());
+    }
+}
@@ -0,0 +1,191 @@
+// Indicadores técnicos para séries de preços (ações, câmbio). Cada função devolve um valor
+// por observação, None enquanto não há histórico suficiente (período de aquecimento).
+
+// Média móvel simples dos últimos `period` valores
+pub fn sma(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    if period == 0 {
+        return vec![None; values.len()];
+    }
+
+    let mut result = Vec::with_capacity(values.len());
+    let mut sum = 0.0;
+    for (i, value) in values.iter().enumerate() {
+        sum += value;
+        if i >= period {
+            sum -= values[i - period];
+        }
+        result.push((i + 1 >= period).then_some(sum / period as f64));
+    }
+
+    result
+}
+
+// Média móvel exponencial com α = 2 / (period + 1), iniciada pela média simples do primeiro período
+pub fn ema(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || values.len() < period {
+        return vec![None; values.len()];
+    }
+
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let mut result = vec![None; period - 1];
+    let mut current = values[..period].iter().sum::<f64>() / period as f64;
+    result.push(Some(current));
+
+    for value in &values[period..] {
+        current = alpha * value + (1.0 - alpha) * current;
+        result.push(Some(current));
+    }
+
+    result
+}
+
+// EMA de uma série com lacunas no início (ex.: a própria MACD)
+fn ema_of(values: &[Option<f64>], period: usize) -> Vec<Option<f64>> {
+    let start = values.iter().position(Option::is_some).unwrap_or(values.len());
+    let defined: Vec<f64> = values[start..].iter().map(|v| v.unwrap_or(0.0)).collect();
+
+    let mut result = vec![None; start];
+    result.extend(ema(&defined, period));
+    result
+}
+
+// Índice de força relativa (Wilder), entre 0 e 100
+pub fn rsi(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut result = vec![None; values.len()];
+    if period == 0 || values.len() <= period {
+        return result;
+    }
+
+    let changes: Vec<f64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut gain = changes[..period].iter().map(|c| c.max(0.0)).sum::<f64>() / period as f64;
+    let mut loss = changes[..period].iter().map(|c| (-c).max(0.0)).sum::<f64>() / period as f64;
+
+    let index = |gain: f64, loss: f64| {
+        if loss == 0.0 {
+            if gain == 0.0 {
+                50.0
+            } else {
+                100.0
+            }
+        } else {
+            100.0 - 100.0 / (1.0 + gain / loss)
+        }
+    };
+
+    result[period] = Some(index(gain, loss));
+    for (i, change) in changes.iter().enumerate().skip(period) {
+        gain = (gain * (period - 1) as f64 + change.max(0.0)) / period as f64;
+        loss = (loss * (period - 1) as f64 + (-change).max(0.0)) / period as f64;
+        result[i + 1] = Some(index(gain, loss));
+    }
+
+    result
+}
+
+#[derive(Debug, Clone)]
+pub struct Macd {
+    pub macd: Vec<Option<f64>>,
+    pub signal: Vec<Option<f64>>,
+    pub histogram: Vec<Option<f64>>,
+}
+
+// MACD: EMA rápida menos EMA lenta, com a linha de sinal (EMA da MACD) e o histograma
+pub fn macd(values: &[f64], fast: usize, slow: usize, signal: usize) -> Macd {
+    let fast = ema(values, fast);
+    let slow = ema(values, slow);
+
+    let macd: Vec<Option<f64>> = fast
+        .iter()
+        .zip(&slow)
+        .map(|(f, s)| Some((*f)? - (*s)?))
+        .collect();
+    let signal = ema_of(&macd, signal);
+    let histogram = macd
+        .iter()
+        .zip(&signal)
+        .map(|(m, s)| Some((*m)? - (*s)?))
+        .collect();
+
+    Macd { macd, signal, histogram }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bollinger {
+    pub middle: Vec<Option<f64>>,
+    pub upper: Vec<Option<f64>>,
+    pub lower: Vec<Option<f64>>,
+}
+
+// Bandas de Bollinger: média simples ± k desvios padrão (populacionais) da janela
+pub fn bollinger(values: &[f64], period: usize, k: f64) -> Bollinger {
+    let middle = sma(values, period);
+
+    let width: Vec<Option<f64>> = middle
+        .iter()
+        .enumerate()
+        .map(|(i, mean)| {
+            let mean = (*mean)?;
+            let window = &values[i + 1 - period..=i];
+            let variance = window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / period as f64;
+            Some(k * variance.sqrt())
+        })
+        .collect();
+
+    let upper = middle.iter().zip(&width).map(|(m, w)| Some((*m)? + (*w)?)).collect();
+    let lower = middle.iter().zip(&width).map(|(m, w)| Some((*m)? - (*w)?)).collect();
+
+    Bollinger { middle, upper, lower }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Option<f64>, b: f64) -> bool {
+        a.is_some_and(|a| (a - b).abs() < 1e-9)
+    }
+
+    #[test]
+    fn moving_averages_warm_up_then_track() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+        let sma = sma(&values, 3);
+        assert_eq!(sma[..2], [None, None]);
+        assert!(close(sma[2], 2.0) && close(sma[4], 4.0));
+
+        // Início pela média simples (2,0) e depois α = 0,5
+        let ema = ema(&values, 3);
+        assert!(close(ema[2], 2.0) && close(ema[3], 3.0) && close(ema[4], 4.0));
+    }
+
+    #[test]
+    fn rsi_is_bounded_and_saturates_on_monotonic_series() {
+        let rising: Vec<f64> = (0..20).map(f64::from).collect();
+        assert!(close(rsi(&rising, 14)[19], 100.0));
+
+        let mixed: Vec<f64> = (0..40).map(|i| if i % 3 == 0 { 10.0 } else { 10.0 + i as f64 % 5.0 }).collect();
+        for value in rsi(&mixed, 14).into_iter().flatten() {
+            assert!((0.0..=100.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn macd_histogram_is_macd_minus_signal() {
+        let values: Vec<f64> = (0..60).map(|i| (i as f64 / 5.0).sin() * 10.0 + i as f64).collect();
+        let result = macd(&values, 12, 26, 9);
+
+        assert!(result.signal[..33].iter().all(Option::is_none));
+        let lines = result.histogram.iter().zip(&result.macd).zip(&result.signal).skip(33);
+        for ((histogram, macd), signal) in lines {
+            assert!(close(*histogram, macd.unwrap() - signal.unwrap()));
+        }
+    }
+
+    #[test]
+    fn bollinger_bands_collapse_on_constant_series() {
+        let bands = bollinger(&[5.0; 10], 4, 2.0);
+
+        assert!(bands.upper[2].is_none());
+        assert!(close(bands.upper[9], 5.0) && close(bands.lower[9], 5.0));
+    }
+}
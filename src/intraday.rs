@@ -0,0 +1,193 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
+
+use crate::dates::canonical;
+use crate::market::Candle;
+
+// Barra intradiária. O instante é guardado em UTC, sem ambiguidade nas mudanças de horário de
+// verão; o fuso do mercado só entra na exibição e na divisão em pregões.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+// Bolsa com seu fuso e o horário do pregão regular, em hora local
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Market {
+    pub name: &'static str,
+    pub timezone: Tz,
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+}
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or_default()
+}
+
+impl Market {
+    pub fn b3() -> Self {
+        Self {
+            name: "B3",
+            timezone: chrono_tz::America::Sao_Paulo,
+            open: time(10, 0),
+            close: time(17, 0),
+        }
+    }
+
+    pub fn nyse() -> Self {
+        Self {
+            name: "NYSE",
+            timezone: chrono_tz::America::New_York,
+            open: time(9, 30),
+            close: time(16, 0),
+        }
+    }
+
+    // Mercado de um ticker: ações brasileiras terminam em dígito (ex.: PETR4, BOVA11)
+    pub fn for_ticker(ticker: &str) -> Self {
+        if ticker.trim().ends_with(|c: char| c.is_ascii_digit()) {
+            Market::b3()
+        } else {
+            Market::nyse()
+        }
+    }
+
+    // Instante em hora local, como "DD/MM/AAAA HH:MM"
+    pub fn display(&self, time: DateTime<Utc>) -> String {
+        time.with_timezone(&self.timezone).format("%d/%m/%Y %H:%M").to_string()
+    }
+
+    // Data do pregão que contém o instante; None fora do horário regular (pré e pós-mercado)
+    pub fn session(&self, time: DateTime<Utc>) -> Option<NaiveDate> {
+        let local = time.with_timezone(&self.timezone);
+        let clock = local.time();
+        (clock >= self.open && clock < self.close).then(|| local.date_naive())
+    }
+
+    // Instante UTC de uma hora local do pregão
+    fn instant(&self, date: NaiveDate, clock: NaiveTime) -> Option<DateTime<Utc>> {
+        self.timezone.from_local_datetime(&date.and_time(clock)).earliest().map(|local| local.with_timezone(&Utc))
+    }
+}
+
+fn merge(bars: &[&Bar], time: DateTime<Utc>) -> Bar {
+    Bar {
+        time,
+        open: bars[0].open,
+        high: bars.iter().map(|bar| bar.high).fold(f64::MIN, f64::max),
+        low: bars.iter().map(|bar| bar.low).fold(f64::MAX, f64::min),
+        close: bars[bars.len() - 1].close,
+        volume: bars.iter().map(|bar| bar.volume).sum(),
+    }
+}
+
+// Barras agrupadas por chave em ordem cronológica; as fora do pregão ficam de fora
+fn group<'a, K: Ord>(
+    market: &Market,
+    bars: &'a [Bar],
+    key: impl Fn(NaiveDate, &Bar) -> K,
+) -> BTreeMap<K, Vec<&'a Bar>> {
+    let mut sorted: Vec<&Bar> = bars.iter().collect();
+    sorted.sort_by_key(|bar| bar.time);
+
+    let mut groups: BTreeMap<K, Vec<&'a Bar>> = BTreeMap::new();
+    for bar in sorted {
+        if let Some(session) = market.session(bar.time) {
+            groups.entry(key(session, bar)).or_default().push(bar);
+        }
+    }
+    groups
+}
+
+// Barras de `minutes` minutos alinhadas à abertura do pregão; nenhuma atravessa o fechamento
+// ou junta dois pregões
+pub fn resample(market: &Market, bars: &[Bar], minutes: i64) -> Vec<Bar> {
+    let minutes = minutes.max(1);
+    let groups = group(market, bars, |session, bar| {
+        let local = bar.time.with_timezone(&market.timezone).time();
+        (session, (local - market.open).num_minutes() / minutes)
+    });
+
+    groups
+        .iter()
+        .filter_map(|((session, slot), bars)| {
+            let start = market.instant(*session, market.open + Duration::minutes(slot * minutes))?;
+            Some(merge(bars, start))
+        })
+        .collect()
+}
+
+// Um candle diário por pregão, pela data local do mercado (e não pelo dia em UTC), no formato
+// das cotações diárias
+pub fn daily(market: &Market, bars: &[Bar]) -> Vec<Candle> {
+    group(market, bars, |session, _| session)
+        .iter()
+        .filter_map(|(session, bars)| {
+            let bar = merge(bars, market.instant(*session, market.open)?);
+            Some(Candle {
+                date: canonical(*session),
+                open: bar.open,
+                high: bar.high,
+                low: bar.low,
+                close: bar.close,
+                volume: bar.volume,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(time: &str, price: f64) -> Bar {
+        Bar {
+            time: DateTime::parse_from_rfc3339(time).unwrap().with_timezone(&Utc),
+            open: price,
+            high: price + 1.0,
+            low: price - 1.0,
+            close: price,
+            volume: 100.0,
+        }
+    }
+
+    #[test]
+    fn sessions_follow_the_market_timezone() {
+        let nyse = Market::nyse();
+        // 23:30 UTC ainda é 19:30 em Nova York (horário de verão): fora do pregão de 09/07
+        assert_eq!(nyse.session(bar("2024-07-09T23:30:00Z", 1.0).time), None);
+        // 14:00 UTC é 10:00 em Nova York no verão e 09:00 no inverno
+        assert!(nyse.session(bar("2024-07-09T14:00:00Z", 1.0).time).is_some());
+        assert_eq!(nyse.session(bar("2024-01-09T14:00:00Z", 1.0).time), None);
+        assert_eq!(nyse.display(bar("2024-01-09T14:45:00Z", 1.0).time), "09/01/2024 09:45");
+    }
+
+    #[test]
+    fn daily_candles_respect_session_boundaries() {
+        let b3 = Market::b3();
+        let bars = [
+            // 20:30 UTC é 17:30 em São Paulo: depois do fechamento
+            bar("2024-03-05T20:30:00Z", 50.0),
+            bar("2024-03-05T13:00:00Z", 10.0),
+            bar("2024-03-05T19:55:00Z", 12.0),
+            bar("2024-03-06T13:05:00Z", 20.0),
+        ];
+
+        let daily = daily(&b3, &bars);
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].date, "05/03/2024");
+        assert_eq!((daily[0].open, daily[0].close, daily[0].high), (10.0, 12.0, 13.0));
+        assert_eq!(daily[0].volume, 200.0);
+
+        let resampled = resample(&b3, &bars, 30);
+        assert_eq!(resampled.len(), 3);
+        assert_eq!(b3.display(resampled[1].time), "05/03/2024 16:30");
+        assert_eq!(b3.display(resampled[2].time), "06/03/2024 10:00");
+    }
+}
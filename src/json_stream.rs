@@ -0,0 +1,213 @@
+use serde::de::DeserializeOwned;
+
+// Lê incrementalmente os elementos do array em `field` de um objeto JSON ({"field": [...]}),
+// recebendo o corpo em partes. Cada elemento é desserializado assim que termina e os bytes já
+// lidos são descartados, de modo que o corpo inteiro nunca fica em memória junto da série.
+pub struct ArrayStream<T> {
+    field: Vec<u8>,
+    // Bytes recebidos e ainda não consumidos
+    buffer: Vec<u8>,
+    // Próximo byte de `buffer` a examinar
+    pos: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    // Início (em `buffer`) da string sendo lida no nível do objeto externo e a última lida
+    key_start: Option<usize>,
+    key: Vec<u8>,
+    // Depois dos dois-pontos da chave `field`, à espera do '['
+    after_field: bool,
+    // Profundidade dentro do array; None antes de encontrá-lo
+    array_depth: Option<usize>,
+    // Início (em `buffer`) do elemento corrente
+    element_start: Option<usize>,
+    done: bool,
+    items: Vec<T>,
+}
+
+impl<T: DeserializeOwned> ArrayStream<T> {
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.as_bytes().to_vec(),
+            buffer: Vec::new(),
+            pos: 0,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            key_start: None,
+            key: Vec::new(),
+            after_field: false,
+            array_depth: None,
+            element_start: None,
+            done: false,
+            items: Vec::new(),
+        }
+    }
+
+    // O array já terminou; o resto do corpo pode ser ignorado
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), serde_json::Error> {
+        if self.done {
+            return Ok(());
+        }
+        self.buffer.extend_from_slice(chunk);
+
+        while self.pos < self.buffer.len() && !self.done {
+            let byte = self.buffer[self.pos];
+            self.scan(byte)?;
+            self.pos += 1;
+        }
+
+        self.compact();
+        Ok(())
+    }
+
+    fn scan(&mut self, byte: u8) -> Result<(), serde_json::Error> {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+                if let Some(start) = self.key_start.take() {
+                    self.key = self.buffer[start + 1..self.pos].to_vec();
+                }
+            }
+            return Ok(());
+        }
+
+        match self.array_depth {
+            Some(array_depth) => self.scan_array(byte, array_depth),
+            None => {
+                self.scan_object(byte);
+                Ok(())
+            }
+        }
+    }
+
+    // Antes do array: procura a chave `field` no objeto externo
+    fn scan_object(&mut self, byte: u8) {
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                if self.depth == 1 && !self.after_field {
+                    self.key_start = Some(self.pos);
+                }
+            }
+            b':' if self.depth == 1 => self.after_field = self.key == self.field,
+            b'[' if self.after_field && self.depth == 1 => {
+                self.depth += 1;
+                self.array_depth = Some(self.depth);
+            }
+            b'{' | b'[' => self.depth += 1,
+            b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+            b',' => self.after_field = false,
+            _ => {}
+        }
+    }
+
+    fn scan_array(&mut self, byte: u8, array_depth: usize) -> Result<(), serde_json::Error> {
+        let at_top = self.depth == array_depth;
+        match byte {
+            b',' if at_top => self.finish_element()?,
+            b']' if at_top => {
+                self.finish_element()?;
+                self.depth -= 1;
+                self.done = true;
+            }
+            _ if byte.is_ascii_whitespace() => {}
+            _ => {
+                if at_top && self.element_start.is_none() {
+                    self.element_start = Some(self.pos);
+                }
+                match byte {
+                    b'"' => self.in_string = true,
+                    b'{' | b'[' => self.depth += 1,
+                    b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish_element(&mut self) -> Result<(), serde_json::Error> {
+        if let Some(start) = self.element_start.take() {
+            self.items.push(serde_json::from_slice(&self.buffer[start..self.pos])?);
+        }
+        Ok(())
+    }
+
+    // Descarta os bytes examinados que não pertencem a um elemento ou chave em andamento
+    fn compact(&mut self) {
+        let keep = self.element_start.or(self.key_start).unwrap_or(self.pos);
+        self.buffer.drain(..keep);
+        self.pos -= keep;
+        if let Some(start) = self.element_start.as_mut() {
+            *start -= keep;
+        }
+        if let Some(start) = self.key_start.as_mut() {
+            *start -= keep;
+        }
+    }
+
+    pub fn finish(self) -> Result<Vec<T>, String> {
+        if self.done {
+            Ok(self.items)
+        } else if self.array_depth.is_some() {
+            Err("Resposta do provedor terminou no meio da série".to_string())
+        } else {
+            Err(format!(
+                "Campo {} não encontrado na resposta do provedor",
+                String::from_utf8_lossy(&self.field)
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::InflationData;
+
+    const BODY: &str = r#"{"meta": {"tags": ["a]", "b"]}, "inflation" : [
+        {"date": "01/02/2024", "value": "4.5", "note": "fim \"]\" do array?"},
+        {"date": "01/01/2024", "value": "4.6"}
+    ], "next": null}"#;
+
+    fn parse(chunk_size: usize) -> Result<Vec<InflationData>, String> {
+        let mut stream = ArrayStream::new("inflation");
+        for chunk in BODY.as_bytes().chunks(chunk_size) {
+            stream.feed(chunk).map_err(|e| e.to_string())?;
+        }
+        stream.finish()
+    }
+
+    #[test]
+    fn elements_are_parsed_across_chunk_boundaries() {
+        for chunk_size in [1, 3, 7, BODY.len()] {
+            let items = parse(chunk_size).unwrap();
+            assert_eq!(items.len(), 2);
+            assert_eq!(items[0].date, "01/02/2024");
+            assert_eq!(items[1].value, 4.6);
+        }
+    }
+
+    #[test]
+    fn missing_field_and_truncated_body_are_errors() {
+        let mut stream = ArrayStream::<InflationData>::new("unemployment");
+        stream.feed(BODY.as_bytes()).unwrap();
+        assert!(stream.finish().unwrap_err().contains("unemployment"));
+
+        let mut stream = ArrayStream::<InflationData>::new("inflation");
+        stream.feed(&BODY.as_bytes()[..60]).unwrap();
+        assert!(stream.finish().is_err());
+
+        let mut stream = ArrayStream::<InflationData>::new("inflation");
+        assert!(stream.feed(br#"{"inflation": [{"date": "01/01/2024", "value": "n/d"}]}"#).is_err());
+    }
+}
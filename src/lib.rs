@@ -0,0 +1,100 @@
+pub mod analytics;
+pub mod annotations;
+pub mod artifact;
+pub mod basket;
+pub mod boxcox;
+pub mod breaks;
+pub mod cache;
+pub mod calendar;
+pub mod catalog;
+pub mod client;
+pub mod compact;
+pub mod components;
+pub mod config;
+pub mod correlation;
+pub mod croston;
+pub mod data;
+pub mod dates;
+pub mod decimate;
+pub mod derived;
+pub mod diagnostics;
+pub mod expectations;
+pub mod explain;
+pub mod export;
+pub mod expr;
+pub mod frequency;
+pub mod fx;
+pub mod garch;
+pub mod goal_seek;
+pub mod health;
+pub mod hierarchy;
+pub mod history;
+pub mod incremental;
+pub mod intraday;
+pub mod indicators;
+pub mod json_stream;
+pub mod loans;
+pub mod lru;
+pub mod market;
+pub mod models;
+pub mod pca;
+pub mod phillips;
+pub mod pinned;
+pub mod plugins;
+pub mod prefetch;
+pub mod presets;
+pub mod progress;
+pub mod project;
+pub mod projection;
+pub mod quota;
+pub mod ranking;
+pub mod rates;
+pub mod rebase;
+pub mod recessions;
+pub mod report;
+pub mod returns;
+pub mod revisions;
+pub mod risk;
+pub mod rng;
+pub mod salary;
+pub mod scale;
+pub mod scenario;
+pub mod search;
+pub mod seasonal;
+pub mod splice;
+pub mod staleness;
+pub mod stats;
+pub mod tags;
+pub mod targets;
+pub mod theta;
+pub mod validation;
+pub mod var;
+pub mod vintage;
+pub mod watchlist;
+pub mod webhooks;
+
+#[cfg(feature = "python-models")]
+pub mod arima;
+// Modo em lote; usa o runtime tokio, disponível com as features gui e tui
+#[cfg(any(feature = "gui", feature = "tui"))]
+pub mod batch;
+// Buscas síncronas, para quem não usa um runtime assíncrono
+#[cfg(feature = "blocking")]
+pub mod blocking;
+// Completações de shell e página de manual dos subcomandos
+#[cfg(feature = "gui")]
+pub mod cli;
+#[cfg(feature = "gui")]
+pub mod gui;
+#[cfg(feature = "ml")]
+pub mod ml;
+// Uso em notebooks (evcxr): exibição em HTML e buscas síncronas
+#[cfg(feature = "notebook")]
+pub mod notebook;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+#[cfg(feature = "tui")]
+pub mod tui;
+
+// Quantidade de passos futuros previstos pelos modelos
+pub const FORECAST_STEPS: usize = 150;
@@ -0,0 +1,189 @@
+use std::path::PathBuf;
+
+use crate::export::write_csv;
+
+// Sistemas de amortização usados no Brasil
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum System {
+    // Sistema de Amortização Constante: amortização fixa, parcelas decrescentes
+    Sac,
+    // Tabela Price: parcelas fixas (sem correção), amortização crescente
+    Price,
+}
+
+impl System {
+    pub fn all() -> [System; 2] {
+        [System::Sac, System::Price]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            System::Sac => "SAC",
+            System::Price => "Price",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Loan {
+    pub principal: f64,
+    // Juros em % a.a.; nos financiamentos indexados, a taxa acima da inflação (ex.: IPCA + 6%)
+    pub annual_rate: f64,
+    pub months: usize,
+    pub system: System,
+    // Saldo corrigido mensalmente pela inflação (IPCA)
+    pub indexed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Installment {
+    pub month: usize,
+    // Correção monetária do saldo no mês (zero sem indexação)
+    pub correction: f64,
+    pub interest: f64,
+    pub amortization: f64,
+    pub payment: f64,
+    // Saldo devedor depois da parcela
+    pub balance: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Totals {
+    pub paid: f64,
+    pub interest: f64,
+    pub correction: f64,
+}
+
+// Taxa mensal equivalente a uma anual, ambas em %
+pub fn monthly_rate(annual: f64) -> f64 {
+    ((1.0 + annual / 100.0).powf(1.0 / 12.0) - 1.0) * 100.0
+}
+
+// Parcela constante que quita `balance` em `months` meses à taxa mensal `rate` (fração)
+fn price_payment(balance: f64, rate: f64, months: f64) -> f64 {
+    if rate == 0.0 {
+        balance / months
+    } else {
+        balance * rate / (1.0 - (1.0 + rate).powf(-months))
+    }
+}
+
+// Cronograma de parcelas. Com indexação, o saldo é corrigido a cada mês pela inflação anual (%)
+// de `inflation` (a previsão; depois do seu horizonte, o último valor previsto) e a parcela é
+// recalculada sobre o saldo corrigido e o prazo restante.
+pub fn schedule(loan: &Loan, inflation: &[f64]) -> Vec<Installment> {
+    let rate = monthly_rate(loan.annual_rate) / 100.0;
+    let mut balance = loan.principal;
+
+    (1..=loan.months)
+        .map(|month| {
+            let remaining = (loan.months - month + 1) as f64;
+            let correction = if loan.indexed {
+                let annual = inflation.get(month - 1).or(inflation.last()).copied().unwrap_or(0.0);
+                balance * monthly_rate(annual) / 100.0
+            } else {
+                0.0
+            };
+            balance += correction;
+
+            let interest = balance * rate;
+            let (amortization, payment) = match loan.system {
+                System::Sac => {
+                    let amortization = balance / remaining;
+                    (amortization, amortization + interest)
+                }
+                System::Price => {
+                    let payment = price_payment(balance, rate, remaining);
+                    (payment - interest, payment)
+                }
+            };
+            // Sem resíduos negativos de arredondamento na última parcela
+            balance = (balance - amortization).max(0.0);
+
+            Installment {
+                month,
+                correction,
+                interest,
+                amortization,
+                payment,
+                balance,
+            }
+        })
+        .collect()
+}
+
+pub fn totals(schedule: &[Installment]) -> Totals {
+    Totals {
+        paid: schedule.iter().map(|installment| installment.payment).sum(),
+        interest: schedule.iter().map(|installment| installment.interest).sum(),
+        correction: schedule.iter().map(|installment| installment.correction).sum(),
+    }
+}
+
+pub fn schedule_csv(schedule: &[Installment]) -> String {
+    let mut csv = String::from("month,correction,interest,amortization,payment,balance\n");
+    for installment in schedule {
+        csv.push_str(&format!(
+            "{},{:.2},{:.2},{:.2},{:.2},{:.2}\n",
+            installment.month,
+            installment.correction,
+            installment.interest,
+            installment.amortization,
+            installment.payment,
+            installment.balance
+        ));
+    }
+    csv
+}
+
+// Grava `rustfin-financiamento-<sistema>.csv` no diretório de exportação
+pub fn write_schedule_csv(loan: &Loan, schedule: &[Installment]) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let name = format!("financiamento-{}", loan.system.label().to_lowercase());
+    write_csv(&name, &schedule_csv(schedule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loan(system: System, annual_rate: f64, indexed: bool) -> Loan {
+        Loan {
+            principal: 1000.0,
+            annual_rate,
+            months: 12,
+            system,
+            indexed,
+        }
+    }
+
+    #[test]
+    fn price_and_sac_pay_off_the_principal() {
+        // 1% ao mês
+        let annual = (1.01f64.powi(12) - 1.0) * 100.0;
+
+        let price = schedule(&loan(System::Price, annual, false), &[]);
+        assert!(price.iter().all(|installment| (installment.payment - 88.848788).abs() < 1e-5));
+        assert!(price[11].balance.abs() < 1e-9);
+
+        let sac = schedule(&loan(System::Sac, annual, false), &[]);
+        assert!((sac[0].interest - 10.0).abs() < 1e-9);
+        assert!((sac[0].payment - (1000.0 / 12.0 + 10.0)).abs() < 1e-9);
+        assert!(sac[0].payment > sac[11].payment);
+        assert!(sac[11].balance.abs() < 1e-9);
+        assert!(totals(&sac).interest < totals(&price).interest);
+    }
+
+    #[test]
+    fn indexed_balance_is_corrected_by_forecast_inflation() {
+        let sac = schedule(&loan(System::Sac, 0.0, true), &[12.0, 12.0, 6.0]);
+
+        // 12% a.a. equivale a ~0,9489% ao mês
+        assert!((sac[0].correction - 9.488793).abs() < 1e-5);
+        // Depois do horizonte da previsão, vale o último valor previsto
+        assert!((sac[11].correction / sac[10].balance - monthly_rate(6.0) / 100.0).abs() < 1e-12);
+
+        let sum = totals(&sac);
+        assert!((sum.paid - (1000.0 + sum.correction)).abs() < 1e-9);
+        assert!(schedule_csv(&sac).starts_with("month,correction,interest,amortization,payment,balance\n1,9.49,"));
+    }
+}
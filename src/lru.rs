@@ -0,0 +1,110 @@
+// Cache em memória com orçamento em bytes: ao passar dele, os itens usados há mais tempo saem
+// primeiro. O tamanho de cada item é estimado por quem o insere.
+#[derive(Debug, Clone)]
+pub struct Lru<V> {
+    budget: usize,
+    used: usize,
+    // Do usado há mais tempo ao mais recente; a busca linear basta para algumas centenas de itens
+    entries: Vec<(String, V, usize)>,
+}
+
+impl<V: Clone> Lru<V> {
+    pub fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            used: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    // Bytes estimados dos itens guardados
+    pub fn used(&self) -> usize {
+        self.used
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Item guardado, que passa a ser o mais recente
+    pub fn get(&mut self, key: &str) -> Option<V> {
+        let position = self.entries.iter().position(|(other, _, _)| other == key)?;
+        let entry = self.entries.remove(position);
+        let value = entry.1.clone();
+        self.entries.push(entry);
+        Some(value)
+    }
+
+    // Guarda o item como o mais recente; um item maior que o orçamento inteiro não é guardado
+    pub fn insert(&mut self, key: &str, value: V, size: usize) {
+        self.remove(key);
+        if size > self.budget {
+            return;
+        }
+
+        self.entries.push((key.to_string(), value, size));
+        self.used += size;
+        self.evict();
+    }
+
+    pub fn remove(&mut self, key: &str) {
+        if let Some(position) = self.entries.iter().position(|(other, _, _)| other == key) {
+            let (_, _, size) = self.entries.remove(position);
+            self.used -= size;
+        }
+    }
+
+    // Novo orçamento; com 0 nada é guardado
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        while self.used > self.budget && !self.entries.is_empty() {
+            let (_, _, size) = self.entries.remove(0);
+            self.used -= size;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used_over_budget() {
+        let mut lru = Lru::new(100);
+        lru.insert("a", 1, 40);
+        lru.insert("b", 2, 40);
+        assert_eq!(lru.get("a"), Some(1));
+
+        // "b" foi usado há mais tempo e sai para caber "c"
+        lru.insert("c", 3, 40);
+        assert_eq!(lru.get("b"), None);
+        assert_eq!(lru.get("a"), Some(1));
+        assert_eq!(lru.used(), 80);
+
+        lru.insert("a", 10, 50);
+        assert_eq!((lru.get("a"), lru.used()), (Some(10), 90));
+    }
+
+    #[test]
+    fn respects_budget_changes_and_oversized_items() {
+        let mut lru = Lru::new(100);
+        lru.insert("grande", 1, 101);
+        assert!(lru.is_empty());
+
+        lru.insert("a", 1, 30);
+        lru.insert("b", 2, 30);
+        lru.set_budget(40);
+        assert_eq!((lru.len(), lru.get("b")), (1, Some(2)));
+
+        lru.set_budget(0);
+        assert!(lru.is_empty() && lru.used() == 0);
+    }
+}
@@ -0,0 +1,144 @@
+use serde::Deserialize;
+
+use chrono::{DateTime, Utc};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::intraday::Bar;
+
+// Período de cotações diárias buscado para um ativo
+const HISTORY_RANGE: &str = "1y";
+// Período de cotações intradiárias: a API só as fornece para os últimos dias
+const INTRADAY_RANGE: &str = "5d";
+
+// Pregão de um ativo (OHLCV), com a data no formato das demais séries ("DD/MM/AAAA")
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candle {
+    pub date: String,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawCandle {
+    // Segundos Unix
+    date: i64,
+    open: Option<f64>,
+    high: Option<f64>,
+    low: Option<f64>,
+    close: Option<f64>,
+    volume: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuoteResult {
+    #[serde(default)]
+    historical_data_price: Vec<RawCandle>,
+}
+
+#[derive(Debug, Deserialize)]
+struct QuoteRaw {
+    results: Vec<QuoteResult>,
+}
+
+// Data civil (UTC) de um instante Unix, como "DD/MM/AAAA" (civil_from_days de Howard Hinnant)
+pub fn format_unix_date(secs: i64) -> String {
+    let z = secs.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{:02}/{:02}/{}", day, month, year)
+}
+
+async fn get_raw_candles(
+    client: &ApiClient,
+    config: &Config,
+    ticker: &str,
+    range: &str,
+    interval: &str,
+) -> Result<Vec<RawCandle>, Box<dyn std::error::Error>> {
+    let endpoint = format!("quote/{}", ticker.trim().to_uppercase());
+    let query = [("range", range), ("interval", interval)];
+    let data: QuoteRaw = client.get_json(config, &endpoint, &query).await?;
+
+    let mut raw = data
+        .results
+        .into_iter()
+        .next()
+        .ok_or("Ativo não encontrado")?
+        .historical_data_price;
+    raw.sort_by_key(|candle| candle.date);
+
+    Ok(raw)
+}
+
+// Cotações diárias do último ano de um ativo (ex.: "PETR4"), em ordem cronológica.
+// Pregões sem algum dos preços são descartados; volume ausente conta como zero.
+pub async fn get_price_history(
+    client: &ApiClient,
+    config: &Config,
+    ticker: &str,
+) -> Result<Vec<Candle>, Box<dyn std::error::Error>> {
+    let raw = get_raw_candles(client, config, ticker, HISTORY_RANGE, "1d").await?;
+
+    Ok(raw
+        .into_iter()
+        .filter_map(|candle| {
+            Some(Candle {
+                date: format_unix_date(candle.date),
+                open: candle.open?,
+                high: candle.high?,
+                low: candle.low?,
+                close: candle.close?,
+                volume: candle.volume.unwrap_or(0.0),
+            })
+        })
+        .collect())
+}
+
+// Cotações intradiárias dos últimos dias em barras de `interval` (ex.: "5m", "1h"), em ordem
+// cronológica e com o instante em UTC; a divisão em pregões fica com `intraday::Market`
+pub async fn get_intraday_prices(
+    client: &ApiClient,
+    config: &Config,
+    ticker: &str,
+    interval: &str,
+) -> Result<Vec<Bar>, Box<dyn std::error::Error>> {
+    let raw = get_raw_candles(client, config, ticker, INTRADAY_RANGE, interval).await?;
+
+    Ok(raw
+        .into_iter()
+        .filter_map(|candle| {
+            Some(Bar {
+                time: DateTime::<Utc>::from_timestamp(candle.date, 0)?,
+                open: candle.open?,
+                high: candle.high?,
+                low: candle.low?,
+                close: candle.close?,
+                volume: candle.volume.unwrap_or(0.0),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_unix_dates() {
+        assert_eq!(format_unix_date(0), "01/01/1970");
+        assert_eq!(format_unix_date(1_704_067_200), "01/01/2024");
+        assert_eq!(format_unix_date(1_709_164_800), "29/02/2024");
+    }
+}
@@ -0,0 +1,171 @@
+use candle_core::{DType, Device, Tensor};
+use candle_nn::{linear, AdamW, Linear, Module, Optimizer, ParamsAdamW, VarBuilder, VarMap};
+
+use crate::artifact::{Layer, MlpParameters};
+use crate::rng::SeededRng;
+
+// Número de observações passadas usadas como entrada da rede
+const LAGS: usize = 12;
+const HIDDEN: usize = 32;
+const EPOCHS: usize = 500;
+const LEARNING_RATE: f64 = 0.01;
+
+struct Mlp {
+    hidden: Linear,
+    output: Linear,
+}
+
+impl Mlp {
+    fn new(vb: VarBuilder) -> candle_core::Result<Self> {
+        let hidden = linear(LAGS, HIDDEN, vb.pp("hidden"))?;
+        let output = linear(HIDDEN, 1, vb.pp("output"))?;
+
+        Ok(Self { hidden, output })
+    }
+}
+
+impl Module for Mlp {
+    fn forward(&self, xs: &Tensor) -> candle_core::Result<Tensor> {
+        let xs = self.hidden.forward(xs)?.relu()?;
+        self.output.forward(&xs)
+    }
+}
+
+// O gerador da CPU do candle não aceita semente; para resultados reprodutíveis os pesos
+// iniciais são sobrescritos a partir da semente global (uniforme em ±1/√entradas, como o candle)
+fn seed_weights(varmap: &mut VarMap, device: &Device) -> candle_core::Result<()> {
+    let mut rng = SeededRng::from_global();
+
+    let mut vars: Vec<(String, Vec<usize>)> = varmap
+        .data()
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(name, var)| (name.clone(), var.dims().to_vec()))
+        .collect();
+    // A ordem do HashMap não é determinística
+    vars.sort();
+
+    for (name, dims) in vars {
+        // O viés usa o número de entradas da camada, como o peso correspondente
+        let fan_in = if name.starts_with("hidden") { LAGS } else { HIDDEN };
+        let bound = 1.0 / (fan_in as f64).sqrt();
+        let count: usize = dims.iter().product();
+        let values: Vec<f32> = (0..count).map(|_| rng.uniform(-bound, bound) as f32).collect();
+
+        varmap.set_one(&name, Tensor::from_vec(values, dims, device)?)?;
+    }
+
+    Ok(())
+}
+
+fn scale(values: &[f64], mean: f64, std: f64) -> Vec<f32> {
+    values.iter().map(|v| ((v - mean) / std) as f32).collect()
+}
+
+// Treina a rede na série e retorna os pesos, a normalização e a variância dos resíduos do treino
+pub fn fit_mlp(values: &[f64]) -> Result<MlpParameters, Box<dyn std::error::Error>> {
+    if values.len() <= LAGS {
+        return Err(format!("Série muito curta para o modelo MLP (mínimo de {} pontos)", LAGS + 1).into());
+    }
+
+    // Normalizando a série para estabilizar o treino
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let std = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64).sqrt();
+    let std = if std > 0.0 { std } else { 1.0 };
+    let scaled = scale(values, mean, std);
+
+    // Montando as janelas defasadas (x = LAGS valores anteriores, y = próximo valor)
+    let samples = scaled.len() - LAGS;
+    let mut inputs = Vec::with_capacity(samples * LAGS);
+    let mut targets = Vec::with_capacity(samples);
+    for window in scaled.windows(LAGS + 1) {
+        inputs.extend_from_slice(&window[..LAGS]);
+        targets.push(window[LAGS]);
+    }
+
+    let device = Device::Cpu;
+    let xs = Tensor::from_vec(inputs, (samples, LAGS), &device)?;
+    let ys = Tensor::from_vec(targets, (samples, 1), &device)?;
+
+    let mut varmap = VarMap::new();
+    let vb = VarBuilder::from_varmap(&varmap, DType::F32, &device);
+    let model = Mlp::new(vb)?;
+    seed_weights(&mut varmap, &device)?;
+
+    let params = ParamsAdamW {
+        lr: LEARNING_RATE,
+        ..Default::default()
+    };
+    let mut optimizer = AdamW::new(varmap.all_vars(), params)?;
+
+    for _ in 0..EPOCHS {
+        let predictions = model.forward(&xs)?;
+        let loss = candle_nn::loss::mse(&predictions, &ys)?;
+        optimizer.backward_step(&loss)?;
+    }
+
+    // Erro quadrático médio do treino, de volta à escala da série
+    let loss = candle_nn::loss::mse(&model.forward(&xs)?, &ys)?.to_scalar::<f32>()?;
+
+    let mut layers = vec![];
+    for (name, var) in varmap.data().lock().unwrap().iter() {
+        layers.push(Layer {
+            name: name.clone(),
+            shape: var.dims().to_vec(),
+            values: var.flatten_all()?.to_vec1::<f32>()?,
+        });
+    }
+    layers.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(MlpParameters {
+        lags: LAGS,
+        mean,
+        std,
+        sigma2: loss as f64 * std * std,
+        layers,
+    })
+}
+
+// Prevê a partir de uma rede já treinada; a série pode ser outra (ex.: com observações novas),
+// normalizada com a média e o desvio do treino
+pub fn forecast_mlp(
+    parameters: &MlpParameters,
+    values: &[f64],
+    steps: usize,
+) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+    if parameters.lags != LAGS {
+        return Err(format!("Rede treinada com {} defasagens; esta versão usa {}", parameters.lags, LAGS).into());
+    }
+    if values.len() < LAGS {
+        return Err(format!("Série muito curta para o modelo MLP (mínimo de {} pontos)", LAGS).into());
+    }
+
+    let device = Device::Cpu;
+    let mut varmap = VarMap::new();
+    let vb = VarBuilder::from_varmap(&varmap, DType::F32, &device);
+    let model = Mlp::new(vb)?;
+    for layer in &parameters.layers {
+        varmap.set_one(&layer.name, Tensor::from_vec(layer.values.clone(), layer.shape.clone(), &device)?)?;
+    }
+
+    // Previsão recursiva: cada passo previsto realimenta a janela de entrada
+    let (mean, std) = (parameters.mean, parameters.std);
+    let mut window = scale(&values[values.len() - LAGS..], mean, std);
+    let mut forecast = Vec::with_capacity(steps);
+    for _ in 0..steps {
+        let input = Tensor::from_vec(window.clone(), (1, LAGS), &device)?;
+        let next = model.forward(&input)?.flatten_all()?.to_vec1::<f32>()?[0];
+
+        forecast.push(next as f64 * std + mean);
+        window.remove(0);
+        window.push(next);
+    }
+
+    Ok(forecast)
+}
+
+pub fn run_mlp_model(values: &[f64], steps: usize) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+    let parameters = fit_mlp(values)?;
+    forecast_mlp(&parameters, values, steps)
+}
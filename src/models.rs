@@ -0,0 +1,501 @@
+#[cfg(feature = "python-models")]
+use crate::arima::{self, run_arima_model};
+#[cfg(feature = "ml")]
+use crate::ml;
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::croston::{self, run_croston_model};
+use crate::data::{get_indicator, Indicator};
+use crate::plugins::Plugin;
+use crate::progress::{Progress, Reporter, Silent};
+use crate::theta::run_theta_model;
+use crate::{stats, FORECAST_STEPS};
+
+// Passos previstos quando o horizonte não é informado
+pub const DEFAULT_HORIZON: usize = 12;
+
+// Modelos de previsão usados pelo modo em lote, pelo modo terminal e pelos scripts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Candidate {
+    // Repete o último valor; referência sempre disponível
+    Naive,
+    #[cfg(feature = "python-models")]
+    Arima(u32, u32, u32),
+    #[cfg(feature = "ml")]
+    Mlp,
+    // Método Theta; nativo, sempre disponível
+    Theta,
+    // Croston com a correção SBA, para séries intermitentes; nativo, sempre disponível
+    Croston,
+    // Índice do plugin na lista descoberta
+    Plugin(usize),
+}
+
+impl Candidate {
+    // Um candidato por modelo disponível, na ordem de preferência da interface:
+    // ARIMA(1,1,1) (se o ambiente Python estiver completo), MLP, plugins, Theta, Croston e, por último, o ingênuo
+    pub fn available(plugins: &[Plugin]) -> Vec<Candidate> {
+        let mut candidates = vec![];
+        #[cfg(feature = "python-models")]
+        if arima::available() {
+            candidates.push(Candidate::Arima(1, 1, 1));
+        }
+        #[cfg(feature = "ml")]
+        candidates.push(Candidate::Mlp);
+        candidates.extend((0..plugins.len()).map(Candidate::Plugin));
+        candidates.push(Candidate::Theta);
+        candidates.push(Candidate::Croston);
+        candidates.push(Candidate::Naive);
+        candidates
+    }
+
+    // Com `auto`, todos os modelos concorrem (ARIMA em uma grade de ordens); sem ela, só o padrão
+    pub fn all(plugins: &[Plugin], auto: bool) -> Vec<Candidate> {
+        let mut candidates = Candidate::available(plugins);
+        if !auto {
+            candidates.truncate(1);
+            return candidates;
+        }
+
+        #[cfg(feature = "python-models")]
+        if arima::available() {
+            let orders = (0..=2).flat_map(|p| (0..=1).flat_map(move |d| (0..=1).map(move |q| Candidate::Arima(p, d, q))));
+            candidates = orders.chain(candidates.into_iter().skip(1)).collect();
+        }
+        candidates
+    }
+
+    // Se o modelo concorre na escolha automática e nas combinações: o Croston subestima séries
+    // sem zeros frequentes e só entra nas intermitentes
+    pub fn suits(&self, values: &[f64]) -> bool {
+        *self != Candidate::Croston || croston::is_intermittent(values)
+    }
+
+    pub fn label(&self, plugins: &[Plugin]) -> String {
+        match self {
+            Candidate::Naive => "Ingênuo (último valor)".to_string(),
+            #[cfg(feature = "python-models")]
+            Candidate::Arima(p, d, q) => format!("ARIMA({},{},{})", p, d, q),
+            #[cfg(feature = "ml")]
+            Candidate::Mlp => "MLP".to_string(),
+            Candidate::Theta => "Theta".to_string(),
+            Candidate::Croston => "Croston (SBA)".to_string(),
+            Candidate::Plugin(i) => plugins[*i].name().to_string(),
+        }
+    }
+
+    pub fn run(&self, plugins: &[Plugin], values: &[f64], steps: usize) -> Result<Vec<f64>, String> {
+        let mut forecast = match self {
+            Candidate::Naive => {
+                let last = values.last().ok_or("Série vazia")?;
+                vec![*last; steps]
+            }
+            #[cfg(feature = "python-models")]
+            Candidate::Arima(p, d, q) => run_arima_model(values, *p, *d, *q).map_err(|e| e.to_string())?,
+            #[cfg(feature = "ml")]
+            Candidate::Mlp => ml::run_mlp_model(values, steps).map_err(|e| e.to_string())?,
+            Candidate::Theta => run_theta_model(values, steps)?,
+            Candidate::Croston => run_croston_model(values, steps)?,
+            Candidate::Plugin(i) => plugins[*i].run(values, steps).map_err(|e| e.to_string())?,
+        };
+
+        if forecast.len() < steps || forecast.iter().any(|v| !v.is_finite()) {
+            return Err(format!("{} não produziu uma previsão válida", self.label(plugins)));
+        }
+        forecast.truncate(steps);
+        Ok(forecast)
+    }
+}
+
+// Modelo escolhido para uma série e sua previsão
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit {
+    pub model: String,
+    // RMSE nas últimas observações, guardadas fora do ajuste; só no modo automático e nas combinações
+    pub holdout_rmse: Option<f64>,
+    pub forecast: Vec<f64>,
+    // Modelos combinados e seus pesos; vazio quando a previsão vem de um único modelo
+    pub components: Vec<Component>,
+}
+
+// Modelo de uma combinação, com o erro fora da amostra que define seu peso
+#[derive(Debug, Clone, PartialEq)]
+pub struct Component {
+    pub model: String,
+    pub holdout_rmse: f64,
+    pub weight: f64,
+}
+
+// Ponderação das previsões combinadas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weighting {
+    // Média simples
+    Equal,
+    // Pesos proporcionais ao inverso do erro quadrático médio fora da amostra
+    InverseError,
+}
+
+impl Weighting {
+    pub fn all() -> [Weighting; 2] {
+        [Weighting::Equal, Weighting::InverseError]
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Weighting::Equal => "equal",
+            Weighting::InverseError => "inverse",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Weighting::Equal => "média simples",
+            Weighting::InverseError => "inverso do erro",
+        }
+    }
+
+    // Pesos somando 1. Modelos sem erro fora da amostra dividem todo o peso no inverso do erro.
+    pub fn weights(&self, rmses: &[f64]) -> Vec<f64> {
+        let raw: Vec<f64> = match self {
+            Weighting::Equal => vec![1.0; rmses.len()],
+            Weighting::InverseError if rmses.contains(&0.0) => {
+                rmses.iter().map(|rmse| if *rmse == 0.0 { 1.0 } else { 0.0 }).collect()
+            }
+            Weighting::InverseError => rmses.iter().map(|rmse| 1.0 / (rmse * rmse)).collect(),
+        };
+
+        let total: f64 = raw.iter().sum();
+        raw.iter().map(|weight| weight / total).collect()
+    }
+}
+
+impl Fit {
+    // Pesos da combinação para exibição, ex.: "ARIMA(1,1,1) 0.62, MLP 0.38"
+    pub fn weights_summary(&self) -> String {
+        self.components
+            .iter()
+            .map(|component| format!("{} {:.2}", component.model, component.weight))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// Sem `auto` usa o modelo padrão. Com ela, guarda as últimas `horizon` observações (no máximo
+// um quarto da série), escolhe o modelo de menor RMSE nelas e o reajusta na série inteira.
+pub fn fit(plugins: &[Plugin], values: &[f64], horizon: usize, auto: bool) -> Result<Fit, String> {
+    fit_with_progress(plugins, values, horizon, auto, &Silent)
+}
+
+// Como `fit`, informando cada candidato avaliado na escolha automática
+pub fn fit_with_progress(
+    plugins: &[Plugin],
+    values: &[f64],
+    horizon: usize,
+    auto: bool,
+    progress: &dyn Reporter,
+) -> Result<Fit, String> {
+    let mut candidates = Candidate::all(plugins, auto);
+    candidates.retain(|candidate| candidate.suits(values));
+
+    let (model, holdout_rmse) = if auto {
+        let holdout = horizon.min(values.len() / 4);
+        if holdout == 0 {
+            return Err(format!("Série curta demais para escolher o modelo ({} observações)", values.len()));
+        }
+        let (train, test) = values.split_at(values.len() - holdout);
+
+        candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(i, candidate)| {
+                let rmse = candidate
+                    .run(plugins, train, holdout)
+                    .ok()
+                    .and_then(|forecast| stats::forecast_metrics(test, &forecast))
+                    .map(|metrics| metrics.rmse)
+                    .filter(|rmse| rmse.is_finite());
+                progress.report(&Progress::new(candidate.label(plugins), i + 1, candidates.len()));
+                rmse.map(|rmse| (*candidate, rmse))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(candidate, rmse)| (candidate, Some(rmse)))
+            .ok_or("Nenhum modelo produziu previsões válidas")?
+    } else {
+        (candidates[0], None)
+    };
+
+    Ok(Fit {
+        model: model.label(plugins),
+        holdout_rmse,
+        forecast: model.run(plugins, values, horizon)?,
+        components: vec![],
+    })
+}
+
+// Média ponderada, passo a passo, das previsões
+fn weighted_sum(forecasts: &[&[f64]], weights: &[f64], len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|step| forecasts.iter().zip(weights).map(|(forecast, weight)| weight * forecast[step]).sum())
+        .collect()
+}
+
+// Combina as previsões de todos os modelos disponíveis, ponderadas pelo erro nas últimas
+// observações
+pub fn ensemble(plugins: &[Plugin], values: &[f64], horizon: usize, weighting: Weighting) -> Result<Fit, String> {
+    ensemble_with_progress(plugins, values, horizon, weighting, &Silent)
+}
+
+// Como `ensemble`, informando cada modelo avaliado. Os erros são medidos nas últimas
+// DEFAULT_HORIZON observações (no máximo um quarto da série), independentemente do horizonte,
+// para que previsões curtas (ex.: um passo no painel) não sejam ponderadas por um único ponto.
+pub fn ensemble_with_progress(
+    plugins: &[Plugin],
+    values: &[f64],
+    horizon: usize,
+    weighting: Weighting,
+    progress: &dyn Reporter,
+) -> Result<Fit, String> {
+    let holdout = DEFAULT_HORIZON.min(values.len() / 4);
+    if holdout == 0 {
+        return Err(format!("Série curta demais para combinar os modelos ({} observações)", values.len()));
+    }
+    let (train, test) = values.split_at(values.len() - holdout);
+    let mut candidates = Candidate::available(plugins);
+    candidates.retain(|candidate| candidate.suits(values));
+
+    // Modelos que produzem previsões válidas fora da amostra e na série inteira
+    let mut members = vec![];
+    for (i, candidate) in candidates.iter().enumerate() {
+        let member = candidate.run(plugins, train, holdout).ok().and_then(|predicted| {
+            let rmse = stats::forecast_metrics(test, &predicted)?.rmse;
+            if !rmse.is_finite() {
+                return None;
+            }
+            let forecast = candidate.run(plugins, values, horizon).ok()?;
+            Some((candidate.label(plugins), rmse, predicted, forecast))
+        });
+        progress.report(&Progress::new(candidate.label(plugins), i + 1, candidates.len()));
+        members.extend(member);
+    }
+    if members.is_empty() {
+        return Err("Nenhum modelo produziu previsões válidas".to_string());
+    }
+
+    let rmses: Vec<f64> = members.iter().map(|(_, rmse, _, _)| *rmse).collect();
+    let weights = weighting.weights(&rmses);
+    let predicted: Vec<&[f64]> = members.iter().map(|(_, _, predicted, _)| predicted.as_slice()).collect();
+    let forecasts: Vec<&[f64]> = members.iter().map(|(_, _, _, forecast)| forecast.as_slice()).collect();
+
+    // O erro da combinação usa as mesmas observações que definiram os pesos; é otimista
+    let holdout_rmse = stats::forecast_metrics(test, &weighted_sum(&predicted, &weights, holdout)).map(|metrics| metrics.rmse);
+
+    Ok(Fit {
+        model: format!("Combinação ({})", weighting.label()),
+        holdout_rmse,
+        forecast: weighted_sum(&forecasts, &weights, horizon),
+        components: members
+            .iter()
+            .zip(&weights)
+            .map(|((model, rmse, _, _), weight)| Component {
+                model: model.clone(),
+                holdout_rmse: *rmse,
+                weight: *weight,
+            })
+            .collect(),
+    })
+}
+
+// Previsão de um indicador de um país, montada com `ForecastRequest::builder()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForecastRequest {
+    pub country: String,
+    pub indicator: Indicator,
+    // Ordem (p, d, q) usada quando o modelo padrão é o ARIMA
+    pub order: (u32, u32, u32),
+    pub horizon: usize,
+    // Escolhe o modelo pelo erro fora da amostra, como `rustfin batch --auto`
+    pub auto: bool,
+}
+
+impl ForecastRequest {
+    pub fn builder() -> ForecastRequestBuilder {
+        ForecastRequestBuilder::default()
+    }
+
+    // Modelo padrão, com a ordem pedida se for o ARIMA
+    pub fn model(&self, plugins: &[Plugin]) -> Candidate {
+        let default = Candidate::available(plugins)[0];
+        #[cfg(feature = "python-models")]
+        if let Candidate::Arima(..) = default {
+            let (p, d, q) = self.order;
+            return Candidate::Arima(p, d, q);
+        }
+        default
+    }
+
+    pub fn forecast(&self, plugins: &[Plugin], values: &[f64]) -> Result<Fit, String> {
+        if self.auto {
+            return fit(plugins, values, self.horizon, true);
+        }
+
+        let model = self.model(plugins);
+        Ok(Fit {
+            model: model.label(plugins),
+            holdout_rmse: None,
+            forecast: model.run(plugins, values, self.horizon)?,
+            components: vec![],
+        })
+    }
+
+    // Busca a série no provedor e a prevê
+    pub async fn run(
+        &self,
+        client: &ApiClient,
+        config: &Config,
+        plugins: &[Plugin],
+    ) -> Result<Fit, Box<dyn std::error::Error>> {
+        let data = get_indicator(client, config, self.indicator, &self.country).await?;
+        let values: Vec<f64> = data.iter().map(|obs| obs.value).collect();
+
+        Ok(self.forecast(plugins, &values)?)
+    }
+}
+
+// Opções não informadas ficam com os padrões da interface: inflação, ARIMA(1,1,1) e 12 passos
+#[derive(Debug, Clone, Default)]
+pub struct ForecastRequestBuilder {
+    country: Option<String>,
+    indicator: Option<Indicator>,
+    order: Option<(u32, u32, u32)>,
+    horizon: Option<usize>,
+    auto: bool,
+}
+
+impl ForecastRequestBuilder {
+    pub fn country(mut self, country: &str) -> Self {
+        self.country = Some(country.trim().to_lowercase());
+        self
+    }
+
+    pub fn indicator(mut self, indicator: Indicator) -> Self {
+        self.indicator = Some(indicator);
+        self
+    }
+
+    pub fn order(mut self, p: u32, d: u32, q: u32) -> Self {
+        self.order = Some((p, d, q));
+        self
+    }
+
+    pub fn horizon(mut self, horizon: usize) -> Self {
+        self.horizon = Some(horizon);
+        self
+    }
+
+    pub fn auto(mut self, auto: bool) -> Self {
+        self.auto = auto;
+        self
+    }
+
+    pub fn build(self) -> Result<ForecastRequest, String> {
+        let country = self.country.filter(|c| !c.is_empty()).ok_or("O país é obrigatório")?;
+        let horizon = self.horizon.unwrap_or(DEFAULT_HORIZON);
+        if horizon == 0 || horizon > FORECAST_STEPS {
+            return Err(format!("O horizonte deve estar entre 1 e {}", FORECAST_STEPS));
+        }
+
+        Ok(ForecastRequest {
+            country,
+            indicator: self.indicator.unwrap_or(Indicator::Inflation),
+            order: self.order.unwrap_or((1, 1, 1)),
+            horizon,
+            auto: self.auto,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_fills_defaults() {
+        let request = ForecastRequest::builder().country(" Brazil ").build().unwrap();
+
+        assert_eq!(request.country, "brazil");
+        assert_eq!(request.indicator, Indicator::Inflation);
+        assert_eq!(request.order, (1, 1, 1));
+        assert_eq!(request.horizon, DEFAULT_HORIZON);
+        assert!(!request.auto);
+    }
+
+    #[test]
+    fn builder_validates_country_and_horizon() {
+        assert!(ForecastRequest::builder().horizon(6).build().is_err());
+        assert!(ForecastRequest::builder().country("chile").horizon(0).build().is_err());
+        assert!(ForecastRequest::builder()
+            .country("chile")
+            .horizon(FORECAST_STEPS + 1)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn inverse_error_weights_favour_accurate_models() {
+        let weights = Weighting::InverseError.weights(&[1.0, 2.0]);
+        assert!((weights[0] - 0.8).abs() < 1e-12);
+        assert!((weights[1] - 0.2).abs() < 1e-12);
+
+        assert_eq!(Weighting::Equal.weights(&[1.0, 2.0, 4.0]), vec![1.0 / 3.0; 3]);
+        assert_eq!(Weighting::InverseError.weights(&[0.5, 0.0]), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn ensemble_lists_weighted_components() {
+        let values: Vec<f64> = (0..48).map(|i| 4.0 + (i as f64 / 6.0).sin()).collect();
+
+        let fit = ensemble(&[], &values, 6, Weighting::InverseError).unwrap();
+
+        assert_eq!(fit.model, "Combinação (inverso do erro)");
+        assert_eq!(fit.forecast.len(), 6);
+        assert!(fit.holdout_rmse.is_some());
+        assert!(fit.components.iter().any(|component| component.model == "Ingênuo (último valor)"));
+        let total: f64 = fit.components.iter().map(|component| component.weight).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(ensemble(&[], &values[..3], 6, Weighting::Equal).is_err());
+    }
+
+    #[test]
+    fn croston_only_joins_intermittent_series() {
+        let smooth: Vec<f64> = (0..48).map(|i| 4.0 + (i as f64 / 6.0).sin()).collect();
+        let intermittent: Vec<f64> = (0..48).map(|i| if i % 4 == 0 { 2.0 + (i % 3) as f64 } else { 0.0 }).collect();
+        let croston = Candidate::Croston.label(&[]);
+
+        let fit = ensemble(&[], &smooth, 6, Weighting::Equal).unwrap();
+        assert!(fit.components.iter().all(|component| component.model != croston));
+
+        let fit = ensemble(&[], &intermittent, 6, Weighting::Equal).unwrap();
+        assert!(fit.components.iter().any(|component| component.model == croston));
+    }
+
+    #[test]
+    fn order_applies_to_the_default_arima() {
+        let request = ForecastRequest::builder()
+            .country("brazil")
+            .indicator(Indicator::Unemployment)
+            .order(2, 1, 2)
+            .horizon(24)
+            .build()
+            .unwrap();
+
+        assert_eq!(request.horizon, 24);
+        // Sem numpy/statsmodels o padrão deixa de ser o ARIMA e a ordem é ignorada
+        let default = Candidate::available(&[])[0];
+        #[cfg(feature = "python-models")]
+        if let Candidate::Arima(..) = default {
+            assert_eq!(request.model(&[]), Candidate::Arima(2, 1, 2));
+            return;
+        }
+        assert_eq!(request.model(&[]), default);
+    }
+}
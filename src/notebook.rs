@@ -0,0 +1,260 @@
+use std::fmt;
+
+use crate::blocking;
+use crate::cache;
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{Indicator, InflationData};
+use crate::models::{self, Candidate, Fit, ForecastRequest, Weighting};
+use crate::plugins::{self, Plugin};
+use crate::progress;
+use crate::rng;
+
+// Linhas exibidas no início e no fim de tabelas longas, como no pandas
+const PREVIEW_ROWS: usize = 5;
+
+// Representação rica no evcxr (kernel Jupyter para Rust). O kernel chama `evcxr_display`
+// no valor da última expressão da célula; use `use rustfin::notebook::*;` para trazê-lo ao escopo.
+pub trait Html {
+    fn to_html(&self) -> String;
+
+    fn evcxr_display(&self) {
+        println!("EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT", self.to_html());
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+// Índices das linhas exibidas; None marca a elipse entre o início e o fim
+fn preview(len: usize) -> Vec<Option<usize>> {
+    if len <= 2 * PREVIEW_ROWS {
+        return (0..len).map(Some).collect();
+    }
+
+    (0..PREVIEW_ROWS)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((len - PREVIEW_ROWS..len).map(Some))
+        .collect()
+}
+
+fn html_table(caption: &str, header: (&str, &str), rows: &[(String, f64)]) -> String {
+    let mut html = format!(
+        "<table><caption>{}</caption><thead><tr><th>{}</th><th>{}</th></tr></thead><tbody>",
+        escape_html(caption),
+        header.0,
+        header.1
+    );
+    for row in preview(rows.len()) {
+        match row {
+            Some(i) => html.push_str(&format!("<tr><td>{}</td><td>{:.2}</td></tr>", escape_html(&rows[i].0), rows[i].1)),
+            None => html.push_str("<tr><td>…</td><td>…</td></tr>"),
+        }
+    }
+    html.push_str("</tbody></table>");
+    html
+}
+
+fn text_table(f: &mut fmt::Formatter, rows: &[(String, f64)]) -> fmt::Result {
+    for row in preview(rows.len()) {
+        match row {
+            Some(i) => writeln!(f, "{:<12} {:>10.2}", rows[i].0, rows[i].1)?,
+            None => writeln!(f, "{:<12} {:>10}", "...", "...")?,
+        }
+    }
+    Ok(())
+}
+
+// Série nomeada em ordem cronológica, como pares (data, valor)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub name: String,
+    pub points: Vec<(String, f64)>,
+}
+
+impl Series {
+    pub fn new(name: &str, observations: &[InflationData]) -> Self {
+        Self {
+            name: name.to_string(),
+            points: observations.iter().map(|obs| (obs.date.clone(), obs.value)).collect(),
+        }
+    }
+
+    pub fn values(&self) -> Vec<f64> {
+        self.points.iter().map(|(_, value)| *value).collect()
+    }
+}
+
+impl fmt::Display for Series {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} ({} observações)", self.name, self.points.len())?;
+        text_table(f, &self.points)
+    }
+}
+
+impl Html for Series {
+    fn to_html(&self) -> String {
+        let caption = format!("{} ({} observações)", self.name, self.points.len());
+        html_table(&caption, ("Data", "Valor"), &self.points)
+    }
+}
+
+fn steps(fit: &Fit) -> Vec<(String, f64)> {
+    fit.forecast.iter().enumerate().map(|(h, v)| ((h + 1).to_string(), *v)).collect()
+}
+
+fn fit_caption(fit: &Fit) -> String {
+    let mut caption = match fit.holdout_rmse {
+        Some(rmse) => format!("Previsão: {} (RMSE fora da amostra {:.3})", fit.model, rmse),
+        None => format!("Previsão: {}", fit.model),
+    };
+    if !fit.components.is_empty() {
+        caption.push_str(&format!("; pesos: {}", fit.weights_summary()));
+    }
+    caption
+}
+
+impl fmt::Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", fit_caption(self))?;
+        text_table(f, &steps(self))
+    }
+}
+
+impl Html for Fit {
+    fn to_html(&self) -> String {
+        html_table(&fit_caption(self), ("Passo", "Valor"), &steps(self))
+    }
+}
+
+// Uso síncrono da biblioteca em notebooks: guarda a configuração e os plugins; as buscas
+// passam por `blocking`, sem depender da interface gráfica
+pub struct Session {
+    pub config: Config,
+    plugins: Vec<Plugin>,
+}
+
+impl Session {
+    // Mesma configuração da interface (arquivo salvo, .env ou API_TOKEN)
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let config = Config::load().ok_or("Configuração não encontrada; defina API_TOKEN no ambiente ou no .env")?;
+        ApiClient::configure(&config)?;
+        cache::configure(&config);
+        Ok(Self::with_config(config))
+    }
+
+    pub fn with_config(config: Config) -> Self {
+        #[cfg(feature = "python-models")]
+        crate::arima::configure(&config);
+        rng::set_global_seed(config.seed);
+
+        Self {
+            config,
+            plugins: plugins::discover_plugins(&plugins::plugins_dir()),
+        }
+    }
+
+    pub fn series(&self, indicator: Indicator, country: &str) -> Result<Series, Box<dyn std::error::Error>> {
+        let data = blocking::fetch_series(&self.config, indicator, country)?;
+        Ok(Series::new(&format!("{}_{}", indicator.key(), country), &data))
+    }
+
+    pub fn inflation(&self, country: &str) -> Result<Series, Box<dyn std::error::Error>> {
+        self.series(Indicator::Inflation, country)
+    }
+
+    // Nomes dos modelos disponíveis, na ordem da interface; o primeiro é o padrão
+    pub fn models(&self) -> Vec<String> {
+        Candidate::available(&self.plugins)
+            .iter()
+            .map(|candidate| candidate.label(&self.plugins))
+            .collect()
+    }
+
+    pub fn forecast(&self, series: &Series, steps: usize) -> Result<Fit, String> {
+        models::fit(&self.plugins, &series.values(), steps, false)
+    }
+
+    // Escolhe o modelo pelo erro fora da amostra, como `rustfin batch --auto`; o andamento
+    // da busca aparece no stderr da célula
+    pub fn auto_forecast(&self, series: &Series, steps: usize) -> Result<Fit, String> {
+        models::fit_with_progress(&self.plugins, &series.values(), steps, true, &progress::Terminal)
+    }
+
+    // Combina as previsões de todos os modelos; os pesos aparecem na legenda
+    pub fn ensemble(&self, series: &Series, steps: usize, weighting: Weighting) -> Result<Fit, String> {
+        models::ensemble_with_progress(&self.plugins, &series.values(), steps, weighting, &progress::Terminal)
+    }
+
+    // Busca e prevê em um passo, ex.: ForecastRequest::builder().country("chile").horizon(24).build()?
+    pub fn run(&self, request: &ForecastRequest) -> Result<Fit, Box<dyn std::error::Error>> {
+        blocking::forecast(&self.config, request, &self.plugins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(len: usize) -> Series {
+        Series {
+            name: "inflation_<teste>".to_string(),
+            points: (0..len).map(|i| (format!("2020-{:02}", i + 1), i as f64)).collect(),
+        }
+    }
+
+    #[test]
+    fn long_tables_show_head_and_tail() {
+        let text = series(12).to_string();
+
+        assert!(text.starts_with("inflation_<teste> (12 observações)\n"));
+        assert!(text.contains("2020-05"));
+        assert!(!text.contains("2020-06"));
+        assert!(text.contains("..."));
+        assert!(text.contains("2020-12"));
+        assert_eq!(series(3).to_string().lines().count(), 4);
+    }
+
+    #[test]
+    fn html_escapes_names() {
+        let html = series(2).to_html();
+
+        assert!(html.contains("<caption>inflation_&lt;teste&gt; (2 observações)</caption>"));
+        assert!(html.contains("<tr><td>2020-02</td><td>1.00</td></tr>"));
+    }
+
+    #[test]
+    fn fit_caption_includes_holdout_error() {
+        let fit = Fit {
+            model: "ARIMA(1,1,1)".to_string(),
+            holdout_rmse: Some(0.25),
+            forecast: vec![4.0, 4.5],
+            components: vec![],
+        };
+
+        assert!(fit.to_html().contains("RMSE fora da amostra 0.250"));
+        let last = fit.to_string().lines().last().unwrap().to_string();
+        assert_eq!(last.split_whitespace().collect::<Vec<_>>(), vec!["2", "4.50"]);
+    }
+
+    #[test]
+    fn ensemble_caption_lists_weights() {
+        let component = |model: &str, weight: f64| models::Component {
+            model: model.to_string(),
+            holdout_rmse: 0.1,
+            weight,
+        };
+        let fit = Fit {
+            model: "Combinação (média simples)".to_string(),
+            holdout_rmse: None,
+            forecast: vec![4.0],
+            components: vec![component("MLP", 0.5), component("Ingênuo (último valor)", 0.5)],
+        };
+
+        assert!(fit
+            .to_string()
+            .starts_with("Previsão: Combinação (média simples); pesos: MLP 0.50, Ingênuo (último valor) 0.50\n"));
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::data::date_sort_key;
+use crate::stats;
+
+const MAX_SWEEPS: usize = 100;
+
+// Autovalores e autovetores (colunas de `vectors`) de uma matriz simétrica pelo método de Jacobi
+fn jacobi_eigen(mut a: Vec<Vec<f64>>) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = a.len();
+    let mut vectors: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |j| *j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j].powi(2))
+            .sum();
+        if off_diagonal < 1e-22 {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() < f64::MIN_POSITIVE {
+                    continue;
+                }
+
+                // Rotação que zera a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut().chain(vectors.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (row_p, row_q) = (a[p].clone(), a[q].clone());
+                for (k, (x, y)) in row_p.iter().zip(&row_q).enumerate() {
+                    a[p][k] = c * x - s * y;
+                    a[q][k] = s * x + c * y;
+                }
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[i][i]).collect(), vectors)
+}
+
+// Componentes principais de um painel de séries, em ordem decrescente de variância explicada
+#[derive(Debug, Clone)]
+pub struct Pca {
+    pub names: Vec<String>,
+    // Datas comuns a todas as séries, em ordem cronológica
+    pub dates: Vec<String>,
+    // Fração da variância total explicada por cada componente
+    pub explained: Vec<f64>,
+    // loadings[k][i]: peso da série i no componente k
+    pub loadings: Vec<Vec<f64>>,
+    // scores[k][t]: valor do fator k na data t
+    pub scores: Vec<Vec<f64>>,
+}
+
+// PCA sobre a matriz de correlação (séries padronizadas), usando só as datas presentes em
+// todas as séries. O sinal de cada componente é escolhido para que a soma dos pesos seja
+// positiva: o primeiro fator de um painel de inflação sobe quando a inflação geral sobe.
+pub fn pca(series: &BTreeMap<String, Vec<(String, f64)>>) -> Result<Pca, String> {
+    if series.len() < 2 {
+        return Err("Selecione ao menos duas séries".to_string());
+    }
+
+    let names: Vec<String> = series.keys().cloned().collect();
+    let lookups: Vec<HashMap<&str, f64>> = series
+        .values()
+        .map(|values| values.iter().map(|(date, value)| (date.as_str(), *value)).collect())
+        .collect();
+
+    let mut dates: Vec<String> = series
+        .values()
+        .next()
+        .into_iter()
+        .flatten()
+        .map(|(date, _)| date.clone())
+        .filter(|date| lookups.iter().all(|lookup| lookup.contains_key(date.as_str())))
+        .collect();
+    dates.sort_by_key(|date| date_sort_key(date));
+    dates.dedup();
+
+    if dates.len() <= names.len() {
+        return Err(format!(
+            "Poucas datas em comum ({}) para {} séries",
+            dates.len(),
+            names.len()
+        ));
+    }
+
+    // Colunas padronizadas
+    let mut columns = Vec::with_capacity(names.len());
+    for (name, lookup) in names.iter().zip(&lookups) {
+        let values: Vec<f64> = dates.iter().map(|date| lookup[date.as_str()]).collect();
+        let (mean, sd) = (stats::mean(&values), stats::std_dev(&values));
+        if sd == 0.0 {
+            return Err(format!("A série {} é constante no período comum", name));
+        }
+        columns.push(values.iter().map(|v| (v - mean) / sd).collect::<Vec<f64>>());
+    }
+
+    let n = dates.len() as f64;
+    let correlation: Vec<Vec<f64>> = columns
+        .iter()
+        .map(|a| columns.iter().map(|b| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>() / n).collect())
+        .collect();
+
+    let (eigenvalues, vectors) = jacobi_eigen(correlation);
+    let total: f64 = eigenvalues.iter().sum();
+
+    let mut order: Vec<usize> = (0..eigenvalues.len()).collect();
+    order.sort_by(|a, b| eigenvalues[*b].total_cmp(&eigenvalues[*a]));
+
+    let mut explained = Vec::new();
+    let mut loadings = Vec::new();
+    let mut scores = Vec::new();
+    for k in order {
+        let mut loading: Vec<f64> = vectors.iter().map(|row| row[k]).collect();
+        if loading.iter().sum::<f64>() < 0.0 {
+            loading.iter_mut().for_each(|w| *w = -*w);
+        }
+
+        let score = (0..dates.len())
+            .map(|t| columns.iter().zip(&loading).map(|(column, w)| column[t] * w).sum())
+            .collect();
+
+        explained.push(eigenvalues[k].max(0.0) / total);
+        loadings.push(loading);
+        scores.push(score);
+    }
+
+    Ok(Pca {
+        names,
+        dates,
+        explained,
+        loadings,
+        scores,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jacobi_diagonalizes_symmetric_matrix() {
+        let (mut values, _) = jacobi_eigen(vec![vec![2.0, 1.0, 0.0], vec![1.0, 2.0, 1.0], vec![0.0, 1.0, 2.0]]);
+        values.sort_by(f64::total_cmp);
+
+        let expected = [2.0 - 2f64.sqrt(), 2.0, 2.0 + 2f64.sqrt()];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn common_factor_dominates_co_moving_panel() {
+        // Três séries guiadas pelo mesmo fator, com ruídos distintos
+        let factor: Vec<f64> = (0..36).map(|t| (t as f64 / 4.0).sin() * 3.0).collect();
+        let mut panel = BTreeMap::new();
+        for (i, name) in ["a", "b", "c"].iter().enumerate() {
+            let values = factor
+                .iter()
+                .enumerate()
+                .map(|(t, f)| {
+                    let noise = ((t * (i + 2)) % 5) as f64 * 0.05;
+                    (format!("01/{:02}/{}", t % 12 + 1, 2020 + t / 12), f + noise + i as f64)
+                })
+                .collect();
+            panel.insert(name.to_string(), values);
+        }
+
+        let result = pca(&panel).unwrap();
+
+        assert_eq!(result.dates.len(), 36);
+        assert!(result.explained[0] > 0.95);
+        assert!(result.loadings[0].iter().all(|w| *w > 0.0));
+        assert!((result.explained.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+}
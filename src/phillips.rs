@@ -0,0 +1,29 @@
+use crate::data::{date_sort_key, InflationData};
+
+// Ponto da curva de Phillips: desemprego (x) contra inflação (y) na mesma data
+#[derive(Debug, Clone)]
+pub struct PhillipsPoint {
+    pub date: String,
+    pub unemployment: f64,
+    pub inflation: f64,
+}
+
+// Pareia as duas séries pelas datas em comum, em ordem cronológica
+pub fn align(inflation: &[(String, f64)], unemployment: &[InflationData]) -> Vec<PhillipsPoint> {
+    let mut points: Vec<PhillipsPoint> = unemployment
+        .iter()
+        .filter_map(|u| {
+            inflation
+                .iter()
+                .find(|(date, _)| *date == u.date)
+                .map(|(_, value)| PhillipsPoint {
+                    date: u.date.clone(),
+                    unemployment: u.value,
+                    inflation: *value,
+                })
+        })
+        .collect();
+
+    points.sort_by_key(|point| date_sort_key(&point.date));
+    points
+}
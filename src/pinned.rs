@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// Previsão guardada para comparação com os dados que chegarem depois
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PinnedForecast {
+    pub series: String,
+    pub model: String,
+    // Momento em que foi fixada (segundos Unix)
+    pub pinned_at: u64,
+    // Última data observada quando foi feita; o primeiro passo é o período seguinte
+    pub last_date: Option<String>,
+    pub values: Vec<f64>,
+    // Etiquetas da previsão (ex.: "q3-report"); a série tem as suas em `tags`
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+pub fn pinned_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("pinned.json"))
+}
+
+pub fn load() -> Vec<PinnedForecast> {
+    pinned_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(pinned: &[PinnedForecast]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = pinned_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(pinned)?)?;
+
+    Ok(())
+}
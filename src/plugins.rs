@@ -0,0 +1,103 @@
+use libloading::Library;
+use std::ffi::{c_char, c_void, CStr};
+use std::path::{Path, PathBuf};
+
+// Interface C esperada de cada plugin (cdylib):
+//   const char* rustfin_plugin_name(void);
+//   void*       rustfin_fit(const double* values, size_t len);
+//   int         rustfin_forecast(void* model, size_t steps, double* out);
+//   void        rustfin_free(void* model);
+// rustfin_fit retorna NULL em caso de falha e rustfin_forecast retorna 0 em caso de sucesso.
+type NameFn = unsafe extern "C" fn() -> *const c_char;
+type FitFn = unsafe extern "C" fn(*const f64, usize) -> *mut c_void;
+type ForecastFn = unsafe extern "C" fn(*mut c_void, usize, *mut f64) -> i32;
+type FreeFn = unsafe extern "C" fn(*mut c_void);
+
+pub struct Plugin {
+    name: String,
+    library: Library,
+}
+
+impl Plugin {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        unsafe {
+            let library = Library::new(path)?;
+
+            // Garante que todos os símbolos existem antes de aceitar o plugin
+            library.get::<FitFn>(b"rustfin_fit\0")?;
+            library.get::<ForecastFn>(b"rustfin_forecast\0")?;
+            library.get::<FreeFn>(b"rustfin_free\0")?;
+
+            let name_fn = library.get::<NameFn>(b"rustfin_plugin_name\0")?;
+            let name_ptr = name_fn();
+            if name_ptr.is_null() {
+                return Err("rustfin_plugin_name retornou NULL".into());
+            }
+            let name = CStr::from_ptr(name_ptr).to_string_lossy().into_owned();
+
+            Ok(Self { name, library })
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn run(&self, values: &[f64], steps: usize) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+        unsafe {
+            let fit = self.library.get::<FitFn>(b"rustfin_fit\0")?;
+            let forecast = self.library.get::<ForecastFn>(b"rustfin_forecast\0")?;
+            let free = self.library.get::<FreeFn>(b"rustfin_free\0")?;
+
+            let model = fit(values.as_ptr(), values.len());
+            if model.is_null() {
+                return Err(format!("Plugin {} falhou ao ajustar o modelo", self.name).into());
+            }
+
+            let mut out = vec![0.0; steps];
+            let status = forecast(model, steps, out.as_mut_ptr());
+            free(model);
+
+            if status != 0 {
+                return Err(format!("Plugin {} falhou ao prever (código {})", self.name, status).into());
+            }
+
+            Ok(out)
+        }
+    }
+}
+
+// Diretório padrão dos plugins, podendo ser sobrescrito por PLUGINS_DIR
+pub fn plugins_dir() -> PathBuf {
+    std::env::var("PLUGINS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("plugins"))
+}
+
+pub fn discover_plugins(dir: &Path) -> Vec<Plugin> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let mut plugins = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_library = path
+            .extension()
+            .map(|ext| ext == std::env::consts::DLL_EXTENSION)
+            .unwrap_or(false);
+
+        if !is_library {
+            continue;
+        }
+
+        match Plugin::load(&path) {
+            Ok(plugin) => plugins.push(plugin),
+            Err(e) => eprintln!("Erro ao carregar plugin {}: {}", path.display(), e),
+        }
+    }
+
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    plugins
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{self, Indicator, InflationData};
+use crate::quota::{self, QuotaState, QuotaUsage};
+
+// Tempo em que uma série buscada antecipadamente é servida sem nova requisição
+pub const MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+// Séries por provedor (url_base), indicador e país, com o momento da busca
+type Key = (String, Indicator, String);
+type Entries = HashMap<Key, (Instant, Vec<InflationData>)>;
+static STORE: Mutex<Option<Entries>> = Mutex::new(None);
+
+fn key(config: &Config, indicator: Indicator, country: &str) -> Key {
+    (config.url_base.clone(), indicator, country.to_string())
+}
+
+fn stored(config: &Config, indicator: Indicator, country: &str) -> Option<Vec<InflationData>> {
+    let store = STORE.lock().unwrap_or_else(|e| e.into_inner());
+    let (fetched_at, series) = store.as_ref()?.get(&key(config, indicator, country))?;
+    (fetched_at.elapsed() < MAX_AGE).then(|| series.clone())
+}
+
+fn store(config: &Config, indicator: Indicator, country: &str, series: &[InflationData]) {
+    let mut store = STORE.lock().unwrap_or_else(|e| e.into_inner());
+    let entries = store.get_or_insert_with(HashMap::new);
+    entries.retain(|_, (fetched_at, _)| fetched_at.elapsed() < MAX_AGE);
+    entries.insert(key(config, indicator, country), (Instant::now(), series.to_vec()));
+}
+
+// Buscas antecipadas só com a opção ligada e longe do limite diário: a partir do aviso de 80%,
+// as requisições restantes ficam para o que o usuário pedir
+pub fn allowed(config: &Config) -> bool {
+    allowed_with(config, &quota::current())
+}
+
+fn allowed_with(config: &Config, usage: &QuotaUsage) -> bool {
+    config.prefetch && usage.state(config) == QuotaState::Ok
+}
+
+// Série de um indicador em ordem cronológica, como `data::get_indicator`, servida da busca
+// antecipada quando recente; a série buscada fica guardada para as próximas trocas
+pub async fn get_indicator(
+    client: &ApiClient,
+    config: &Config,
+    indicator: Indicator,
+    country: &str,
+) -> Result<Vec<InflationData>, Box<dyn std::error::Error>> {
+    if let Some(series) = stored(config, indicator, country) {
+        return Ok(series);
+    }
+
+    let series = data::get_indicator(client, config, indicator, country).await?;
+    store(config, indicator, country, &series);
+    Ok(series)
+}
+
+// Busca em segundo plano os demais indicadores de um país recém-escolhido, para a troca de
+// indicador ser imediata. Para ao atingir o aviso de cota. Devolve as falhas, que não impedem
+// nada: o indicador é buscado de novo quando pedido.
+pub async fn prefetch_country(client: &ApiClient, config: &Config, country: &str, except: Indicator) -> Vec<String> {
+    // A busca antecipada nunca passa do limite, mesmo que o usuário tenha optado por ignorá-lo
+    let config = Config {
+        ignore_quota: false,
+        ..config.clone()
+    };
+
+    let mut failures = Vec::new();
+    for indicator in Indicator::all().into_iter().filter(|indicator| *indicator != except) {
+        if !allowed(&config) {
+            break;
+        }
+        if stored(&config, indicator, country).is_some() {
+            continue;
+        }
+        if let Err(e) = get_indicator(client, &config, indicator, country).await {
+            failures.push(format!("Erro na busca antecipada de {}_{}: {}", indicator.key(), country, e));
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serves_stored_series_per_provider() {
+        let config = Config {
+            url_base: "http://prefetch.test/".to_string(),
+            ..Config::default()
+        };
+        let series = vec![InflationData {
+            date: "01/01/2024".to_string(),
+            value: 7.5,
+        }];
+
+        assert!(stored(&config, Indicator::Unemployment, "chile").is_none());
+        store(&config, Indicator::Unemployment, "chile", &series);
+        assert_eq!(stored(&config, Indicator::Unemployment, "chile").unwrap()[0].value, 7.5);
+        assert!(stored(&config, Indicator::Inflation, "chile").is_none());
+
+        let other = Config {
+            url_base: "http://outro.test/".to_string(),
+            ..Config::default()
+        };
+        assert!(stored(&other, Indicator::Unemployment, "chile").is_none());
+    }
+
+    #[test]
+    fn respects_the_option_and_the_quota_limit() {
+        let disabled = Config {
+            prefetch: false,
+            ..Config::default()
+        };
+        assert!(!allowed(&disabled));
+
+        let unlimited = Config {
+            daily_request_limit: 0,
+            ..Config::default()
+        };
+        assert!(allowed(&unlimited));
+
+        let limited = Config {
+            daily_request_limit: 100,
+            ..Config::default()
+        };
+        let usage = |requests| QuotaUsage { day: 0, requests };
+        assert!(allowed_with(&limited, &usage(79)));
+        // Aviso de 80% e limite atingido
+        assert!(!allowed_with(&limited, &usage(80)));
+        assert!(!allowed_with(&limited, &usage(100)));
+    }
+}
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::scale::Scale;
+
+// Configuração de modelagem salva com um nome: transformações, modelo, ordem e horizonte
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Preset {
+    pub name: String,
+    // Modelo da interface: arima, mlp, theta, croston, ensemble-equal, ensemble-inverse ou o nome
+    // do plugin
+    pub model: String,
+    pub order: (u32, u32, u32),
+    pub horizon: usize,
+    pub seasonal_adjust: bool,
+    pub box_cox: bool,
+    // Escala do modelo; predefinições antigas não a têm e ficam no nível
+    #[serde(default)]
+    pub scale: Scale,
+    // Ajusta só no último regime após a quebra estrutural mais recente
+    pub latest_regime: bool,
+}
+
+// Predefinições e a padrão de cada série, indexada pelo nome da série (indicador_país, ex.:
+// inflation_brazil, ou o nome da série derivada)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Presets {
+    pub presets: Vec<Preset>,
+    pub defaults: BTreeMap<String, String>,
+}
+
+pub fn presets_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("presets.json"))
+}
+
+impl Presets {
+    pub fn load() -> Self {
+        presets_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = presets_path().ok_or("Diretório de configuração não encontrado")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Preset> {
+        self.presets.iter().find(|preset| preset.name == name)
+    }
+
+    // Substitui a predefinição de mesmo nome, mantendo as séries que a usam como padrão
+    pub fn upsert(&mut self, preset: Preset) {
+        match self.presets.iter_mut().find(|existing| existing.name == preset.name) {
+            Some(existing) => *existing = preset,
+            None => {
+                self.presets.push(preset);
+                self.presets.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+    }
+
+    // Remove a predefinição e deixa sem padrão as séries que a usavam
+    pub fn remove(&mut self, name: &str) {
+        self.presets.retain(|preset| preset.name != name);
+        self.defaults.retain(|_, preset| preset != name);
+    }
+
+    pub fn bind(&mut self, series: &str, name: &str) -> Result<(), String> {
+        if self.get(name).is_none() {
+            return Err(format!("Predefinição {} não encontrada", name));
+        }
+        self.defaults.insert(series.to_string(), name.to_string());
+        Ok(())
+    }
+
+    pub fn unbind(&mut self, series: &str) {
+        self.defaults.remove(series);
+    }
+
+    // Predefinição aplicada ao abrir a série
+    pub fn default_for(&self, series: &str) -> Option<&Preset> {
+        self.defaults.get(series).and_then(|name| self.get(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(name: &str, model: &str) -> Preset {
+        Preset {
+            name: name.to_string(),
+            model: model.to_string(),
+            order: (1, 1, 1),
+            horizon: 12,
+            seasonal_adjust: false,
+            box_cox: true,
+            scale: Scale::Levels,
+            latest_regime: false,
+        }
+    }
+
+    #[test]
+    fn upsert_replaces_and_keeps_bindings() {
+        let mut presets = Presets::default();
+        presets.upsert(preset("mensal", "arima"));
+        presets.bind("inflation_brazil", "mensal").unwrap();
+
+        presets.upsert(preset("mensal", "theta"));
+
+        assert_eq!(presets.presets.len(), 1);
+        assert_eq!(presets.default_for("inflation_brazil").unwrap().model, "theta");
+        assert!(presets.default_for("inflation_chile").is_none());
+        assert!(presets.bind("inflation_chile", "anual").is_err());
+    }
+
+    #[test]
+    fn remove_drops_bindings() {
+        let mut presets = Presets::default();
+        presets.upsert(preset("mensal", "arima"));
+        presets.upsert(preset("curto", "croston"));
+        presets.bind("inflation_brazil", "mensal").unwrap();
+        presets.bind("unemployment_brazil", "curto").unwrap();
+
+        presets.remove("mensal");
+
+        assert!(presets.default_for("inflation_brazil").is_none());
+        assert_eq!(presets.defaults.len(), 1);
+
+        let json = serde_json::to_string(&presets).unwrap();
+        assert_eq!(serde_json::from_str::<Presets>(&json).unwrap(), presets);
+    }
+}
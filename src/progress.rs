@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+
+const BAR_WIDTH: usize = 30;
+
+// Andamento de uma operação longa: `done` de `total` etapas; `stage` descreve a atual
+// (país buscado, modelo avaliado, método do backtest...)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    pub stage: String,
+    pub done: usize,
+    pub total: usize,
+}
+
+impl Progress {
+    pub fn new(stage: impl Into<String>, done: usize, total: usize) -> Self {
+        Self {
+            stage: stage.into(),
+            done,
+            total,
+        }
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        self.done as f32 / self.total as f32
+    }
+
+    pub fn finished(&self) -> bool {
+        self.done >= self.total
+    }
+}
+
+// Quem acompanha a operação: barra da interface, saída no terminal ou um closure.
+// Pode ser chamado de outras threads (ex.: dentro de spawn_blocking).
+pub trait Reporter: Send + Sync {
+    fn report(&self, progress: &Progress);
+}
+
+impl<F: Fn(&Progress) + Send + Sync> Reporter for F {
+    fn report(&self, progress: &Progress) {
+        self(progress)
+    }
+}
+
+// Descarta o andamento
+pub struct Silent;
+
+impl Reporter for Silent {
+    fn report(&self, _: &Progress) {}
+}
+
+// Guarda o último andamento para a interface ler a cada quadro
+#[derive(Debug, Clone, Default)]
+pub struct Shared(Arc<Mutex<Option<Progress>>>);
+
+impl Shared {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self) -> Option<Progress> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl Reporter for Shared {
+    fn report(&self, progress: &Progress) {
+        *self.0.lock().unwrap() = Some(progress.clone());
+    }
+}
+
+// Barra no stderr, reescrita na mesma linha e encerrada ao fim da operação
+pub struct Terminal;
+
+impl Reporter for Terminal {
+    fn report(&self, progress: &Progress) {
+        eprint!("\r{} {:<20}", bar(progress.done, progress.total), progress.stage);
+        if progress.finished() {
+            eprintln!();
+        }
+    }
+}
+
+pub fn bar(done: usize, total: usize) -> String {
+    let filled = (done.min(total) * BAR_WIDTH).checked_div(total).unwrap_or(BAR_WIDTH);
+    format!("[{}{}] {}/{}", "#".repeat(filled), "-".repeat(BAR_WIDTH - filled), done, total)
+}
+
+// Em laços longos, informa só a cada ~1% das etapas (e na última)
+pub fn should_report(done: usize, total: usize) -> bool {
+    done == total || done.is_multiple_of((total / 100).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar_fills_proportionally() {
+        assert_eq!(bar(1, 2), format!("[{}{}] 1/2", "#".repeat(15), "-".repeat(15)));
+        assert_eq!(bar(0, 0), format!("[{}] 0/0", "#".repeat(30)));
+    }
+
+    #[test]
+    fn shared_keeps_the_latest_report() {
+        let shared = Shared::new();
+        let reporter: &dyn Reporter = &shared;
+
+        reporter.report(&Progress::new("brazil", 1, 4));
+        reporter.report(&Progress::new("chile", 2, 4));
+
+        assert_eq!(shared.get(), Some(Progress::new("chile", 2, 4)));
+        assert_eq!(shared.get().unwrap().fraction(), 0.5);
+    }
+
+    #[test]
+    fn long_loops_report_about_a_hundred_times() {
+        let reports = (1..=1000).filter(|done| should_report(*done, 1000)).count();
+
+        assert_eq!(reports, 100);
+        assert!(should_report(7, 7));
+    }
+}
@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::annotations::{self, Annotation};
+use crate::export::export_dir;
+use crate::pinned::{self, PinnedForecast};
+use crate::presets::{Preset, Presets};
+use crate::tags::Tags;
+use crate::watchlist::{self, WatchItem};
+
+// Versão do formato dos projetos; arquivos de versões mais novas são recusados
+pub const FORMAT_VERSION: u32 = 1;
+pub const EXTENSION: &str = "rustfin";
+
+// Estado da tela salvo no projeto
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Layout {
+    // Visão aberta (ex.: "forecast"); visões desconhecidas abrem o painel
+    pub view: String,
+    // País da série do provedor
+    pub country: String,
+    // Série da previsão: None para a do provedor
+    pub series: Option<String>,
+    pub settings: Preset,
+}
+
+// Análise completa em um único arquivo, para ser compartilhada: séries acompanhadas, previsões
+// fixadas, anotações, predefinições, etiquetas e o estado da tela
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    pub format: u32,
+    pub watchlist: Vec<WatchItem>,
+    pub pinned: Vec<PinnedForecast>,
+    pub annotations: BTreeMap<String, Vec<Annotation>>,
+    pub presets: Presets,
+    // Projetos anteriores às etiquetas abrem sem nenhuma
+    #[serde(default)]
+    pub tags: Tags,
+    pub layout: Layout,
+}
+
+// Caminho sugerido para um novo projeto
+pub fn default_path() -> PathBuf {
+    export_dir().join(format!("projeto.{}", EXTENSION))
+}
+
+impl Project {
+    // Reúne o que está salvo no diretório de configuração com o estado da tela
+    pub fn capture(layout: Layout) -> Self {
+        Self {
+            format: FORMAT_VERSION,
+            watchlist: watchlist::load(&layout.country),
+            pinned: pinned::load(),
+            annotations: annotations::load_all(),
+            presets: Presets::load(),
+            tags: Tags::load(),
+            layout,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let project: Self = serde_json::from_str(json)?;
+        if project.format > FORMAT_VERSION {
+            return Err(format!(
+                "Projeto salvo no formato {}, mais novo que o suportado ({}); atualize o RustFin",
+                project.format, FORMAT_VERSION
+            )
+            .into());
+        }
+        Ok(project)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    // Substitui no diretório de configuração as séries acompanhadas, as previsões fixadas, as
+    // anotações, as predefinições e as etiquetas pelas do projeto; o estado da tela fica com a interface
+    pub fn install(&self) -> Result<(), Box<dyn std::error::Error>> {
+        watchlist::save(&self.watchlist)?;
+        pinned::save(&self.pinned)?;
+        annotations::save_all(&self.annotations)?;
+        self.tags.save()?;
+        self.presets.save()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Indicator;
+    use crate::scale::Scale;
+
+    fn project() -> Project {
+        let mut presets = Presets::default();
+        presets.upsert(Preset {
+            name: "mensal".to_string(),
+            model: "theta".to_string(),
+            order: (1, 1, 1),
+            horizon: 12,
+            seasonal_adjust: true,
+            box_cox: false,
+            scale: Scale::Logs,
+            latest_regime: false,
+        });
+        presets.bind("inflation_brazil", "mensal").unwrap();
+
+        Project {
+            format: FORMAT_VERSION,
+            watchlist: vec![WatchItem {
+                country: "brazil".to_string(),
+                indicator: Indicator::Inflation,
+            }],
+            pinned: vec![PinnedForecast {
+                series: "inflation_brazil".to_string(),
+                model: "Theta".to_string(),
+                pinned_at: 1_700_000_000,
+                last_date: Some("01/12/2023".to_string()),
+                values: vec![4.5, 4.4],
+                tags: vec!["q3-report".to_string()],
+            }],
+            annotations: BTreeMap::from([(
+                "brazil".to_string(),
+                vec![Annotation {
+                    date: "01/10/2022".to_string(),
+                    label: "Eleição".to_string(),
+                }],
+            )]),
+            tags: {
+                let mut tags = Tags::default();
+                tags.add("inflation_brazil", "latam");
+                tags
+            },
+            layout: Layout {
+                view: "forecast".to_string(),
+                country: "brazil".to_string(),
+                series: None,
+                settings: presets.presets[0].clone(),
+            },
+            presets,
+        }
+    }
+
+    #[test]
+    fn project_round_trips_through_json() {
+        let project = project();
+
+        let restored = Project::from_json(&project.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored, project);
+    }
+
+    #[test]
+    fn newer_formats_are_rejected() {
+        let mut project = project();
+        project.format = FORMAT_VERSION + 1;
+
+        let error = Project::from_json(&project.to_json().unwrap()).unwrap_err();
+
+        assert!(error.to_string().contains("mais novo"));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::diagnostics::difference;
+use crate::rng::SeededRng;
+use crate::stats::std_dev;
+
+pub const SIMULATIONS: usize = 1000;
+// Persistência mensal do desvio da inflação simulada em relação à prevista: choques duram meses,
+// mas a trajetória não se afasta indefinidamente da previsão
+const SHOCK_PERSISTENCE: f64 = 0.95;
+
+// Plano de poupança: saldo inicial, aporte ao fim de cada mês e retorno nominal suposto
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plan {
+    pub initial: f64,
+    pub monthly_contribution: f64,
+    // Em % a.a.
+    pub nominal_return: f64,
+    pub months: usize,
+    // Aportes corrigidos pela inflação, mantendo o seu poder de compra
+    pub indexed: bool,
+}
+
+// Percentis 10, 50 e 90 dos resultados simulados
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Band {
+    pub p10: f64,
+    pub median: f64,
+    pub p90: f64,
+}
+
+// Saldo ao fim de cada mês, nominal e real (em dinheiro de hoje, descontada a inflação simulada)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Projection {
+    pub nominal: Vec<Band>,
+    pub real: Vec<Band>,
+}
+
+impl Projection {
+    pub fn last(&self) -> Option<(Band, Band)> {
+        Some((*self.nominal.last()?, *self.real.last()?))
+    }
+}
+
+// Desvio padrão das variações mensais da série de inflação, a escala dos choques simulados
+pub fn volatility(values: &[f64]) -> f64 {
+    let changes = difference(values);
+    if changes.len() < 2 {
+        return 0.0;
+    }
+    std_dev(&changes)
+}
+
+// Inflação anual (%) de cada mês: a prevista mais um desvio autorregressivo; depois do horizonte da
+// previsão, o último valor previsto
+pub fn simulate_inflation(forecast: &[f64], volatility: f64, months: usize, rng: &mut SeededRng) -> Vec<f64> {
+    let mut deviation = 0.0;
+    (0..months)
+        .map(|month| {
+            deviation = SHOCK_PERSISTENCE * deviation + volatility * rng.normal();
+            forecast.get(month).or(forecast.last()).copied().unwrap_or(0.0) + deviation
+        })
+        .collect()
+}
+
+fn band(mut outcomes: Vec<f64>) -> Band {
+    outcomes.sort_by(f64::total_cmp);
+    let percentile = |p: f64| outcomes[(p * (outcomes.len() - 1) as f64).round() as usize];
+
+    Band {
+        p10: percentile(0.1),
+        median: percentile(0.5),
+        p90: percentile(0.9),
+    }
+}
+
+pub fn project(
+    plan: &Plan,
+    forecast: &[f64],
+    volatility: f64,
+    simulations: usize,
+    rng: &mut SeededRng,
+) -> Option<Projection> {
+    if plan.months == 0 || forecast.is_empty() || simulations == 0 {
+        return None;
+    }
+
+    let monthly_return = (1.0 + plan.nominal_return / 100.0).powf(1.0 / 12.0);
+    let mut nominal = vec![Vec::with_capacity(simulations); plan.months];
+    let mut real = vec![Vec::with_capacity(simulations); plan.months];
+
+    for _ in 0..simulations {
+        let mut balance = plan.initial;
+        // Nível de preços relativo ao de hoje
+        let mut prices = 1.0;
+        for (month, inflation) in simulate_inflation(forecast, volatility, plan.months, rng).into_iter().enumerate() {
+            // Taxas anuais aplicadas mês a mês; uma deflação abaixo de -100% não tem sentido
+            prices *= (1.0 + inflation / 100.0).max(f64::EPSILON).powf(1.0 / 12.0);
+            let contribution = if plan.indexed {
+                plan.monthly_contribution * prices
+            } else {
+                plan.monthly_contribution
+            };
+            balance = balance * monthly_return + contribution;
+            nominal[month].push(balance);
+            real[month].push(balance / prices);
+        }
+    }
+
+    Some(Projection {
+        nominal: nominal.into_iter().map(band).collect(),
+        real: real.into_iter().map(band).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(nominal_return: f64, indexed: bool) -> Plan {
+        Plan {
+            initial: 1000.0,
+            monthly_contribution: 100.0,
+            nominal_return,
+            months: 12,
+            indexed,
+        }
+    }
+
+    #[test]
+    fn without_uncertainty_real_value_follows_the_fisher_equation() {
+        let mut rng = SeededRng::new(1);
+
+        let flat = project(&plan(0.0, false), &[0.0], 0.0, 10, &mut rng).unwrap();
+        let (nominal, real) = flat.last().unwrap();
+        assert!((nominal.median - 2200.0).abs() < 1e-9);
+        assert_eq!(real, nominal);
+
+        // Retorno igual à inflação e aportes corrigidos: o poder de compra é o total aportado
+        let matched = project(&plan(12.0, true), &[12.0; 6], 0.0, 10, &mut rng).unwrap();
+        let (nominal, real) = matched.last().unwrap();
+        assert!((real.median - 2200.0).abs() < 1e-6);
+        assert!((nominal.median - 2200.0 * 1.12).abs() < 1e-6);
+    }
+
+    #[test]
+    fn inflation_uncertainty_spreads_only_the_real_outcome() {
+        let mut rng = SeededRng::new(42);
+
+        let projection = project(&plan(10.0, false), &[5.0; 12], 0.5, SIMULATIONS, &mut rng).unwrap();
+        let (nominal, real) = projection.last().unwrap();
+
+        assert_eq!(nominal.p10, nominal.p90);
+        assert!(real.p10 < real.median && real.median < real.p90);
+        assert!(real.median < nominal.median);
+    }
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+
+// Fração do limite diário a partir da qual o uso é sinalizado como próximo do limite
+pub const WARN_RATIO: f64 = 0.8;
+
+// Uso em memória, carregado do disco na primeira consulta; o lock também evita que
+// requisições simultâneas (busca + verificação de saúde) percam incrementos
+static USAGE: Mutex<Option<QuotaUsage>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    // Dias desde a época Unix (UTC); o contador é zerado quando o dia muda
+    pub day: u64,
+    pub requests: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuotaState {
+    Ok,
+    Warning,
+    Exceeded,
+}
+
+#[derive(Debug)]
+pub struct QuotaExceeded {
+    pub used: u64,
+    pub limit: u64,
+}
+
+impl fmt::Display for QuotaExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Limite diário de requisições atingido ({}/{})",
+            self.used, self.limit
+        )
+    }
+}
+
+impl std::error::Error for QuotaExceeded {}
+
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86400)
+        .unwrap_or(0)
+}
+
+pub fn usage_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("rustfin").join("quota.json"))
+}
+
+impl QuotaUsage {
+    // Uso do dia atual; um registro de outro dia conta como zero
+    fn load() -> Self {
+        let day = today();
+
+        usage_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<QuotaUsage>(&content).ok())
+            .filter(|usage| usage.day == day)
+            .unwrap_or(QuotaUsage { day, requests: 0 })
+    }
+
+    fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = usage_path().ok_or("Diretório de dados não encontrado")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        std::fs::write(path, serde_json::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn state(&self, config: &Config) -> QuotaState {
+        match config.daily_request_limit {
+            0 => QuotaState::Ok,
+            limit if self.requests >= limit => QuotaState::Exceeded,
+            limit if self.requests as f64 >= limit as f64 * WARN_RATIO => QuotaState::Warning,
+            _ => QuotaState::Ok,
+        }
+    }
+}
+
+fn with_usage<T>(f: impl FnOnce(&mut QuotaUsage) -> T) -> T {
+    let mut guard = USAGE.lock().unwrap_or_else(|e| e.into_inner());
+    let usage = guard.get_or_insert_with(QuotaUsage::load);
+    if usage.day != today() {
+        *usage = QuotaUsage { day: today(), requests: 0 };
+    }
+
+    f(usage)
+}
+
+// Uso do dia atual, sem registrar requisição
+pub fn current() -> QuotaUsage {
+    with_usage(|usage| usage.clone())
+}
+
+// Registra uma requisição ao provedor; recusa quando o limite diário foi atingido,
+// a menos que o usuário tenha optado por ignorá-lo (config.ignore_quota).
+// Com o controle desativado (limite 0) nada é registrado.
+pub fn reserve(config: &Config) -> Result<QuotaUsage, QuotaExceeded> {
+    with_usage(|usage| {
+        if config.daily_request_limit == 0 {
+            return Ok(usage.clone());
+        }
+
+        if usage.state(config) == QuotaState::Exceeded && !config.ignore_quota {
+            return Err(QuotaExceeded {
+                used: usage.requests,
+                limit: config.daily_request_limit,
+            });
+        }
+
+        usage.requests += 1;
+        if let Err(e) = usage.save() {
+            eprintln!("Erro ao salvar o uso da cota: {}", e);
+        }
+
+        Ok(usage.clone())
+    })
+}
@@ -0,0 +1,131 @@
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+use crate::data::date_sort_key;
+use crate::export::write_csv;
+
+// Países comparados quando não há lista salva
+const DEFAULT_COUNTRIES: [&str; 8] = ["brazil", "usa", "argentina", "mexico", "chile", "colombia", "germany", "japan"];
+
+pub fn ranking_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("ranking.json"))
+}
+
+pub fn load() -> Vec<String> {
+    ranking_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_else(|| DEFAULT_COUNTRIES.iter().map(|country| country.to_string()).collect())
+}
+
+pub fn save(countries: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = ranking_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(countries)?)?;
+
+    Ok(())
+}
+
+// Última leitura de inflação de um país e sua trajetória nos 12 meses anteriores
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub country: String,
+    pub date: String,
+    pub latest: f64,
+    // Variação em pontos percentuais desde a observação de um ano antes; None quando a série não
+    // cobre um ano
+    pub change: Option<f64>,
+    // Valores dos últimos 12 meses, em ordem cronológica, para o minigráfico
+    pub recent: Vec<f64>,
+}
+
+// Resume a série de um país; None se estiver vazia
+pub fn entry(country: &str, series: &[(String, f64)]) -> Option<Entry> {
+    let mut sorted: Vec<(String, &(String, f64))> =
+        series.iter().map(|observation| (date_sort_key(&observation.0), observation)).collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+    let (key, (date, latest)) = sorted.last()?;
+
+    // Mesma data um ano antes; vale a última observação até ela
+    let year: i32 = key.get(..4)?.parse().ok()?;
+    let year_ago = format!("{:04}{}", year - 1, &key[4..]);
+    let start = sorted.iter().rposition(|(other, _)| *other <= year_ago);
+
+    Some(Entry {
+        country: country.to_string(),
+        date: date.clone(),
+        latest: *latest,
+        change: start.map(|i| latest - sorted[i].1 .1),
+        recent: sorted[start.unwrap_or(0)..].iter().map(|(_, (_, value))| *value).collect(),
+    })
+}
+
+// Da maior para a menor inflação
+pub fn rank(entries: &mut [Entry]) {
+    entries.sort_by(|a, b| b.latest.partial_cmp(&a.latest).unwrap_or(Ordering::Equal));
+}
+
+pub fn ranking_csv(entries: &[Entry]) -> String {
+    let mut csv = String::from("rank,country,date,latest,change_12m\n");
+    for (i, entry) in entries.iter().enumerate() {
+        let change = entry.change.map(|change| format!("{:.2}", change)).unwrap_or_default();
+        csv.push_str(&format!("{},{},{},{:.2},{}\n", i + 1, entry.country, entry.date, entry.latest, change));
+    }
+    csv
+}
+
+// Grava `rustfin-ranking-inflacao.csv` no diretório de exportação
+pub fn write_ranking_csv(entries: &[Entry]) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    write_csv("ranking-inflacao", &ranking_csv(entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monthly(values: &[f64]) -> Vec<(String, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (format!("01/{:02}/{}", i % 12 + 1, 2023 + i / 12), *value))
+            .rev()
+            .collect()
+    }
+
+    #[test]
+    fn change_is_measured_against_the_same_month_a_year_before() {
+        let values: Vec<f64> = (0..15).map(|i| i as f64).collect();
+        let entry = entry("brazil", &monthly(&values)).unwrap();
+
+        assert_eq!(entry.date, "01/03/2024");
+        assert_eq!(entry.latest, 14.0);
+        assert_eq!(entry.change, Some(12.0));
+        assert_eq!(entry.recent.len(), 13);
+        assert_eq!(entry.recent[0], 2.0);
+
+        let short = super::entry("chile", &monthly(&[3.0, 4.0])).unwrap();
+        assert_eq!(short.change, None);
+        assert_eq!(short.recent, vec![3.0, 4.0]);
+        assert_eq!(super::entry("usa", &[]), None);
+    }
+
+    #[test]
+    fn ranking_puts_the_highest_inflation_first() {
+        let mut entries: Vec<Entry> = [("usa", 3.0), ("argentina", 200.0), ("brazil", 4.5)]
+            .iter()
+            .map(|(country, value)| entry(country, &monthly(&[*value])).unwrap())
+            .collect();
+        rank(&mut entries);
+
+        let order: Vec<&str> = entries.iter().map(|entry| entry.country.as_str()).collect();
+        assert_eq!(order, vec!["argentina", "brazil", "usa"]);
+        assert_eq!(
+            ranking_csv(&entries),
+            "rank,country,date,latest,change_12m\n1,argentina,01/01/2023,200.00,\n\
+             2,brazil,01/01/2023,4.50,\n3,usa,01/01/2023,3.00,\n"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::fx::{infer_frequency, Frequency};
+
+// Base de uma série de taxas de inflação (%). Os provedores costumam publicar a variação de
+// cada período (MoM, no caso mensal); as outras bases são calculadas a partir dela com
+// capitalização composta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Basis {
+    // Variação no período, como publicada
+    Period,
+    // Variação do período elevada a um ano: (1 + r)^n - 1, com n períodos por ano
+    Annualized,
+    // Variação acumulada nos últimos n períodos (YoY): produto de (1 + r) - 1
+    YearOverYear,
+}
+
+impl Basis {
+    pub fn all() -> [Basis; 3] {
+        [Basis::Period, Basis::Annualized, Basis::YearOverYear]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Basis::Period => "No período (MoM)",
+            Basis::Annualized => "Anualizada",
+            Basis::YearOverYear => "Acumulada em 12 meses (YoY)",
+        }
+    }
+}
+
+// Períodos por ano; séries diárias não são taxas de inflação
+pub fn periods_per_year(frequency: Frequency) -> Option<usize> {
+    match frequency {
+        Frequency::Daily => None,
+        Frequency::Monthly => Some(12),
+        Frequency::Quarterly => Some(4),
+        Frequency::Annual => Some(1),
+    }
+}
+
+fn growth(rate: f64) -> f64 {
+    1.0 + rate / 100.0
+}
+
+// Converte as taxas do período (%, em ordem cronológica) para a base pedida. O acumulado em 12
+// meses só existe a partir do 12º período, e as primeiras datas ficam de fora.
+pub fn rebase(dates: &[String], values: &[f64], basis: Basis) -> Result<(Vec<String>, Vec<f64>), String> {
+    let periods = periods_per_year(infer_frequency(dates))
+        .ok_or("A série é diária; as bases só se aplicam a taxas mensais, trimestrais ou anuais")?;
+
+    match basis {
+        Basis::Period => Ok((dates.to_vec(), values.to_vec())),
+        Basis::Annualized => Ok((
+            dates.to_vec(),
+            values.iter().map(|rate| (growth(*rate).powi(periods as i32) - 1.0) * 100.0).collect(),
+        )),
+        Basis::YearOverYear => {
+            if values.len() < periods {
+                return Err(format!("O acumulado em 12 meses exige ao menos {} observações", periods));
+            }
+            let accumulated = values
+                .windows(periods)
+                .map(|window| (window.iter().map(|rate| growth(*rate)).product::<f64>() - 1.0) * 100.0)
+                .collect();
+            Ok((dates[periods - 1..].to_vec(), accumulated))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn months(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("01/{:02}/{}", i % 12 + 1, 2023 + i / 12)).collect()
+    }
+
+    #[test]
+    fn compounds_monthly_rates() {
+        let dates = months(13);
+        let values = vec![1.0; 13];
+
+        let (_, annualized) = rebase(&dates, &values, Basis::Annualized).unwrap();
+        assert!((annualized[0] - 12.682503).abs() < 1e-6);
+
+        let (yoy_dates, yoy) = rebase(&dates, &values, Basis::YearOverYear).unwrap();
+        assert_eq!(yoy_dates, dates[11..].to_vec());
+        assert_eq!(yoy.len(), 2);
+        assert!((yoy[0] - annualized[0]).abs() < 1e-9);
+
+        assert_eq!(rebase(&dates, &values, Basis::Period).unwrap().1, values);
+    }
+
+    #[test]
+    fn quarterly_series_compound_four_periods() {
+        let dates: Vec<String> = (0..4).map(|i| format!("01/{:02}/2024", 3 * i + 1)).collect();
+        let values = [2.0, 1.0, 0.5, 0.0];
+
+        let (_, yoy) = rebase(&dates, &values, Basis::YearOverYear).unwrap();
+        assert!((yoy[0] - (1.02 * 1.01 * 1.005 - 1.0) * 100.0).abs() < 1e-9);
+
+        let daily: Vec<String> = (1..=5).map(|day| format!("{:02}/01/2024", day)).collect();
+        assert!(rebase(&daily, &[0.1; 5], Basis::Annualized).is_err());
+        assert!(rebase(&months(3), &[0.1; 3], Basis::YearOverYear).unwrap_err().contains("12"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::data::date_sort_key;
+
+// Período de referência, em que o índice vale 100
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reference {
+    // Valor em vigor na data: o da última observação até ela
+    Date(String),
+    // Média das observações do ano (ex.: "2020 = 100", como nos índices oficiais)
+    Year(i32),
+}
+
+impl Reference {
+    pub fn period(&self) -> String {
+        match self {
+            Reference::Date(date) => date.clone(),
+            Reference::Year(year) => year.to_string(),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        format!("{} = 100", self.period())
+    }
+}
+
+fn sorted(series: &[(String, f64)]) -> Vec<(String, f64)> {
+    let mut sorted = series.to_vec();
+    sorted.sort_by_key(|(date, _)| date_sort_key(date));
+    sorted
+}
+
+pub fn base_value(series: &[(String, f64)], reference: &Reference) -> Option<f64> {
+    let series = sorted(series);
+    match reference {
+        Reference::Date(date) => {
+            let key = date_sort_key(date);
+            series.iter().rev().find(|(other, _)| date_sort_key(other) <= key).map(|(_, value)| *value)
+        }
+        Reference::Year(year) => {
+            let prefix = format!("{:04}-", year);
+            let values: Vec<f64> = series
+                .iter()
+                .filter(|(date, _)| date_sort_key(date).starts_with(&prefix))
+                .map(|(_, value)| *value)
+                .collect();
+            (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+        }
+    }
+}
+
+// Índice com o período de referência igual a 100, em ordem cronológica. Só faz sentido para
+// séries de nível (índices de preços, cotações), não para taxas.
+pub fn rebase(series: &[(String, f64)], reference: &Reference) -> Result<Vec<(String, f64)>, String> {
+    let missing = || format!("A série não tem observações em {}", reference.period());
+    let base = base_value(series, reference).ok_or_else(missing)?;
+    if base <= 0.0 {
+        return Err("O valor na referência não é positivo; rebaseie apenas séries de nível".to_string());
+    }
+
+    Ok(sorted(series).into_iter().map(|(date, value)| (date, 100.0 * value / base)).collect())
+}
+
+// Datas de todas as séries, sem repetição e em ordem cronológica: o eixo comum do gráfico
+pub fn all_dates(series: &BTreeMap<String, Vec<(String, f64)>>) -> Vec<String> {
+    let dates: BTreeSet<(String, String)> =
+        series.values().flatten().map(|(date, _)| (date_sort_key(date), date.clone())).collect();
+    dates.into_iter().map(|(_, date)| date).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> Vec<(String, f64)> {
+        vec![
+            ("01/01/2021".to_string(), 120.0),
+            ("01/01/2020".to_string(), 100.0),
+            ("01/07/2020".to_string(), 110.0),
+        ]
+    }
+
+    #[test]
+    fn reference_date_and_year_become_100() {
+        let on_date = rebase(&index(), &Reference::Date("15/08/2020".to_string())).unwrap();
+        assert_eq!(on_date[0].0, "01/01/2020");
+        assert!((on_date[0].1 - 100.0 / 1.1).abs() < 1e-9);
+        assert_eq!(on_date[1].1, 100.0);
+
+        let on_year = rebase(&index(), &Reference::Year(2020)).unwrap();
+        assert!((on_year[2].1 - 120.0 / 105.0 * 100.0).abs() < 1e-9);
+
+        assert!(rebase(&index(), &Reference::Year(2019)).unwrap_err().contains("2019"));
+        assert!(rebase(&index(), &Reference::Date("01/12/2019".to_string())).is_err());
+    }
+
+    #[test]
+    fn dates_are_merged_chronologically() {
+        let series = BTreeMap::from([
+            ("a".to_string(), index()),
+            ("b".to_string(), vec![("01/03/2020".to_string(), 1.0), ("01/01/2020".to_string(), 2.0)]),
+        ]);
+
+        assert_eq!(all_dates(&series), vec!["01/01/2020", "01/03/2020", "01/07/2020", "01/01/2021"]);
+        let negative = vec![("01/01/2020".to_string(), -1.0)];
+        assert!(rebase(&negative, &Reference::Year(2020)).unwrap_err().contains("positivo"));
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::data::date_sort_key;
+
+// Recessão do ciclo econômico, do pico à vale (DD/MM/AAAA, primeiro dia do mês ou trimestre)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recession {
+    pub start: String,
+    pub end: String,
+}
+
+// Datações oficiais incluídas na aplicação: NBER (EUA), CODACE (Brasil), gabinete do governo
+// (Japão) e CEPR, da zona do euro, para a Alemanha
+const BUNDLED: [(&str, &[(&str, &str)]); 4] = [
+    (
+        "usa",
+        &[
+            ("01/12/1969", "01/11/1970"),
+            ("01/11/1973", "01/03/1975"),
+            ("01/01/1980", "01/07/1980"),
+            ("01/07/1981", "01/11/1982"),
+            ("01/07/1990", "01/03/1991"),
+            ("01/03/2001", "01/11/2001"),
+            ("01/12/2007", "01/06/2009"),
+            ("01/02/2020", "01/04/2020"),
+        ],
+    ),
+    (
+        "brazil",
+        &[
+            ("01/01/1981", "01/01/1983"),
+            ("01/07/1987", "01/10/1988"),
+            ("01/07/1989", "01/01/1992"),
+            ("01/04/1995", "01/07/1995"),
+            ("01/01/1998", "01/01/1999"),
+            ("01/04/2001", "01/10/2001"),
+            ("01/01/2003", "01/04/2003"),
+            ("01/10/2008", "01/01/2009"),
+            ("01/04/2014", "01/10/2016"),
+            ("01/01/2020", "01/04/2020"),
+        ],
+    ),
+    (
+        "japan",
+        &[
+            ("01/02/2008", "01/03/2009"),
+            ("01/03/2012", "01/11/2012"),
+            ("01/10/2018", "01/05/2020"),
+        ],
+    ),
+    (
+        "germany",
+        &[
+            ("01/01/2008", "01/04/2009"),
+            ("01/07/2011", "01/01/2013"),
+            ("01/10/2019", "01/04/2020"),
+        ],
+    ),
+];
+
+pub fn bundled(country: &str) -> Vec<Recession> {
+    BUNDLED
+        .iter()
+        .find(|(other, _)| *other == country)
+        .map(|(_, recessions)| {
+            recessions
+                .iter()
+                .map(|(start, end)| Recession {
+                    start: start.to_string(),
+                    end: end.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn recessions_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("recessions.json"))
+}
+
+// Recessões editadas pelo usuário, indexadas pelo país
+pub fn load_all() -> BTreeMap<String, Vec<Recession>> {
+    recessions_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// As do usuário quando ele já editou as do país (mesmo que para removê-las todas); senão, as incluídas
+pub fn load(country: &str) -> Vec<Recession> {
+    load_all().remove(country).unwrap_or_else(|| bundled(country))
+}
+
+pub fn save(country: &str, recessions: &[Recession]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut all = load_all();
+    let mut recessions = recessions.to_vec();
+    recessions.sort_by_key(|recession| date_sort_key(&recession.start));
+    all.insert(country.to_string(), recessions);
+
+    let path = recessions_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(&all)?)?;
+
+    Ok(())
+}
+
+// Posições da primeira e da última data da recessão em uma série de datas cronológicas; None se
+// a recessão não cruza a série
+pub fn span(recession: &Recession, dates: &[String]) -> Option<(usize, usize)> {
+    let (start, end) = (date_sort_key(&recession.start), date_sort_key(&recession.end));
+    let first = dates.iter().position(|date| date_sort_key(date) >= start)?;
+    let last = dates.iter().rposition(|date| date_sort_key(date) <= end)?;
+    (first <= last).then_some((first, last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_observations_inside_a_recession() {
+        let dates: Vec<String> = ["01/10/2008", "01/11/2008", "01/12/2008", "01/01/2009", "01/02/2009"]
+            .iter()
+            .map(|date| date.to_string())
+            .collect();
+        let recession = Recession {
+            start: "15/10/2008".to_string(),
+            end: "01/01/2009".to_string(),
+        };
+
+        assert_eq!(span(&recession, &dates), Some((1, 3)));
+        let before = Recession {
+            start: "01/01/2001".to_string(),
+            end: "01/12/2001".to_string(),
+        };
+        assert_eq!(span(&before, &dates), None);
+    }
+
+    #[test]
+    fn bundles_major_economies() {
+        assert_eq!(bundled("usa").last().unwrap().start, "01/02/2020");
+        assert!(bundled("brazil").len() >= 10);
+        assert!(bundled("argentina").is_empty());
+    }
+}
@@ -0,0 +1,300 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::annotations::{self, Annotation};
+use crate::config::Config;
+use crate::export;
+use crate::stats::{self, ForecastMetrics};
+
+// Dimensões do gráfico SVG embutido no relatório
+const WIDTH: f64 = 900.0;
+const HEIGHT: f64 = 320.0;
+const MARGIN: f64 = 40.0;
+
+// Conteúdo de um relatório compartilhável
+pub struct Report<'a> {
+    pub title: String,
+    // Ex.: "ARIMA (statsmodels), ordem (1, 1, 1)"
+    pub model: String,
+    pub dates: &'a [String],
+    pub values: &'a [f64],
+    pub forecast: &'a [f64],
+    pub annotations: &'a [Annotation],
+    // Erros da previsão fora da amostra, quando calculados
+    pub holdout: Option<(usize, ForecastMetrics)>,
+}
+
+// Cartão do painel no retrato em HTML
+pub struct DashboardCard<'a> {
+    pub title: String,
+    pub dates: &'a [String],
+    pub values: &'a [f64],
+    // Próximo ponto previsto
+    pub next: Option<f64>,
+    pub tags: Vec<String>,
+}
+
+// Observações mais recentes listadas na tabela de cada cartão do painel
+const DASHBOARD_ROWS: usize = 12;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Gráfico de linhas (histórico + previsão) com as anotações, como SVG autocontido
+pub fn svg_chart(dates: &[String], values: &[f64], forecast: &[f64], annotations: &[Annotation]) -> String {
+    let total = values.len() + forecast.len();
+    let all: Vec<f64> = values.iter().chain(forecast).copied().collect();
+    if total < 2 {
+        return String::new();
+    }
+
+    let min = all.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = all.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let span = if max > min { max - min } else { 1.0 };
+
+    let x = |i: usize| MARGIN + i as f64 / (total - 1) as f64 * (WIDTH - 2.0 * MARGIN);
+    let y = |v: f64| HEIGHT - MARGIN - (v - min) / span * (HEIGHT - 2.0 * MARGIN);
+    let path = |points: Vec<(usize, f64)>| {
+        points
+            .iter()
+            .map(|(i, v)| format!("{:.1},{:.1}", x(*i), y(*v)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let history = path(values.iter().copied().enumerate().collect());
+    let start = values.len().saturating_sub(1);
+    let projected = path(
+        values
+            .last()
+            .into_iter()
+            .chain(forecast)
+            .enumerate()
+            .map(|(i, v)| (start + i, *v))
+            .collect(),
+    );
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {w} {h}\" width=\"{w}\" height=\"{h}\">\n",
+        w = WIDTH,
+        h = HEIGHT
+    );
+    svg.push_str(&format!(
+        "<rect x=\"0\" y=\"0\" width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        WIDTH, HEIGHT
+    ));
+    svg.push_str(&format!(
+        "<text x=\"4\" y=\"{:.1}\" font-size=\"11\">{:.2}</text>\n<text x=\"4\" y=\"{:.1}\" font-size=\"11\">{:.2}</text>\n",
+        y(max) + 4.0,
+        max,
+        y(min) + 4.0,
+        min
+    ));
+
+    for annotation in annotations {
+        if let Some(i) = annotations::position(annotation, dates) {
+            svg.push_str(&format!(
+                "<line x1=\"{x:.1}\" y1=\"{top}\" x2=\"{x:.1}\" y2=\"{bottom}\" stroke=\"#c87800\" stroke-dasharray=\"3,3\"/>\n<text x=\"{tx:.1}\" y=\"{ty}\" font-size=\"11\" fill=\"#c87800\">{label}</text>\n",
+                x = x(i),
+                top = MARGIN,
+                bottom = HEIGHT - MARGIN,
+                tx = x(i) + 3.0,
+                ty = MARGIN + 10.0,
+                label = escape(&annotation.label)
+            ));
+        }
+    }
+
+    svg.push_str(&format!(
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"#1f77b4\" stroke-width=\"1.5\"/>\n",
+        history
+    ));
+    if !forecast.is_empty() {
+        svg.push_str(&format!(
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"#d62728\" stroke-width=\"1.5\" stroke-dasharray=\"6,4\"/>\n",
+            projected
+        ));
+    }
+
+    if let (Some(first), Some(last)) = (dates.first(), dates.last()) {
+        svg.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"11\">{}</text>\n<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"11\" text-anchor=\"end\">{}</text>\n",
+            x(0),
+            HEIGHT - 12.0,
+            escape(first),
+            x(values.len() - 1),
+            HEIGHT - 12.0,
+            escape(last)
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Início do documento, com o estilo embutido, até o título
+fn html_head(title: &str, config: &Config) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n",
+        escape(&config.locale),
+        escape(title)
+    );
+    html.push_str(
+        "<style>body{font-family:sans-serif;max-width:960px;margin:2em auto;color:#222}\
+         table{border-collapse:collapse;margin:1em 0}td,th{border:1px solid #ccc;padding:4px 10px;text-align:right}\
+         th{background:#f3f3f3}td:first-child,th:first-child{text-align:left}</style>\n</head>\n<body>\n",
+    );
+    html.push_str(&format!("<h1>{}</h1>\n", escape(title)));
+    html
+}
+
+pub fn render_html(report: &Report, config: &Config) -> String {
+    let values = report.values;
+    let mut html = html_head(&report.title, config);
+
+    html.push_str(&svg_chart(report.dates, values, report.forecast, report.annotations));
+
+    html.push_str("<h2>Modelo</h2>\n");
+    html.push_str(&format!("<p>{}</p>\n", escape(&report.model)));
+
+    if !values.is_empty() {
+        html.push_str("<h2>Diagnóstico da série</h2>\n<table>\n");
+        let mut row = |name: &str, value: String| {
+            html.push_str(&format!("<tr><td>{}</td><td>{}</td></tr>\n", name, escape(&value)));
+        };
+        row("Observações", values.len().to_string());
+        if let (Some(first), Some(last)) = (report.dates.first(), report.dates.last()) {
+            row("Período", format!("{} a {}", first, last));
+        }
+        row("Média", config.format_value(stats::mean(values)));
+        row("Desvio padrão", config.format_value(stats::std_dev(values)));
+        row(
+            "Mínimo",
+            config.format_value(values.iter().cloned().fold(f64::INFINITY, f64::min)),
+        );
+        row(
+            "Máximo",
+            config.format_value(values.iter().cloned().fold(f64::NEG_INFINITY, f64::max)),
+        );
+        if let Some(acf) = stats::autocorrelation(values, 1) {
+            row("Autocorrelação (defasagem 1)", config.format_value(acf));
+        }
+        html.push_str("</table>\n");
+    }
+
+    if let Some((horizon, metrics)) = report.holdout {
+        html.push_str(&format!(
+            "<h2>Métricas</h2>\n<p>Previsão das últimas {} observações a partir das anteriores.</p>\n<table>\n",
+            horizon
+        ));
+        html.push_str(&format!("<tr><td>MAE</td><td>{}</td></tr>\n", config.format_value(metrics.mae)));
+        html.push_str(&format!("<tr><td>RMSE</td><td>{}</td></tr>\n", config.format_value(metrics.rmse)));
+        if let Some(mape) = metrics.mape {
+            html.push_str(&format!("<tr><td>MAPE</td><td>{}%</td></tr>\n", config.format_value(mape)));
+        }
+        html.push_str("</table>\n");
+    }
+
+    if !report.forecast.is_empty() {
+        html.push_str("<h2>Previsão</h2>\n<table>\n<tr><th>Passo</th><th>Valor</th></tr>\n");
+        for (i, value) in report.forecast.iter().enumerate() {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td></tr>\n",
+                i + 1,
+                config.format_value(*value)
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    if !report.annotations.is_empty() {
+        html.push_str("<h2>Anotações</h2>\n<ul>\n");
+        for annotation in report.annotations {
+            html.push_str(&format!(
+                "<li>{}: {}</li>\n",
+                escape(&annotation.date),
+                escape(&annotation.label)
+            ));
+        }
+        html.push_str("</ul>\n");
+    }
+
+    html.push_str("<p><small>Gerado pelo RustFin</small></p>\n</body>\n</html>\n");
+    html
+}
+
+// Retrato do painel: um gráfico e uma tabela com as últimas observações e a próxima previsão
+// por série, em um HTML que abre sem o RustFin
+pub fn render_dashboard_html(title: &str, cards: &[DashboardCard], config: &Config) -> String {
+    let mut html = html_head(title, config);
+
+    for card in cards {
+        html.push_str(&format!("<h2>{}</h2>\n", escape(&card.title)));
+        if !card.tags.is_empty() {
+            html.push_str(&format!("<p>Etiquetas: {}</p>\n", escape(&card.tags.join(", "))));
+        }
+        let forecast: Vec<f64> = card.next.into_iter().collect();
+        html.push_str(&svg_chart(card.dates, card.values, &forecast, &[]));
+
+        html.push_str("<table>\n<tr><th>Data</th><th>Valor</th></tr>\n");
+        let start = card.values.len().saturating_sub(DASHBOARD_ROWS);
+        for (date, value) in card.dates.iter().zip(card.values).skip(start) {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td></tr>\n",
+                escape(date),
+                config.format_value(*value)
+            ));
+        }
+        if let Some(next) = card.next {
+            html.push_str(&format!(
+                "<tr><th>Próxima previsão</th><th>{}</th></tr>\n",
+                config.format_value(next)
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("<p><small>Gerado pelo RustFin</small></p>\n</body>\n</html>\n");
+    html
+}
+
+pub fn write_dashboard_html(cards: &[DashboardCard], config: &Config) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = export::export_dir();
+    std::fs::create_dir_all(&dir)?;
+
+    let path = dir.join("rustfin-painel.html");
+    std::fs::write(&path, render_dashboard_html("Painel RustFin", cards, config))?;
+
+    Ok(path)
+}
+
+pub fn write_html(name: &str, report: &Report, config: &Config) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = export::export_dir();
+    std::fs::create_dir_all(&dir)?;
+
+    let path = dir.join(format!("rustfin-{}.html", name));
+    std::fs::write(&path, render_html(report, config))?;
+
+    Ok(path)
+}
+
+// Converte o HTML em PDF com o wkhtmltopdf, quando instalado
+pub fn html_to_pdf(html: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let pdf = html.with_extension("pdf");
+    let status = Command::new("wkhtmltopdf")
+        .arg("--quiet")
+        .arg(html)
+        .arg(&pdf)
+        .status()
+        .map_err(|_| "wkhtmltopdf não encontrado; instale-o para gerar PDF")?;
+
+    if !status.success() {
+        return Err(format!("wkhtmltopdf terminou com {}", status).into());
+    }
+
+    Ok(pdf)
+}
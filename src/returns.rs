@@ -0,0 +1,131 @@
+use crate::stats;
+
+// Retornos simples: p[t] / p[t-1] - 1. Pares com algum preço não positivo não têm retorno
+// definido e são descartados.
+pub fn simple_returns(prices: &[f64]) -> Vec<f64> {
+    prices
+        .windows(2)
+        .filter(|w| w[0] > 0.0 && w[1] > 0.0)
+        .map(|w| w[1] / w[0] - 1.0)
+        .collect()
+}
+
+// Retornos logarítmicos: ln(p[t] / p[t-1])
+pub fn log_returns(prices: &[f64]) -> Vec<f64> {
+    prices
+        .windows(2)
+        .filter(|w| w[0] > 0.0 && w[1] > 0.0)
+        .map(|w| (w[1] / w[0]).ln())
+        .collect()
+}
+
+// Momentos da distribuição e teste de normalidade de Jarque-Bera
+#[derive(Debug, Clone, Copy)]
+pub struct Distribution {
+    pub observations: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub skewness: f64,
+    // Curtose em excesso (0 para a normal)
+    pub excess_kurtosis: f64,
+    pub jarque_bera: f64,
+    // Sob normalidade, JB segue uma qui-quadrado com 2 graus de liberdade: p = e^(-JB/2)
+    pub p_value: f64,
+}
+
+impl Distribution {
+    // Rejeita a normalidade ao nível de 5%
+    pub fn is_normal(&self) -> bool {
+        self.p_value >= 0.05
+    }
+}
+
+pub fn distribution(returns: &[f64]) -> Option<Distribution> {
+    let n = returns.len();
+    if n < 3 {
+        return None;
+    }
+
+    let mean = stats::mean(returns);
+    let std_dev = stats::std_dev(returns);
+    if std_dev == 0.0 {
+        return None;
+    }
+
+    let moment = |k: i32| returns.iter().map(|r| ((r - mean) / std_dev).powi(k)).sum::<f64>() / n as f64;
+    let skewness = moment(3);
+    let excess_kurtosis = moment(4) - 3.0;
+    let jarque_bera = n as f64 / 6.0 * (skewness.powi(2) + excess_kurtosis.powi(2) / 4.0);
+
+    Some(Distribution {
+        observations: n,
+        mean,
+        std_dev,
+        skewness,
+        excess_kurtosis,
+        jarque_bera,
+        p_value: (-jarque_bera / 2.0).exp(),
+    })
+}
+
+// Histograma com `bins` classes de mesma largura: (centro da classe, largura, contagem)
+pub fn histogram(values: &[f64], bins: usize) -> Vec<(f64, f64, usize)> {
+    if values.is_empty() || bins == 0 {
+        return vec![];
+    }
+
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let width = if max > min { (max - min) / bins as f64 } else { 1.0 };
+
+    let mut counts = vec![0; bins];
+    for value in values {
+        let bin = (((value - min) / width) as usize).min(bins - 1);
+        counts[bin] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (min + (i as f64 + 0.5) * width, width, count))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_simple_and_log_returns() {
+        let prices = [100.0, 110.0, 99.0];
+
+        let simple = simple_returns(&prices);
+        assert!((simple[0] - 0.1).abs() < 1e-12 && (simple[1] + 0.1).abs() < 1e-12);
+
+        let log = log_returns(&prices);
+        assert!((log[0] - 1.1f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn symmetric_sample_has_no_skew_and_heavy_tails_reject_normality() {
+        let symmetric = [-2.0, -1.0, 0.0, 1.0, 2.0];
+        assert!(distribution(&symmetric).unwrap().skewness.abs() < 1e-12);
+
+        // Muitos retornos nulos e alguns extremos: curtose alta
+        let mut fat_tails = vec![0.0; 200];
+        fat_tails.extend([10.0, -10.0, 12.0, -12.0]);
+        let fat = distribution(&fat_tails).unwrap();
+        assert!(fat.excess_kurtosis > 10.0);
+        assert!(!fat.is_normal());
+    }
+
+    #[test]
+    fn histogram_counts_every_value() {
+        let values = [0.0, 0.1, 0.5, 0.9, 1.0];
+        let bins = histogram(&values, 2);
+
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins.iter().map(|(_, _, count)| count).sum::<usize>(), 5);
+        assert_eq!(bins[0].2, 2);
+    }
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+// Revisão de um valor já publicado, detectada ao buscar a série novamente
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Revision {
+    pub country: String,
+    pub date: String,
+    pub old_value: f64,
+    pub new_value: f64,
+    // Segundos Unix
+    pub detected_at: u64,
+}
+
+// Log somente de acréscimo: uma revisão (JSON) por linha
+pub fn log_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("rustfin").join("revisions.jsonl"))
+}
+
+pub fn append(revisions: &[Revision]) -> Result<(), Box<dyn std::error::Error>> {
+    if revisions.is_empty() {
+        return Ok(());
+    }
+
+    let path = log_path().ok_or("Diretório de dados não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    for revision in revisions {
+        writeln!(file, "{}", serde_json::to_string(revision)?)?;
+    }
+
+    Ok(())
+}
+
+// Todas as revisões de um país, em ordem de detecção
+pub fn history(country: &str) -> Vec<Revision> {
+    let content = match log_path().and_then(|path| std::fs::read_to_string(path).ok()) {
+        Some(content) => content,
+        None => return vec![],
+    };
+
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Revision>(line).ok())
+        .filter(|revision| revision.country == country)
+        .collect()
+}
+
+// Revisões de uma observação específica
+pub fn for_date<'a>(history: &'a [Revision], date: &str) -> Vec<&'a Revision> {
+    history.iter().filter(|revision| revision.date == date).collect()
+}
+
+// Diferença entre a série em uso e uma versão recém-buscada dela
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeriesDiff {
+    // Posições, na versão nova, das datas que não estavam na série em uso
+    pub added: Vec<usize>,
+    // Posições das observações cujo valor mudou, com o valor anterior
+    pub revised: Vec<(usize, f64)>,
+    // Datas da série em uso que sumiram da versão nova, com o valor que tinham
+    pub removed: Vec<(String, f64)>,
+}
+
+impl SeriesDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.revised.is_empty() && self.removed.is_empty()
+    }
+
+    // A versão nova só acrescenta observações ao fim de uma série com `known` observações; uma
+    // data removida conta como revisão
+    pub fn appends_only(&self, known: usize) -> bool {
+        self.revised.is_empty() && self.removed.is_empty() && self.added.iter().enumerate().all(|(k, i)| *i == known + k)
+    }
+}
+
+// Observações novas, revisadas e removidas de `fresh` em relação a `current`, ambas como pares
+// (data, valor)
+pub fn diff(current: &[(String, f64)], fresh: &[(String, f64)]) -> SeriesDiff {
+    let mut known: HashMap<&str, f64> = current.iter().map(|(date, value)| (date.as_str(), *value)).collect();
+    let mut diff = SeriesDiff::default();
+    for (i, (date, value)) in fresh.iter().enumerate() {
+        match known.remove(date.as_str()) {
+            None => diff.added.push(i),
+            Some(old_value) if old_value != *value => diff.revised.push((i, old_value)),
+            Some(_) => {}
+        }
+    }
+    // O que sobrou não está mais na versão nova; mantém a ordem da série em uso
+    diff.removed = current
+        .iter()
+        .filter(|(date, _)| known.contains_key(date.as_str()))
+        .cloned()
+        .collect();
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separates_new_and_revised_observations() {
+        let pairs = |values: &[(&str, f64)]| -> Vec<(String, f64)> {
+            values.iter().map(|(date, value)| (date.to_string(), *value)).collect()
+        };
+        let current = pairs(&[("01/01/2024", 0.4), ("01/02/2024", 0.8)]);
+        let fresh = pairs(&[("01/01/2024", 0.4), ("01/02/2024", 0.7), ("01/03/2024", 0.2)]);
+
+        let changes = diff(&current, &fresh);
+        assert_eq!(changes.added, vec![2]);
+        assert_eq!(changes.revised, vec![(1, 0.8)]);
+        assert!(!changes.appends_only(2));
+        assert!(diff(&current, &current).is_empty());
+        let appended = pairs(&[("01/01/2024", 0.4), ("01/02/2024", 0.8), ("01/03/2024", 0.2)]);
+        assert!(diff(&current, &appended).appends_only(2));
+
+        // Uma data que some da versão nova é uma revisão, mesmo com outra acrescentada ao fim
+        let shifted = pairs(&[("01/02/2024", 0.8), ("01/03/2024", 0.2)]);
+        let changes = diff(&current, &shifted);
+        assert_eq!(changes.removed, vec![("01/01/2024".to_string(), 0.4)]);
+        assert!(!changes.is_empty());
+        assert!(!changes.appends_only(2));
+    }
+}
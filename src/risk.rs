@@ -0,0 +1,201 @@
+use crate::garch::{self, Garch};
+use crate::progress::{self, Progress, Reporter, Silent};
+use crate::stats;
+
+// VaR e ES são perdas (retornos com sinal trocado), positivas quando há risco de perda
+#[derive(Debug, Clone, Copy)]
+pub struct RiskEstimate {
+    pub var: f64,
+    pub es: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Historical,
+    Parametric,
+    Garch,
+}
+
+impl Method {
+    pub fn all() -> [Method; 3] {
+        [Method::Historical, Method::Parametric, Method::Garch]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Method::Historical => "Histórico",
+            Method::Parametric => "Paramétrico (normal)",
+            Method::Garch => "Condicional (GARCH)",
+        }
+    }
+}
+
+// Quantil empírico das perdas; ES é a média das perdas a partir dele
+pub fn historical(returns: &[f64], confidence: f64) -> Option<RiskEstimate> {
+    if returns.is_empty() {
+        return None;
+    }
+
+    let mut losses: Vec<f64> = returns.iter().map(|r| -r).collect();
+    losses.sort_by(f64::total_cmp);
+
+    let index = ((confidence * losses.len() as f64).ceil() as usize).clamp(1, losses.len()) - 1;
+    let tail = &losses[index..];
+
+    Some(RiskEstimate {
+        var: losses[index],
+        es: stats::mean(tail),
+    })
+}
+
+// Normal com a média e o desvio dados: VaR = -μ + σ·z, ES = -μ + σ·φ(z) / (1 - c)
+fn gaussian(mean: f64, sigma: f64, confidence: f64) -> RiskEstimate {
+    let z = stats::normal_quantile(confidence);
+
+    RiskEstimate {
+        var: -mean + sigma * z,
+        es: -mean + sigma * stats::normal_pdf(z) / (1.0 - confidence),
+    }
+}
+
+pub fn parametric(returns: &[f64], confidence: f64) -> Option<RiskEstimate> {
+    if returns.len() < 2 {
+        return None;
+    }
+
+    Some(gaussian(stats::mean(returns), stats::std_dev(returns), confidence))
+}
+
+// Risco do próximo período com a volatilidade prevista pelo GARCH(1,1)
+pub fn conditional(model: &Garch, returns: &[f64], confidence: f64) -> RiskEstimate {
+    gaussian(model.mean, model.forecast_variance(returns).sqrt(), confidence)
+}
+
+pub fn estimate(returns: &[f64], confidence: f64, method: Method) -> Option<RiskEstimate> {
+    match method {
+        Method::Historical => historical(returns, confidence),
+        Method::Parametric => parametric(returns, confidence),
+        Method::Garch => garch::fit(returns).map(|model| conditional(&model, returns, confidence)),
+    }
+}
+
+// Teste de proporção de falhas de Kupiec: as violações do VaR são compatíveis com 1 - c?
+#[derive(Debug, Clone, Copy)]
+pub struct Backtest {
+    pub observations: usize,
+    pub exceptions: usize,
+    pub expected: f64,
+    pub likelihood_ratio: f64,
+    pub p_value: f64,
+}
+
+impl Backtest {
+    // Rejeita o modelo de VaR ao nível de 5%
+    pub fn rejects(&self) -> bool {
+        self.p_value < 0.05
+    }
+}
+
+pub fn kupiec(observations: usize, exceptions: usize, confidence: f64) -> Backtest {
+    let n = observations as f64;
+    let x = exceptions as f64;
+    let p = 1.0 - confidence;
+
+    // k·ln(q), com 0·ln(0) = 0
+    let term = |k: f64, q: f64| if k == 0.0 { 0.0 } else { k * q.ln() };
+    let null = term(n - x, 1.0 - p) + term(x, p);
+    let observed = term(n - x, 1.0 - x / n) + term(x, x / n);
+    let likelihood_ratio = (-2.0 * (null - observed)).max(0.0);
+
+    Backtest {
+        observations,
+        exceptions,
+        expected: n * p,
+        likelihood_ratio,
+        p_value: stats::chi_squared_1_sf(likelihood_ratio),
+    }
+}
+
+// Backtest fora da amostra: o VaR de cada dia é estimado com os `window` retornos anteriores.
+// No GARCH os parâmetros são estimados uma vez na primeira janela e a volatilidade é
+// atualizada a cada dia pelo filtro.
+pub fn backtest(returns: &[f64], confidence: f64, window: usize, method: Method) -> Option<Backtest> {
+    backtest_with_progress(returns, confidence, window, method, &Silent)
+}
+
+// Como `backtest`, informando os dias já avaliados
+pub fn backtest_with_progress(
+    returns: &[f64],
+    confidence: f64,
+    window: usize,
+    method: Method,
+    progress: &dyn Reporter,
+) -> Option<Backtest> {
+    if window == 0 || returns.len() <= window {
+        return None;
+    }
+
+    // Modelo e variâncias condicionais, no GARCH
+    let filtered = match method {
+        Method::Garch => {
+            let model = garch::fit(&returns[..window])?;
+            Some((model, model.variances(returns)))
+        }
+        _ => None,
+    };
+
+    let total = returns.len() - window;
+    let mut exceptions = 0;
+    for t in window..returns.len() {
+        let var = match &filtered {
+            Some((model, variances)) => gaussian(model.mean, variances[t].sqrt(), confidence).var,
+            None => estimate(&returns[t - window..t], confidence, method)?.var,
+        };
+        if -returns[t] > var {
+            exceptions += 1;
+        }
+
+        let done = t - window + 1;
+        if progress::should_report(done, total) {
+            progress.report(&Progress::new(method.label(), done, total));
+        }
+    }
+
+    Some(kupiec(total, exceptions, confidence))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn historical_var_is_empirical_quantile_of_losses() {
+        // Perdas de 1 a 100: VaR 95% = 95, ES = média de 95..=100
+        let returns: Vec<f64> = (1..=100).map(|i| -(i as f64)).collect();
+
+        let estimate = historical(&returns, 0.95).unwrap();
+
+        assert_eq!(estimate.var, 95.0);
+        assert_eq!(estimate.es, 97.5);
+    }
+
+    #[test]
+    fn parametric_es_exceeds_var() {
+        let returns = [0.01, -0.02, 0.015, -0.005, 0.0, -0.012, 0.008];
+
+        let estimate = parametric(&returns, 0.99).unwrap();
+
+        assert!(estimate.var > 0.0);
+        assert!(estimate.es > estimate.var);
+    }
+
+    #[test]
+    fn kupiec_accepts_expected_and_rejects_excess_exceptions() {
+        let expected = kupiec(1000, 10, 0.99);
+        assert!(expected.likelihood_ratio < 1e-9);
+        assert!(!expected.rejects());
+
+        assert!(kupiec(1000, 30, 0.99).rejects());
+        assert!(kupiec(1000, 0, 0.99).rejects());
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Semente global dos componentes estocásticos (pesos iniciais da MLP, simulações...).
+// 0 indica que nenhuma semente foi fixada e cada execução usa uma semente aleatória.
+static GLOBAL_SEED: AtomicU64 = AtomicU64::new(0);
+
+pub fn set_global_seed(seed: Option<u64>) {
+    GLOBAL_SEED.store(seed.map(|s| s.max(1)).unwrap_or(0), Ordering::Relaxed);
+}
+
+pub fn global_seed() -> Option<u64> {
+    match GLOBAL_SEED.load(Ordering::Relaxed) {
+        0 => None,
+        seed => Some(seed),
+    }
+}
+
+// Gerador SplitMix64: simples, rápido e reprodutível entre plataformas
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    // Usa a semente global ou, sem ela, uma derivada do relógio
+    pub fn from_global() -> Self {
+        let seed = global_seed().unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0)
+        });
+        Self::new(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniforme em [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next_f64()
+    }
+
+    // Normal padrão (Box-Muller)
+    pub fn normal(&mut self) -> f64 {
+        let u1 = self.next_f64().max(f64::MIN_POSITIVE);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    // Índice uniforme em [0, n)
+    pub fn index(&mut self, n: usize) -> usize {
+        (self.next_f64() * n as f64) as usize % n.max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = SeededRng::new(7);
+        let mut b = SeededRng::new(7);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn uniform_stays_in_range() {
+        let mut rng = SeededRng::new(1);
+
+        for _ in 0..1000 {
+            let value = rng.uniform(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&value));
+        }
+    }
+}
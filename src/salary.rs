@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::data::date_sort_key;
+
+// Valor nominal de um salário a partir de uma data (ex.: reajuste anual do salário mínimo)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SalaryRecord {
+    pub date: String,
+    pub value: f64,
+}
+
+pub fn salaries_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("salaries.json"))
+}
+
+pub fn load() -> Vec<SalaryRecord> {
+    salaries_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(records: &[SalaryRecord]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = salaries_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(records)?)?;
+
+    Ok(())
+}
+
+// Salário mínimo nacional do Brasil, em reais, desde 2015
+pub fn brazil_minimum_wage() -> Vec<SalaryRecord> {
+    [
+        ("01/01/2015", 788.0),
+        ("01/01/2016", 880.0),
+        ("01/01/2017", 937.0),
+        ("01/01/2018", 954.0),
+        ("01/01/2019", 998.0),
+        ("01/01/2020", 1039.0),
+        ("01/02/2020", 1045.0),
+        ("01/01/2021", 1100.0),
+        ("01/01/2022", 1212.0),
+        ("01/01/2023", 1302.0),
+        ("01/05/2023", 1320.0),
+        ("01/01/2024", 1412.0),
+    ]
+    .into_iter()
+    .map(|(date, value)| SalaryRecord {
+        date: date.to_string(),
+        value,
+    })
+    .collect()
+}
+
+// Índice de preços (100 na primeira data) a partir da série de inflação anual (%), com cada
+// observação mensal aplicando um duodécimo da taxa anual
+pub fn price_index(inflation: &[(String, f64)]) -> Vec<(String, f64)> {
+    let mut sorted = inflation.to_vec();
+    sorted.sort_by_key(|(date, _)| date_sort_key(date));
+
+    let mut level = 100.0;
+    sorted
+        .into_iter()
+        .enumerate()
+        .map(|(i, (date, rate))| {
+            if i > 0 {
+                level *= (1.0 + rate / 100.0).powf(1.0 / 12.0);
+            }
+            (date, level)
+        })
+        .collect()
+}
+
+// Nível de preços em vigor na data: o da última observação até ela
+pub fn index_at(index: &[(String, f64)], date: &str) -> Option<f64> {
+    let key = date_sort_key(date);
+    index
+        .iter()
+        .take_while(|(other, _)| date_sort_key(other) <= key)
+        .last()
+        .map(|(_, level)| *level)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealIncome {
+    pub date: String,
+    pub nominal: f64,
+    // Em dinheiro da data-base
+    pub real: f64,
+}
+
+// Salário a manter o poder de compra do ano-base
+#[derive(Debug, Clone, PartialEq)]
+pub struct Restoration {
+    pub base_date: String,
+    pub base_nominal: f64,
+    pub current_nominal: f64,
+    // Salário que hoje compra o mesmo que o da data-base
+    pub required_nominal: f64,
+    // Reajuste necessário sobre o salário atual, em % (negativo quando o poder de compra é maior)
+    pub raise: f64,
+}
+
+// Último registro do ano-base (ex.: "2019"), a referência de poder de compra
+fn base_record<'a>(records: &'a [SalaryRecord], base_year: &str) -> Option<&'a SalaryRecord> {
+    records.iter().rev().find(|record| date_sort_key(&record.date).starts_with(base_year))
+}
+
+fn sorted(records: &[SalaryRecord]) -> Vec<SalaryRecord> {
+    let mut sorted = records.to_vec();
+    sorted.sort_by_key(|record| date_sort_key(&record.date));
+    sorted
+}
+
+// Salários deflacionados para o dinheiro do ano-base; registros anteriores à série de inflação
+// ficam de fora
+pub fn deflate(records: &[SalaryRecord], index: &[(String, f64)], base_year: &str) -> Result<Vec<RealIncome>, String> {
+    let records = sorted(records);
+    let base = base_record(&records, base_year).ok_or_else(|| format!("Nenhum salário registrado em {}", base_year))?;
+    let base_level =
+        index_at(index, &base.date).ok_or_else(|| format!("A série de inflação não cobre o ano-base {}", base_year))?;
+
+    Ok(records
+        .iter()
+        .filter_map(|record| {
+            let level = index_at(index, &record.date)?;
+            Some(RealIncome {
+                date: record.date.clone(),
+                nominal: record.value,
+                real: record.value * base_level / level,
+            })
+        })
+        .collect())
+}
+
+pub fn restore(records: &[SalaryRecord], index: &[(String, f64)], base_year: &str) -> Result<Restoration, String> {
+    let records = sorted(records);
+    let base = base_record(&records, base_year).ok_or_else(|| format!("Nenhum salário registrado em {}", base_year))?;
+    let current = records.last().ok_or("Nenhum salário registrado")?;
+    let base_level =
+        index_at(index, &base.date).ok_or_else(|| format!("A série de inflação não cobre o ano-base {}", base_year))?;
+    // Preços de hoje: a última observação da série de inflação
+    let current_level = index.last().map(|(_, level)| *level).ok_or("Série de inflação vazia")?;
+
+    let required_nominal = base.value * current_level / base_level;
+    Ok(Restoration {
+        base_date: base.date.clone(),
+        base_nominal: base.value,
+        current_nominal: current.value,
+        required_nominal,
+        raise: (required_nominal / current.value - 1.0) * 100.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(date: &str, value: f64) -> SalaryRecord {
+        SalaryRecord {
+            date: date.to_string(),
+            value,
+        }
+    }
+
+    // Doze meses de inflação anual de 12% depois de 01/01/2020: os preços sobem 12% em um ano
+    fn inflation() -> Vec<(String, f64)> {
+        (1..=13)
+            .map(|i| (format!("01/{:02}/{}", (i - 1) % 12 + 1, 2020 + (i - 1) / 12), 12.0))
+            .rev()
+            .collect()
+    }
+
+    #[test]
+    fn index_compounds_annual_rates_monthly() {
+        let index = price_index(&inflation());
+
+        assert_eq!(index[0], ("01/01/2020".to_string(), 100.0));
+        assert!((index_at(&index, "15/01/2021").unwrap() - 112.0).abs() < 1e-9);
+        assert_eq!(index_at(&index, "01/12/2019"), None);
+    }
+
+    #[test]
+    fn raise_restores_base_year_purchasing_power() {
+        let index = price_index(&inflation());
+        let records = [record("01/01/2021", 1050.0), record("01/01/2020", 1000.0)];
+
+        let real = deflate(&records, &index, "2020").unwrap();
+        assert_eq!(real[0].real, 1000.0);
+        assert!((real[1].real - 1050.0 / 1.12).abs() < 1e-9);
+
+        let restoration = restore(&records, &index, "2020").unwrap();
+        assert!((restoration.required_nominal - 1120.0).abs() < 1e-9);
+        assert!((restoration.raise - (1120.0 / 1050.0 - 1.0) * 100.0).abs() < 1e-9);
+
+        assert!(restore(&records, &index, "2018").unwrap_err().contains("2018"));
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use crate::boxcox::{Band, BoxCox};
+use crate::stats;
+
+// Escala em que o modelo é ajustado; as previsões sempre voltam ao nível da série
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scale {
+    #[default]
+    Levels,
+    Logs,
+    // Variação percentual em relação à observação anterior
+    PercentChange,
+}
+
+impl Scale {
+    pub fn all() -> [Scale; 3] {
+        [Scale::Levels, Scale::Logs, Scale::PercentChange]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Scale::Levels => "Nível",
+            Scale::Logs => "Logaritmo",
+            Scale::PercentChange => "Variação percentual",
+        }
+    }
+
+    // Como ler os coeficientes do modelo ajustado nesta escala
+    pub fn interpretation(&self) -> &'static str {
+        match self {
+            Scale::Levels => "Coeficientes na unidade da série: um choque de 1 é um desvio de 1 unidade no nível.",
+            Scale::Logs => {
+                "Coeficientes sobre o log da série: diferenças de 0,01 equivalem a cerca de 1% no nível, e a \
+                 constante de um modelo diferenciado é a taxa de crescimento por período."
+            }
+            Scale::PercentChange => {
+                "Coeficientes sobre as variações percentuais de um período para o outro: a média do modelo é o \
+                 crescimento típico por período, em %."
+            }
+        }
+    }
+
+    // Série na escala do modelo; a variação percentual tem uma observação a menos
+    pub fn transform(&self, values: &[f64]) -> Result<Vec<f64>, String> {
+        match self {
+            Scale::Levels => Ok(values.to_vec()),
+            Scale::Logs => {
+                if values.iter().any(|value| *value <= 0.0) {
+                    return Err("A escala logarítmica exige valores positivos".to_string());
+                }
+                Ok(values.iter().map(|value| value.ln()).collect())
+            }
+            Scale::PercentChange => {
+                if values.len() < 2 {
+                    return Err("A variação percentual exige ao menos duas observações".to_string());
+                }
+                if values.contains(&0.0) {
+                    return Err("A variação percentual não é definida com valores nulos".to_string());
+                }
+                Ok(values.windows(2).map(|pair| (pair[1] / pair[0] - 1.0) * 100.0).collect())
+            }
+        }
+    }
+
+    // Previsões na escala do modelo de volta ao nível, continuando a partir da última observação
+    // `last`, com intervalo de 95%. `sigma2` é a variância de um passo na escala do modelo: as
+    // variâncias somam ao longo do horizonte, como em um passeio aleatório.
+    pub fn back_transform(&self, last: f64, forecast: &[f64], sigma2: f64) -> Vec<Band> {
+        let z = stats::normal_quantile(0.975);
+        match self {
+            Scale::Levels => forecast
+                .iter()
+                .enumerate()
+                .map(|(i, mean)| {
+                    let margin = z * (sigma2 * (i + 1) as f64).sqrt();
+                    Band {
+                        mean: *mean,
+                        lower: mean - margin,
+                        upper: mean + margin,
+                    }
+                })
+                .collect(),
+            Scale::Logs => BoxCox { lambda: 0.0 }.back_transform(forecast, sigma2),
+            // O nível acumula as variações previstas; a incerteza é a do log do nível, em que cada
+            // variação contribui com a variância de (1 + r/100)
+            Scale::PercentChange => {
+                let mut level = last;
+                forecast
+                    .iter()
+                    .enumerate()
+                    .map(|(i, rate)| {
+                        level *= 1.0 + rate / 100.0;
+                        let margin = z * (sigma2 * (i + 1) as f64).sqrt() / 100.0;
+                        Band {
+                            mean: level,
+                            lower: level * (-margin).exp(),
+                            upper: level * margin.exp(),
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+// Ajusta o modelo (`run`) na escala escolhida e devolve as previsões no nível da série. A variância
+// de um passo é a das variações da série transformada (erro do modelo ingênuo) ou, na variação
+// percentual, que já é uma diferença, a dispersão das próprias variações.
+pub fn forecast(
+    scale: Scale,
+    values: &[f64],
+    run: impl FnOnce(&[f64]) -> Result<Vec<f64>, String>,
+) -> Result<Vec<Band>, String> {
+    let last = *values.last().ok_or("Série vazia")?;
+    let transformed = scale.transform(values)?;
+    let sigma2 = match scale {
+        Scale::PercentChange => stats::std_dev(&transformed).powi(2),
+        Scale::Levels | Scale::Logs => {
+            let changes: Vec<f64> = transformed.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            stats::std_dev(&changes).powi(2)
+        }
+    };
+
+    let forecast = run(&transformed)?;
+    Ok(scale.back_transform(last, &forecast, sigma2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_changes_accumulate_back_to_levels() {
+        let values = [100.0, 110.0, 121.0];
+        let scale = Scale::PercentChange;
+
+        let transformed = scale.transform(&values).unwrap();
+        assert_eq!(transformed.len(), 2);
+        assert!(transformed.iter().all(|rate| (rate - 10.0).abs() < 1e-9));
+
+        let bands = forecast(scale, &values, |rates| Ok(vec![rates[1]; 2])).unwrap();
+        assert!((bands[0].mean - 133.1).abs() < 1e-9);
+        assert!((bands[1].mean - 146.41).abs() < 1e-9);
+        // Variações constantes: sem incerteza
+        assert!((bands[1].upper - bands[1].lower).abs() < 1e-9);
+
+        assert!(scale.transform(&[1.0, 0.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn logs_return_to_levels_with_widening_intervals() {
+        let values = [1.0, 2.0, 3.0, 5.0, 8.0];
+        let scale = Scale::Logs;
+
+        let bands = forecast(scale, &values, |logs| Ok(vec![*logs.last().unwrap(); 3])).unwrap();
+        assert!(bands[0].lower < 8.0 && bands[0].upper > 8.0);
+        // A média corrigida fica acima da mediana (o último valor)
+        assert!(bands[0].mean > 8.0);
+        assert!(bands[2].upper - bands[2].lower > bands[0].upper - bands[0].lower);
+
+        assert!(scale.transform(&[1.0, -1.0]).is_err());
+        assert_eq!(Scale::Levels.transform(&values).unwrap(), values.to_vec());
+    }
+
+    #[test]
+    fn serializes_in_snake_case() {
+        assert_eq!(serde_json::to_string(&Scale::PercentChange).unwrap(), "\"percent_change\"");
+        assert_eq!(Scale::default(), Scale::Levels);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::derived::DerivedSeries;
+use crate::expr;
+
+// Trajetória futura de uma série derivada: com a previsão do modelo e com o cenário do usuário
+#[derive(Debug, Clone, PartialEq)]
+pub struct Propagated {
+    pub name: String,
+    pub baseline: Vec<f64>,
+    pub scenario: Vec<f64>,
+}
+
+// Leva a trajetória futura de `target` às derivadas que dependem dela, direta ou indiretamente,
+// na ordem das definições. As demais séries referenciadas ficam no último valor observado.
+// Derivadas que não dependem de `target` ou que não puderam ser avaliadas ficam de fora.
+pub fn propagate(
+    definitions: &[DerivedSeries],
+    series: &BTreeMap<String, Vec<(String, f64)>>,
+    target: &str,
+    path: &[f64],
+) -> BTreeMap<String, Vec<f64>> {
+    let mut future = BTreeMap::from([(target.to_string(), path.to_vec())]);
+
+    for definition in definitions {
+        let Ok(expr) = expr::parse(&definition.expression) else {
+            continue;
+        };
+        let names = expr.series();
+        if !names.iter().any(|name| future.contains_key(name)) {
+            continue;
+        }
+
+        let values: Option<Vec<f64>> = (0..path.len())
+            .map(|step| {
+                let values: HashMap<String, f64> = names
+                    .iter()
+                    .map(|name| {
+                        let value = match future.get(name) {
+                            Some(path) => path.get(step).copied(),
+                            None => series.get(name).and_then(|observed| observed.last()).map(|(_, value)| *value),
+                        };
+                        value.map(|value| (name.clone(), value))
+                    })
+                    .collect::<Option<_>>()?;
+                expr.eval(&values)
+            })
+            .collect();
+        if let Some(values) = values {
+            future.insert(definition.name.clone(), values);
+        }
+    }
+
+    future.remove(target);
+    future
+}
+
+// Compara as derivadas sob a previsão do modelo e sob o cenário desenhado
+pub fn compare(
+    definitions: &[DerivedSeries],
+    series: &BTreeMap<String, Vec<(String, f64)>>,
+    target: &str,
+    baseline: &[f64],
+    scenario: &[f64],
+) -> Vec<Propagated> {
+    let mut baseline = propagate(definitions, series, target, baseline);
+    propagate(definitions, series, target, scenario)
+        .into_iter()
+        .filter_map(|(name, scenario)| {
+            let baseline = baseline.remove(&name)?;
+            Some(Propagated { name, baseline, scenario })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observed(values: &[f64]) -> Vec<(String, f64)> {
+        values.iter().enumerate().map(|(i, value)| (format!("01/{:02}/2024", i + 1), *value)).collect()
+    }
+
+    fn definition(name: &str, expression: &str) -> DerivedSeries {
+        DerivedSeries {
+            name: name.to_string(),
+            expression: expression.to_string(),
+        }
+    }
+
+    #[test]
+    fn scenario_flows_through_chained_derived_series() {
+        let series = BTreeMap::from([
+            ("inflation_brazil".to_string(), observed(&[4.0, 4.5])),
+            ("selic".to_string(), observed(&[10.5, 11.0])),
+        ]);
+        let definitions = [
+            definition("juro_real", "selic - inflation_brazil"),
+            definition("carteira", "100 * (1 + juro_real / 100)"),
+            definition("sem_relacao", "selic * 2"),
+        ];
+
+        let compared = compare(&definitions, &series, "inflation_brazil", &[4.5, 4.5], &[10.0, 6.0]);
+
+        assert_eq!(compared.len(), 2);
+        let real = compared.iter().find(|p| p.name == "juro_real").unwrap();
+        assert_eq!(real.baseline, vec![6.5, 6.5]);
+        assert_eq!(real.scenario, vec![1.0, 5.0]);
+        let portfolio = compared.iter().find(|p| p.name == "carteira").unwrap();
+        assert!((portfolio.scenario[0] - 101.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unknown_references_leave_the_derived_series_out() {
+        let series = BTreeMap::from([("inflation_chile".to_string(), observed(&[3.0]))]);
+        let definitions = [definition("spread", "inflation_chile - inflation_usa")];
+
+        assert!(propagate(&definitions, &series, "inflation_chile", &[5.0]).is_empty());
+    }
+}
@@ -0,0 +1,387 @@
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, FLOAT, INT};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::correlation::{self, Transform};
+use crate::data::date_sort_key;
+use crate::models::{self, Candidate};
+use crate::plugins::Plugin;
+use crate::{breaks, indicators, seasonal, stats, FORECAST_STEPS};
+
+// Limite de operações por execução, para que um laço infinito não trave a interface
+const MAX_OPERATIONS: u64 = 10_000_000;
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+// Operação aritmética entre dois valores, aplicada ponto a ponto
+type Operation = fn(f64, f64) -> f64;
+
+// Série exposta aos scripts: pares (data, valor) em ordem cronológica, como em `derived`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    pub name: String,
+    pub points: Vec<(String, f64)>,
+}
+
+impl TimeSeries {
+    pub fn new(name: &str, points: Vec<(String, f64)>) -> Self {
+        Self {
+            name: name.to_string(),
+            points,
+        }
+    }
+
+    pub fn values(&self) -> Vec<f64> {
+        self.points.iter().map(|(_, value)| *value).collect()
+    }
+
+    fn describe(&self) -> String {
+        match (self.points.first(), self.points.last()) {
+            (Some((first, _)), Some((last, _))) => {
+                format!("{}: {} observações ({} a {})", self.name, self.points.len(), first, last)
+            }
+            _ => format!("{}: vazia", self.name),
+        }
+    }
+
+    // Mesmas datas, valores transformados; indicadores com aquecimento descartam as primeiras datas
+    fn map_values(&self, name: String, values: Vec<Option<f64>>) -> TimeSeries {
+        let points = self
+            .points
+            .iter()
+            .zip(values)
+            .filter_map(|((date, _), value)| value.map(|v| (date.clone(), v)))
+            .collect();
+        TimeSeries { name, points }
+    }
+
+    // Valores das duas séries nas datas presentes em ambas
+    fn aligned(&self, other: &TimeSeries) -> Vec<(String, f64, f64)> {
+        let values: HashMap<&str, f64> = other.points.iter().map(|(date, v)| (date.as_str(), *v)).collect();
+        self.points
+            .iter()
+            .filter_map(|(date, v)| values.get(date.as_str()).map(|w| (date.clone(), *v, *w)))
+            .collect()
+    }
+
+    fn combine(&self, other: &TimeSeries, op: &str, f: Operation) -> TimeSeries {
+        let points = self.aligned(other).into_iter().map(|(date, v, w)| (date, f(v, w))).collect();
+        TimeSeries {
+            name: format!("({} {} {})", self.name, op, other.name),
+            points,
+        }
+    }
+
+    fn scalar(&self, op: &str, k: f64, f: Operation) -> TimeSeries {
+        TimeSeries {
+            name: format!("({} {} {})", self.name, op, k),
+            points: self.points.iter().map(|(date, v)| (date.clone(), f(*v, k))).collect(),
+        }
+    }
+}
+
+// Resultado de uma execução: o que o script imprimiu, o valor da última expressão e as
+// séries publicadas com `publish`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Output {
+    pub printed: Vec<String>,
+    pub result: Option<String>,
+    pub published: Vec<TimeSeries>,
+}
+
+// Diretório dos scripts (.rhai), podendo ser sobrescrito por SCRIPTS_DIR
+pub fn scripts_dir() -> Option<PathBuf> {
+    std::env::var("SCRIPTS_DIR")
+        .map(PathBuf::from)
+        .ok()
+        .or_else(|| dirs::config_dir().map(|dir| dir.join("rustfin").join("scripts")))
+}
+
+pub fn list_scripts(dir: &Path) -> Vec<PathBuf> {
+    let mut scripts: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "rhai"))
+                .collect()
+        })
+        .unwrap_or_default();
+    scripts.sort();
+    scripts
+}
+
+fn positive(n: INT, what: &str) -> ScriptResult<usize> {
+    usize::try_from(n)
+        .ok()
+        .filter(|n| *n > 0)
+        .ok_or_else(|| format!("{} deve ser positivo (recebido {})", what, n).into())
+}
+
+fn floats(values: &[f64]) -> Array {
+    values.iter().map(|v| Dynamic::from(*v)).collect()
+}
+
+// Nome usado nos scripts para cada modelo: naive, arima, mlp, theta, croston ou o nome do plugin
+fn model_name(candidate: Candidate, plugins: &[Plugin]) -> String {
+    match candidate {
+        Candidate::Naive => "naive".to_string(),
+        #[cfg(feature = "python-models")]
+        Candidate::Arima(..) => "arima".to_string(),
+        #[cfg(feature = "ml")]
+        Candidate::Mlp => "mlp".to_string(),
+        Candidate::Theta => "theta".to_string(),
+        Candidate::Croston => "croston".to_string(),
+        Candidate::Plugin(i) => plugins[i].name().to_string(),
+    }
+}
+
+fn forecast(candidate: Candidate, plugins: &[Plugin], series: &TimeSeries, steps: INT) -> ScriptResult<Array> {
+    let steps = positive(steps, "O horizonte")?;
+    if steps > FORECAST_STEPS {
+        return Err(format!("O horizonte deve ser no máximo {}", FORECAST_STEPS).into());
+    }
+
+    let forecast = candidate.run(plugins, &series.values(), steps)?;
+    Ok(floats(&forecast))
+}
+
+fn register_series(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<TimeSeries>("TimeSeries")
+        .register_get("name", |s: &mut TimeSeries| s.name.clone())
+        .register_fn("to_string", |s: &mut TimeSeries| s.describe())
+        .register_fn("to_debug", |s: &mut TimeSeries| s.describe())
+        .register_fn("len", |s: &mut TimeSeries| s.points.len() as INT)
+        .register_fn("values", |s: &mut TimeSeries| floats(&s.values()))
+        .register_fn("dates", |s: &mut TimeSeries| -> Array {
+            s.points.iter().map(|(date, _)| Dynamic::from(date.clone())).collect()
+        })
+        .register_fn("rename", |s: &mut TimeSeries, name: &str| TimeSeries::new(name, s.points.clone()))
+        .register_fn("last", |s: &mut TimeSeries| -> ScriptResult<FLOAT> {
+            s.points.last().map(|(_, v)| *v).ok_or_else(|| format!("{} está vazia", s.name).into())
+        })
+        .register_fn("mean", |s: &mut TimeSeries| stats::mean(&s.values()))
+        .register_fn("std", |s: &mut TimeSeries| stats::std_dev(&s.values()))
+        .register_fn("tail", |s: &mut TimeSeries, n: INT| -> ScriptResult<TimeSeries> {
+            let n = positive(n, "A quantidade")?.min(s.points.len());
+            Ok(TimeSeries::new(&s.name, s.points[s.points.len() - n..].to_vec()))
+        })
+        .register_fn("since", |s: &mut TimeSeries, date: &str| {
+            let start = date_sort_key(date);
+            let points = s.points.iter().filter(|(d, _)| date_sort_key(d) >= start).cloned().collect();
+            TimeSeries::new(&s.name, points)
+        })
+        .register_fn("diff", |s: &mut TimeSeries| {
+            TimeSeries::new(&format!("diff({})", s.name), Transform::Differences.apply(&s.points))
+        })
+        .register_fn("returns", |s: &mut TimeSeries| {
+            TimeSeries::new(&format!("returns({})", s.name), Transform::Returns.apply(&s.points))
+        })
+        .register_fn("sma", |s: &mut TimeSeries, period: INT| -> ScriptResult<TimeSeries> {
+            let period = positive(period, "O período")?;
+            Ok(s.map_values(format!("sma({}, {})", s.name, period), indicators::sma(&s.values(), period)))
+        })
+        .register_fn("ema", |s: &mut TimeSeries, period: INT| -> ScriptResult<TimeSeries> {
+            let period = positive(period, "O período")?;
+            Ok(s.map_values(format!("ema({}, {})", s.name, period), indicators::ema(&s.values(), period)))
+        })
+        .register_fn("adjust", |s: &mut TimeSeries, period: INT| -> ScriptResult<TimeSeries> {
+            let period = positive(period, "O período")?;
+            let adjusted = seasonal::seasonally_adjust(&s.values(), period)
+                .ok_or_else(|| format!("{} é curta demais para o ajuste sazonal", s.name))?;
+            Ok(s.map_values(format!("adjust({})", s.name), adjusted.into_iter().map(Some).collect()))
+        })
+        // Datas em que começam novos regimes
+        .register_fn("breaks", |s: &mut TimeSeries| -> Array {
+            breaks::detect_breaks(&s.values())
+                .into_iter()
+                .map(|i| Dynamic::from(s.points[i].0.clone()))
+                .collect()
+        })
+        .register_fn("corr", |a: &mut TimeSeries, b: TimeSeries| -> ScriptResult<FLOAT> {
+            let (x, y): (Vec<f64>, Vec<f64>) = a.aligned(&b).into_iter().map(|(_, v, w)| (v, w)).unzip();
+            correlation::pearson(&x, &y)
+                .ok_or_else(|| format!("Correlação indefinida entre {} e {}", a.name, b.name).into())
+        });
+
+    let operations: [(&str, Operation); 4] = [
+        ("+", |a, b| a + b),
+        ("-", |a, b| a - b),
+        ("*", |a, b| a * b),
+        ("/", |a, b| a / b),
+    ];
+    for (op, f) in operations {
+        engine
+            .register_fn(op, move |a: TimeSeries, b: TimeSeries| a.combine(&b, op, f))
+            .register_fn(op, move |a: TimeSeries, k: FLOAT| a.scalar(op, k, f))
+            .register_fn(op, move |a: TimeSeries, k: INT| a.scalar(op, k as f64, f));
+    }
+}
+
+fn register_models(engine: &mut Engine, plugins: Rc<Vec<Plugin>>) {
+    let available = Rc::new(Candidate::available(&plugins));
+
+    let (names, list) = (plugins.clone(), available.clone());
+    engine.register_fn("models", move || -> Array {
+        list.iter().map(|c| Dynamic::from(model_name(*c, &names))).collect()
+    });
+
+    // Modelo padrão: o primeiro disponível, na mesma ordem da interface
+    let (default_plugins, default) = (plugins.clone(), available[0]);
+    engine.register_fn("forecast", move |s: TimeSeries, steps: INT| {
+        forecast(default, &default_plugins, &s, steps)
+    });
+
+    let (named_plugins, list) = (plugins.clone(), available.clone());
+    engine.register_fn("forecast", move |s: TimeSeries, model: &str, steps: INT| -> ScriptResult<Array> {
+        let candidate = list
+            .iter()
+            .copied()
+            .find(|c| model_name(*c, &named_plugins).eq_ignore_ascii_case(model))
+            .ok_or_else(|| format!("Modelo desconhecido: {}", model))?;
+        forecast(candidate, &named_plugins, &s, steps)
+    });
+
+    #[cfg(feature = "python-models")]
+    {
+        let arima_plugins = plugins.clone();
+        engine.register_fn(
+            "arima",
+            move |s: TimeSeries, p: INT, d: INT, q: INT, steps: INT| -> ScriptResult<Array> {
+                let order = |n: INT| u32::try_from(n).map_err(|_| format!("Ordem inválida: {}", n));
+                let candidate = Candidate::Arima(order(p)?, order(d)?, order(q)?);
+                forecast(candidate, &arima_plugins, &s, steps)
+            },
+        );
+    }
+
+    // Escolha automática, como `rustfin batch --auto`
+    engine.register_fn("auto_forecast", move |s: TimeSeries, steps: INT| -> ScriptResult<Map> {
+        let steps = positive(steps, "O horizonte")?;
+        let fit = models::fit(&plugins, &s.values(), steps, true)?;
+
+        let mut result = Map::new();
+        result.insert("model".into(), Dynamic::from(fit.model));
+        result.insert("rmse".into(), fit.holdout_rmse.map(Dynamic::from).unwrap_or(Dynamic::UNIT));
+        result.insert("forecast".into(), Dynamic::from(floats(&fit.forecast)));
+        Ok(result)
+    });
+}
+
+// Executa um script com acesso às séries informadas (por nome, via `series("...")`) e aos modelos
+pub fn run(
+    script: &str,
+    series: BTreeMap<String, Vec<(String, f64)>>,
+    plugins: Rc<Vec<Plugin>>,
+) -> Result<Output, String> {
+    let printed = Rc::new(RefCell::new(vec![]));
+    let published = Rc::new(RefCell::new(vec![]));
+
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    register_series(&mut engine);
+    register_models(&mut engine, plugins);
+
+    let output = printed.clone();
+    engine.on_print(move |text| output.borrow_mut().push(text.to_string()));
+
+    let series = Rc::new(series);
+    let names = series.clone();
+    engine.register_fn("names", move || -> Array {
+        names.keys().map(|name| Dynamic::from(name.clone())).collect()
+    });
+    engine.register_fn("series", move |name: &str| -> ScriptResult<TimeSeries> {
+        series
+            .get(name)
+            .map(|points| TimeSeries::new(name, points.clone()))
+            .ok_or_else(|| format!("Série desconhecida: {}", name).into())
+    });
+
+    let results = published.clone();
+    engine.register_fn("publish", move |s: TimeSeries, name: &str| {
+        results.borrow_mut().push(TimeSeries::new(name, s.points));
+    });
+
+    let value = engine.eval::<Dynamic>(script).map_err(|e| e.to_string())?;
+    let result = if value.is_unit() {
+        None
+    } else if value.is::<TimeSeries>() {
+        Some(value.cast::<TimeSeries>().describe())
+    } else {
+        Some(value.to_string())
+    };
+
+    let printed = printed.borrow().clone();
+    let published = published.borrow().clone();
+    Ok(Output {
+        printed,
+        result,
+        published,
+    })
+}
+
+pub fn run_file(
+    path: &Path,
+    series: BTreeMap<String, Vec<(String, f64)>>,
+    plugins: Rc<Vec<Plugin>>,
+) -> Result<Output, String> {
+    let script = std::fs::read_to_string(path).map_err(|e| format!("Erro ao ler {}: {}", path.display(), e))?;
+    run(&script, series, plugins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(dates: &[&str], values: &[f64]) -> Vec<(String, f64)> {
+        dates.iter().map(|d| d.to_string()).zip(values.iter().copied()).collect()
+    }
+
+    fn sample() -> BTreeMap<String, Vec<(String, f64)>> {
+        BTreeMap::from([
+            ("a".to_string(), points(&["2020-01", "2020-02", "2020-03"], &[1.0, 2.0, 3.0])),
+            ("b".to_string(), points(&["2020-02", "2020-03", "2020-04"], &[4.0, 5.0, 6.0])),
+        ])
+    }
+
+    #[test]
+    fn arithmetic_aligns_series_by_date() {
+        let script = r#"
+            let a = series("a");
+            publish(a + series("b"), "soma");
+            publish(a.diff() * 10, "dif");
+        "#;
+
+        let output = run(script, sample(), Rc::new(vec![])).unwrap();
+
+        assert_eq!(output.published[0].points, points(&["2020-02", "2020-03"], &[6.0, 8.0]));
+        assert_eq!(output.published[1].name, "dif");
+        assert_eq!(output.published[1].values(), vec![10.0, 10.0]);
+        assert_eq!(output.result, None);
+    }
+
+    #[test]
+    fn prints_are_captured_and_models_forecast() {
+        let script = r#"
+            let f = forecast(series("b"), "naive", 2);
+            print(f.len());
+            print(series("a"));
+            f[1] == 6.0
+        "#;
+
+        let output = run(script, sample(), Rc::new(vec![])).unwrap();
+
+        assert_eq!(output.printed, vec!["2", "a: 3 observações (2020-01 a 2020-03)"]);
+        assert_eq!(output.result.as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn errors_name_the_missing_series() {
+        let error = run(r#"series("c").len()"#, sample(), Rc::new(vec![])).unwrap_err();
+
+        assert!(error.contains("Série desconhecida: c"), "{}", error);
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::annotations::{self, Annotation};
+use crate::data::Indicator;
+use crate::pinned::{self, PinnedForecast};
+use crate::presets::Presets;
+use crate::tags::Tags;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Series,
+    Annotation,
+    Forecast,
+    Preset,
+}
+
+impl Kind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Kind::Series => "Série",
+            Kind::Annotation => "Anotação",
+            Kind::Forecast => "Previsão fixada",
+            Kind::Preset => "Predefinição",
+        }
+    }
+}
+
+// O que abrir ao escolher um resultado
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    // Série pelo nome (ex.: inflation_brazil)
+    Series(String),
+    Preset(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    pub kind: Kind,
+    pub title: String,
+    pub detail: String,
+    pub target: Target,
+}
+
+// Minúsculas e sem acentos, para que "inflacao" encontre "Inflação"
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            c => c,
+        })
+        .collect()
+}
+
+// Palavras do texto normalizado; '_' e '-' também separam (inflation_brazil, q3-report)
+fn terms(text: &str) -> Vec<String> {
+    normalize(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+// Índice invertido em memória sobre as séries, anotações, previsões fixadas e predefinições;
+// as séries e as previsões também são encontradas pelas suas etiquetas.
+// Cada palavra da busca precisa ser o início de alguma palavra do resultado.
+#[derive(Debug, Clone, Default)]
+pub struct Index {
+    hits: Vec<Hit>,
+    terms: BTreeMap<String, BTreeSet<usize>>,
+}
+
+impl Index {
+    pub fn add(&mut self, hit: Hit, text: &str) {
+        let id = self.hits.len();
+        for term in terms(&format!("{} {} {}", hit.title, hit.detail, text)) {
+            self.terms.entry(term).or_default().insert(id);
+        }
+        self.hits.push(hit);
+    }
+
+    pub fn build<'a>(
+        series: impl IntoIterator<Item = &'a String>,
+        annotations: &BTreeMap<String, Vec<Annotation>>,
+        pinned: &[PinnedForecast],
+        presets: &Presets,
+        tags: &Tags,
+    ) -> Self {
+        let mut index = Index::default();
+
+        for name in series {
+            let indicator = Indicator::from_series_name(name).map(|indicator| indicator.label()).unwrap_or("derivada");
+            index.add(
+                Hit {
+                    kind: Kind::Series,
+                    title: name.clone(),
+                    detail: indicator.to_string(),
+                    target: Target::Series(name.clone()),
+                },
+                &tags.of(name).join(" "),
+            );
+        }
+
+        for (country, annotations) in annotations {
+            for annotation in annotations {
+                index.add(
+                    Hit {
+                        kind: Kind::Annotation,
+                        title: annotation.label.clone(),
+                        detail: format!("{} · {}", country, annotation.date),
+                        target: Target::Series(format!("{}_{}", Indicator::Inflation.key(), country)),
+                    },
+                    "",
+                );
+            }
+        }
+
+        for forecast in pinned {
+            index.add(
+                Hit {
+                    kind: Kind::Forecast,
+                    title: format!("{} · {}", forecast.series, forecast.model),
+                    detail: forecast.last_date.clone().map(|date| format!("após {}", date)).unwrap_or_default(),
+                    target: Target::Series(forecast.series.clone()),
+                },
+                &forecast.tags.join(" "),
+            );
+        }
+
+        for preset in &presets.presets {
+            // As séries que usam a predefinição como padrão também a encontram
+            let bound: Vec<&str> = presets
+                .defaults
+                .iter()
+                .filter(|(_, name)| **name == preset.name)
+                .map(|(series, _)| series.as_str())
+                .collect();
+            index.add(
+                Hit {
+                    kind: Kind::Preset,
+                    title: preset.name.clone(),
+                    detail: format!("{}, horizonte {}", preset.model, preset.horizon),
+                    target: Target::Preset(preset.name.clone()),
+                },
+                &bound.join(" "),
+            );
+        }
+
+        index
+    }
+
+    // Índice dos dados salvos; `series` são os nomes das séries disponíveis (cache e derivadas)
+    pub fn load<'a>(series: impl IntoIterator<Item = &'a String>) -> Self {
+        Self::build(series, &annotations::load_all(), &pinned::load(), &Presets::load(), &Tags::load())
+    }
+
+    // Resultados na ordem em que foram indexados: séries, anotações, previsões e predefinições
+    pub fn search(&self, query: &str) -> Vec<&Hit> {
+        let mut found: Option<BTreeSet<usize>> = None;
+        for term in terms(query) {
+            let matching: BTreeSet<usize> = self
+                .terms
+                .range(term.clone()..)
+                .take_while(|(indexed, _)| indexed.starts_with(&term))
+                .flat_map(|(_, ids)| ids.iter().copied())
+                .collect();
+            found = Some(match found {
+                Some(found) => found.intersection(&matching).copied().collect(),
+                None => matching,
+            });
+        }
+
+        found
+            .unwrap_or_default()
+            .into_iter()
+            .map(|id| &self.hits[id])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets::Preset;
+    use crate::scale::Scale;
+
+    fn index() -> Index {
+        let series = ["inflation_brazil".to_string(), "juro_real".to_string()];
+        let annotations = BTreeMap::from([(
+            "chile".to_string(),
+            vec![Annotation {
+                date: "01/10/2019".to_string(),
+                label: "Estallido social".to_string(),
+            }],
+        )]);
+        let mut presets = Presets::default();
+        presets.upsert(Preset {
+            name: "Relatório trimestral".to_string(),
+            model: "theta".to_string(),
+            order: (1, 1, 1),
+            horizon: 6,
+            seasonal_adjust: false,
+            box_cox: false,
+            scale: Scale::Levels,
+            latest_regime: false,
+        });
+        presets.bind("inflation_brazil", "Relatório trimestral").unwrap();
+        let mut tags = Tags::default();
+        tags.add("juro_real", "q3-report");
+
+        Index::build(&series, &annotations, &[], &presets, &tags)
+    }
+
+    #[test]
+    fn every_term_must_prefix_a_word() {
+        let index = index();
+        let titles = |query: &str| -> Vec<String> { index.search(query).iter().map(|hit| hit.title.clone()).collect() };
+
+        assert_eq!(titles("INFLACAO"), vec!["inflation_brazil"]);
+        assert_eq!(titles("relat trim"), vec!["Relatório trimestral"]);
+        assert_eq!(titles("brazil"), vec!["inflation_brazil", "Relatório trimestral"]);
+        assert_eq!(titles("estallido chile"), vec!["Estallido social"]);
+        assert!(titles("estallido brazil").is_empty());
+        assert!(titles("  ").is_empty());
+        assert_eq!(titles("q3 report"), vec!["juro_real"]);
+    }
+
+    #[test]
+    fn annotations_open_the_country_series() {
+        let index = index();
+
+        let hit = index.search("social")[0];
+
+        assert_eq!(hit.kind, Kind::Annotation);
+        assert_eq!(hit.target, Target::Series("inflation_chile".to_string()));
+    }
+}
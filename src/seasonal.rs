@@ -0,0 +1,118 @@
+// Decomposição sazonal baseada no STL (Cleveland et al., 1990), em versão simplificada:
+// laço interno com suavização LOESS das subséries de ciclo e da tendência, sem as
+// iterações de robustez a outliers.
+
+// Período sazonal de séries mensais
+pub const MONTHLY_PERIOD: usize = 12;
+
+const INNER_ITERATIONS: usize = 2;
+// Janela (ímpar) do suavizador das subséries de ciclo
+const SEASONAL_SPAN: usize = 7;
+
+#[derive(Debug, Clone)]
+pub struct Decomposition {
+    pub trend: Vec<f64>,
+    pub seasonal: Vec<f64>,
+    pub remainder: Vec<f64>,
+}
+
+fn next_odd(value: f64) -> usize {
+    let n = value.ceil() as usize;
+    if n.is_multiple_of(2) {
+        n + 1
+    } else {
+        n
+    }
+}
+
+// Regressão linear local com pesos tricúbicos sobre os `span` pontos mais próximos de cada posição
+fn loess(y: &[f64], span: usize) -> Vec<f64> {
+    let n = y.len();
+    if n < 3 {
+        return y.to_vec();
+    }
+    let span = span.clamp(3, n);
+
+    (0..n)
+        .map(|i| {
+            // Janela de `span` pontos contendo i, deslocada nas bordas
+            let start = i.saturating_sub(span / 2).min(n - span);
+            let end = start + span;
+            let max_distance = (i - start).max(end - 1 - i) as f64 + 1.0;
+
+            let (mut sw, mut swx, mut swy, mut swxx, mut swxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for (j, yj) in y.iter().enumerate().take(end).skip(start) {
+                let u = (j as f64 - i as f64).abs() / max_distance;
+                let w = (1.0 - u.powi(3)).powi(3);
+                let x = j as f64;
+                sw += w;
+                swx += w * x;
+                swy += w * yj;
+                swxx += w * x * x;
+                swxy += w * x * yj;
+            }
+
+            let denominator = sw * swxx - swx * swx;
+            if denominator.abs() < 1e-12 {
+                return swy / sw;
+            }
+            let slope = (sw * swxy - swx * swy) / denominator;
+            let intercept = (swy - slope * swx) / sw;
+            intercept + slope * i as f64
+        })
+        .collect()
+}
+
+// Requer ao menos dois ciclos completos
+pub fn stl(values: &[f64], period: usize) -> Option<Decomposition> {
+    let n = values.len();
+    if period < 2 || n < 2 * period {
+        return None;
+    }
+
+    let low_pass_span = next_odd(period as f64);
+    let trend_span = next_odd(1.5 * period as f64 / (1.0 - 1.5 / SEASONAL_SPAN as f64));
+
+    let mut trend = vec![0.0; n];
+    let mut seasonal = vec![0.0; n];
+
+    for _ in 0..INNER_ITERATIONS {
+        let detrended: Vec<f64> = values.iter().zip(&trend).map(|(y, t)| y - t).collect();
+
+        // Suaviza cada subsérie de ciclo (todos os janeiros, todos os fevereiros...)
+        let mut cycle = vec![0.0; n];
+        for k in 0..period {
+            let subseries: Vec<f64> = detrended.iter().skip(k).step_by(period).copied().collect();
+            for (j, value) in loess(&subseries, SEASONAL_SPAN).into_iter().enumerate() {
+                cycle[k + j * period] = value;
+            }
+        }
+
+        // Remove o nível de baixa frequência que vazou para o componente sazonal
+        let low_pass = loess(&cycle, low_pass_span);
+        seasonal = cycle.iter().zip(&low_pass).map(|(c, l)| c - l).collect();
+
+        let deseasonalized: Vec<f64> = values.iter().zip(&seasonal).map(|(y, s)| y - s).collect();
+        trend = loess(&deseasonalized, trend_span);
+    }
+
+    let remainder = values
+        .iter()
+        .zip(&trend)
+        .zip(&seasonal)
+        .map(|((y, t), s)| y - t - s)
+        .collect();
+
+    Some(Decomposition {
+        trend,
+        seasonal,
+        remainder,
+    })
+}
+
+// Série dessazonalizada (original menos o componente sazonal)
+pub fn seasonally_adjust(values: &[f64], period: usize) -> Option<Vec<f64>> {
+    let decomposition = stl(values, period)?;
+
+    Some(values.iter().zip(&decomposition.seasonal).map(|(y, s)| y - s).collect())
+}
@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use crate::data::date_sort_key;
+
+// Como a série antiga é levada ao nível da nova na data de emenda
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Method {
+    // Multiplica a antiga pela razão nova / antiga na emenda, preservando as variações percentuais
+    // (para índices rebaseados)
+    #[default]
+    Ratio,
+    // Soma a diferença nova - antiga na emenda, preservando as variações absolutas (para taxas
+    // e séries que podem ser negativas)
+    Difference,
+}
+
+impl Method {
+    pub fn all() -> [Method; 2] {
+        [Method::Ratio, Method::Difference]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Method::Ratio => "Razão",
+            Method::Difference => "Diferença",
+        }
+    }
+}
+
+// Série contínua formada por uma série descontinuada (`old`) e a que a substituiu (`new`),
+// tratada como qualquer outra série
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Splice {
+    pub name: String,
+    pub old: String,
+    pub new: String,
+    // Data de emenda (DD/MM/AAAA), presente nas duas séries; None para a última data em comum
+    #[serde(default)]
+    pub date: Option<String>,
+    #[serde(default)]
+    pub method: Method,
+}
+
+pub fn splices_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("splices.json"))
+}
+
+pub fn load() -> Vec<Splice> {
+    splices_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(splices: &[Splice]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = splices_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(splices)?)?;
+
+    Ok(())
+}
+
+fn sorted<'a>(series: &'a BTreeMap<String, Vec<(String, f64)>>, name: &str) -> Result<Vec<&'a (String, f64)>, String> {
+    let values = series.get(name).ok_or_else(|| format!("Série desconhecida: {}", name))?;
+    let mut sorted: Vec<&(String, f64)> = values.iter().collect();
+    sorted.sort_by_key(|(date, _)| date_sort_key(date));
+    Ok(sorted)
+}
+
+// Série emendada, em ordem cronológica: a antiga ajustada até a véspera da emenda e a nova, sem
+// alterações, a partir dela
+pub fn evaluate(splice: &Splice, series: &BTreeMap<String, Vec<(String, f64)>>) -> Result<Vec<(String, f64)>, String> {
+    let old = sorted(series, &splice.old)?;
+    let new = sorted(series, &splice.new)?;
+    let old_by_date: HashMap<&str, f64> = old.iter().map(|(date, value)| (date.as_str(), *value)).collect();
+
+    let link = match &splice.date {
+        Some(date) => new
+            .iter()
+            .find(|(other, _)| other == date)
+            .filter(|(other, _)| old_by_date.contains_key(other.as_str()))
+            .ok_or_else(|| format!("{} não está nas duas séries", date))?,
+        None => new
+            .iter()
+            .rev()
+            .find(|(date, _)| old_by_date.contains_key(date.as_str()))
+            .ok_or("As séries não têm datas em comum")?,
+    };
+    let (link_date, new_value) = link;
+    let old_value = old_by_date[link_date.as_str()];
+    if splice.method == Method::Ratio && old_value == 0.0 {
+        return Err(format!("A série antiga é zero em {}; use a diferença", link_date));
+    }
+
+    let link_key = date_sort_key(link_date);
+    let adjusted = old.iter().filter(|(date, _)| date_sort_key(date) < link_key).map(|(date, value)| {
+        let value = match splice.method {
+            Method::Ratio => value * new_value / old_value,
+            Method::Difference => value + new_value - old_value,
+        };
+        (date.clone(), value)
+    });
+    let continued = new
+        .iter()
+        .filter(|(date, _)| date_sort_key(date) >= link_key)
+        .map(|observation| (*observation).clone());
+
+    Ok(adjusted.chain(continued).collect())
+}
+
+// Acrescenta às séries as emendas que puderam ser avaliadas; emendas definidas antes podem ser
+// usadas nas seguintes
+pub fn extend(series: &mut BTreeMap<String, Vec<(String, f64)>>, splices: &[Splice]) {
+    for splice in splices {
+        if let Ok(values) = evaluate(splice, series) {
+            series.insert(splice.name.clone(), values);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> BTreeMap<String, Vec<(String, f64)>> {
+        BTreeMap::from([
+            (
+                "ipc_antigo".to_string(),
+                vec![
+                    ("01/03/2020".to_string(), 220.0),
+                    ("01/01/2020".to_string(), 200.0),
+                    ("01/02/2020".to_string(), 210.0),
+                ],
+            ),
+            (
+                "ipc_novo".to_string(),
+                vec![
+                    ("01/02/2020".to_string(), 100.0),
+                    ("01/03/2020".to_string(), 104.0),
+                    ("01/04/2020".to_string(), 106.0),
+                ],
+            ),
+        ])
+    }
+
+    fn splice(date: Option<&str>, method: Method) -> Splice {
+        Splice {
+            name: "ipc".to_string(),
+            old: "ipc_antigo".to_string(),
+            new: "ipc_novo".to_string(),
+            date: date.map(str::to_string),
+            method,
+        }
+    }
+
+    #[test]
+    fn ratio_splice_keeps_old_growth_on_the_new_basis() {
+        let spliced = evaluate(&splice(None, Method::Ratio), &series()).unwrap();
+
+        // Emenda na última data em comum (01/03), com razão 104 / 220
+        let dates: Vec<&str> = spliced.iter().map(|(date, _)| date.as_str()).collect();
+        assert_eq!(dates, vec!["01/01/2020", "01/02/2020", "01/03/2020", "01/04/2020"]);
+        assert!((spliced[0].1 - 200.0 * 104.0 / 220.0).abs() < 1e-9);
+        assert_eq!(spliced[2].1, 104.0);
+        assert_eq!(spliced[3].1, 106.0);
+
+        let earlier = evaluate(&splice(Some("01/02/2020"), Method::Ratio), &series()).unwrap();
+        assert!((earlier[0].1 - 200.0 * 100.0 / 210.0).abs() < 1e-9);
+        assert_eq!(earlier[2].1, 104.0);
+    }
+
+    #[test]
+    fn difference_splice_and_invalid_dates() {
+        let spliced = evaluate(&splice(Some("01/02/2020"), Method::Difference), &series()).unwrap();
+        assert_eq!(spliced[0].1, 90.0);
+
+        assert!(evaluate(&splice(Some("01/04/2020"), Method::Ratio), &series()).unwrap_err().contains("01/04/2020"));
+
+        let mut all = series();
+        extend(&mut all, &[splice(None, Method::Ratio)]);
+        assert_eq!(all["ipc"].len(), 4);
+    }
+}
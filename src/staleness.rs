@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+// O que fazer com a previsão exibida quando a série muda depois do ajuste do modelo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefitPolicy {
+    // Reajusta o modelo assim que a série muda
+    Always,
+    // Marca a previsão como desatualizada e oferece o reajuste
+    #[default]
+    Ask,
+    // Mantém a previsão, só com um aviso discreto
+    Never,
+}
+
+// Situação da previsão em relação à série atual
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Staleness {
+    Fresh,
+    // Observações que chegaram depois do ajuste
+    NewObservations(usize),
+    // Valores usados no ajuste foram revisados, ou a série é outra
+    Changed,
+}
+
+impl Staleness {
+    pub fn is_stale(self) -> bool {
+        self != Staleness::Fresh
+    }
+
+    pub fn describe(self) -> String {
+        match self {
+            Staleness::Fresh => "Previsão atualizada".to_string(),
+            Staleness::NewObservations(1) => "1 observação nova desde o ajuste do modelo".to_string(),
+            Staleness::NewObservations(new) => format!("{} observações novas desde o ajuste do modelo", new),
+            Staleness::Changed => "A série usada no ajuste do modelo foi revisada".to_string(),
+        }
+    }
+}
+
+fn fingerprint(dates: &[String], values: &[f64]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for (date, value) in dates.iter().zip(values) {
+        date.hash(&mut hasher);
+        value.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+// Série com que a previsão exibida foi feita: o tamanho e um resumo (hash) das datas e valores
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FitStamp {
+    observations: usize,
+    fingerprint: u64,
+}
+
+impl FitStamp {
+    pub fn of(dates: &[String], values: &[f64]) -> Self {
+        Self {
+            observations: values.len(),
+            fingerprint: fingerprint(dates, values),
+        }
+    }
+
+    // A série atual só é considerada continuação da ajustada se começar exatamente por ela
+    pub fn staleness(&self, dates: &[String], values: &[f64]) -> Staleness {
+        let known = self.observations;
+        if dates.len() < known || values.len() < known {
+            return Staleness::Changed;
+        }
+        if fingerprint(&dates[..known], &values[..known]) != self.fingerprint {
+            return Staleness::Changed;
+        }
+
+        match values.len() - known {
+            0 => Staleness::Fresh,
+            new => Staleness::NewObservations(new),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dates(n: usize) -> Vec<String> {
+        (1..=n).map(|month| format!("01/{:02}/2024", month)).collect()
+    }
+
+    #[test]
+    fn counts_observations_appended_after_the_fit() {
+        let stamp = FitStamp::of(&dates(3), &[0.4, 0.5, 0.6]);
+
+        assert_eq!(stamp.staleness(&dates(3), &[0.4, 0.5, 0.6]), Staleness::Fresh);
+        assert_eq!(stamp.staleness(&dates(5), &[0.4, 0.5, 0.6, 0.3, 0.2]), Staleness::NewObservations(2));
+        assert!(!Staleness::Fresh.is_stale());
+    }
+
+    #[test]
+    fn revisions_and_other_series_are_changes() {
+        let stamp = FitStamp::of(&dates(3), &[0.4, 0.5, 0.6]);
+
+        assert_eq!(stamp.staleness(&dates(4), &[0.4, 0.55, 0.6, 0.3]), Staleness::Changed);
+        assert_eq!(stamp.staleness(&dates(2), &[0.4, 0.5]), Staleness::Changed);
+        assert!(Staleness::Changed.is_stale());
+    }
+}
@@ -0,0 +1,211 @@
+// Regressão linear simples por mínimos quadrados
+#[derive(Debug, Clone, Copy)]
+pub struct LinearFit {
+    pub intercept: f64,
+    pub slope: f64,
+    pub r_squared: f64,
+}
+
+impl LinearFit {
+    pub fn predict(&self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+}
+
+pub fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+pub fn linear_regression(x: &[f64], y: &[f64]) -> Option<LinearFit> {
+    if x.len() != y.len() || x.len() < 2 {
+        return None;
+    }
+
+    let mean_x = mean(x);
+    let mean_y = mean(y);
+    let sxx: f64 = x.iter().map(|xi| (xi - mean_x).powi(2)).sum();
+    let sxy: f64 = x.iter().zip(y).map(|(xi, yi)| (xi - mean_x) * (yi - mean_y)).sum();
+    let syy: f64 = y.iter().map(|yi| (yi - mean_y).powi(2)).sum();
+
+    if sxx == 0.0 {
+        return None;
+    }
+
+    let slope = sxy / sxx;
+    let r_squared = if syy == 0.0 { 1.0 } else { sxy * sxy / (sxx * syy) };
+
+    Some(LinearFit {
+        intercept: mean_y - slope * mean_x,
+        slope,
+        r_squared,
+    })
+}
+
+pub fn std_dev(values: &[f64]) -> f64 {
+    let m = mean(values);
+    (values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / values.len() as f64).sqrt()
+}
+
+// Autocorrelação amostral na defasagem `lag`
+pub fn autocorrelation(values: &[f64], lag: usize) -> Option<f64> {
+    if values.len() <= lag {
+        return None;
+    }
+
+    let m = mean(values);
+    let denominator: f64 = values.iter().map(|v| (v - m).powi(2)).sum();
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let numerator: f64 = values.windows(lag + 1).map(|w| (w[0] - m) * (w[lag] - m)).sum();
+    Some(numerator / denominator)
+}
+
+// Erros de previsão contra os valores observados
+#[derive(Debug, Clone, Copy)]
+pub struct ForecastMetrics {
+    pub mae: f64,
+    pub rmse: f64,
+    // None quando algum valor observado é zero
+    pub mape: Option<f64>,
+}
+
+pub fn forecast_metrics(actual: &[f64], forecast: &[f64]) -> Option<ForecastMetrics> {
+    let n = actual.len().min(forecast.len());
+    if n == 0 {
+        return None;
+    }
+
+    let errors: Vec<f64> = actual.iter().zip(forecast).map(|(a, f)| a - f).collect();
+    let mape = if actual[..n].contains(&0.0) {
+        None
+    } else {
+        Some(errors.iter().zip(actual).map(|(e, a)| (e / a).abs()).sum::<f64>() / n as f64 * 100.0)
+    };
+
+    Some(ForecastMetrics {
+        mae: errors.iter().map(|e| e.abs()).sum::<f64>() / n as f64,
+        rmse: (errors.iter().map(|e| e * e).sum::<f64>() / n as f64).sqrt(),
+        mape,
+    })
+}
+
+pub fn normal_pdf(z: f64) -> f64 {
+    (-z * z / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+// Inversa da distribuição normal padrão (algoritmo de Acklam, erro relativo < 1,2e-9)
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    if p <= 0.0 {
+        f64::NEG_INFINITY
+    } else if p >= 1.0 {
+        f64::INFINITY
+    } else if p < LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+// Função erro complementar (Abramowitz e Stegun 7.1.26, erro absoluto < 1,5e-7)
+pub fn erfc(x: f64) -> f64 {
+    if x < 0.0 {
+        return 2.0 - erfc(-x);
+    }
+
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    poly * (-x * x).exp()
+}
+
+// Distribuição acumulada da normal padrão
+pub fn normal_cdf(z: f64) -> f64 {
+    0.5 * erfc(-z / std::f64::consts::SQRT_2)
+}
+
+// P(X > x) para uma qui-quadrado com 1 grau de liberdade
+pub fn chi_squared_1_sf(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    erfc((x / 2.0).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regression_recovers_exact_line() {
+        let x = [1.0, 2.0, 3.0, 4.0];
+        let y = [3.0, 5.0, 7.0, 9.0];
+
+        let fit = linear_regression(&x, &y).unwrap();
+
+        assert!((fit.slope - 2.0).abs() < 1e-12);
+        assert!((fit.intercept - 1.0).abs() < 1e-12);
+        assert!((fit.r_squared - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn forecast_metrics_match_hand_computation() {
+        let metrics = forecast_metrics(&[1.0, 2.0], &[2.0, 4.0]).unwrap();
+
+        assert_eq!(metrics.mae, 1.5);
+        assert!((metrics.rmse - 2.5_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(metrics.mape, Some(100.0));
+    }
+
+    #[test]
+    fn normal_quantiles_and_chi_squared_tail() {
+        assert!((normal_quantile(0.95) - 1.644854).abs() < 1e-6);
+        assert!((normal_quantile(0.01) + 2.326348).abs() < 1e-6);
+        assert!(normal_quantile(0.5).abs() < 1e-12);
+
+        // Valor crítico de 5% da qui-quadrado(1)
+        assert!((chi_squared_1_sf(3.841459) - 0.05).abs() < 1e-5);
+        assert!((normal_cdf(1.959964) - 0.975).abs() < 1e-6);
+    }
+}
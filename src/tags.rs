@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+// Etiquetas das séries (ex.: "latam", "q3-report"), indexadas pelo nome da série
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Tags {
+    pub series: BTreeMap<String, BTreeSet<String>>,
+}
+
+pub fn tags_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("tags.json"))
+}
+
+// Minúsculas e hífens no lugar de espaços; None para etiqueta vazia
+pub fn normalize(tag: &str) -> Option<String> {
+    let tag = tag.split_whitespace().collect::<Vec<_>>().join("-").to_lowercase();
+    (!tag.is_empty()).then_some(tag)
+}
+
+impl Tags {
+    pub fn load() -> Self {
+        tags_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = tags_path().ok_or("Diretório de configuração não encontrado")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    // Retorna a etiqueta normalizada, ou None se ela for vazia
+    pub fn add(&mut self, series: &str, tag: &str) -> Option<String> {
+        let tag = normalize(tag)?;
+        self.series.entry(series.to_string()).or_default().insert(tag.clone());
+        Some(tag)
+    }
+
+    pub fn remove(&mut self, series: &str, tag: &str) {
+        if let Some(tags) = self.series.get_mut(series) {
+            tags.remove(tag);
+            if tags.is_empty() {
+                self.series.remove(series);
+            }
+        }
+    }
+
+    pub fn of(&self, series: &str) -> Vec<String> {
+        self.series.get(series).map(|tags| tags.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    pub fn has(&self, series: &str, tag: &str) -> bool {
+        self.series.get(series).is_some_and(|tags| tags.contains(tag))
+    }
+
+    // Todas as etiquetas em uso, em ordem alfabética
+    pub fn all(&self) -> BTreeSet<String> {
+        self.series.values().flatten().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_are_normalized_and_removed_with_the_last_use() {
+        let mut tags = Tags::default();
+
+        assert_eq!(tags.add("inflation_brazil", " Q3 Report "), Some("q3-report".to_string()));
+        assert_eq!(tags.add("inflation_brazil", "   "), None);
+        tags.add("inflation_chile", "latam");
+        tags.add("inflation_brazil", "latam");
+
+        assert_eq!(tags.of("inflation_brazil"), vec!["latam", "q3-report"]);
+        assert!(tags.has("inflation_chile", "latam"));
+
+        tags.remove("inflation_chile", "latam");
+        assert!(!tags.series.contains_key("inflation_chile"));
+        assert_eq!(tags.all().into_iter().collect::<Vec<_>>(), vec!["latam", "q3-report"]);
+    }
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+// Meta de inflação do banco central e o intervalo de tolerância em torno dela, em % em 12 meses
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InflationTarget {
+    pub center: f64,
+    pub tolerance: f64,
+}
+
+impl InflationTarget {
+    pub fn lower(&self) -> f64 {
+        self.center - self.tolerance
+    }
+
+    pub fn upper(&self) -> f64 {
+        self.center + self.tolerance
+    }
+}
+
+// Metas vigentes dos países do provedor; `inflation_targets` no config.json as substitui ou
+// acrescenta outras
+pub fn default_target(country: &str) -> Option<InflationTarget> {
+    let (center, tolerance) = match country {
+        "brazil" => (3.0, 1.5),
+        "chile" | "colombia" | "mexico" => (3.0, 1.0),
+        "peru" => (2.0, 1.0),
+        _ => return None,
+    };
+    Some(InflationTarget { center, tolerance })
+}
+
+pub fn for_country(config: &Config, country: &str) -> Option<InflationTarget> {
+    config
+        .inflation_targets
+        .get(country)
+        .copied()
+        .or_else(|| default_target(country))
+}
+
+// Observações fora do intervalo de tolerância
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BandStats {
+    pub total: usize,
+    pub above: usize,
+    pub below: usize,
+    // Observações seguidas fora do intervalo até a mais recente
+    pub current_streak: usize,
+}
+
+impl BandStats {
+    pub fn outside(&self) -> usize {
+        self.above + self.below
+    }
+}
+
+pub fn band_stats(target: &InflationTarget, values: &[f64]) -> BandStats {
+    let mut stats = BandStats {
+        total: values.len(),
+        ..BandStats::default()
+    };
+    for value in values {
+        if *value > target.upper() {
+            stats.above += 1;
+        } else if *value < target.lower() {
+            stats.below += 1;
+        }
+    }
+    stats.current_streak = values
+        .iter()
+        .rev()
+        .take_while(|value| **value > target.upper() || **value < target.lower())
+        .count();
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_observations_outside_the_band() {
+        let target = default_target("brazil").unwrap();
+        let stats = band_stats(&target, &[1.0, 3.0, 4.5, 5.0, 6.2]);
+
+        assert_eq!((target.lower(), target.upper()), (1.5, 4.5));
+        assert_eq!((stats.above, stats.below, stats.outside(), stats.total), (2, 1, 3, 5));
+        assert_eq!(stats.current_streak, 2);
+    }
+
+    #[test]
+    fn configured_targets_take_precedence() {
+        let mut config = Config::default();
+        config.inflation_targets.insert(
+            "brazil".to_string(),
+            InflationTarget {
+                center: 3.0,
+                tolerance: 1.0,
+            },
+        );
+        config.inflation_targets.insert(
+            "argentina".to_string(),
+            InflationTarget {
+                center: 5.0,
+                tolerance: 2.0,
+            },
+        );
+
+        assert_eq!(for_country(&config, "brazil").unwrap().upper(), 4.0);
+        assert_eq!(for_country(&config, "argentina").unwrap().center, 5.0);
+        assert_eq!(for_country(&config, "chile"), default_target("chile"));
+        assert!(for_country(&config, "venezuela").is_none());
+    }
+}
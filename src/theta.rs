@@ -0,0 +1,126 @@
+use crate::stats;
+
+// Mínimo de observações para estimar a tendência e a suavização
+const MIN_OBSERVATIONS: usize = 3;
+// Valores do parâmetro de suavização avaliados: 0.01, 0.02, ..., 0.99
+const ALPHA_GRID: usize = 99;
+
+// Método Theta (Assimakopoulos e Nikolopoulos, 2000), vencedor da competição M3, na forma de
+// Hyndman e Billah (2003): suavização exponencial simples com deriva igual à metade da
+// inclinação da tendência linear da série
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theta {
+    pub alpha: f64,
+    pub slope: f64,
+    // Nível da suavização após a última observação
+    pub level: f64,
+    pub observations: usize,
+    // Variância dos erros de um passo na série usada no ajuste
+    pub sigma2: f64,
+}
+
+// Nível final da suavização e soma dos quadrados dos erros de um passo
+fn smooth(values: &[f64], alpha: f64) -> (f64, f64) {
+    let mut level = values[0];
+    let mut sse = 0.0;
+    for value in &values[1..] {
+        let error = value - level;
+        sse += error * error;
+        level += alpha * error;
+    }
+    (level, sse)
+}
+
+impl Theta {
+    // Inclinação por mínimos quadrados; α pelo menor erro de um passo na grade
+    pub fn fit(values: &[f64]) -> Option<Self> {
+        if values.len() < MIN_OBSERVATIONS || values.iter().any(|v| !v.is_finite()) {
+            return None;
+        }
+
+        let time: Vec<f64> = (0..values.len()).map(|t| t as f64).collect();
+        let slope = stats::linear_regression(&time, values)?.slope;
+        let alpha = (1..=ALPHA_GRID)
+            .map(|i| i as f64 / (ALPHA_GRID + 1) as f64)
+            .map(|alpha| (alpha, smooth(values, alpha).1))
+            .min_by(|a, b| a.1.total_cmp(&b.1))?
+            .0;
+
+        Self::with_params(values, alpha, slope)
+    }
+
+    // Aplica parâmetros já estimados a uma série (ex.: com observações novas)
+    pub fn with_params(values: &[f64], alpha: f64, slope: f64) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+
+        let (level, sse) = smooth(values, alpha);
+        Some(Self {
+            alpha,
+            slope,
+            level,
+            observations: values.len(),
+            sigma2: sse / (values.len() - 1).max(1) as f64,
+        })
+    }
+
+    // Atualiza o nível com uma observação nova, sem reestimar α nem a inclinação
+    pub fn update(&mut self, value: f64) {
+        self.level += self.alpha * (value - self.level);
+        self.observations += 1;
+    }
+
+    pub fn forecast(&self, steps: usize) -> Vec<f64> {
+        // Correção pela inicialização do nível na primeira observação
+        let n = self.observations as f64;
+        let correction = 1.0 / self.alpha - (1.0 - self.alpha).powf(n) / self.alpha;
+
+        (1..=steps)
+            .map(|h| self.level + self.slope / 2.0 * (h as f64 - 1.0 + correction))
+            .collect()
+    }
+}
+
+pub fn run_theta_model(values: &[f64], steps: usize) -> Result<Vec<f64>, String> {
+    let theta = Theta::fit(values)
+        .ok_or_else(|| format!("Série inválida para o método Theta (mínimo de {} pontos)", MIN_OBSERVATIONS))?;
+    Ok(theta.forecast(steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_trend_is_extrapolated() {
+        let values: Vec<f64> = (0..40).map(|t| 2.0 + 0.5 * t as f64).collect();
+
+        let theta = Theta::fit(&values).unwrap();
+        let forecast = theta.forecast(3);
+
+        assert!((theta.slope - 0.5).abs() < 1e-9);
+        // A deriva é metade da inclinação: o passo entre previsões é 0,25
+        assert!((forecast[1] - forecast[0] - 0.25).abs() < 1e-9);
+        assert!(forecast[0] > *values.last().unwrap() - 0.5);
+    }
+
+    #[test]
+    fn constant_series_forecasts_its_level() {
+        assert_eq!(run_theta_model(&[3.0; 12], 2).unwrap(), vec![3.0, 3.0]);
+        assert!(run_theta_model(&[1.0, 2.0], 2).is_err());
+    }
+
+    #[test]
+    fn update_matches_smoothing_the_longer_series() {
+        let values = [4.0, 4.4, 4.1, 4.8, 5.0, 4.7, 5.3];
+        let mut theta = Theta::with_params(&values[..5], 0.3, 0.1).unwrap();
+
+        theta.update(values[5]);
+        theta.update(values[6]);
+
+        let full = Theta::with_params(&values, 0.3, 0.1).unwrap();
+        assert!((theta.level - full.level).abs() < 1e-12);
+        assert_eq!(theta.forecast(4), full.forecast(4));
+    }
+}
@@ -0,0 +1,409 @@
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Sparkline};
+use ratatui::{Frame, Terminal};
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::client::ApiClient;
+use crate::config::Config;
+use crate::data::{Indicator, InflationData, COUNTRIES};
+use crate::models::Candidate;
+use crate::plugins::{self, Plugin};
+use crate::prefetch;
+use crate::FORECAST_STEPS;
+
+// Intervalo entre redesenhos enquanto não há teclas, para acompanhar as buscas em andamento
+const TICK: Duration = Duration::from_millis(100);
+
+// Resultado de uma tarefa em segundo plano; None enquanto ela não termina
+type Slot<T> = Arc<Mutex<Option<Result<T, String>>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Countries,
+    Indicators,
+    Form,
+}
+
+impl Focus {
+    fn next(self) -> Focus {
+        match self {
+            Focus::Countries => Focus::Indicators,
+            Focus::Indicators => Focus::Form,
+            Focus::Form => Focus::Countries,
+        }
+    }
+}
+
+// Campos do formulário de parâmetros; a ordem (p, d, q) só existe com o ARIMA
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Model,
+    #[cfg(feature = "python-models")]
+    P,
+    #[cfg(feature = "python-models")]
+    D,
+    #[cfg(feature = "python-models")]
+    Q,
+    Horizon,
+}
+
+impl Field {
+    fn all() -> Vec<Field> {
+        vec![
+            Field::Model,
+            #[cfg(feature = "python-models")]
+            Field::P,
+            #[cfg(feature = "python-models")]
+            Field::D,
+            #[cfg(feature = "python-models")]
+            Field::Q,
+            Field::Horizon,
+        ]
+    }
+}
+
+fn step(value: usize, delta: i32, max: usize) -> usize {
+    value.saturating_add_signed(delta as isize).min(max)
+}
+
+struct App {
+    config: Config,
+    plugins: Arc<Vec<Plugin>>,
+    countries: Vec<String>,
+    country: ListState,
+    indicator: ListState,
+    focus: Focus,
+    // Índice em `models`; a ordem do ARIMA vem de (p, d, q)
+    models: Vec<Candidate>,
+    model: usize,
+    field: usize,
+    order: (usize, usize, usize),
+    horizon: usize,
+    // Série carregada, com o país e o indicador
+    loaded: Option<(String, Indicator)>,
+    data: Slot<Vec<InflationData>>,
+    forecast: Slot<Vec<f64>>,
+}
+
+impl App {
+    fn new(config: &Config) -> Self {
+        let mut countries: Vec<String> = COUNTRIES.iter().map(|country| country.to_string()).collect();
+        if !countries.contains(&config.country) {
+            countries.insert(0, config.country.clone());
+        }
+        let selected = countries.iter().position(|country| *country == config.country);
+        let plugins = plugins::discover_plugins(&plugins::plugins_dir());
+
+        Self {
+            config: config.clone(),
+            models: Candidate::available(&plugins),
+            plugins: Arc::new(plugins),
+            countries,
+            country: ListState::default().with_selected(selected),
+            indicator: ListState::default().with_selected(Some(0)),
+            focus: Focus::Countries,
+            model: 0,
+            field: 0,
+            order: (1, 1, 1),
+            horizon: 12,
+            loaded: None,
+            data: Arc::new(Mutex::new(None)),
+            forecast: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn selection(&self) -> (String, Indicator) {
+        let country = self.countries[self.country.selected().unwrap_or(0)].clone();
+        let indicator = Indicator::all()[self.indicator.selected().unwrap_or(0)];
+        (country, indicator)
+    }
+
+    fn candidate(&self) -> Candidate {
+        match self.models[self.model] {
+            #[cfg(feature = "python-models")]
+            Candidate::Arima(..) => {
+                let (p, d, q) = self.order;
+                Candidate::Arima(p as u32, d as u32, q as u32)
+            }
+            other => other,
+        }
+    }
+
+    fn load(&mut self) {
+        let (country, indicator) = self.selection();
+        let config = self.config.clone();
+        let data = self.data.clone();
+
+        self.loaded = Some((country.clone(), indicator));
+        *data.lock().unwrap() = None;
+        *self.forecast.lock().unwrap() = None;
+
+        tokio::spawn(async move {
+            let result = prefetch::get_indicator(ApiClient::shared(), &config, indicator, &country)
+                .await
+                .map_err(|e| e.to_string());
+            let loaded = result.is_ok();
+            *data.lock().unwrap() = Some(result);
+
+            // Os demais indicadores do país ficam prontos para a troca. As falhas são descartadas: não
+            // há onde mostrá-las sem a tela em modo bruto, e o indicador é buscado de novo ao ser escolhido
+            if loaded {
+                let _ = prefetch::prefetch_country(ApiClient::shared(), &config, &country, indicator).await;
+            }
+        });
+    }
+
+    fn predict(&mut self) {
+        let values: Vec<f64> = match &*self.data.lock().unwrap() {
+            Some(Ok(data)) => data.iter().map(|obs| obs.value).collect(),
+            _ => return,
+        };
+        let (candidate, horizon) = (self.candidate(), self.horizon);
+        let plugins = self.plugins.clone();
+        let forecast = self.forecast.clone();
+
+        *forecast.lock().unwrap() = None;
+        // O ajuste (Python, candle ou plugins) é bloqueante
+        tokio::task::spawn_blocking(move || {
+            let result = candidate.run(&plugins, &values, horizon);
+            *forecast.lock().unwrap() = Some(result);
+        });
+    }
+
+    fn adjust(&mut self, delta: i32) {
+        match Field::all()[self.field] {
+            Field::Model => self.model = step(self.model, delta, self.models.len() - 1),
+            #[cfg(feature = "python-models")]
+            Field::P => self.order.0 = step(self.order.0, delta, 5),
+            #[cfg(feature = "python-models")]
+            Field::D => self.order.1 = step(self.order.1, delta, 2),
+            #[cfg(feature = "python-models")]
+            Field::Q => self.order.2 = step(self.order.2, delta, 5),
+            Field::Horizon => self.horizon = step(self.horizon, delta, FORECAST_STEPS).max(1),
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        match self.focus {
+            Focus::Countries => {
+                let selected = step(self.country.selected().unwrap_or(0), delta, self.countries.len() - 1);
+                self.country.select(Some(selected));
+            }
+            Focus::Indicators => {
+                let selected = step(self.indicator.selected().unwrap_or(0), delta, Indicator::all().len() - 1);
+                self.indicator.select(Some(selected));
+            }
+            Focus::Form => self.field = step(self.field, delta, Field::all().len() - 1),
+        }
+    }
+
+    // Retorna false para encerrar
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Tab => self.focus = self.focus.next(),
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::Left if self.focus == Focus::Form => self.adjust(-1),
+            KeyCode::Right if self.focus == Focus::Form => self.adjust(1),
+            KeyCode::Enter if self.focus == Focus::Form => self.predict(),
+            KeyCode::Enter => self.load(),
+            KeyCode::Char('p') => self.predict(),
+            _ => {}
+        }
+        true
+    }
+}
+
+fn block(title: &str, focused: bool) -> Block<'_> {
+    let style = if focused { Style::default().fg(Color::Yellow) } else { Style::default() };
+    Block::default().title(title).borders(Borders::ALL).border_style(style)
+}
+
+// Sparklines só aceitam inteiros não negativos: valores levados para 0..=100 na escala comum
+fn scale(values: &[f64], min: f64, max: f64) -> Vec<u64> {
+    let range = if max > min { max - min } else { 1.0 };
+    values.iter().map(|v| ((v - min) / range * 100.0).round() as u64).collect()
+}
+
+fn draw_sidebar(frame: &mut Frame, app: &mut App, area: Rect) {
+    let highlight = Style::default().add_modifier(Modifier::REVERSED);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Length(4), Constraint::Min(7)])
+        .split(area);
+
+    let items: Vec<ListItem> = app.countries.iter().map(|country| ListItem::new(country.as_str())).collect();
+    let list = List::new(items)
+        .block(block("País", app.focus == Focus::Countries))
+        .highlight_style(highlight);
+    frame.render_stateful_widget(list, rows[0], &mut app.country);
+
+    let items: Vec<ListItem> = Indicator::all().iter().map(|indicator| ListItem::new(indicator.label())).collect();
+    let list = List::new(items)
+        .block(block("Indicador", app.focus == Focus::Indicators))
+        .highlight_style(highlight);
+    frame.render_stateful_widget(list, rows[1], &mut app.indicator);
+
+    let lines: Vec<Line> = Field::all()
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let (label, value) = match field {
+                Field::Model => ("Modelo", app.models[app.model].label(&app.plugins)),
+                #[cfg(feature = "python-models")]
+                Field::P => ("p", app.order.0.to_string()),
+                #[cfg(feature = "python-models")]
+                Field::D => ("d", app.order.1.to_string()),
+                #[cfg(feature = "python-models")]
+                Field::Q => ("q", app.order.2.to_string()),
+                Field::Horizon => ("Passos", app.horizon.to_string()),
+            };
+            let style = if app.focus == Focus::Form && i == app.field { highlight } else { Style::default() };
+            Line::from(vec![Span::raw(format!("{:<8}", label)), Span::styled(format!("< {} >", value), style)])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block("Parâmetros", app.focus == Focus::Form)), rows[2]);
+}
+
+fn draw_series(frame: &mut Frame, app: &App, area: Rect) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(6), Constraint::Length(6), Constraint::Length(1)])
+        .split(area);
+
+    let data = app.data.lock().unwrap().clone();
+    let forecast = app.forecast.lock().unwrap().clone();
+    let title = app
+        .loaded
+        .as_ref()
+        .map(|(country, indicator)| format!("{} ({})", indicator.label(), country))
+        .unwrap_or_else(|| "Nenhuma série carregada".to_string());
+
+    let (info, history) = match (&app.loaded, &data) {
+        (None, _) => ("Escolha o país e o indicador e tecle Enter.".to_string(), vec![]),
+        (Some(_), None) => ("Buscando...".to_string(), vec![]),
+        (Some(_), Some(Err(e))) => (format!("Erro ao buscar: {}", e), vec![]),
+        (Some(_), Some(Ok(data))) => {
+            let last = data
+                .last()
+                .map(|obs| format!("último valor {} em {}", app.config.format_value(obs.value), obs.date))
+                .unwrap_or_default();
+            let values: Vec<f64> = data.iter().map(|obs| obs.value).collect();
+            (format!("{} observações, {}", data.len(), last), values)
+        }
+    };
+    let (status, predicted) = match &forecast {
+        None => (String::new(), vec![]),
+        Some(Err(e)) => (format!("Erro na previsão: {}", e), vec![]),
+        Some(Ok(values)) => (
+            format!(
+                "Previsão: {}",
+                values.iter().map(|v| app.config.format_value(*v)).collect::<Vec<_>>().join(" ")
+            ),
+            values.clone(),
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(vec![Line::from(info), Line::from(status)]).block(block(&title, false)),
+        rows[0],
+    );
+
+    // Só cabe um ponto por coluna: mostra as observações mais recentes
+    let width = rows[1].width.saturating_sub(2) as usize;
+    let recent = &history[history.len().saturating_sub(width)..];
+    let (min, max) = recent
+        .iter()
+        .chain(&predicted)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(*v), max.max(*v)));
+
+    let history = scale(recent, min, max);
+    frame.render_widget(
+        Sparkline::default()
+            .block(block("Histórico", false))
+            .data(&history)
+            .style(Style::default().fg(Color::Cyan)),
+        rows[1],
+    );
+    let predicted = scale(&predicted, min, max);
+    frame.render_widget(
+        Sparkline::default()
+            .block(block("Previsão (mesma escala)", false))
+            .data(&predicted)
+            .style(Style::default().fg(Color::Green)),
+        rows[2],
+    );
+
+    frame.render_widget(
+        Paragraph::new("Tab: alterna painel  ↑↓: navega  ←→: altera parâmetro  Enter: carrega/prevê  p: prevê  q: sai")
+            .style(Style::default().fg(Color::DarkGray)),
+        rows[3],
+    );
+}
+
+fn draw(frame: &mut Frame, app: &mut App) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(32), Constraint::Min(40)])
+        .split(frame.size());
+
+    draw_sidebar(frame, app, columns[0]);
+    draw_series(frame, app, columns[1]);
+}
+
+fn event_loop(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        if event::poll(TICK)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !app.handle_key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+// Modo terminal: escolha de país, indicador e parâmetros do modelo, com a série e a previsão
+// em sparklines. Deve ser chamado dentro do runtime tokio (as buscas rodam em tarefas).
+pub fn run(config: &Config) -> io::Result<()> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+    let result = event_loop(&mut terminal, &mut App::new(config));
+
+    // O terminal é restaurado mesmo quando o laço termina com erro
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_values_to_common_range() {
+        assert_eq!(scale(&[1.0, 2.0, 3.0], 1.0, 5.0), vec![0, 25, 50]);
+        assert_eq!(scale(&[2.0, 2.0], 2.0, 2.0), vec![0, 0]);
+    }
+
+    #[test]
+    fn steps_stay_within_bounds() {
+        assert_eq!(step(0, -1, 5), 0);
+        assert_eq!(step(5, 1, 5), 5);
+        assert_eq!(step(2, 1, 5), 3);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::croston;
+use crate::data::{date_sort_key, Indicator};
+use crate::vintage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    // Série claramente corrompida: os modelos não devem ser ajustados
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Issue {
+    fn warning(message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message,
+        }
+    }
+
+    fn error(message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            message,
+        }
+    }
+}
+
+// Intervalo em dias entre datas consecutivas, quando ambas são reconhecidas
+fn gaps_in_days(dates: &[String]) -> Vec<i64> {
+    let days: Vec<Option<i64>> = dates
+        .iter()
+        .map(|date| vintage::parse_date(&date_sort_key(date)).map(|secs| (secs / 86400) as i64))
+        .collect();
+
+    days.windows(2)
+        .filter_map(|pair| match pair {
+            [Some(a), Some(b)] => Some(b - a),
+            _ => None,
+        })
+        .collect()
+}
+
+// Verificações de sanidade de uma série (datas e valores na mesma ordem)
+pub fn validate(dates: &[String], values: &[f64], indicator: Option<Indicator>) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    if values.is_empty() {
+        issues.push(Issue::error("Série vazia".to_string()));
+        return issues;
+    }
+    if dates.len() != values.len() {
+        issues.push(Issue::error(format!(
+            "{} datas para {} valores",
+            dates.len(),
+            values.len()
+        )));
+    }
+
+    let invalid = values.iter().filter(|value| !value.is_finite()).count();
+    if invalid > 0 {
+        issues.push(Issue::error(format!("{} valores não numéricos ou infinitos", invalid)));
+    }
+
+    if let Some(indicator) = indicator {
+        let (min, max) = indicator.plausible_range();
+        let outside = values.iter().filter(|value| **value < min || **value > max).count();
+        if outside > 0 {
+            issues.push(Issue::error(format!(
+                "{} valores fora da faixa plausível para {} ({} a {})",
+                outside,
+                indicator.label(),
+                min,
+                max
+            )));
+        }
+    }
+
+    let keys: Vec<String> = dates.iter().map(|date| date_sort_key(date)).collect();
+    let duplicates = keys.windows(2).filter(|pair| pair[0] == pair[1]).count();
+    if duplicates > 0 {
+        issues.push(Issue::error(format!("{} datas duplicadas", duplicates)));
+    }
+    let out_of_order = keys.windows(2).filter(|pair| pair[0] > pair[1]).count();
+    if out_of_order > 0 {
+        issues.push(Issue::error(format!("{} datas fora de ordem cronológica", out_of_order)));
+    }
+
+    let unparsed = keys.iter().filter(|key| vintage::parse_date(key).is_none()).count();
+    if unparsed > 0 {
+        issues.push(Issue::warning(format!("{} datas em formato não reconhecido", unparsed)));
+    }
+
+    // Consistência de frequência: intervalos muito diferentes do típico indicam lacunas
+    let mut gaps: Vec<i64> = gaps_in_days(dates).into_iter().filter(|gap| *gap > 0).collect();
+    if !gaps.is_empty() {
+        gaps.sort_unstable();
+        let median = gaps[gaps.len() / 2];
+        // Séries diárias de pregões pulam fins de semana e feriados
+        let tolerance = if median == 1 { 3.0 } else { median as f64 * 0.5 };
+        let irregular = gaps
+            .iter()
+            .filter(|gap| (**gap - median).abs() as f64 > tolerance)
+            .count();
+        if irregular > 0 {
+            issues.push(Issue::warning(format!(
+                "{} intervalos entre observações diferem da frequência típica ({} dias)",
+                irregular, median
+            )));
+        }
+    }
+
+    // Muitos zeros: os modelos usuais não capturam a alternância entre zeros e picos
+    if croston::is_intermittent(values) {
+        let zeros = values.iter().filter(|value| **value == 0.0).count();
+        issues.push(Issue::warning(format!(
+            "Série intermitente ({} de {} valores nulos); prefira o método de Croston",
+            zeros,
+            values.len()
+        )));
+    }
+
+    issues
+}
+
+pub fn is_corrupt(issues: &[Issue]) -> bool {
+    issues.iter().any(|issue| issue.severity == Severity::Error)
+}
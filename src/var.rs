@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+
+use crate::data::date_sort_key;
+use crate::rng::SeededRng;
+
+// Defasagens aceitas no VAR
+pub const MAX_LAGS: usize = 12;
+
+// Matriz densa, linha a linha
+pub type Matrix = Vec<Vec<f64>>;
+
+fn identity(n: usize) -> Matrix {
+    (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect()
+}
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    a.iter()
+        .map(|row| (0..b[0].len()).map(|j| row.iter().zip(b).map(|(x, b_row)| x * b_row[j]).sum::<f64>()).collect())
+        .collect()
+}
+
+// Resolve A·X = B (A quadrada, B com várias colunas) por eliminação de Gauss com pivotamento
+// parcial; None se A for singular
+fn solve(mut a: Matrix, mut b: Matrix) -> Option<Matrix> {
+    let n = a.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|x, y| a[*x][col].abs().total_cmp(&a[*y][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (pivot_a, pivot_b) = (a[col].clone(), b[col].clone());
+        for (a_row, b_row) in a.iter_mut().zip(b.iter_mut()).skip(col + 1) {
+            let factor = a_row[col] / pivot_a[col];
+            for (x, pivot) in a_row.iter_mut().zip(&pivot_a).skip(col) {
+                *x -= factor * pivot;
+            }
+            for (x, pivot) in b_row.iter_mut().zip(&pivot_b) {
+                *x -= factor * pivot;
+            }
+        }
+    }
+
+    let mut x: Matrix = vec![vec![]; n];
+    for row in (0..n).rev() {
+        x[row] = (0..b[row].len())
+            .map(|k| {
+                let known: f64 = (row + 1..n).map(|j| a[row][j] * x[j][k]).sum();
+                (b[row][k] - known) / a[row][row]
+            })
+            .collect();
+    }
+    Some(x)
+}
+
+// Fator triangular inferior P de uma matriz simétrica positiva definida (P·Pᵀ = A)
+fn cholesky(a: &Matrix) -> Option<Matrix> {
+    let n = a.len();
+    let mut p = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| p[i][k] * p[j][k]).sum();
+            if i == j {
+                let diagonal = a[i][i] - sum;
+                if diagonal <= 0.0 {
+                    return None;
+                }
+                p[i][j] = diagonal.sqrt();
+            } else {
+                p[i][j] = (a[i][j] - sum) / p[j][j];
+            }
+        }
+    }
+    Some(p)
+}
+
+// Quantil `q` de valores ordenados, pela observação mais próxima
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    sorted[((sorted.len() - 1) as f64 * q).round() as usize]
+}
+
+// Vetor autorregressivo estimado por mínimos quadrados, equação por equação:
+// y_t = c + A_1·y_{t−1} + ... + A_p·y_{t−p} + u_t
+#[derive(Debug, Clone)]
+pub struct Var {
+    // Séries na ordem da identificação dos choques: cada uma só reage no mesmo período aos
+    // choques das anteriores
+    pub names: Vec<String>,
+    // Datas comuns a todas as séries, em ordem cronológica
+    pub dates: Vec<String>,
+    pub lags: usize,
+    pub intercept: Vec<f64>,
+    // coefficients[l][i][j]: efeito da série j, l + 1 períodos atrás, sobre a série i
+    pub coefficients: Vec<Matrix>,
+    // Covariância dos resíduos, com correção de graus de liberdade
+    pub sigma: Matrix,
+    // Observações (data a data) e resíduos, guardados para o bootstrap
+    data: Matrix,
+    residuals: Matrix,
+}
+
+// Intervalos de 95% das respostas ao impulso, nas mesmas posições de `Var::impulse_responses`
+#[derive(Debug, Clone)]
+pub struct ResponseBands {
+    pub lower: Vec<Matrix>,
+    pub upper: Vec<Matrix>,
+    // Reamostragens que produziram um VAR estimável
+    pub draws: usize,
+}
+
+// Decomposição da variância do erro de previsão a partir das respostas ortogonalizadas
+// (`Var::impulse_responses`): shares[h − 1][i][j] é a fração da variância do erro de previsão da
+// série i, h passos à frente, devida aos choques da série j. Cada linha soma 1.
+pub fn variance_decomposition(responses: &[Matrix]) -> Vec<Matrix> {
+    let Some(first) = responses.first() else {
+        return vec![];
+    };
+    let n = first.len();
+    let mut accumulated = vec![vec![0.0; n]; n];
+    responses
+        .iter()
+        .map(|matrix| {
+            for (total, row) in accumulated.iter_mut().zip(matrix) {
+                for (x, response) in total.iter_mut().zip(row) {
+                    *x += response * response;
+                }
+            }
+            accumulated
+                .iter()
+                .map(|row| {
+                    let variance: f64 = row.iter().sum();
+                    row.iter().map(|x| if variance > 0.0 { x / variance } else { 0.0 }).collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Ajusta o VAR às séries (nome e pares data/valor), usando só as datas presentes em todas. A
+// ordem das séries é a da identificação dos choques.
+pub fn fit(series: &[(String, Vec<(String, f64)>)], lags: usize) -> Result<Var, String> {
+    if series.len() < 2 {
+        return Err("Selecione ao menos duas séries".to_string());
+    }
+    if !(1..=MAX_LAGS).contains(&lags) {
+        return Err(format!("O número de defasagens deve estar entre 1 e {}", MAX_LAGS));
+    }
+
+    let names: Vec<String> = series.iter().map(|(name, _)| name.clone()).collect();
+    let lookups: Vec<HashMap<&str, f64>> = series
+        .iter()
+        .map(|(_, values)| values.iter().map(|(date, value)| (date.as_str(), *value)).collect())
+        .collect();
+    let mut dates: Vec<String> = series[0]
+        .1
+        .iter()
+        .map(|(date, _)| date.clone())
+        .filter(|date| lookups.iter().all(|lookup| lookup.contains_key(date.as_str())))
+        .collect();
+    dates.sort_by_key(|date| date_sort_key(date));
+    dates.dedup();
+
+    let data = dates
+        .iter()
+        .map(|date| lookups.iter().map(|lookup| lookup[date.as_str()]).collect())
+        .collect();
+    fit_data(names, dates, data, lags)
+}
+
+fn fit_data(names: Vec<String>, dates: Vec<String>, data: Matrix, lags: usize) -> Result<Var, String> {
+    let n = names.len();
+    // Constante e n valores por defasagem
+    let k = 1 + n * lags;
+    let observations = data.len().saturating_sub(lags);
+    if observations <= k {
+        return Err(format!(
+            "Poucas datas em comum ({}) para {} séries com {} defasagem(ns)",
+            data.len(),
+            n,
+            lags
+        ));
+    }
+
+    let regressors: Matrix = (lags..data.len())
+        .map(|t| {
+            let mut row = vec![1.0];
+            for l in 1..=lags {
+                row.extend(&data[t - l]);
+            }
+            row
+        })
+        .collect();
+    let targets = &data[lags..];
+
+    // Equações normais: (XᵀX)·B = XᵀY, com uma coluna de B por série
+    let xtx: Matrix = (0..k)
+        .map(|a| (0..k).map(|b| regressors.iter().map(|row| row[a] * row[b]).sum::<f64>()).collect())
+        .collect();
+    let xty: Matrix = (0..k)
+        .map(|a| {
+            (0..n)
+                .map(|i| regressors.iter().zip(targets).map(|(row, y)| row[a] * y[i]).sum::<f64>())
+                .collect()
+        })
+        .collect();
+    let b = solve(xtx, xty).ok_or("As séries são colineares; o VAR não pode ser estimado")?;
+
+    let intercept = b[0].clone();
+    let coefficients: Vec<Matrix> = (0..lags)
+        .map(|l| (0..n).map(|i| (0..n).map(|j| b[1 + l * n + j][i]).collect()).collect())
+        .collect();
+
+    let fitted = multiply(&regressors, &b);
+    let residuals: Matrix = targets
+        .iter()
+        .zip(&fitted)
+        .map(|(y, fit)| y.iter().zip(fit).map(|(y, fit)| y - fit).collect())
+        .collect();
+    let degrees = (observations - k) as f64;
+    let sigma = (0..n)
+        .map(|i| (0..n).map(|j| residuals.iter().map(|u| u[i] * u[j]).sum::<f64>() / degrees).collect())
+        .collect();
+
+    Ok(Var {
+        names,
+        dates,
+        lags,
+        intercept,
+        coefficients,
+        sigma,
+        data,
+        residuals,
+    })
+}
+
+impl Var {
+    // Matrizes da representação de médias móveis: Φ_0 = I e Φ_h = Σ Φ_{h−l}·A_l
+    fn moving_average(&self, horizon: usize) -> Vec<Matrix> {
+        let n = self.names.len();
+        let mut phi = vec![identity(n)];
+        for h in 1..=horizon {
+            let mut next = vec![vec![0.0; n]; n];
+            for (l, a) in self.coefficients.iter().enumerate().take(h) {
+                let term = multiply(&phi[h - 1 - l], a);
+                for (row, term_row) in next.iter_mut().zip(term) {
+                    for (x, t) in row.iter_mut().zip(term_row) {
+                        *x += t;
+                    }
+                }
+            }
+            phi.push(next);
+        }
+        phi
+    }
+
+    // Respostas a choques ortogonalizados de um desvio padrão (Cholesky, na ordem das séries):
+    // responses[h][i][j] é a resposta da série i, h períodos depois, a um choque na série j
+    pub fn impulse_responses(&self, horizon: usize) -> Result<Vec<Matrix>, String> {
+        let p = cholesky(&self.sigma).ok_or("A covariância dos resíduos não é positiva definida")?;
+        Ok(self.moving_average(horizon).iter().map(|phi| multiply(phi, &p)).collect())
+    }
+
+    // Bootstrap dos resíduos: recria a série a partir das primeiras observações com resíduos
+    // sorteados, reestima o VAR e guarda os percentis de 2,5% e 97,5% de cada resposta
+    pub fn bootstrap(&self, horizon: usize, draws: usize, rng: &mut SeededRng) -> Result<ResponseBands, String> {
+        let n = self.names.len();
+        let mut samples: Vec<Vec<f64>> = vec![vec![]; (horizon + 1) * n * n];
+
+        for _ in 0..draws {
+            let mut data = self.data[..self.lags].to_vec();
+            for _ in self.lags..self.data.len() {
+                let shock = &self.residuals[rng.index(self.residuals.len())];
+                let t = data.len();
+                let next = (0..n)
+                    .map(|i| {
+                        let dynamics: f64 = self
+                            .coefficients
+                            .iter()
+                            .enumerate()
+                            .map(|(l, a)| a[i].iter().zip(&data[t - 1 - l]).map(|(x, y)| x * y).sum::<f64>())
+                            .sum();
+                        self.intercept[i] + dynamics + shock[i]
+                    })
+                    .collect();
+                data.push(next);
+            }
+
+            let responses = fit_data(self.names.clone(), self.dates.clone(), data, self.lags)
+                .and_then(|var| var.impulse_responses(horizon));
+            // Reamostragens degeneradas são descartadas
+            let Ok(responses) = responses else {
+                continue;
+            };
+            for (h, matrix) in responses.iter().enumerate() {
+                for (i, row) in matrix.iter().enumerate() {
+                    for (j, value) in row.iter().enumerate() {
+                        samples[(h * n + i) * n + j].push(*value);
+                    }
+                }
+            }
+        }
+
+        let succeeded = samples[0].len();
+        if succeeded < draws.div_ceil(2).max(2) {
+            return Err("Reamostragens insuficientes para os intervalos das respostas".to_string());
+        }
+        for sample in samples.iter_mut() {
+            sample.sort_by(f64::total_cmp);
+        }
+        let band = |q: f64| -> Vec<Matrix> {
+            (0..=horizon)
+                .map(|h| {
+                    (0..n)
+                        .map(|i| (0..n).map(|j| quantile(&samples[(h * n + i) * n + j], q)).collect())
+                        .collect()
+                })
+                .collect()
+        };
+
+        Ok(ResponseBands {
+            lower: band(0.025),
+            upper: band(0.975),
+            draws: succeeded,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // VAR(1) com a primeira série influenciando a segunda
+    fn simulated() -> Vec<(String, Vec<(String, f64)>)> {
+        let mut rng = SeededRng::new(7);
+        let (mut x, mut y) = (0.0, 0.0);
+        let mut first = vec![];
+        let mut second = vec![];
+        for t in 0..400 {
+            let date = format!("01/{:02}/{}", t % 12 + 1, 1900 + t / 12);
+            (x, y) = (1.0 + 0.5 * x + rng.normal() * 0.1, 0.4 * x + 0.3 * y + rng.normal() * 0.1);
+            first.push((date.clone(), x));
+            second.push((date, y));
+        }
+        vec![("x".to_string(), first), ("y".to_string(), second)]
+    }
+
+    #[test]
+    fn recovers_the_coefficients_of_a_simulated_var() {
+        let var = fit(&simulated(), 1).unwrap();
+
+        let a = &var.coefficients[0];
+        for (estimate, truth) in [(a[0][0], 0.5), (a[0][1], 0.0), (a[1][0], 0.4), (a[1][1], 0.3)] {
+            assert!((estimate - truth).abs() < 0.1, "{} vs {}", estimate, truth);
+        }
+        assert!((var.intercept[0] - 1.0).abs() < 0.2);
+        assert!((var.sigma[0][0] - 0.01).abs() < 0.003);
+
+        assert!(fit(&simulated()[..1], 1).is_err());
+        assert!(fit(&simulated(), 0).is_err());
+    }
+
+    #[test]
+    fn responses_follow_the_cholesky_ordering() {
+        let var = fit(&simulated(), 1).unwrap();
+        let responses = var.impulse_responses(2).unwrap();
+        let p = cholesky(&var.sigma).unwrap();
+
+        // No impacto, a primeira série não reage ao choque da segunda
+        assert_eq!(responses[0], p);
+        assert_eq!(responses[0][0][1], 0.0);
+        // Em um VAR(1), a resposta em h é A^h·P
+        let expected = multiply(&var.coefficients[0], &p);
+        for (row, expected_row) in responses[1].iter().zip(&expected) {
+            for (value, expected) in row.iter().zip(expected_row) {
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+        // O choque na primeira série chega à segunda um período depois
+        assert!(responses[1][1][0] > 0.0);
+    }
+
+    #[test]
+    fn variance_shares_add_up_and_grow_with_the_spillover() {
+        let var = fit(&simulated(), 1).unwrap();
+        let shares = variance_decomposition(&var.impulse_responses(8).unwrap());
+
+        // Do primeiro ao nono passo à frente
+        assert_eq!(shares.len(), 9);
+        for matrix in &shares {
+            for row in matrix {
+                assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            }
+        }
+        // Um passo à frente, a primeira série só responde aos próprios choques
+        assert_eq!(shares[0][0][1], 0.0);
+        // O choque na primeira série ganha peso na variância da segunda com o horizonte
+        assert!(shares[8][1][0] > shares[0][1][0]);
+        assert!(variance_decomposition(&[]).is_empty());
+    }
+
+    #[test]
+    fn bootstrap_bands_surround_the_estimates() {
+        let var = fit(&simulated(), 1).unwrap();
+        let responses = var.impulse_responses(4).unwrap();
+        let bands = var.bootstrap(4, 50, &mut SeededRng::new(3)).unwrap();
+
+        assert_eq!(bands.draws, 50);
+        assert_eq!(bands.lower.len(), 5);
+        for ((lower, upper), response) in bands.lower.iter().zip(&bands.upper).zip(&responses) {
+            assert!(lower[0][0] <= response[0][0] && response[0][0] <= upper[0][0]);
+            assert!(lower[1][0] <= upper[1][0]);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::cache::CachedObservation;
+use crate::revisions::Revision;
+
+// Converte "AAAA-MM-DD" no último segundo (UTC) desse dia, em segundos Unix
+pub fn parse_date(date: &str) -> Option<u64> {
+    let mut parts = date.trim().splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Dias desde 1970-01-01 (algoritmo days_from_civil de Howard Hinnant)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    u64::try_from((days + 1) * 86400 - 1).ok()
+}
+
+// Reconstrói a série como era conhecida no instante `as_of` (segundos Unix):
+// descarta observações recebidas depois dele e desfaz as revisões posteriores.
+// Só cobre o que foi observado localmente: antes da primeira busca não há vintage.
+pub fn as_of(observations: &[CachedObservation], history: &[Revision], as_of: u64) -> Vec<CachedObservation> {
+    observations
+        .iter()
+        .filter(|obs| obs.first_seen <= as_of)
+        .map(|obs| {
+            // A primeira revisão após `as_of` guarda o valor que vigorava naquele momento
+            let value = history
+                .iter()
+                .filter(|revision| revision.date == obs.date && revision.detected_at > as_of)
+                .min_by_key(|revision| revision.detected_at)
+                .map(|revision| revision.old_value)
+                .unwrap_or(obs.value);
+
+            CachedObservation {
+                value,
+                ..obs.clone()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(date: &str, value: f64, first_seen: u64) -> CachedObservation {
+        CachedObservation {
+            date: date.to_string(),
+            value,
+            first_seen,
+        }
+    }
+
+    #[test]
+    fn parses_end_of_day() {
+        assert_eq!(parse_date("1970-01-01"), Some(86399));
+        assert_eq!(parse_date("2024-03-01"), Some(1709337599));
+        assert_eq!(parse_date("2024-13-01"), None);
+    }
+
+    #[test]
+    fn undoes_later_revisions_and_drops_later_observations() {
+        let observations = vec![observation("01/01/2024", 0.5, 100), observation("01/02/2024", 0.8, 300)];
+        let history = vec![Revision {
+            country: "brazil".to_string(),
+            date: "01/01/2024".to_string(),
+            old_value: 0.4,
+            new_value: 0.5,
+            detected_at: 250,
+        }];
+
+        let series = as_of(&observations, &history, 200);
+
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].value, 0.4);
+    }
+}
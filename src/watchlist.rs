@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::data::Indicator;
+
+// Par (país, indicador) acompanhado no painel inicial
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchItem {
+    pub country: String,
+    pub indicator: Indicator,
+}
+
+impl WatchItem {
+    pub fn name(&self) -> String {
+        format!("{}_{}", self.indicator.key(), self.country)
+    }
+}
+
+pub fn watchlist_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("rustfin").join("watchlist.json"))
+}
+
+// Sem lista salva, acompanha a inflação do país padrão
+pub fn load(default_country: &str) -> Vec<WatchItem> {
+    watchlist_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_else(|| {
+            vec![WatchItem {
+                country: default_country.to_string(),
+                indicator: Indicator::Inflation,
+            }]
+        })
+}
+
+pub fn save(items: &[WatchItem]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = watchlist_path().ok_or("Diretório de configuração não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(items)?)?;
+
+    Ok(())
+}
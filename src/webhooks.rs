@@ -0,0 +1,60 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::client::ApiClient;
+
+// Evento enviado por POST em JSON às URLs de `Config::webhooks`; o campo "event" identifica o tipo
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    // A série ganhou observações desde a última execução (nova `last_date`)
+    NewData {
+        // indicador_país, ex.: inflation_brazil
+        series: String,
+        indicator: String,
+        country: String,
+        last_date: String,
+        previous_last_date: String,
+        // Modelo e previsão atualizados, quando o ajuste deu certo
+        model: Option<String>,
+        forecast: Vec<f64>,
+    },
+}
+
+// Última data vista de cada série, para notificar só quando chegam dados novos
+pub fn last_seen_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("rustfin").join("last_seen.json"))
+}
+
+pub fn load_last_seen() -> BTreeMap<String, String> {
+    last_seen_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_last_seen(seen: &BTreeMap<String, String>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = last_seen_path().ok_or("Diretório de dados não encontrado")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    std::fs::write(path, serde_json::to_string_pretty(seen)?)?;
+
+    Ok(())
+}
+
+// Envia cada evento a cada URL, sem novas tentativas; retorna as falhas, que não interrompem os
+// demais envios
+pub async fn notify(client: &ApiClient, urls: &[String], events: &[Event]) -> Vec<String> {
+    let mut failures = Vec::new();
+    for event in events {
+        for url in urls {
+            if let Err(e) = client.post_json(url, event).await {
+                failures.push(format!("Erro ao enviar o webhook para {}: {}", url, e));
+            }
+        }
+    }
+    failures
+}
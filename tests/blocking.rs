@@ -0,0 +1,55 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rustfin::blocking;
+use rustfin::data::Indicator;
+
+// O servidor simulado roda em um runtime próprio; as buscas síncronas são feitas fora dele
+fn inflation_server(runtime: &tokio::runtime::Runtime) -> MockServer {
+    runtime.block_on(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/inflation"))
+            .and(query_param("country", "brazil"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(common::fixture("inflation_brazil.json"), "application/json"),
+            )
+            .mount(&server)
+            .await;
+        server
+    })
+}
+
+#[test]
+fn fetches_series_without_a_runtime() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = inflation_server(&runtime);
+    let config = common::test_config(&server.uri());
+
+    let series = blocking::fetch_series(&config, Indicator::Inflation, "brazil").unwrap();
+    let raw = blocking::get_historical_inflation(&config, "brazil").unwrap();
+
+    assert_eq!(series.len(), 24);
+    assert_eq!(series.first().unwrap().date, "01/01/2022");
+    assert_eq!(raw.inflation[0].date, "01/12/2023");
+}
+
+#[test]
+fn provider_errors_are_returned() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+        server
+    });
+    let config = common::test_config(&server.uri());
+
+    assert!(blocking::fetch_series(&config, Indicator::Inflation, "brazil").is_err());
+}
@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use rustfin::config::Config;
+
+pub fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures")
+}
+
+pub fn fixture(name: &str) -> String {
+    std::fs::read_to_string(fixtures_dir().join(name)).expect("fixture não encontrada")
+}
+
+// Configuração apontando para o servidor simulado, sem controle de cota
+// (para que os testes não gravem o uso no diretório de dados do usuário)
+pub fn test_config(url_base: &str) -> Config {
+    Config {
+        api_token: "test-token".to_string(),
+        url_base: format!("{}/", url_base),
+        daily_request_limit: 0,
+        ..Config::default()
+    }
+}
+
+// Compara com o arquivo em tests/golden; com UPDATE_GOLDEN=1 o arquivo é regravado
+pub fn assert_golden(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden").join(name);
+
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        std::fs::write(&path, actual).expect("erro ao gravar o arquivo golden");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).expect("arquivo golden não encontrado");
+    assert_eq!(actual, expected, "saída difere de {}", path.display());
+}
@@ -0,0 +1,416 @@
+mod common;
+
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rustfin::client::{ApiClient, Conditional, PayloadTooLarge};
+use rustfin::config::Provider;
+use rustfin::dates::DateFormat;
+use rustfin::data::{get_historical_inflation, InflationData, get_indicator, get_indicator_since, get_indicator_with_source, Indicator};
+use rustfin::expectations::{self, get_expectations};
+use rustfin::fx::get_latest_quotes;
+use rustfin::health::check_provider;
+use rustfin::intraday::{self, Market};
+use rustfin::market::{get_intraday_prices, get_price_history};
+use rustfin::prefetch;
+
+async fn inflation_server() -> MockServer {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/inflation"))
+        .and(query_param("country", "brazil"))
+        .and(query_param("token", "test-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(common::fixture("inflation_brazil.json"), "application/json")
+                .insert_header("x-ratelimit-remaining", "42"),
+        )
+        .mount(&server)
+        .await;
+
+    server
+}
+
+#[tokio::test]
+async fn fetches_inflation_with_token_and_country() {
+    let server = inflation_server().await;
+    let config = common::test_config(&server.uri());
+
+    let raw = get_historical_inflation(&ApiClient::new(), &config, "brazil").await.unwrap();
+
+    assert_eq!(raw.inflation.len(), 24);
+    assert_eq!(raw.inflation[0].date, "01/12/2023");
+    assert_eq!(raw.inflation[0].value, 4.62);
+}
+
+#[tokio::test]
+async fn indicator_series_is_chronological() {
+    let server = inflation_server().await;
+    let config = common::test_config(&server.uri());
+
+    let series = get_indicator(&ApiClient::new(), &config, Indicator::Inflation, "brazil").await.unwrap();
+
+    assert_eq!(series.first().unwrap().date, "01/01/2022");
+    assert_eq!(series.last().unwrap().date, "01/12/2023");
+}
+
+#[tokio::test]
+async fn provider_dates_are_normalized() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/inflation"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"inflation":[{"date":"2023Q4","value":"4.62"},{"date":"2023-07-01","value":"3.99"},{"date":1672531200000,"value":"5.77"}]}"#,
+            "application/json",
+        ))
+        .mount(&server)
+        .await;
+    let mut config = common::test_config(&server.uri());
+
+    let series = get_indicator(&ApiClient::new(), &config, Indicator::Inflation, "brazil").await.unwrap();
+    let dates: Vec<&str> = series.iter().map(|item| item.date.as_str()).collect();
+    assert_eq!(dates, vec!["01/01/2023", "01/07/2023", "01/10/2023"]);
+
+    // Com a dica do formato, datas em outro formato são um erro
+    config.date_format = DateFormat::Iso;
+    assert!(get_indicator(&ApiClient::new(), &config, Indicator::Inflation, "brazil").await.is_err());
+}
+
+#[tokio::test]
+async fn prefetched_indicators_are_served_without_a_new_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/unemployment"))
+        .and(query_param("country", "brazil"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"unemployment":[{"date":"01/02/2024","value":"7.8"},{"date":"01/01/2024","value":"7.6"}]}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+
+    prefetch::prefetch_country(&ApiClient::new(), &config, "brazil", Indicator::Inflation).await;
+    let series = prefetch::get_indicator(&ApiClient::new(), &config, Indicator::Unemployment, "brazil")
+        .await
+        .unwrap();
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].date, "01/01/2024");
+}
+
+#[tokio::test]
+async fn provider_error_status_is_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+
+    let result = get_historical_inflation(&ApiClient::new(), &config, "brazil").await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn failover_uses_the_next_provider_in_the_chain() {
+    let broken = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&broken)
+        .await;
+    let backup = inflation_server().await;
+
+    let mut config = common::test_config(&broken.uri());
+    let provider = |name: &str, uri: &str| Provider {
+        name: name.to_string(),
+        url_base: format!("{}/", uri),
+        api_token: String::new(),
+        since_param: None,
+        date_format: DateFormat::Auto,
+    };
+    config.providers.insert(
+        "inflation".to_string(),
+        vec![provider("principal", &broken.uri()), provider("reserva", &backup.uri())],
+    );
+
+    let (series, source) = get_indicator_with_source(&ApiClient::new(), &config, Indicator::Inflation, "brazil")
+        .await
+        .unwrap();
+
+    assert_eq!(series.len(), 24);
+    assert_eq!(source.provider, "reserva");
+    assert!(source.is_fallback());
+    assert!(source.failures[0].starts_with("principal: "));
+
+    // Sem reserva para o desemprego, a falha do provedor principal é o erro
+    assert!(get_indicator(&ApiClient::new(), &config, Indicator::Unemployment, "brazil").await.is_err());
+}
+
+#[tokio::test]
+async fn delta_sync_sends_the_start_date_when_supported() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/inflation"))
+        .and(query_param("start", "01/11/2023"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"inflation":[{"date":"01/12/2023","value":"4.62"},{"date":"01/11/2023","value":"4.68"}]}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let mut config = common::test_config(&server.uri());
+    config.since_param = Some("start".to_string());
+
+    let (series, _) = get_indicator_since(&ApiClient::new(), &config, Indicator::Inflation, "brazil", Some("01/11/2023"))
+        .await
+        .unwrap();
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].date, "01/11/2023");
+}
+
+#[tokio::test]
+async fn delta_sync_sends_the_start_date_in_the_provider_format() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/inflation"))
+        .and(query_param("start", "2023-11-01"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"inflation":[{"date":"2023-12-01","value":"4.62"},{"date":"2023-11-01","value":"4.68"}]}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let mut config = common::test_config(&server.uri());
+    config.since_param = Some("start".to_string());
+    config.date_format = DateFormat::Iso;
+
+    let (series, _) = get_indicator_since(&ApiClient::new(), &config, Indicator::Inflation, "brazil", Some("01/11/2023"))
+        .await
+        .unwrap();
+
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].date, "01/11/2023");
+}
+
+#[tokio::test]
+async fn oversized_response_is_rejected() {
+    let server = inflation_server().await;
+    let mut config = common::test_config(&server.uri());
+    config.max_response_bytes = 100;
+
+    let error = get_historical_inflation(&ApiClient::new(), &config, "brazil").await.unwrap_err();
+
+    assert_eq!(error.downcast_ref::<PayloadTooLarge>(), Some(&PayloadTooLarge { limit: 100 }));
+}
+
+#[tokio::test]
+async fn conditional_request_reuses_validators() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(header("if-none-match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(common::fixture("inflation_brazil.json"), "application/json")
+                .insert_header("etag", "\"v1\""),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+    let client = ApiClient::new();
+
+    let first = client
+        .get_series_if_modified::<InflationData>(&config, "inflation", &[], "inflation", None)
+        .await
+        .unwrap();
+    let Conditional::Modified { body, validators } = first else {
+        panic!("a primeira busca deveria trazer a série");
+    };
+    assert_eq!(body.len(), 24);
+    assert_eq!(validators.etag.as_deref(), Some("\"v1\""));
+
+    let second = client
+        .get_series_if_modified::<InflationData>(&config, "inflation", &[], "inflation", Some(&validators))
+        .await
+        .unwrap();
+    assert!(matches!(second, Conditional::NotModified));
+}
+
+#[tokio::test]
+async fn requests_go_through_the_configured_proxy() {
+    // O servidor simulado faz o papel do proxy para um provedor inexistente
+    let proxy = inflation_server().await;
+    let mut config = common::test_config("http://provedor.invalid");
+    config.proxy = Some(proxy.uri());
+
+    let client = ApiClient::from_config(&config).unwrap();
+    let raw = get_historical_inflation(&client, &config, "brazil").await.unwrap();
+
+    assert_eq!(raw.inflation.len(), 24);
+}
+
+#[test]
+fn unreadable_certificate_is_a_configuration_error() {
+    let mut config = common::test_config("http://localhost");
+    config.ca_certificates = vec!["/nao/existe/ca.pem".to_string()];
+
+    let error = ApiClient::from_config(&config).unwrap_err();
+
+    assert!(error.contains("/nao/existe/ca.pem"));
+}
+
+// Único teste deste arquivo a usar a instância compartilhada, que só pode ser configurada uma vez
+#[tokio::test]
+async fn invalid_network_configuration_blocks_the_shared_client() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).respond_with(ResponseTemplate::new(200)).expect(0).mount(&server).await;
+    let mut config = common::test_config(&server.uri());
+    config.proxy = Some("http://[proxy".to_string());
+
+    let error = ApiClient::configure(&config).unwrap_err();
+    assert!(error.contains("Proxy inválido"));
+
+    let error = get_historical_inflation(ApiClient::shared(), &config, "brazil").await.unwrap_err();
+    assert!(error.to_string().contains("Proxy inválido"));
+}
+
+#[tokio::test]
+async fn non_numeric_value_fails_to_deserialize() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"inflation":[{"date":"01/01/2024","value":"n/d"}]}"#,
+            "application/json",
+        ))
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+
+    let result = get_historical_inflation(&ApiClient::new(), &config, "brazil").await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn health_check_reads_rate_limit_header() {
+    let server = inflation_server().await;
+    let config = common::test_config(&server.uri());
+
+    let (_, quota) = check_provider(&ApiClient::new(), &config).await.unwrap();
+
+    assert_eq!(quota, Some(42));
+}
+
+#[tokio::test]
+async fn fetches_latest_fx_quotes_for_all_pairs() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/currency"))
+        .and(query_param("currency", "USD-BRL,EUR-BRL"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"currency":[
+                {"fromCurrency":"USD","toCurrency":"BRL","bidPrice":"4.9512","percentageChange":"-0.35","updatedAtDate":"2024-01-05 17:59:59"},
+                {"fromCurrency":"EUR","toCurrency":"BRL","bidPrice":"5.4120","percentageChange":"0.12","updatedAtDate":"2024-01-05 17:59:59"}
+            ]}"#,
+            "application/json",
+        ))
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+    let pairs = vec!["USD-BRL".to_string(), "EUR-BRL".to_string()];
+
+    let quotes = get_latest_quotes(&ApiClient::new(), &config, &pairs).await.unwrap();
+
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes[0].pair(), "USD-BRL");
+    assert_eq!(quotes[0].bid_price, 4.9512);
+    assert_eq!(quotes[1].percentage_change, 0.12);
+}
+
+#[tokio::test]
+async fn price_history_is_chronological_and_skips_incomplete_sessions() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/quote/PETR4"))
+        .and(query_param("range", "1y"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"results":[{"symbol":"PETR4","historicalDataPrice":[
+                {"date":1704245400,"open":37.2,"high":37.9,"low":36.8,"close":37.5,"volume":41000000},
+                {"date":1704159000,"open":36.9,"high":37.4,"low":36.5,"close":37.2,"volume":38000000},
+                {"date":1704331800,"open":null,"high":null,"low":null,"close":null,"volume":null}
+            ]}]}"#,
+            "application/json",
+        ))
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+
+    let candles = get_price_history(&ApiClient::new(), &config, "petr4").await.unwrap();
+
+    assert_eq!(candles.len(), 2);
+    assert_eq!(candles[0].date, "02/01/2024");
+    assert_eq!(candles[1].close, 37.5);
+}
+
+#[tokio::test]
+async fn intraday_prices_are_grouped_by_local_session() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/quote/PETR4"))
+        .and(query_param("interval", "5m"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            // 13:00 e 19:55 UTC de 02/01/2024 (10:00 e 16:55 em São Paulo); 02:00 UTC de 03/01 ainda é
+            // 23:00 de 02/01 no horário local, fora do pregão
+            r#"{"results":[{"symbol":"PETR4","historicalDataPrice":[
+                {"date":1704225300,"open":37.4,"high":37.6,"low":37.3,"close":37.5,"volume":900},
+                {"date":1704200400,"open":37.0,"high":37.2,"low":36.9,"close":37.1,"volume":1000},
+                {"date":1704247200,"open":38.0,"high":38.0,"low":38.0,"close":38.0,"volume":5}
+            ]}]}"#,
+            "application/json",
+        ))
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+
+    let bars = get_intraday_prices(&ApiClient::new(), &config, "petr4", "5m").await.unwrap();
+    let market = Market::for_ticker("PETR4");
+
+    assert_eq!(bars.len(), 3);
+    assert_eq!(market.display(bars[0].time), "02/01/2024 10:00");
+    let daily = intraday::daily(&market, &bars);
+    assert_eq!(daily.len(), 1);
+    assert_eq!(daily[0].date, "02/01/2024");
+    assert_eq!((daily[0].open, daily[0].close, daily[0].volume), (37.0, 37.5, 1900.0));
+}
+
+#[tokio::test]
+async fn expectations_are_chronological_and_aligned_with_the_forecast() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/inflation/expectations"))
+        .and(query_param("country", "brazil"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"expectations":[{"date":"2025-02-01","value":"4.20"},{"date":"01/12/2024","value":"4.50"}]}"#,
+            "application/json",
+        ))
+        .mount(&server)
+        .await;
+    let config = common::test_config(&server.uri());
+
+    let expected = get_expectations(&ApiClient::new(), &config, "brazil").await.unwrap();
+    let dates: Vec<&str> = expected.iter().map(|item| item.date.as_str()).collect();
+    assert_eq!(dates, vec!["01/12/2024", "01/02/2025"]);
+    assert_eq!(expectations::align("01/11/2024", &expected, 3), vec![Some(4.5), None, Some(4.2)]);
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use rustfin::data::{date_sort_key, InflationRaw};
+use rustfin::derived::{self, DerivedSeries};
+use rustfin::export;
+
+fn brazil_series() -> Vec<(String, f64)> {
+    let raw: InflationRaw = serde_json::from_str(&common::fixture("inflation_brazil.json")).unwrap();
+    let mut series: Vec<(String, f64)> = raw.inflation.into_iter().map(|obs| (obs.date, obs.value)).collect();
+    series.sort_by_key(|(date, _)| date_sort_key(date));
+    series
+}
+
+fn to_csv(series: &[(String, f64)]) -> String {
+    let dates: Vec<String> = series.iter().map(|(date, _)| date.clone()).collect();
+    let values: Vec<f64> = series.iter().map(|(_, value)| *value).collect();
+    export::series_csv(&dates, &values, None)
+}
+
+#[test]
+fn deserializes_provider_response() {
+    common::assert_golden("inflation_brazil.csv", &to_csv(&brazil_series()));
+}
+
+#[test]
+fn evaluates_derived_spread() {
+    let usa = [3.35, 3.24, 3.70, 3.67, 3.18, 3.09, 3.00, 4.05, 4.93, 4.98, 5.00, 6.41];
+    let usa: Vec<(String, f64)> = usa
+        .iter()
+        .enumerate()
+        .map(|(i, value)| (format!("01/{:02}/2023", 12 - i), *value))
+        .collect();
+
+    let mut series = BTreeMap::new();
+    series.insert("inflation_brazil".to_string(), brazil_series());
+    series.insert("inflation_usa".to_string(), usa);
+
+    let definition = DerivedSeries {
+        name: "spread".to_string(),
+        expression: "inflation_usa - inflation_brazil".to_string(),
+    };
+    let spread = derived::evaluate(&definition, &series).unwrap();
+
+    common::assert_golden("spread_usa_brazil.csv", &to_csv(&spread));
+}
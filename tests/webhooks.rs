@@ -0,0 +1,65 @@
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use rustfin::client::ApiClient;
+use rustfin::webhooks::{notify, Event};
+
+fn event() -> Event {
+    Event::NewData {
+        series: "inflation_brazil".to_string(),
+        indicator: "inflation".to_string(),
+        country: "brazil".to_string(),
+        last_date: "01/03/2024".to_string(),
+        previous_last_date: "01/02/2024".to_string(),
+        model: Some("Theta".to_string()),
+        forecast: vec![4.5, 4.4],
+    }
+}
+
+#[tokio::test]
+async fn posts_new_data_event_as_json() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(body_json(json!({
+            "event": "new_data",
+            "series": "inflation_brazil",
+            "indicator": "inflation",
+            "country": "brazil",
+            "last_date": "01/03/2024",
+            "previous_last_date": "01/02/2024",
+            "model": "Theta",
+            "forecast": [4.5, 4.4],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let failures = notify(&ApiClient::new(), &[format!("{}/hook", server.uri())], &[event()]).await;
+
+    assert!(failures.is_empty(), "{:?}", failures);
+}
+
+#[tokio::test]
+async fn failed_delivery_does_not_stop_other_urls() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/quebrado"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let urls = [format!("{}/quebrado", server.uri()), format!("{}/hook", server.uri())];
+    let failures = notify(&ApiClient::new(), &urls, &[event()]).await;
+
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].contains("/quebrado"));
+}